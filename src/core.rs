@@ -10,19 +10,42 @@
 
 //! Traits and enumerations necessary for the toolkit.
 
+mod border;
 mod color;
 pub mod enums;
+mod fill;
+mod font;
+mod input;
+mod nine_slice;
+mod painter;
 pub mod prelude;
+mod shadow;
+mod svg;
+mod text_edit;
+mod texture;
 mod theme;
 pub mod traits;
 pub mod widgets;
 
-use self::prelude::*;
+use self::{
+    input::{
+        Hover,
+        InputState,
+    },
+    prelude::*,
+    widgets::{
+        window::MouseButton,
+        Dialog,
+        DialogBuilder,
+        Tooltip,
+    },
+};
 use indexmap::IndexMap;
 use parking_lot::Mutex;
 use std::{
     collections::HashMap,
     sync::Arc,
+    time::Instant,
 };
 use vulkano::instance::Instance;
 /// Identifier type for looking up widgets
@@ -55,14 +78,73 @@ impl Vertex {
 /// The position and color of the vertices to be drawn
 #[derive(Debug, Clone, Copy)]
 pub struct DrawVertex {
-    position: Vertex,
-    color:    [f32; 4],
+    position:      Vertex,
+    color:         [f32; 4],
+    bounds:        Option<(Vertex, Vertex)>,
+    corner_radius: CornerRadius,
+    border_width:  f32,
+    border_color:  [f32; 4],
+    offset:        (f32, f32),
+    blur_radius:   f32,
+    texture:       Option<usize>,
+    uv:            [f32; 2],
 }
 
 impl DrawVertex {
     /// Initialize a vertex
     pub fn new(position: Vertex, color: [f32; 4]) -> Self {
-        Self { position, color }
+        Self {
+            position,
+            color,
+            bounds: None,
+            corner_radius: CornerRadius::default(),
+            border_width: 0.0,
+            border_color: [0.0; 4],
+            offset: (0.0, 0.0),
+            blur_radius: 0.0,
+            texture: None,
+            uv: [0.0; 2],
+        }
+    }
+
+    /// Set the bounds of the shape this vertex belongs to, along with the radius of its corners
+    /// and the width and color of its border
+    pub fn with_shape(
+        self,
+        bounds: (Vertex, Vertex),
+        corner_radius: CornerRadius,
+        border_width: f32,
+        border_color: [f32; 4],
+    ) -> Self {
+        Self {
+            bounds: Some(bounds),
+            corner_radius,
+            border_width,
+            border_color,
+            ..self
+        }
+    }
+
+    /// Move the shape this vertex belongs to by an offset in pixels and fade its edges out over the
+    /// blur radius, the vertex is pushed outwards by the blur radius to make room for the faded
+    /// edge
+    pub fn with_blur(self, offset: (f32, f32), blur_radius: f32) -> Self {
+        Self {
+            offset,
+            blur_radius,
+            ..self
+        }
+    }
+
+    /// Sample the color of the vertex from a texture at the uv coordinates, from (0.0, 0.0) for the
+    /// top left to (1.0, 1.0) for the bottom right of the texture, tinted by the color of the
+    /// vertex
+    pub fn with_texture(self, texture: usize, uv: [f32; 2]) -> Self {
+        Self {
+            texture: Some(texture),
+            uv,
+            ..self
+        }
     }
 
     /// Retrieve the position of the vertex
@@ -74,16 +156,58 @@ impl DrawVertex {
     pub fn color(&self) -> [f32; 4] {
         self.color
     }
+
+    /// Retrieve the bottom left and top right vertices of the shape this vertex belongs to, if any
+    pub fn bounds(&self) -> Option<(Vertex, Vertex)> {
+        self.bounds
+    }
+
+    /// Retrieve the radius of the corners of the shape this vertex belongs to
+    pub fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    /// Retrieve the width of the border of the shape this vertex belongs to
+    pub fn border_width(&self) -> f32 {
+        self.border_width
+    }
+
+    /// Retrieve the color of the border of the shape this vertex belongs to
+    pub fn border_color(&self) -> [f32; 4] {
+        self.border_color
+    }
+
+    /// Retrieve the offset in pixels of the shape this vertex belongs to
+    pub fn offset(&self) -> (f32, f32) {
+        self.offset
+    }
+
+    /// Retrieve the blur radius in pixels of the shape this vertex belongs to
+    pub fn blur_radius(&self) -> f32 {
+        self.blur_radius
+    }
+
+    /// Retrieve the id of the texture the color of the vertex is sampled from, if any
+    pub fn texture(&self) -> Option<usize> {
+        self.texture
+    }
+
+    /// Retrieve the uv coordinates of the vertex in its texture
+    pub fn uv(&self) -> [f32; 2] {
+        self.uv
+    }
 }
 
 /// The main UI structure
 #[derive(Clone)]
 pub struct Ui {
-    app_id:    Id,
-    theme:     Theme,
-    instance:  Arc<Instance>,
-    heirarchy: HashMap<Id, Vec<Id>>,
-    widgets:   IndexMap<Id, Arc<Mutex<Box<WidgetTrait>>>>,
+    app_id:      Id,
+    theme:       Theme,
+    instance:    Arc<Instance>,
+    heirarchy:   HashMap<Id, Vec<Id>>,
+    widgets:     IndexMap<Id, Arc<Mutex<Box<WidgetTrait>>>>,
+    window_size: [u32; 2],
+    input:       Arc<Mutex<InputState>>,
 }
 
 impl Ui {
@@ -101,6 +225,8 @@ impl Ui {
             instance,
             heirarchy,
             widgets,
+            window_size: [0, 0],
+            input: Arc::new(Mutex::new(InputState::default())),
         }
     }
 
@@ -138,4 +264,670 @@ impl Ui {
     pub fn widgets_mut<'a>(&'a mut self) -> &'a mut IndexMap<Id, Arc<Mutex<Box<WidgetTrait>>>> {
         &mut self.widgets
     }
+
+    /// Retrieve the size of the window in pixels, or [0, 0] before it is known
+    pub fn window_size(&self) -> [u32; 2] {
+        self.window_size
+    }
+
+    /// Set the size of the window in pixels
+    pub fn set_window_size(&mut self, window_size: [u32; 2]) {
+        self.window_size = window_size;
+    }
+
+    /// Retrieve the id of the widget with the keyboard focus
+    pub fn focused(&self) -> Option<Id> {
+        self.input.lock().focused.clone()
+    }
+
+    /// Give the keyboard focus to a widget, or take it away from every widget
+    pub fn set_focus(&self, id: Option<Id>) {
+        self.input.lock().focused = id;
+    }
+
+    /// Retrieve the position of the cursor in pixels from the top left corner of the window
+    pub fn cursor_position(&self) -> (f32, f32) {
+        self.input.lock().cursor
+    }
+
+    /// Retrieve the text copied by the widgets, shared by every widget of the application
+    pub fn clipboard(&self) -> String {
+        self.input.lock().clipboard.clone()
+    }
+
+    /// Set the text pasted by the widgets
+    pub fn set_clipboard<V: Into<String>>(&self, text: V) {
+        self.input.lock().clipboard = text.into();
+    }
+
+    /// Retrieve the id of the menu opened by pressing the right button over a widget
+    pub fn context_menu(&self, id: &str) -> Option<Id> {
+        self.input.lock().menus.get(id).cloned()
+    }
+
+    /// Attach a menu to a widget, opened by pressing the right button over the widget or its
+    /// children when they don't handle it themselves, or detach the menu of the widget
+    pub fn set_context_menu<V: Into<Id>>(&self, id: V, menu: Option<Id>) {
+        let id = id.into();
+        let mut input = self.input.lock();
+        match menu {
+            None => input.menus.remove(&id),
+            Some(val) => input.menus.insert(id, val),
+        };
+    }
+
+    /// Retrieve the id of the tooltip shown after the cursor stays over a widget
+    pub fn tooltip(&self, id: &str) -> Option<Id> {
+        self.input.lock().tooltips.get(id).cloned()
+    }
+
+    /// Attach a tooltip to a widget, shown while the cursor stays over the widget or its children
+    /// unless they have a tooltip of their own, or detach the tooltip of the widget
+    pub fn set_tooltip<V: Into<Id>>(&self, id: V, tooltip: Option<Id>) {
+        let id = id.into();
+        let mut input = self.input.lock();
+        match tooltip {
+            None => input.tooltips.remove(&id),
+            Some(val) => input.tooltips.insert(id, val),
+        };
+    }
+
+    /// Retrieve the id of the tooltip shown, if any
+    pub fn shown_tooltip(&self) -> Option<Id> {
+        match self.input.lock().hover {
+            Some(ref hover) if hover.shown() => Some(hover.tooltip.clone()),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the id of the modal dialog open over the others, if any
+    pub fn modal(&self) -> Option<Id> {
+        let modals = self.input.lock().modals.clone();
+        modals.into_iter().rev().find(|id| match self.widgets.get(id) {
+            None => false,
+            Some(widget) => widget.lock().visible(),
+        })
+    }
+
+    /// Show a modal dialog in the middle of the first window container with a title, a text and
+    /// buttons, calling the callback with the label of the button chosen, or None when the dialog
+    /// is dismissed with escape. The dialogs of the message boxes closed are reused, and the id of
+    /// the dialog shown is returned.
+    pub fn message_box<V, F>(&mut self, title: V, text: V, buttons: &[&str], on_close: F) -> Result<Id, Error>
+    where
+        V: Into<String>,
+        F: Fn(Option<&str>) + Send + Sync + 'static,
+    {
+        let mut index = 0;
+        loop {
+            let id = format!("message_box_{}", index);
+            let widget = match self.widgets.get(&id) {
+                None => break,
+                Some(val) => val.clone(),
+            };
+            let mut widget = widget.lock();
+            if let Some(dialog) = widget.downcast_mut::<Dialog>() {
+                if !dialog.is_open() {
+                    dialog.set_title(title);
+                    dialog.set_text(text);
+                    dialog.set_buttons(buttons.iter().map(|button| String::from(*button)).collect());
+                    dialog.set_on_close(on_close);
+                    dialog.open(self);
+                    return Ok(id);
+                }
+            }
+            index += 1;
+        }
+
+        let parent_id = match self.widgets.values().find(|widget| widget.lock().parent_id().is_none()) {
+            None => {
+                return Err(err_msg("A message box needs a window container to be shown in"));
+            }
+            Some(widget) => widget.lock().id(),
+        };
+
+        let id = format!("message_box_{}", index);
+        DialogBuilder::new(id.clone(), parent_id, Position::Center)
+            .with_title(title)
+            .with_text(text)
+            .with_buttons(buttons.iter().cloned())
+            .with_on_close(on_close)
+            .build(self)?;
+
+        let widget = self.widgets[&id].clone();
+        if let Some(dialog) = widget.lock().downcast_mut::<Dialog>() {
+            dialog.open(self);
+        }
+
+        Ok(id)
+    }
+
+    /// Deliver an input event to the widgets, returning whether one of them handled it.
+    ///
+    /// Mouse events go to the top most widget under the cursor, or to the widget that handled the
+    /// press of the button until it is released, and pressing a button focuses the first focusable
+    /// widget under the cursor. Keyboard events go to the focused widget. Events a widget doesn't
+    /// handle bubble up to its parents. While a modal dialog is open, the events aimed at widgets
+    /// outside of it go to the dialog instead, and don't bubble up past it.
+    pub fn dispatch(&self, event: InputEvent) -> Result<bool, Error> {
+        let modal = self.modal();
+        let target = match event {
+            InputEvent::CursorMoved { x, y } => {
+                self.input.lock().cursor = (x, y);
+                let captured = self.input.lock().captured.clone();
+                match captured {
+                    None => {
+                        let hit = self.trap(&modal, self.hit_test_pixels(x, y)?)?;
+                        self.hover(hit.as_ref(), x, y)?;
+                        hit
+                    }
+                    Some(val) => Some(val),
+                }
+            }
+            InputEvent::MousePressed { x, y, .. } => {
+                self.input.lock().cursor = (x, y);
+                if let Some(ref mut hover) = self.input.lock().hover {
+                    hover.dismissed = true;
+                }
+                let hit = self.hit_test_pixels(x, y)?;
+                let trapped = self.trap(&modal, hit.clone())?;
+
+                // Pressing outside of a modal dialog leaves the focus where it is
+                if trapped == hit {
+                    let focused = match hit {
+                        None => None,
+                        Some(ref id) => self.ancestors(id)?.into_iter().find(|id| match self.widgets.get(id) {
+                            None => false,
+                            Some(widget) => widget.lock().focusable(),
+                        }),
+                    };
+                    self.set_focus(focused);
+                }
+                trapped
+            }
+            InputEvent::MouseReleased { x, y, .. } => {
+                let captured = self.input.lock().captured.take();
+                match captured {
+                    None => self.hit_test_pixels(x, y)?,
+                    Some(val) => Some(val),
+                }
+            }
+            InputEvent::MouseWheel { x, y, .. } => self.hit_test_pixels(x, y)?,
+            InputEvent::KeyPressed { .. }
+            | InputEvent::KeyReleased { .. }
+            | InputEvent::Character(_)
+            | InputEvent::Composition { .. }
+            | InputEvent::Commit(_) => self.focused(),
+        };
+
+        let target = match self.trap(&modal, target)? {
+            None => {
+                return Ok(false);
+            }
+            Some(val) => val,
+        };
+
+        for id in self.chain(&target, &modal)? {
+            let handled = match self.widgets.get(&id) {
+                None => false,
+                Some(widget) => widget.lock().handle_event(self, &event)?,
+            };
+            if handled {
+                if let InputEvent::MousePressed { .. } = event {
+                    self.input.lock().captured = Some(id);
+                }
+                return Ok(true);
+            }
+        }
+
+        // The right button pressed over a widget opens the menu attached to it or to its parents
+        if let InputEvent::MousePressed {
+            button: MouseButton::Right,
+            ..
+        } = event
+        {
+            for id in self.chain(&target, &modal)? {
+                let menu = match self.context_menu(&id) {
+                    None => continue,
+                    Some(val) => val,
+                };
+                let handled = match self.widgets.get(&menu) {
+                    None => false,
+                    Some(widget) => widget.lock().handle_event(self, &event)?,
+                };
+                if handled {
+                    self.input.lock().captured = Some(menu);
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Retrieve the ids of the widgets in the order they are drawn: the content depth-first with
+    /// siblings sorted by their z-index, followed by the widgets in the overlay layer
+    pub fn draw_order(&self) -> Vec<Id> {
+        let mut order = Vec::new();
+        let mut overlays = Vec::new();
+
+        let roots: Vec<Id> = self
+            .widgets
+            .values()
+            .filter_map(|widget| {
+                let widget = widget.lock();
+                match widget.parent_id() {
+                    None => Some(widget.id()),
+                    Some(_) => None,
+                }
+            })
+            .collect();
+
+        for root in self.sort_by_z_index(&roots) {
+            self.visit(&root, &mut order, &mut overlays);
+        }
+
+        let mut overlays = self.sort_by_z_index(&overlays);
+        let mut i = 0;
+        while i < overlays.len() {
+            let mut nested = Vec::new();
+            self.visit(&overlays[i].clone(), &mut order, &mut nested);
+            overlays.append(&mut self.sort_by_z_index(&nested));
+            i += 1;
+        }
+
+        order
+    }
+
+    /// Turn the full sizes of the widgets into the room left by their siblings sharing the same
+    /// row or column, in percent of their parent
+    pub fn normalize_sizes(&self) -> Result<(), Error> {
+        for (parent, children) in self.heirarchy() {
+            // The overlays and the children their parent places itself are left out, they don't
+            // share the room of their siblings
+            let children: Vec<&Id> = children
+                .iter()
+                .filter(|child| {
+                    let overlay = match self.widgets.get(*child) {
+                        None => false,
+                        Some(widget) => widget.lock().layer() == Layer::Overlay,
+                    };
+                    let placed = match self.widgets.get(parent) {
+                        None => false,
+                        Some(widget) => widget.lock().places_child(child),
+                    };
+                    !overlay & !placed
+                })
+                .collect();
+
+            let mut top_count: u16 = 0;
+            let mut hcenter_count: u16 = 0;
+            let mut bottom_count: u16 = 0;
+            let mut left_count: u16 = 0;
+            let mut vcenter_count: u16 = 0;
+            let mut right_count: u16 = 0;
+
+            let mut top_width_percent = 0.0;
+            let mut center_width_percent = 0.0;
+            let mut bottom_width_percent = 0.0;
+            let mut left_height_percent = 0.0;
+            let mut center_height_percent = 0.0;
+            let mut right_height_percent = 0.0;
+
+            for child in children.iter() {
+                let widget = self.widgets[*child].lock();
+                match widget.size() {
+                    Size::Full => match widget.position() {
+                        Position::TopLeft => {
+                            top_count += 1;
+                            left_count += 1;
+                        }
+                        Position::Top => {
+                            top_count += 1;
+                            vcenter_count += 1;
+                        }
+                        Position::TopRight => {
+                            top_count += 1;
+                            right_count += 1;
+                        }
+                        Position::Left => {
+                            hcenter_count += 1;
+                            left_count += 1;
+                        }
+                        Position::Center => {
+                            hcenter_count += 1;
+                            vcenter_count += 1;
+                        }
+                        Position::Right => {
+                            hcenter_count += 1;
+                            right_count += 1;
+                        }
+                        Position::BottomLeft => {
+                            bottom_count += 1;
+                            left_count += 1;
+                        }
+                        Position::Bottom => {
+                            bottom_count += 1;
+                            vcenter_count += 1;
+                        }
+                        Position::BottomRight => {
+                            bottom_count += 1;
+                            right_count += 1;
+                        }
+                    },
+                    Size::Size(width, height) => match widget.position() {
+                        Position::TopLeft => {
+                            top_width_percent += width;
+                            left_height_percent += height;
+                        }
+                        Position::Top => {
+                            top_width_percent += width;
+                            center_height_percent += height;
+                        }
+                        Position::TopRight => {
+                            top_width_percent += width;
+                            right_height_percent += height;
+                        }
+                        Position::Left => {
+                            center_width_percent += width;
+                            left_height_percent += height;
+                        }
+                        Position::Center => {
+                            center_width_percent += width;
+                            center_height_percent += height;
+                        }
+                        Position::Right => {
+                            center_width_percent += width;
+                            right_height_percent += height;
+                        }
+                        Position::BottomLeft => {
+                            bottom_width_percent += width;
+                            left_height_percent += height;
+                        }
+                        Position::Bottom => {
+                            bottom_width_percent += width;
+                            center_height_percent += height;
+                        }
+                        Position::BottomRight => {
+                            bottom_width_percent += width;
+                            right_height_percent += height;
+                        }
+                    },
+                }
+            }
+
+            if top_width_percent > 100.0 {
+                return Err(err_msg("The top width exceeds the maximum 100%"));
+            } else if center_width_percent > 100.0 {
+                return Err(err_msg("The center width exceeds the maximum 100%"));
+            } else if bottom_width_percent > 100.0 {
+                return Err(err_msg("The bottome width exceeds the maximum 100%"));
+            } else if left_height_percent > 100.0 {
+                return Err(err_msg("The left height exceeds the maximum 100%"));
+            } else if center_height_percent > 100.0 {
+                return Err(err_msg("The center height exceeds the maximum 100%"));
+            } else if right_height_percent > 100.0 {
+                return Err(err_msg("The right height exceeds the maximum 100%"));
+            }
+            for child in children.iter() {
+                match self.widgets.get(*child) {
+                    None => {
+                        return Err(err_msg("NCError: Attempted to use an id that does not exist"));
+                    }
+                    Some(widget) => {
+                        let mut widget = widget.lock();
+                        if let Size::Full = widget.size() {
+                            let size = match widget.position() {
+                                Position::TopLeft => {
+                                    let full_width = (100.0 - top_width_percent) / f32::from(top_count);
+                                    let full_height = (100.0 - left_height_percent) / f32::from(left_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::Top => {
+                                    let full_width = (100.0 - top_width_percent) / f32::from(top_count);
+                                    let full_height = (100.0 - center_height_percent) / f32::from(vcenter_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::TopRight => {
+                                    let full_width = (100.0 - top_width_percent) / f32::from(top_count);
+                                    let full_height = (100.0 - right_height_percent) / f32::from(right_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::Left => {
+                                    let full_width = (100.0 - center_width_percent) / f32::from(hcenter_count);
+                                    let full_height = (100.0 - left_height_percent) / f32::from(left_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::Center => {
+                                    let full_width = (100.0 - center_width_percent) / f32::from(hcenter_count);
+                                    let full_height = (100.0 - center_height_percent) / f32::from(vcenter_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::Right => {
+                                    let full_width = (100.0 - center_width_percent) / f32::from(hcenter_count);
+                                    let full_height = (100.0 - right_height_percent) / f32::from(right_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::BottomLeft => {
+                                    let full_width = (100.0 - bottom_width_percent) / f32::from(bottom_count);
+                                    let full_height = (100.0 - left_height_percent) / f32::from(left_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::Bottom => {
+                                    let full_width = (100.0 - bottom_width_percent) / f32::from(bottom_count);
+                                    let full_height = (100.0 - center_height_percent) / f32::from(vcenter_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                                Position::BottomRight => {
+                                    let full_width = (100.0 - bottom_width_percent) / f32::from(bottom_count);
+                                    let full_height = (100.0 - right_height_percent) / f32::from(right_count);
+                                    Size::Size(full_width, full_height)
+                                }
+                            };
+                            widget.set_size(size);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Find the top most visible widget under the point (x, y) in the 0.0 to 2.0 format of the
+    /// vertices, the widgets in the overlay layer are tested first
+    pub fn hit_test(&self, x: f32, y: f32) -> Result<Option<Id>, Error> {
+        let contains = |(bl, tr): (Vertex, Vertex)| (x >= bl.x()) & (x <= tr.x()) & (y >= bl.y()) & (y <= tr.y());
+
+        for id in self.draw_order().iter().rev() {
+            let widget = match self.widgets.get(id) {
+                None => {
+                    return Err(err_msg("HTError: Attempted to use an id that does not exist"));
+                }
+                Some(val) => val.lock(),
+            };
+
+            let bounds = match widget.bounds(self)? {
+                None => continue,
+                Some(val) => val,
+            };
+
+            let clipped = match widget.clip(self)? {
+                None => false,
+                Some(val) => !contains(val),
+            };
+
+            if contains(bounds) && !clipped {
+                return Ok(Some(id.clone()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Mark a modal dialog as open, over the dialogs opened before it
+    pub(crate) fn open_modal(&self, id: Id) {
+        let mut input = self.input.lock();
+        input.modals.retain(|val| *val != id);
+        input.modals.push(id);
+    }
+
+    /// Mark a modal dialog as closed
+    pub(crate) fn close_modal(&self, id: &str) {
+        self.input.lock().modals.retain(|val| val != id);
+    }
+
+    /// Follow the tooltip of the widget under the cursor, the one of the closest parent with a
+    /// tooltip attached. The tooltip shown stays while the cursor is over it.
+    fn hover(&self, hit: Option<&Id>, x: f32, y: f32) -> Result<(), Error> {
+        let current = self
+            .input
+            .lock()
+            .hover
+            .as_ref()
+            .map(|hover| (hover.target.clone(), hover.tooltip.clone()));
+
+        let mut hovered = None;
+        if let Some(id) = hit {
+            for id in self.ancestors(id)? {
+                if current.as_ref().map(|(_, tooltip)| tooltip) == Some(&id) {
+                    return Ok(());
+                }
+                if let Some(val) = self.tooltip(&id) {
+                    hovered = Some((id, val));
+                    break;
+                }
+            }
+        }
+
+        let (target, tooltip) = match hovered {
+            None => {
+                self.input.lock().hover = None;
+                return Ok(());
+            }
+            Some(val) => val,
+        };
+
+        // The tooltip follows the cursor until it is shown
+        if current == Some((target.clone(), tooltip.clone())) {
+            if let Some(ref mut hover) = self.input.lock().hover {
+                if !hover.shown() {
+                    hover.position = (x, y);
+                }
+            }
+            return Ok(());
+        }
+
+        let delay = match self.widgets.get(&tooltip) {
+            None => {
+                return Err(err_msg("TTError: Attempted to use an id that does not exist"));
+            }
+            Some(widget) => match widget.lock().downcast_ref::<Tooltip>() {
+                None => {
+                    return Err(err_msg(
+                        "TTError: Attempted to use a widget that is not a tooltip as a tooltip",
+                    ));
+                }
+                Some(val) => val.delay(),
+            },
+        };
+
+        self.input.lock().hover = Some(Hover {
+            target,
+            tooltip,
+            since: Instant::now(),
+            delay,
+            position: (x, y),
+            dismissed: false,
+        });
+
+        Ok(())
+    }
+
+    /// Retrieve the tooltip of the widget under the cursor, along with where the cursor is
+    pub(crate) fn hovered(&self) -> Option<Hover> {
+        self.input.lock().hover.clone()
+    }
+
+    /// Replace the target of an event by the modal dialog open, if any, when the target is outside
+    /// of it
+    fn trap(&self, modal: &Option<Id>, target: Option<Id>) -> Result<Option<Id>, Error> {
+        let modal = match *modal {
+            None => {
+                return Ok(target);
+            }
+            Some(ref val) => val,
+        };
+
+        match target {
+            Some(ref id) if self.ancestors(id)?.contains(modal) => Ok(target),
+            _ => Ok(Some(modal.clone())),
+        }
+    }
+
+    /// Retrieve the ids of the widgets an event bubbles up through, from its target up to the
+    /// modal dialog open or to the window container
+    fn chain(&self, target: &Id, modal: &Option<Id>) -> Result<Vec<Id>, Error> {
+        let mut ancestors = self.ancestors(target)?;
+        if let Some(ref modal) = *modal {
+            if let Some(index) = ancestors.iter().position(|id| id == modal) {
+                ancestors.truncate(index + 1);
+            }
+        }
+        Ok(ancestors)
+    }
+
+    fn hit_test_pixels(&self, x: f32, y: f32) -> Result<Option<Id>, Error> {
+        if (self.window_size[0] == 0) | (self.window_size[1] == 0) {
+            return Ok(None);
+        }
+
+        self.hit_test(
+            (x * 2.0) / self.window_size[0] as f32,
+            (y * 2.0) / self.window_size[1] as f32,
+        )
+    }
+
+    /// Retrieve the id of a widget followed by the ids of its parents
+    fn ancestors(&self, id: &Id) -> Result<Vec<Id>, Error> {
+        let mut ancestors = Vec::new();
+        let mut id = Some(id.clone());
+
+        while let Some(val) = id {
+            id = match self.widgets.get(&val) {
+                None => {
+                    return Err(err_msg("DPError: Attempted to use an id that does not exist"));
+                }
+                Some(widget) => widget.lock().parent_id(),
+            };
+            ancestors.push(val);
+        }
+
+        Ok(ancestors)
+    }
+
+    fn visit(&self, id: &Id, order: &mut Vec<Id>, overlays: &mut Vec<Id>) {
+        order.push(id.clone());
+
+        if let Some(children) = self.heirarchy.get(id) {
+            for child in self.sort_by_z_index(children) {
+                let layer = match self.widgets.get(&child) {
+                    None => continue,
+                    Some(widget) => widget.lock().layer(),
+                };
+                match layer {
+                    Layer::Content => self.visit(&child, order, overlays),
+                    Layer::Overlay => overlays.push(child),
+                }
+            }
+        }
+    }
+
+    fn sort_by_z_index(&self, ids: &[Id]) -> Vec<Id> {
+        let mut sorted: Vec<(i32, Id)> = ids
+            .iter()
+            .filter_map(|id| self.widgets.get(id).map(|widget| (widget.lock().z_index(), id.clone())))
+            .collect();
+        sorted.sort_by_key(|(z_index, _)| *z_index);
+        sorted.into_iter().map(|(_, id)| id).collect()
+    }
 }
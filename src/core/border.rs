@@ -0,0 +1,135 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! The border and the rounded corners drawn along the edges of a widget.
+
+use self::super::{
+    err_msg,
+    Color,
+    Error,
+};
+
+/// The border drawn inside the edges of a widget
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Border {
+    /// The width of the border in pixels
+    width: f32,
+    /// The color of the border
+    color: Color,
+}
+
+impl Border {
+    /// Create a border
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::*;
+    ///
+    /// let border = Border::new(2.0, Color::from_hex("#B7410EFF").unwrap()).unwrap();
+    /// assert_eq!(border.width(), 2.0);
+    /// assert!(Border::new(-1.0, Color::default()).is_err());
+    /// ```
+    pub fn new(width: f32, color: Color) -> Result<Self, Error> {
+        if width < 0.0 {
+            return Err(err_msg("The border width must be 0 or greater"));
+        }
+
+        Ok(Self { width, color })
+    }
+
+    /// Retrieve the width of the border in pixels
+    pub fn width(self) -> f32 {
+        self.width
+    }
+
+    /// Retrieve the color of the border
+    pub fn color(self) -> Color {
+        self.color
+    }
+}
+
+/// The radius of each corner of a widget in pixels
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CornerRadius {
+    /// The radius of the top left corner
+    top_left:     f32,
+    /// The radius of the top right corner
+    top_right:    f32,
+    /// The radius of the bottom right corner
+    bottom_right: f32,
+    /// The radius of the bottom left corner
+    bottom_left:  f32,
+}
+
+impl CornerRadius {
+    /// Create the radius of each corner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::CornerRadius;
+    ///
+    /// let radius = CornerRadius::new(1.0, 2.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(radius.as_array(), [1.0, 2.0, 3.0, 4.0]);
+    /// assert!(CornerRadius::new(1.0, -2.0, 3.0, 4.0).is_err());
+    /// ```
+    pub fn new(top_left: f32, top_right: f32, bottom_right: f32, bottom_left: f32) -> Result<Self, Error> {
+        if (top_left < 0.0) | (top_right < 0.0) | (bottom_right < 0.0) | (bottom_left < 0.0) {
+            return Err(err_msg("The corner radius must be 0 or greater"));
+        }
+
+        Ok(Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        })
+    }
+
+    /// Create the same radius for every corner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::CornerRadius;
+    ///
+    /// let radius = CornerRadius::uniform(4.0).unwrap();
+    /// assert_eq!(radius.as_array(), [4.0; 4]);
+    /// ```
+    pub fn uniform(radius: f32) -> Result<Self, Error> {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// Retrieve the radius of the top left corner
+    pub fn top_left(self) -> f32 {
+        self.top_left
+    }
+
+    /// Retrieve the radius of the top right corner
+    pub fn top_right(self) -> f32 {
+        self.top_right
+    }
+
+    /// Retrieve the radius of the bottom right corner
+    pub fn bottom_right(self) -> f32 {
+        self.bottom_right
+    }
+
+    /// Retrieve the radius of the bottom left corner
+    pub fn bottom_left(self) -> f32 {
+        self.bottom_left
+    }
+
+    /// Retrieve the radii in the order top left, top right, bottom right, bottom left
+    pub fn as_array(self) -> [f32; 4] {
+        [self.top_left, self.top_right, self.bottom_right, self.bottom_left]
+    }
+}
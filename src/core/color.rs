@@ -125,6 +125,34 @@ impl Color {
         })
     }
 
+    /// Retrieve the alpha value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::Color;
+    ///
+    /// let c = Color::from_hex("#B7410E80").unwrap();
+    /// assert_eq!(c.alpha(), 128);
+    /// ```
+    pub fn alpha(self) -> u8 {
+        self.alpha
+    }
+
+    /// Create a copy of the color with a different alpha value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::Color;
+    ///
+    /// let c = Color::from_hex("#B7410EFF").unwrap();
+    /// assert_eq!(c.with_alpha(0).into_hex(), String::from("#B7410E00"));
+    /// ```
+    pub fn with_alpha(self, alpha: u8) -> Self {
+        Self { alpha, ..self }
+    }
+
     fn into_rgba_float(self) -> [f32; 4] {
         let r = f32::from(self.red);
         let g = f32::from(self.green);
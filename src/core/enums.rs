@@ -10,16 +10,28 @@
 
 //! All the enumeraions of the library
 
+mod check_state;
+mod fit;
+mod layer;
 mod orientation;
+mod overflow;
 mod position;
 mod run;
+mod selection_mode;
 mod size;
+mod sort_order;
 mod widgets;
 
 pub use self::{
+    check_state::*,
+    fit::*,
+    layer::*,
     orientation::*,
+    overflow::*,
     position::*,
     run::*,
+    selection_mode::*,
     size::*,
+    sort_order::*,
     widgets::*,
 };
@@ -0,0 +1,37 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use serde_derive::Deserialize;
+use std::fmt;
+
+/// The state of a checkbox
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+pub enum CheckState {
+    /// The checkbox is not checked
+    Unchecked,
+    /// The checkbox is checked
+    Checked,
+    /// The checkbox is neither checked nor unchecked (i.e. some of the options it stands for are
+    /// checked)
+    Indeterminate,
+}
+
+impl Default for CheckState {
+    /// The default CheckState
+    fn default() -> Self {
+        CheckState::Unchecked
+    }
+}
+
+impl fmt::Display for CheckState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use serde_derive::Deserialize;
+use std::fmt;
+
+/// How an image is scaled to fit the bounds of its widget, always centered
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+pub enum Fit {
+    /// Stretch the image to fill the bounds, ignoring its aspect ratio
+    Stretch,
+    /// Scale the image to fit entirely inside the bounds, keeping its aspect ratio
+    Contain,
+    /// Scale the image to cover the bounds, keeping its aspect ratio and cropping the rest
+    Cover,
+    /// Keep the image at its size in pixels, cropping what exceeds the bounds
+    None,
+}
+
+impl Default for Fit {
+    /// The default Fit
+    fn default() -> Self {
+        Fit::Contain
+    }
+}
+
+impl fmt::Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
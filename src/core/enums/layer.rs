@@ -0,0 +1,35 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use serde_derive::Deserialize;
+use std::fmt;
+
+/// The layer a widget is drawn in
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+pub enum Layer {
+    /// Draw the widget with the rest of the content, in the order of the heirarchy
+    Content,
+    /// Draw the widget and its children on top of all the content, unclipped by its parents, and
+    /// hit-test it first (i.e. popups, tooltips and menus)
+    Overlay,
+}
+
+impl Default for Layer {
+    /// The default Layer
+    fn default() -> Self {
+        Layer::Content
+    }
+}
+
+impl fmt::Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
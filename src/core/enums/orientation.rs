@@ -8,11 +8,15 @@
 //
 // You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use serde_derive::Deserialize;
+use serde_derive::{
+    Deserialize,
+    Serialize,
+};
 use std::fmt;
 
-/// The orientation of the bar/dock
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+/// The orientation of the bar/dock, or of the track of a slider/progress bar: along the top or
+/// the bottom it is horizontal, along the left or the right it is vertical
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Orientation {
     /// Position the bar/dock at the top of the screen
     Top,
@@ -24,6 +28,16 @@ pub enum Orientation {
     Bottom,
 }
 
+impl Orientation {
+    /// Whether the orientation runs horizontally, along the top or the bottom
+    pub fn horizontal(self) -> bool {
+        match self {
+            Orientation::Top | Orientation::Bottom => true,
+            Orientation::Left | Orientation::Right => false,
+        }
+    }
+}
+
 impl Default for Orientation {
     /// The default Position
     fn default() -> Self {
@@ -0,0 +1,34 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use serde_derive::Deserialize;
+use std::fmt;
+
+/// How the children of a widget are drawn when they exceed its bounds
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+pub enum Overflow {
+    /// Clip the children to the bounds of the widget
+    Hidden,
+    /// Let the children draw outside the bounds of the widget
+    Visible,
+}
+
+impl Default for Overflow {
+    /// The default Overflow
+    fn default() -> Self {
+        Overflow::Hidden
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
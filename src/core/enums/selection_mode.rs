@@ -0,0 +1,35 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use serde_derive::Deserialize;
+use std::fmt;
+
+/// How many rows of a list view can be selected at once
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+pub enum SelectionMode {
+    /// At most one row is selected
+    Single,
+    /// Any number of rows are selected, with the control key to add or remove a row and with the
+    /// shift key to select a range of rows
+    Multiple,
+}
+
+impl Default for SelectionMode {
+    /// The default SelectionMode
+    fn default() -> Self {
+        SelectionMode::Single
+    }
+}
+
+impl fmt::Display for SelectionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -15,14 +15,62 @@ use std::fmt;
 pub enum WidgetType {
     /// The Button Widget,
     Button,
+    /// The Canvas Widget
+    Canvas,
+    /// The Checkbox Widget
+    Checkbox,
+    /// The ComboBox Widget
+    ComboBox,
+    /// The popup list of a ComboBox Widget
+    ComboPopup,
     /// The Container Widget
     Container,
+    /// The Dialog Widget
+    Dialog,
     /// The Dock Widget
     Dock,
+    /// The DockArea Widget
+    DockArea,
+    /// The DockPanel Widget
+    DockPanel,
+    /// The Image Widget
+    Image,
     /// The Label Widget,
     Label,
+    /// The ListView Widget
+    ListView,
+    /// The Menu Widget
+    Menu,
+    /// The MenuBar Widget
+    MenuBar,
+    /// The ProgressBar Widget
+    ProgressBar,
+    /// The RadioButton Widget
+    RadioButton,
+    /// The RadioGroup Widget
+    RadioGroup,
+    /// The Slider Widget
+    Slider,
+    /// The SpinBox Widget
+    SpinBox,
+    /// The Splitter Widget
+    Splitter,
+    /// The Table Widget
+    Table,
+    /// The Tabs Widget
+    Tabs,
+    /// The TextArea Widget
+    TextArea,
+    /// The TextInput Widget
+    TextInput,
+    /// The Toggle Widget
+    Toggle,
     /// The Toolbar Widget
     Toolbar,
+    /// The Tooltip Widget
+    Tooltip,
+    /// The TreeView Widget
+    TreeView,
     /// Undefined widgets
     Unknown,
     /// The main WindowContainer Widget
@@ -0,0 +1,439 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! This library is used to fill the background of a widget with a solid color or a gradient, and
+//! to convert them from and into strings (i.e. `#RRGGBBAA` or `linear-gradient(90, #000 0.0, #FFF
+//! 1.0)`).
+
+use self::super::{
+    err_msg,
+    Color,
+    Error,
+    Vertex,
+};
+use std::f32::consts::PI;
+
+/// The number of segments used to approximate the circles of a radial gradient
+const RADIAL_SEGMENTS: usize = 48;
+
+/// A color at an offset along a gradient
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// The offset from 0.0 (start) to 1.0 (end) of the gradient
+    offset: f32,
+    /// The color at the offset
+    color:  Color,
+}
+
+impl ColorStop {
+    /// Create a color stop at an offset from 0.0 (start) to 1.0 (end) of the gradient
+    pub fn new(offset: f32, color: Color) -> Result<Self, Error> {
+        if (offset < 0.0) | (offset > 1.0) {
+            return Err(err_msg("The offset of a color stop must be between 0.0 and 1.0"));
+        }
+
+        Ok(Self { offset, color })
+    }
+
+    /// Retrieve the offset of the stop
+    pub fn offset(self) -> f32 {
+        self.offset
+    }
+
+    /// Retrieve the color of the stop
+    pub fn color(self) -> Color {
+        self.color
+    }
+}
+
+/// How the background of a widget is filled
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fill {
+    /// Fill with a single color
+    Solid(Color),
+    /// Blend the stops along a line crossing the widget at an angle in degrees, 0 going from left
+    /// to right and 90 from top to bottom
+    LinearGradient {
+        /// The angle of the gradient in degrees
+        angle: f32,
+        /// The colors along the gradient, sorted by their offset
+        stops: Vec<ColorStop>,
+    },
+    /// Blend the stops outwards from a center, the center and the radius are relative to the size
+    /// of the widget so the gradient stretches into an ellipse for widgets that are not square
+    RadialGradient {
+        /// The center from (0.0, 0.0) for the top left to (1.0, 1.0) for the bottom right
+        center: (f32, f32),
+        /// The radius at which the gradient reaches its last stop
+        radius: f32,
+        /// The colors along the gradient, sorted by their offset
+        stops:  Vec<ColorStop>,
+    },
+}
+
+impl Default for Fill {
+    /// The default Fill
+    fn default() -> Self {
+        Fill::Solid(Color::default())
+    }
+}
+
+impl From<Color> for Fill {
+    fn from(color: Color) -> Self {
+        Fill::Solid(color)
+    }
+}
+
+impl Fill {
+    /// Create a linear gradient, the stops are sorted by their offset
+    pub fn linear_gradient(angle: f32, stops: Vec<ColorStop>) -> Result<Self, Error> {
+        let stops = sort_stops(stops)?;
+        Ok(Fill::LinearGradient { angle, stops })
+    }
+
+    /// Create a radial gradient, the stops are sorted by their offset
+    pub fn radial_gradient(center: (f32, f32), radius: f32, stops: Vec<ColorStop>) -> Result<Self, Error> {
+        if radius <= 0.0 {
+            return Err(err_msg("The radius of a radial gradient must be greater than 0"));
+        }
+
+        let stops = sort_stops(stops)?;
+        Ok(Fill::RadialGradient { center, radius, stops })
+    }
+
+    /// Create the Fill from a string, either a hex color or a gradient with its parameters
+    /// followed by its color stops. The offsets of the stops can be left out to spread them evenly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::*;
+    ///
+    /// let solid = Fill::parse("#B7410EFF").unwrap();
+    /// assert_eq!(solid, Fill::Solid(Color::from_hex("#B7410EFF").unwrap()));
+    ///
+    /// let linear = Fill::parse("linear-gradient(90, #B7410EFF 0.0, #000000FF 1.0)").unwrap();
+    /// let radial = Fill::parse("radial-gradient(0.5 0.5, 0.5, #B7410EFF, #000000FF)").unwrap();
+    /// assert_eq!(linear.color(), radial.color());
+    ///
+    /// assert!(Fill::parse("linear-gradient(90)").is_err());
+    /// ```
+    pub fn parse<V>(fill: V) -> Result<Self, Error>
+    where
+        V: Into<String>,
+    {
+        let fill = fill.into();
+        let fill = fill.trim();
+
+        if fill.starts_with('#') {
+            return Ok(Fill::Solid(Color::from_hex(fill)?));
+        }
+
+        let (name, args) = match (fill.find('('), fill.ends_with(')')) {
+            (Some(i), true) => (fill[..i].trim(), &fill[i + 1..fill.len() - 1]),
+            _ => {
+                return Err(err_msg("The fill must be a hex color or a gradient"));
+            }
+        };
+
+        let args: Vec<&str> = args.split(',').map(|arg| arg.trim()).collect();
+        if args.len() < 3 {
+            return Err(err_msg("A gradient needs its parameters and at least two color stops"));
+        }
+
+        match name {
+            "linear-gradient" => {
+                let angle = parse_f32(args[0].trim_end_matches("deg"))?;
+                Self::linear_gradient(angle, parse_stops(&args[1..])?)
+            }
+            "radial-gradient" => {
+                let center: Vec<&str> = args[0].split_whitespace().collect();
+                if center.len() != 2 {
+                    return Err(err_msg("The center of a radial gradient must be two numbers"));
+                }
+                let center = (parse_f32(center[0])?, parse_f32(center[1])?);
+                let radius = parse_f32(args[1])?;
+                Self::radial_gradient(center, radius, parse_stops(&args[2..])?)
+            }
+            _ => Err(err_msg(format!("Unknown fill: {}", name))),
+        }
+    }
+
+    /// Convert the Fill into a string that can be parsed back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::Fill;
+    ///
+    /// let fill = "linear-gradient(90, #B7410EFF 0, #000000FF 1)";
+    /// assert_eq!(Fill::parse(fill).unwrap().into_string(), String::from(fill));
+    /// ```
+    pub fn into_string(self) -> String {
+        let stops = |stops: Vec<ColorStop>| {
+            stops
+                .iter()
+                .map(|stop| format!("{} {}", stop.color.into_hex(), stop.offset))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        match self {
+            Fill::Solid(color) => color.into_hex(),
+            Fill::LinearGradient { angle, stops: s } => format!("linear-gradient({}, {})", angle, stops(s)),
+            Fill::RadialGradient {
+                center,
+                radius,
+                stops: s,
+            } => format!("radial-gradient({} {}, {}, {})", center.0, center.1, radius, stops(s)),
+        }
+    }
+
+    /// Retrieve the color of a solid fill, or the first color of a gradient
+    pub fn color(&self) -> Color {
+        match self {
+            Fill::Solid(color) => *color,
+            Fill::LinearGradient { stops, .. } | Fill::RadialGradient { stops, .. } => stops[0].color,
+        }
+    }
+
+    /// Tessellate the rectangle between the bottom left and top right vertices into a triangle
+    /// list, with the color of each vertex taken from the fill
+    pub(crate) fn triangles(&self, bl_vertex: Vertex, tr_vertex: Vertex) -> Vec<(Vertex, [f32; 4])> {
+        let rect = vec![(1.0, 0.0), (0.0, 0.0), (0.0, 1.0), (1.0, 1.0)];
+
+        let polygons = match self {
+            Fill::Solid(_) => {
+                let color = self.color().into_scaled_rgba_float();
+                let to_vertex = |(u, v): (f32, f32)| (into_vertex(bl_vertex, tr_vertex, u, v), color);
+                // Keep the two triangles in the order tr, tl, bl, tr, br, bl
+                return vec![(1.0, 1.0), (0.0, 1.0), (0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)]
+                    .into_iter()
+                    .map(to_vertex)
+                    .collect();
+            }
+            Fill::LinearGradient { stops, .. } => {
+                let mut bands = vec![0.0];
+                bands.extend(stops.iter().map(|stop| stop.offset));
+                bands.push(1.0);
+
+                bands
+                    .windows(2)
+                    .filter(|band| band[0] < band[1])
+                    .map(|band| {
+                        let clipped = clip_polygon(rect.clone(), |p| self.offset_at(p) - band[0]);
+                        clip_polygon(clipped, |p| band[1] - self.offset_at(p))
+                    })
+                    .collect::<Vec<Vec<(f32, f32)>>>()
+            }
+            Fill::RadialGradient { center, radius, stops } => {
+                // Reach past the farthest corner so the outer ring covers the whole rectangle
+                let farthest = rect
+                    .iter()
+                    .map(|(u, v)| ((u - center.0).powi(2) + (v - center.1).powi(2)).sqrt())
+                    .fold(0.0, f32::max);
+                let outer = (farthest / radius).max(1.0) / (PI / RADIAL_SEGMENTS as f32).cos();
+
+                let mut rings = vec![0.0];
+                rings.extend(stops.iter().map(|stop| stop.offset));
+                rings.push(outer);
+
+                let mut polygons = Vec::new();
+                for ring in rings.windows(2).filter(|ring| ring[0] < ring[1]) {
+                    for segment in 0..RADIAL_SEGMENTS {
+                        let angle0 = (2.0 * PI * segment as f32) / RADIAL_SEGMENTS as f32;
+                        let angle1 = (2.0 * PI * (segment + 1) as f32) / RADIAL_SEGMENTS as f32;
+                        let point = |offset: f32, angle: f32| {
+                            (
+                                center.0 + (offset * radius * angle.cos()),
+                                center.1 + (offset * radius * angle.sin()),
+                            )
+                        };
+
+                        let mut cell = vec![point(ring[1], angle0), point(ring[1], angle1)];
+                        if ring[0] > 0.0 {
+                            cell.push(point(ring[0], angle1));
+                            cell.push(point(ring[0], angle0));
+                        } else {
+                            cell.push(*center);
+                        }
+
+                        let cell = clip_polygon(cell, |(u, _)| u);
+                        let cell = clip_polygon(cell, |(u, _)| 1.0 - u);
+                        let cell = clip_polygon(cell, |(_, v)| v);
+                        polygons.push(clip_polygon(cell, |(_, v)| 1.0 - v));
+                    }
+                }
+                polygons
+            }
+        };
+
+        let mut triangles = Vec::new();
+        for polygon in polygons.iter().filter(|polygon| polygon.len() >= 3) {
+            for i in 1..polygon.len() - 1 {
+                for &(u, v) in &[polygon[0], polygon[i], polygon[i + 1]] {
+                    let color = self.color_at((u, v));
+                    triangles.push((into_vertex(bl_vertex, tr_vertex, u, v), color));
+                }
+            }
+        }
+
+        triangles
+    }
+
+    /// Retrieve the offset along the gradient at the relative point (u, v) of the widget
+    fn offset_at(&self, (u, v): (f32, f32)) -> f32 {
+        match self {
+            Fill::Solid(_) => 0.0,
+            Fill::LinearGradient { angle, .. } => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let project = |(u, v): (f32, f32)| (u * cos) + (v * sin);
+                let corners = [
+                    project((0.0, 0.0)),
+                    project((1.0, 0.0)),
+                    project((0.0, 1.0)),
+                    project((1.0, 1.0)),
+                ];
+                let min = corners.iter().cloned().fold(std::f32::MAX, f32::min);
+                let max = corners.iter().cloned().fold(std::f32::MIN, f32::max);
+                (project((u, v)) - min) / (max - min)
+            }
+            Fill::RadialGradient { center, radius, .. } => {
+                ((u - center.0).powi(2) + (v - center.1).powi(2)).sqrt() / radius
+            }
+        }
+    }
+
+    /// Retrieve the scaled rgba color at the relative point (u, v) of the widget
+    fn color_at(&self, point: (f32, f32)) -> [f32; 4] {
+        let stops = match self {
+            Fill::Solid(color) => {
+                return color.into_scaled_rgba_float();
+            }
+            Fill::LinearGradient { stops, .. } | Fill::RadialGradient { stops, .. } => stops,
+        };
+
+        let offset = self.offset_at(point);
+        let first = stops[0];
+        let last = stops[stops.len() - 1];
+
+        if offset <= first.offset {
+            return first.color.into_scaled_rgba_float();
+        } else if offset >= last.offset {
+            return last.color.into_scaled_rgba_float();
+        }
+
+        for pair in stops.windows(2) {
+            if (offset >= pair[0].offset) & (offset <= pair[1].offset) {
+                let a = pair[0].color.into_scaled_rgba_float();
+                let b = pair[1].color.into_scaled_rgba_float();
+                let range = pair[1].offset - pair[0].offset;
+                let t = if range > 0.0 {
+                    (offset - pair[0].offset) / range
+                } else {
+                    0.0
+                };
+                return [
+                    a[0] + ((b[0] - a[0]) * t),
+                    a[1] + ((b[1] - a[1]) * t),
+                    a[2] + ((b[2] - a[2]) * t),
+                    a[3] + ((b[3] - a[3]) * t),
+                ];
+            }
+        }
+
+        last.color.into_scaled_rgba_float()
+    }
+}
+
+fn sort_stops(mut stops: Vec<ColorStop>) -> Result<Vec<ColorStop>, Error> {
+    if stops.len() < 2 {
+        return Err(err_msg("A gradient needs at least two color stops"));
+    }
+
+    stops.sort_by(|a, b| a.offset.partial_cmp(&b.offset).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(stops)
+}
+
+fn parse_f32(value: &str) -> Result<f32, Error> {
+    match value.trim().parse::<f32>() {
+        Err(err) => Err(err_msg(format!("Could not parse the number {}: {}", value, err))),
+        Ok(val) => Ok(val),
+    }
+}
+
+fn parse_stops(args: &[&str]) -> Result<Vec<ColorStop>, Error> {
+    // The offsets left out are spread over the stops, which takes at least two of them
+    if args.len() < 2 {
+        return Err(err_msg("A gradient needs at least two color stops"));
+    }
+
+    let mut stops = Vec::new();
+    let mut offsets = 0;
+
+    for (i, arg) in args.iter().enumerate() {
+        let parts: Vec<&str> = arg.split_whitespace().collect();
+        let color = match parts.get(0) {
+            None => {
+                return Err(err_msg("A color stop must start with a hex color"));
+            }
+            Some(val) => Color::from_hex(*val)?,
+        };
+        let offset = match parts.get(1) {
+            None => i as f32 / (args.len() - 1) as f32,
+            Some(val) => {
+                offsets += 1;
+                parse_f32(val)?
+            }
+        };
+        stops.push(ColorStop::new(offset, color)?);
+    }
+
+    if (offsets != 0) & (offsets != args.len()) {
+        return Err(err_msg("Either all or none of the color stops must have an offset"));
+    }
+
+    Ok(stops)
+}
+
+/// The clipped polygons can stray outside the rect by a rounding error, so the position is
+/// clamped to it
+fn into_vertex(bl_vertex: Vertex, tr_vertex: Vertex, u: f32, v: f32) -> Vertex {
+    let (u, v) = (u.max(0.0).min(1.0), v.max(0.0).min(1.0));
+    Vertex {
+        x: bl_vertex.x() + ((tr_vertex.x() - bl_vertex.x()) * u),
+        y: bl_vertex.y() + ((tr_vertex.y() - bl_vertex.y()) * v),
+    }
+}
+
+/// Clip a convex polygon to the side of a line where the linear function is positive
+pub(crate) fn clip_polygon<F>(polygon: Vec<(f32, f32)>, side: F) -> Vec<(f32, f32)>
+where
+    F: Fn((f32, f32)) -> f32,
+{
+    let mut clipped = Vec::new();
+
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        let side_a = side(a);
+        let side_b = side(b);
+
+        if side_a >= 0.0 {
+            clipped.push(a);
+        }
+        if (side_a >= 0.0) != (side_b >= 0.0) {
+            let t = side_a / (side_a - side_b);
+            clipped.push((a.0 + ((b.0 - a.0) * t), a.1 + ((b.1 - a.1) * t)));
+        }
+    }
+
+    clipped
+}
@@ -0,0 +1,152 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Fonts used to draw text
+
+use self::super::{
+    err_msg,
+    Error,
+};
+use rusttype::{
+    point,
+    PositionedGlyph,
+    Scale,
+};
+use std::{
+    fmt,
+    fs::File,
+    io::Read,
+    path::Path,
+    sync::Arc,
+};
+
+/// A TrueType or OpenType font
+#[derive(Clone)]
+pub struct Font {
+    font: Arc<rusttype::Font<'static>>,
+}
+
+impl Font {
+    /// Load a font from the contents of a TrueType or OpenType file
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, Error> {
+        match rusttype::Font::from_bytes(bytes) {
+            Err(err) => Err(err_msg(format!("Failed to load font: {}", err))),
+            Ok(val) => Ok(Self { font: Arc::new(val) }),
+        }
+    }
+
+    /// Load a font from a TrueType or OpenType file
+    pub fn from_path<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let mut f = match File::open(path) {
+            Err(err) => {
+                return Err(err_msg(format!("Failed opening font file: {}", err)));
+            }
+            Ok(val) => val,
+        };
+
+        let mut bytes = Vec::new();
+        f.read_to_end(&mut bytes)?;
+
+        Self::from_bytes(bytes)
+    }
+
+    /// Measure the width and height in pixels of a single line of text drawn at a size in pixels
+    pub fn measure(&self, text: &str, size: f32) -> (f32, f32) {
+        let width = *self.offsets(text, size).last().unwrap();
+        let v_metrics = self.font.v_metrics(Scale::uniform(size));
+
+        (width, v_metrics.ascent - v_metrics.descent)
+    }
+
+    /// Measure the distance in pixels from the start of a single line of text to each of its
+    /// characters, followed by its width
+    pub fn offsets(&self, text: &str, size: f32) -> Vec<f32> {
+        let glyphs = self.layout(text, size, (0.0, 0.0));
+        let width = glyphs
+            .last()
+            .map(|glyph| glyph.position().x + glyph.unpositioned().h_metrics().advance_width)
+            .unwrap_or(0.0);
+
+        let mut offsets: Vec<f32> = glyphs.iter().map(|glyph| glyph.position().x).collect();
+        offsets.push(width);
+        offsets
+    }
+
+    /// Retrieve the start of a single line of text fitting in a width in pixels, cut after the
+    /// last character ending within it
+    pub fn fit<'a>(&self, text: &'a str, size: f32, width: f32) -> &'a str {
+        let count = self
+            .offsets(text, size)
+            .iter()
+            .skip(1)
+            .take_while(|&&offset| offset <= width)
+            .count();
+        text.char_indices().nth(count).map(|(i, _)| &text[..i]).unwrap_or(text)
+    }
+
+    /// Break a text into the lines fitting in a width in pixels, retrieving the start and end of
+    /// each line as byte offsets. The text is broken at its new lines, then after the whitespace
+    /// closest to the width, or within a word longer than the width.
+    pub fn wrap(&self, text: &str, size: f32, width: f32) -> Vec<(usize, usize)> {
+        let mut lines = Vec::new();
+        let mut line_start = 0;
+
+        for line in text.split('\n') {
+            let offsets = self.offsets(line, size);
+            let chars: Vec<(usize, char)> = line.char_indices().collect();
+            let mut start = 0;
+            let mut last_break = None;
+
+            for (i, &(_, c)) in chars.iter().enumerate() {
+                if (offsets[i + 1] - offsets[start] > width) & (i > start) & !c.is_whitespace() {
+                    let end = last_break.unwrap_or(i);
+                    lines.push((line_start + chars[start].0, line_start + chars[end].0));
+                    start = end;
+                    last_break = None;
+                }
+                if c.is_whitespace() {
+                    last_break = Some(i + 1).filter(|i| *i < chars.len());
+                }
+            }
+
+            lines.push((
+                line_start + chars.get(start).map(|(i, _)| *i).unwrap_or(0),
+                line_start + line.len(),
+            ));
+            line_start += line.len() + 1;
+        }
+
+        lines
+    }
+
+    /// Lay out a single line of text with the top left corner of its first glyph at a position
+    pub(crate) fn layout(&self, text: &str, size: f32, position: (f32, f32)) -> Vec<PositionedGlyph<'static>> {
+        let scale = Scale::uniform(size);
+        let ascent = self.font.v_metrics(scale).ascent;
+
+        self.font
+            .layout(text, scale, point(position.0, position.1 + ascent))
+            .collect()
+    }
+
+    /// Whether two fonts were loaded once and shared, rather than from the same bytes twice
+    pub(crate) fn same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.font, &other.font)
+    }
+}
+
+impl fmt::Debug for Font {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Font").finish()
+    }
+}
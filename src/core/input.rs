@@ -0,0 +1,204 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Input events delivered to the widgets
+
+use self::super::{
+    widgets::window::{
+        dpi::LogicalPosition,
+        ElementState,
+        KeyboardInput,
+        ModifiersState,
+        MouseButton,
+        MouseScrollDelta,
+        VirtualKeyCode,
+        WindowEvent,
+    },
+    Id,
+};
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The distance in pixels scrolled by a line of the mouse wheel
+const LINE_SCROLL: f32 = 20.0;
+
+/// An input event delivered to the widgets, positions are in pixels from the top left corner of
+/// the window.
+///
+/// The window doesn't report the composition of an input method, the platform integration feeds
+/// the composition events to the Ui itself.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputEvent {
+    /// The cursor moved
+    CursorMoved {
+        /// The horizontal position of the cursor
+        x: f32,
+        /// The vertical position of the cursor
+        y: f32,
+    },
+    /// A mouse button was pressed where the cursor is
+    MousePressed {
+        /// The horizontal position of the cursor
+        x:         f32,
+        /// The vertical position of the cursor
+        y:         f32,
+        /// The button pressed
+        button:    MouseButton,
+        /// The modifier keys held down
+        modifiers: ModifiersState,
+    },
+    /// A mouse button was released where the cursor is
+    MouseReleased {
+        /// The horizontal position of the cursor
+        x:      f32,
+        /// The vertical position of the cursor
+        y:      f32,
+        /// The button released
+        button: MouseButton,
+    },
+    /// The mouse wheel or the touchpad scrolled where the cursor is
+    MouseWheel {
+        /// The horizontal position of the cursor
+        x:       f32,
+        /// The vertical position of the cursor
+        y:       f32,
+        /// The distance in pixels scrolled to the right, negative to the left
+        delta_x: f32,
+        /// The distance in pixels scrolled up, negative down
+        delta_y: f32,
+    },
+    /// A key was pressed
+    KeyPressed {
+        /// The key pressed
+        key:       VirtualKeyCode,
+        /// The modifier keys held down
+        modifiers: ModifiersState,
+    },
+    /// A key was released
+    KeyReleased {
+        /// The key released
+        key:       VirtualKeyCode,
+        /// The modifier keys held down
+        modifiers: ModifiersState,
+    },
+    /// A character was typed
+    Character(char),
+    /// An input method composes a text, shown at the caret until it is committed
+    Composition {
+        /// The text being composed, empty when the composition is cancelled
+        text:  String,
+        /// The position of the caret in the text being composed, in bytes
+        caret: usize,
+    },
+    /// An input method committed the text it composed
+    Commit(String),
+}
+
+impl InputEvent {
+    /// Convert an event of the window, the position of the cursor is needed for the mouse buttons
+    /// and the hidpi factor of the window to convert the logical positions into pixels
+    pub fn from_window_event(event: &WindowEvent, cursor: (f32, f32), hidpi_factor: f64) -> Option<Self> {
+        match *event {
+            WindowEvent::CursorMoved { position, .. } => {
+                let (x, y) = pixels(position, hidpi_factor);
+                Some(InputEvent::CursorMoved { x, y })
+            }
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button,
+                modifiers,
+                ..
+            } => Some(InputEvent::MousePressed {
+                x: cursor.0,
+                y: cursor.1,
+                button,
+                modifiers,
+            }),
+            WindowEvent::MouseInput {
+                state: ElementState::Released,
+                button,
+                ..
+            } => Some(InputEvent::MouseReleased {
+                x: cursor.0,
+                y: cursor.1,
+                button,
+            }),
+            WindowEvent::MouseWheel { delta, .. } => {
+                let (delta_x, delta_y) = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => (x * LINE_SCROLL, y * LINE_SCROLL),
+                    MouseScrollDelta::PixelDelta(position) => pixels(position, hidpi_factor),
+                };
+                Some(InputEvent::MouseWheel {
+                    x: cursor.0,
+                    y: cursor.1,
+                    delta_x,
+                    delta_y,
+                })
+            }
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        state,
+                        virtual_keycode: Some(key),
+                        modifiers,
+                        ..
+                    },
+                ..
+            } => match state {
+                ElementState::Pressed => Some(InputEvent::KeyPressed { key, modifiers }),
+                ElementState::Released => Some(InputEvent::KeyReleased { key, modifiers }),
+            },
+            WindowEvent::ReceivedCharacter(c) => Some(InputEvent::Character(c)),
+            _ => None,
+        }
+    }
+}
+
+fn pixels(position: LogicalPosition, hidpi_factor: f64) -> (f32, f32) {
+    let position = position.to_physical(hidpi_factor);
+    (position.x as f32, position.y as f32)
+}
+
+/// The tooltip of the widget under the cursor, shown once the cursor stayed over the widget for the
+/// delay of the tooltip
+#[derive(Debug, Clone)]
+pub(crate) struct Hover {
+    pub(crate) target:    Id,
+    pub(crate) tooltip:   Id,
+    pub(crate) since:     Instant,
+    pub(crate) delay:     Duration,
+    pub(crate) position:  (f32, f32),
+    pub(crate) dismissed: bool,
+}
+
+impl Hover {
+    /// Whether the tooltip is shown
+    pub(crate) fn shown(&self) -> bool {
+        !self.dismissed & (self.since.elapsed() >= self.delay)
+    }
+}
+
+/// The state of the input shared by every clone of the Ui
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InputState {
+    pub(crate) cursor:    (f32, f32),
+    pub(crate) focused:   Option<Id>,
+    pub(crate) captured:  Option<Id>,
+    pub(crate) clipboard: String,
+    pub(crate) menus:     HashMap<Id, Id>,
+    pub(crate) modals:    Vec<Id>,
+    pub(crate) tooltips:  HashMap<Id, Id>,
+    pub(crate) hover:     Option<Hover>,
+}
@@ -0,0 +1,153 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Background images split into nine slices, the corners keep their size while the edges and the
+//! center stretch to fill the widget.
+
+use self::super::{
+    err_msg,
+    Error,
+    Texture,
+    Vertex,
+};
+
+/// A texture with the insets in pixels from each of its edges that split it into nine slices
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NineSlice {
+    texture: Texture,
+    insets:  [u32; 4],
+}
+
+impl NineSlice {
+    /// Create a nine slice image from a texture and its insets in the order top, right, bottom,
+    /// left
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::*;
+    ///
+    /// let texture = Texture::new(4, 4, vec![255; 64]).unwrap();
+    /// let background = NineSlice::new(texture.clone(), [1, 1, 1, 1]).unwrap();
+    /// assert_eq!(background.insets(), [1, 1, 1, 1]);
+    /// assert!(NineSlice::new(texture, [2, 0, 2, 0]).is_err());
+    /// ```
+    pub fn new(texture: Texture, insets: [u32; 4]) -> Result<Self, Error> {
+        if (insets[1] + insets[3] >= texture.width()) | (insets[0] + insets[2] >= texture.height()) {
+            return Err(err_msg(
+                "The insets of a nine slice image must leave room for its center",
+            ));
+        }
+
+        Ok(Self { texture, insets })
+    }
+
+    /// Load a nine slice image from a PNG or JPEG file
+    pub fn from_path<P>(path: P, insets: [u32; 4]) -> Result<Self, Error>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Self::new(Texture::from_path(path)?, insets)
+    }
+
+    /// Retrieve the texture
+    pub fn texture(&self) -> Texture {
+        self.texture.clone()
+    }
+
+    /// Retrieve the insets in pixels in the order top, right, bottom, left
+    pub fn insets(&self) -> [u32; 4] {
+        self.insets
+    }
+
+    /// Tessellate the slices stretched between the bottom left and top right vertices into a
+    /// triangle list, along with the uv coordinates of each vertex
+    pub(crate) fn triangles(
+        &self,
+        bl_vertex: Vertex,
+        tr_vertex: Vertex,
+        window_size: [u32; 2],
+    ) -> Vec<(Vertex, [f32; 2])> {
+        let width = tr_vertex.x() - bl_vertex.x();
+        let height = tr_vertex.y() - bl_vertex.y();
+
+        // Until the size of the window is known the corners can't keep their size in pixels
+        let (left, right, top, bottom) = if (window_size[0] == 0) | (window_size[1] == 0) {
+            (0.0, 0.0, 0.0, 0.0)
+        } else {
+            let to_x = |pixels: u32| (pixels as f32 * 2.0) / window_size[0] as f32;
+            let to_y = |pixels: u32| (pixels as f32 * 2.0) / window_size[1] as f32;
+            (
+                to_x(self.insets[3]),
+                to_x(self.insets[1]),
+                to_y(self.insets[0]),
+                to_y(self.insets[2]),
+            )
+        };
+
+        // Corners larger than the widget shrink together so opposite slices never overlap
+        let scale_x = if left + right > width {
+            width / (left + right)
+        } else {
+            1.0
+        };
+        let scale_y = if top + bottom > height {
+            height / (top + bottom)
+        } else {
+            1.0
+        };
+
+        let xs = [
+            bl_vertex.x(),
+            bl_vertex.x() + (left * scale_x),
+            tr_vertex.x() - (right * scale_x),
+            tr_vertex.x(),
+        ];
+        let ys = [
+            bl_vertex.y(),
+            bl_vertex.y() + (top * scale_y),
+            tr_vertex.y() - (bottom * scale_y),
+            tr_vertex.y(),
+        ];
+
+        let texture_width = self.texture.width() as f32;
+        let texture_height = self.texture.height() as f32;
+        let us = [
+            0.0,
+            self.insets[3] as f32 / texture_width,
+            1.0 - (self.insets[1] as f32 / texture_width),
+            1.0,
+        ];
+        let vs = [
+            0.0,
+            self.insets[0] as f32 / texture_height,
+            1.0 - (self.insets[2] as f32 / texture_height),
+            1.0,
+        ];
+
+        let mut triangles = Vec::new();
+        for row in 0..3 {
+            for column in 0..3 {
+                if (xs[column] >= xs[column + 1]) | (ys[row] >= ys[row + 1]) {
+                    continue;
+                }
+
+                let corner = |c: usize, r: usize| (Vertex { x: xs[c], y: ys[r] }, [us[c], vs[r]]);
+                let tr = corner(column + 1, row + 1);
+                let tl = corner(column, row + 1);
+                let bl = corner(column, row);
+                let br = corner(column + 1, row);
+                triangles.extend_from_slice(&[tr, tl, bl, tr, br, bl]);
+            }
+        }
+
+        triangles
+    }
+}
@@ -0,0 +1,709 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! The immediate mode drawing api of the Canvas widget, tessellating shapes into triangle lists
+
+use self::super::{
+    fill::clip_polygon,
+    Color,
+    DrawVertex,
+    Font,
+    Texture,
+    Vertex,
+};
+use parking_lot::Mutex;
+use std::f32::consts::PI;
+
+/// The longest a flattened curve segment or circle edge is allowed to be, in pixels
+const TOLERANCE: f32 = 4.0;
+
+/// A path made of lines and bezier curves, split into subpaths by `move_to`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Path {
+    subpaths: Vec<(Vec<(f32, f32)>, bool)>,
+}
+
+impl Path {
+    /// Initialize an empty path
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new subpath at a point
+    pub fn move_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.subpaths.push((vec![(x, y)], false));
+        self
+    }
+
+    /// Draw a straight line to a point
+    pub fn line_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.current().push((x, y));
+        self
+    }
+
+    /// Draw a quadratic bezier curve with one control point to a point
+    pub fn quadratic_to(&mut self, control: (f32, f32), x: f32, y: f32) -> &mut Self {
+        let start = self.last();
+        let length = distance(start, control) + distance(control, (x, y));
+        for i in 1..=segments(length) {
+            let t = i as f32 / segments(length) as f32;
+            let u = 1.0 - t;
+            self.current().push((
+                (u * u * start.0) + (2.0 * u * t * control.0) + (t * t * x),
+                (u * u * start.1) + (2.0 * u * t * control.1) + (t * t * y),
+            ));
+        }
+        self
+    }
+
+    /// Draw a cubic bezier curve with two control points to a point
+    pub fn cubic_to(&mut self, control1: (f32, f32), control2: (f32, f32), x: f32, y: f32) -> &mut Self {
+        let start = self.last();
+        let length = distance(start, control1) + distance(control1, control2) + distance(control2, (x, y));
+        for i in 1..=segments(length) {
+            let t = i as f32 / segments(length) as f32;
+            let u = 1.0 - t;
+            self.current().push((
+                (u * u * u * start.0)
+                    + (3.0 * u * u * t * control1.0)
+                    + (3.0 * u * t * t * control2.0)
+                    + (t * t * t * x),
+                (u * u * u * start.1)
+                    + (3.0 * u * u * t * control1.1)
+                    + (3.0 * u * t * t * control2.1)
+                    + (t * t * t * y),
+            ));
+        }
+        self
+    }
+
+    /// Close the current subpath with a line back to its first point
+    pub fn close(&mut self) -> &mut Self {
+        if let Some(subpath) = self.subpaths.last_mut() {
+            subpath.1 = true;
+        }
+        self
+    }
+
+    /// Retrieve the flattened points of every subpath and whether it is closed
+    pub fn subpaths(&self) -> Vec<(Vec<(f32, f32)>, bool)> {
+        self.subpaths.clone()
+    }
+
+    fn current(&mut self) -> &mut Vec<(f32, f32)> {
+        if self.subpaths.is_empty() {
+            self.subpaths.push((vec![(0.0, 0.0)], false));
+        }
+        &mut self.subpaths.last_mut().unwrap().0
+    }
+
+    fn last(&mut self) -> (f32, f32) {
+        *self.current().last().unwrap()
+    }
+}
+
+/// A vertex of the shapes drawn on a canvas, in pixels from its top left corner
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PaintVertex {
+    position: (f32, f32),
+    color:    [f32; 4],
+    uv:       Option<[f32; 2]>,
+}
+
+/// The text or the image drawn into the pixels of a canvas, rasterized once its texture is made
+#[derive(Debug, Clone)]
+enum Raster {
+    Text {
+        position: (f32, f32),
+        text:     String,
+        size:     f32,
+        color:    Color,
+        font:     Font,
+    },
+    Image {
+        position: (f32, f32),
+        size:     (f32, f32),
+        texture:  Texture,
+        uv:       ([f32; 2], [f32; 2]),
+        tint:     Color,
+    },
+}
+
+impl PartialEq for Raster {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Raster::Text {
+                    position,
+                    text,
+                    size,
+                    color,
+                    font,
+                },
+                Raster::Text {
+                    position: other_position,
+                    text: other_text,
+                    size: other_size,
+                    color: other_color,
+                    font: other_font,
+                },
+            ) => {
+                (position == other_position)
+                    & (text == other_text)
+                    & (size == other_size)
+                    & (color == other_color)
+                    & font.same(other_font)
+            }
+            (
+                Raster::Image {
+                    position,
+                    size,
+                    texture,
+                    uv,
+                    tint,
+                },
+                Raster::Image {
+                    position: other_position,
+                    size: other_size,
+                    texture: other_texture,
+                    uv: other_uv,
+                    tint: other_tint,
+                },
+            ) => {
+                (position == other_position)
+                    & (size == other_size)
+                    & (texture == other_texture)
+                    & (uv == other_uv)
+                    & (tint == other_tint)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The texture a widget made from its painter on the last frame, kept so the same texture and
+/// its id are reused until the text, the images or the size of the widget change
+#[derive(Debug, Default)]
+pub(crate) struct TextureCache {
+    cached: Mutex<Option<Rasterized>>,
+}
+
+/// The texture made from the text and the images drawn on a canvas of a size in pixels
+#[derive(Debug)]
+struct Rasterized {
+    size:    (u32, u32),
+    rasters: Vec<Raster>,
+    texture: Texture,
+}
+
+impl TextureCache {
+    /// Retrieve the texture made on the last frame, if any
+    pub(crate) fn get(&self) -> Option<Texture> {
+        self.cached.lock().as_ref().map(|rasterized| rasterized.texture.clone())
+    }
+
+    /// Forget the texture, when the widget is not drawn
+    pub(crate) fn clear(&self) {
+        *self.cached.lock() = None;
+    }
+}
+
+/// The drawing api given to the callback of a Canvas every frame. Positions and sizes are in
+/// pixels from the top left corner of the canvas, and everything is clipped to its bounds.
+#[derive(Debug, Clone)]
+pub struct Painter {
+    width:    f32,
+    height:   f32,
+    vertices: Vec<PaintVertex>,
+    rasters:  Vec<Raster>,
+}
+
+impl Painter {
+    pub(crate) fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            vertices: Vec::new(),
+            rasters: Vec::new(),
+        }
+    }
+
+    /// Retrieve the width and height of the canvas in pixels
+    pub fn size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    /// Draw a line of a width
+    pub fn line(&mut self, from: (f32, f32), to: (f32, f32), width: f32, color: Color) {
+        self.stroke(&[from, to], false, width, color);
+    }
+
+    /// Draw connected lines of a width through a list of points
+    pub fn polyline(&mut self, points: &[(f32, f32)], width: f32, color: Color) {
+        self.stroke(points, false, width, color);
+    }
+
+    /// Fill a rectangle from its top left corner and size
+    pub fn rect(&mut self, position: (f32, f32), size: (f32, f32), color: Color) {
+        self.polygon(&rect_points(position, size), color);
+    }
+
+    /// Outline a rectangle from its top left corner and size
+    pub fn stroke_rect(&mut self, position: (f32, f32), size: (f32, f32), width: f32, color: Color) {
+        self.stroke(&rect_points(position, size), true, width, color);
+    }
+
+    /// Fill a circle
+    pub fn circle(&mut self, center: (f32, f32), radius: f32, color: Color) {
+        self.polygon(&circle_points(center, radius), color);
+    }
+
+    /// Outline a circle
+    pub fn stroke_circle(&mut self, center: (f32, f32), radius: f32, width: f32, color: Color) {
+        self.stroke(&circle_points(center, radius), true, width, color);
+    }
+
+    /// Fill a simple polygon, convex or not, from its corners in either winding order
+    pub fn polygon(&mut self, points: &[(f32, f32)], color: Color) {
+        let color = color.into_scaled_rgba_float();
+        for triangle in triangulate(points) {
+            self.triangle(triangle, color);
+        }
+    }
+
+    /// Outline a polygon
+    pub fn stroke_polygon(&mut self, points: &[(f32, f32)], width: f32, color: Color) {
+        self.stroke(points, true, width, color);
+    }
+
+    /// Fill every subpath of a path as its own polygon
+    pub fn fill_path(&mut self, path: &Path, color: Color) {
+        for (points, _) in path.subpaths() {
+            self.polygon(&points, color);
+        }
+    }
+
+    /// Outline every subpath of a path with lines of a width
+    pub fn stroke_path(&mut self, path: &Path, width: f32, color: Color) {
+        for (points, closed) in path.subpaths() {
+            self.stroke(&points, closed, width, color);
+        }
+    }
+
+    /// Draw a single line of text with the top left corner at a position, the size is the height
+    /// of the font in pixels
+    pub fn text(&mut self, position: (f32, f32), text: &str, size: f32, color: Color, font: &Font) {
+        if (self.width.ceil() as usize == 0) | (self.height.ceil() as usize == 0) {
+            return;
+        }
+
+        let mut min = (self.width, self.height);
+        let mut max = (0.0_f32, 0.0_f32);
+        for glyph in font.layout(text, size, position) {
+            if let Some(bounding_box) = glyph.pixel_bounding_box() {
+                min = (
+                    min.0.min(bounding_box.min.x as f32),
+                    min.1.min(bounding_box.min.y as f32),
+                );
+                max = (
+                    max.0.max(bounding_box.max.x as f32),
+                    max.1.max(bounding_box.max.y as f32),
+                );
+            }
+        }
+
+        // The text is shown through a quad over its glyphs, sampling the texture of the canvas
+        self.rasters.push(Raster::Text {
+            position,
+            text: text.to_string(),
+            size,
+            color,
+            font: font.clone(),
+        });
+        self.quad(min, max);
+    }
+
+    /// Draw a texture stretched over a rectangle from its top left corner and size, tinted by a
+    /// color. The texture is sampled on the CPU into the pixels of the canvas, so the image is
+    /// drawn the same by every renderer.
+    pub fn image(&mut self, position: (f32, f32), size: (f32, f32), texture: &Texture, tint: Color) {
+        self.image_part(position, size, texture, ([0.0, 0.0], [1.0, 1.0]), tint);
+    }
+
+    /// Draw the part of a texture between two uv coordinates stretched over a rectangle
+    pub(crate) fn image_part(
+        &mut self,
+        position: (f32, f32),
+        size: (f32, f32),
+        texture: &Texture,
+        uv: ([f32; 2], [f32; 2]),
+        tint: Color,
+    ) {
+        if (self.width.ceil() as usize == 0) | (self.height.ceil() as usize == 0) | (size.0 <= 0.0) | (size.1 <= 0.0) {
+            return;
+        }
+
+        self.rasters.push(Raster::Image {
+            position,
+            size,
+            texture: texture.clone(),
+            uv,
+            tint,
+        });
+        self.quad(
+            (position.0.max(0.0), position.1.max(0.0)),
+            (position.0 + size.0, position.1 + size.1),
+        );
+    }
+
+    /// Convert the shapes drawn so far into the vertices of a widget whose top left corner is at
+    /// a vertex, the text and the images are sampled from the texture made by `texture`
+    pub(crate) fn draw_vertices<F>(
+        &self,
+        bl_vertex: Vertex,
+        window_size: [u32; 2],
+        texture: Option<&Texture>,
+        shape: F,
+    ) -> Vec<DrawVertex>
+    where
+        F: Fn(Vertex, [f32; 4]) -> DrawVertex,
+    {
+        self.vertices
+            .iter()
+            .map(|vertex| {
+                let position = Vertex {
+                    x: bl_vertex.x() + ((vertex.position.0 * 2.0) / window_size[0] as f32),
+                    y: bl_vertex.y() + ((vertex.position.1 * 2.0) / window_size[1] as f32),
+                };
+                let draw_vertex = shape(position, vertex.color);
+                match (vertex.uv, texture) {
+                    (Some(uv), Some(texture)) => draw_vertex.with_texture(texture.id(), uv),
+                    _ => draw_vertex,
+                }
+            })
+            .collect()
+    }
+
+    /// Retrieve the texture holding the text and the images drawn so far, if any. The texture of
+    /// the last frame is kept in a cache and handed out again while the same text and images are
+    /// drawn at the same size, so they are only rasterized and uploaded when they change.
+    pub(crate) fn texture(&self, cache: &TextureCache) -> Option<Texture> {
+        let mut cached = cache.cached.lock();
+        if self.rasters.is_empty() {
+            *cached = None;
+            return None;
+        }
+
+        let size = (self.width.ceil() as u32, self.height.ceil() as u32);
+        if let Some(ref rasterized) = *cached {
+            if (rasterized.size == size) & (rasterized.rasters == self.rasters) {
+                return Some(rasterized.texture.clone());
+            }
+        }
+
+        let texture = Texture::new(size.0, size.1, self.rasterize()).ok();
+        *cached = texture.clone().map(|texture| Rasterized {
+            size,
+            rasters: self.rasters.clone(),
+            texture,
+        });
+        texture
+    }
+
+    /// Blend the text and the images drawn so far into the pixels of the canvas
+    fn rasterize(&self) -> Vec<u8> {
+        let (width, height) = (self.width.ceil() as usize, self.height.ceil() as usize);
+        let mut pixels = vec![0; width * height * 4];
+
+        for raster in self.rasters.iter() {
+            match *raster {
+                Raster::Text {
+                    position,
+                    ref text,
+                    size,
+                    color,
+                    ref font,
+                } => {
+                    let color = color.into_scaled_rgba_float();
+                    for glyph in font.layout(text, size, position) {
+                        let bounding_box = match glyph.pixel_bounding_box() {
+                            None => continue,
+                            Some(val) => val,
+                        };
+
+                        glyph.draw(|x, y, coverage| {
+                            let x = bounding_box.min.x + x as i32;
+                            let y = bounding_box.min.y + y as i32;
+                            if (x < 0) | (y < 0) | (x as usize >= width) | (y as usize >= height) {
+                                return;
+                            }
+
+                            // Glyphs are blended over the text already drawn
+                            let i = ((y as usize * width) + x as usize) * 4;
+                            blend(&mut pixels[i..i + 4], color, coverage);
+                        });
+                    }
+                }
+                Raster::Image {
+                    position,
+                    size,
+                    ref texture,
+                    uv: (uv_min, uv_max),
+                    tint,
+                } => {
+                    let tint = tint.into_scaled_rgba_float();
+                    let min = (position.0.max(0.0), position.1.max(0.0));
+                    let max = (
+                        (position.0 + size.0).min(self.width),
+                        (position.1 + size.1).min(self.height),
+                    );
+
+                    // Every pixel whose center is inside the rectangle samples the texture at that
+                    // center
+                    for y in (min.1.round() as usize)..(max.1.round() as usize).min(height) {
+                        let v = (((y as f32 + 0.5) - position.1) / size.1) * (uv_max[1] - uv_min[1]);
+                        for x in (min.0.round() as usize)..(max.0.round() as usize).min(width) {
+                            let u = (((x as f32 + 0.5) - position.0) / size.0) * (uv_max[0] - uv_min[0]);
+                            let sample = texture.sample(uv_min[0] + u, uv_min[1] + v);
+                            let color = [
+                                sample[0] * tint[0],
+                                sample[1] * tint[1],
+                                sample[2] * tint[2],
+                                sample[3] * tint[3],
+                            ];
+                            let i = ((y * width) + x) * 4;
+                            blend(&mut pixels[i..i + 4], color, 1.0);
+                        }
+                    }
+                }
+            }
+        }
+
+        pixels
+    }
+
+    /// Show the pixels of the canvas between two corners through a quad sampling its texture
+    fn quad(&mut self, min: (f32, f32), max: (f32, f32)) {
+        let min = (min.0.max(0.0), min.1.max(0.0));
+        let max = (max.0.min(self.width), max.1.min(self.height));
+        if (min.0 >= max.0) | (min.1 >= max.1) {
+            return;
+        }
+
+        let (width, height) = (self.width.ceil(), self.height.ceil());
+        let corner = |x: f32, y: f32| PaintVertex {
+            position: (x, y),
+            color:    [1.0; 4],
+            uv:       Some([x / width, y / height]),
+        };
+        let tr = corner(max.0, min.1);
+        let tl = corner(min.0, min.1);
+        let bl = corner(min.0, max.1);
+        let br = corner(max.0, max.1);
+        self.vertices.extend_from_slice(&[tr, tl, bl, tr, br, bl]);
+    }
+
+    fn stroke(&mut self, points: &[(f32, f32)], closed: bool, width: f32, color: Color) {
+        let color = color.into_scaled_rgba_float();
+        let mut points = points.to_vec();
+        points.dedup();
+        if closed && (points.len() > 1) && (points.first() == points.last()) {
+            points.pop();
+        }
+        if (points.len() < 2) | (width <= 0.0) {
+            return;
+        }
+
+        // Every point is pushed out on both sides along the miter of its two segments, the
+        // miter is limited so sharp corners don't spike out
+        let half_width = width / 2.0;
+        let count = points.len();
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for i in 0..count {
+            let previous = if i > 0 {
+                Some(points[i - 1])
+            } else if closed {
+                Some(points[count - 1])
+            } else {
+                None
+            };
+            let next = if i + 1 < count {
+                Some(points[i + 1])
+            } else if closed {
+                Some(points[0])
+            } else {
+                None
+            };
+
+            let normal_in = previous.map(|previous| normal(previous, points[i]));
+            let normal_out = next.map(|next| normal(points[i], next));
+            let (miter, scale) = match (normal_in, normal_out) {
+                (Some(a), Some(b)) => {
+                    let miter = normalize((a.0 + b.0, a.1 + b.1)).unwrap_or(a);
+                    let cos = (miter.0 * a.0) + (miter.1 * a.1);
+                    (miter, (half_width / cos.max(0.25)))
+                }
+                (Some(a), None) | (None, Some(a)) => (a, half_width),
+                (None, None) => return,
+            };
+
+            left.push((points[i].0 + (miter.0 * scale), points[i].1 + (miter.1 * scale)));
+            right.push((points[i].0 - (miter.0 * scale), points[i].1 - (miter.1 * scale)));
+        }
+
+        let segments = if closed { count } else { count - 1 };
+        for i in 0..segments {
+            let j = (i + 1) % count;
+            self.triangle([left[i], left[j], right[j]], color);
+            self.triangle([left[i], right[j], right[i]], color);
+        }
+    }
+
+    fn triangle(&mut self, triangle: [(f32, f32); 3], color: [f32; 4]) {
+        let (width, height) = (self.width, self.height);
+        let clipped = clip_polygon(triangle.to_vec(), |point| point.0);
+        let clipped = clip_polygon(clipped, |point| width - point.0);
+        let clipped = clip_polygon(clipped, |point| point.1);
+        let clipped = clip_polygon(clipped, |point| height - point.1);
+
+        for i in 1..clipped.len().saturating_sub(1) {
+            for position in &[clipped[0], clipped[i], clipped[i + 1]] {
+                self.vertices.push(PaintVertex {
+                    position: *position,
+                    color,
+                    uv: None,
+                });
+            }
+        }
+    }
+}
+
+/// Blend a color over an RGBA pixel with a coverage from 0.0 to 1.0, the pixels keep straight
+/// alpha
+fn blend(pixel: &mut [u8], color: [f32; 4], coverage: f32) {
+    let alpha = coverage * color[3];
+    let below = f32::from(pixel[3]) / 255.0;
+    let out = alpha + (below * (1.0 - alpha));
+    if out > 0.0 {
+        for c in 0..3 {
+            let mixed = ((color[c] * alpha) + ((f32::from(pixel[c]) / 255.0) * below * (1.0 - alpha))) / out;
+            pixel[c] = (mixed * 255.0).round() as u8;
+        }
+    }
+    pixel[3] = (out * 255.0).round() as u8;
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+fn segments(length: f32) -> usize {
+    ((length / TOLERANCE).ceil() as usize).max(1).min(256)
+}
+
+fn normalize(vector: (f32, f32)) -> Option<(f32, f32)> {
+    let length = distance((0.0, 0.0), vector);
+    if length <= std::f32::EPSILON {
+        None
+    } else {
+        Some((vector.0 / length, vector.1 / length))
+    }
+}
+
+/// The unit normal on the left of the direction from a to b
+fn normal(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    normalize((a.1 - b.1, b.0 - a.0)).unwrap_or((0.0, 0.0))
+}
+
+fn rect_points(position: (f32, f32), size: (f32, f32)) -> Vec<(f32, f32)> {
+    vec![
+        position,
+        (position.0 + size.0, position.1),
+        (position.0 + size.0, position.1 + size.1),
+        (position.0, position.1 + size.1),
+    ]
+}
+
+fn circle_points(center: (f32, f32), radius: f32) -> Vec<(f32, f32)> {
+    let count = segments(2.0 * PI * radius).max(12);
+    (0..count)
+        .map(|i| {
+            let angle = (2.0 * PI * i as f32) / count as f32;
+            (center.0 + (radius * angle.cos()), center.1 + (radius * angle.sin()))
+        })
+        .collect()
+}
+
+fn cross(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> f32 {
+    ((b.0 - a.0) * (c.1 - a.1)) - ((b.1 - a.1) * (c.0 - a.0))
+}
+
+/// Split a simple polygon into triangles by clipping its ears
+fn triangulate(points: &[(f32, f32)]) -> Vec<[(f32, f32); 3]> {
+    let mut triangles = Vec::new();
+    let mut points = points.to_vec();
+    points.dedup();
+    if (points.len() > 1) && (points.first() == points.last()) {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return triangles;
+    }
+
+    // Ears are found on the same side as the winding of the polygon
+    let area: f32 = (0..points.len())
+        .map(|i| {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            (a.0 * b.1) - (b.0 * a.1)
+        })
+        .sum();
+    let winding = area.signum();
+
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let ear = (0..count).find(|&i| {
+            let a = points[remaining[(i + count - 1) % count]];
+            let b = points[remaining[i]];
+            let c = points[remaining[(i + 1) % count]];
+            if cross(a, b, c) * winding <= 0.0 {
+                return false;
+            }
+
+            remaining.iter().map(|&j| points[j]).all(|p| {
+                (p == a) | (p == b) | (p == c)
+                    || (cross(a, b, p) * winding < 0.0)
+                    || (cross(b, c, p) * winding < 0.0)
+                    || (cross(c, a, p) * winding < 0.0)
+            })
+        });
+
+        // Polygons crossing themselves have no ear left, the rest is fanned out instead
+        let i = match ear {
+            None => break,
+            Some(val) => val,
+        };
+
+        triangles.push([
+            points[remaining[(i + count - 1) % count]],
+            points[remaining[i]],
+            points[remaining[(i + 1) % count]],
+        ]);
+        remaining.remove(i);
+    }
+
+    for i in 1..remaining.len() - 1 {
+        triangles.push([points[remaining[0]], points[remaining[i]], points[remaining[i + 1]]]);
+    }
+
+    triangles
+}
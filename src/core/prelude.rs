@@ -10,20 +10,48 @@
 
 //! Traits and essential types intended for blanket imports.
 pub use self::super::{
+    border::{
+        Border,
+        CornerRadius,
+    },
     color::Color,
     enums::{
+        CheckState,
         DockSize,
+        Fit,
+        Layer,
         Orientation,
+        Overflow,
         Position,
         Run,
+        SelectionMode,
         Size,
+        SortOrder,
         WidgetType,
     },
+    fill::{
+        ColorStop,
+        Fill,
+    },
+    font::Font,
+    input::InputEvent,
+    nine_slice::NineSlice,
+    painter::{
+        Painter,
+        Path,
+    },
+    shadow::Shadow,
+    svg::Svg,
+    text_edit::TextEdit,
+    texture::Texture,
     theme::Theme,
     traits::{
         ButtonTrait,
         DockTrait,
+        ListModel,
+        TableModel,
         TextTrait,
+        TreeModel,
         WidgetTrait,
     },
     DrawVertex,
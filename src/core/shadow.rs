@@ -0,0 +1,93 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! The soft shadow drawn behind a widget to lift it above the content beneath it.
+
+use self::super::{
+    err_msg,
+    Color,
+    Error,
+};
+
+/// The shadow cast by a widget, drawn with the shape of the widget behind it
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The offset of the shadow from the widget in pixels
+    offset:      (f32, f32),
+    /// The distance in pixels over which the edge of the shadow fades out
+    blur_radius: f32,
+    /// The color of the shadow
+    color:       Color,
+}
+
+impl Shadow {
+    /// Create a shadow
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::*;
+    ///
+    /// let shadow = Shadow::new((0.0, 2.0), 8.0, Color::from_hex("#00000066").unwrap()).unwrap();
+    /// assert_eq!(shadow.offset(), (0.0, 2.0));
+    /// assert!(Shadow::new((0.0, 2.0), -8.0, Color::default()).is_err());
+    /// ```
+    pub fn new(offset: (f32, f32), blur_radius: f32, color: Color) -> Result<Self, Error> {
+        if blur_radius < 0.0 {
+            return Err(err_msg("The shadow blur radius must be 0 or greater"));
+        }
+
+        Ok(Self {
+            offset,
+            blur_radius,
+            color,
+        })
+    }
+
+    /// Create the shadow of a widget floating at an elevation in pixels above the content, the
+    /// higher the elevation the further the shadow drops and the softer it gets
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::*;
+    ///
+    /// let shadow = Shadow::from_elevation(4.0, Color::from_hex("#00000066").unwrap()).unwrap();
+    /// assert_eq!(shadow.offset(), (0.0, 4.0));
+    /// assert_eq!(shadow.blur_radius(), 8.0);
+    /// ```
+    pub fn from_elevation(elevation: f32, color: Color) -> Result<Self, Error> {
+        if elevation < 0.0 {
+            return Err(err_msg("The elevation must be 0 or greater"));
+        }
+
+        Self::new((0.0, elevation), elevation * 2.0, color)
+    }
+
+    /// Retrieve the offset of the shadow in pixels
+    pub fn offset(self) -> (f32, f32) {
+        self.offset
+    }
+
+    /// Retrieve the blur radius of the shadow in pixels
+    pub fn blur_radius(self) -> f32 {
+        self.blur_radius
+    }
+
+    /// Retrieve the color of the shadow
+    pub fn color(self) -> Color {
+        self.color
+    }
+
+    /// Whether the shadow can be seen at all, a sharp shadow right behind the widget is hidden
+    pub fn visible(self) -> bool {
+        (self.color.alpha() > 0) & ((self.blur_radius > 0.0) | (self.offset != (0.0, 0.0)))
+    }
+}
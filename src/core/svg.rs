@@ -0,0 +1,132 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Vector images, rasterized into textures at the size they are drawn so they stay crisp at any
+//! scale.
+
+use self::super::{
+    err_msg,
+    Error,
+    Texture,
+};
+use std::{
+    fmt,
+    fs,
+    path::Path,
+    sync::Arc,
+};
+use tiny_skia::{
+    Pixmap,
+    Transform,
+};
+use usvg::{
+    FitTo,
+    Options,
+    Tree,
+};
+
+/// An SVG document along with its size in pixels
+#[derive(Clone)]
+pub struct Svg {
+    data:   Arc<Vec<u8>>,
+    width:  f32,
+    height: f32,
+}
+
+impl Svg {
+    /// Parse an SVG document from its bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let tree = parse(bytes)?;
+        let size = tree.svg_node().size;
+
+        Ok(Self {
+            data:   Arc::new(bytes.to_vec()),
+            width:  size.width() as f32,
+            height: size.height() as f32,
+        })
+    }
+
+    /// Parse an SVG document from a file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::Svg;
+    ///
+    /// let logo = Svg::from_path("assets/logo.svg").unwrap();
+    /// let texture = logo.rasterize(64, 24).unwrap();
+    /// assert_eq!((texture.width(), texture.height()), (64, 24));
+    /// ```
+    pub fn from_path<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        match fs::read(path.as_ref()) {
+            Err(err) => Err(err_msg(format!(
+                "Failed to load the svg {}: {}",
+                path.as_ref().display(),
+                err
+            ))),
+            Ok(val) => Self::from_bytes(&val),
+        }
+    }
+
+    /// Retrieve the width in pixels the document was drawn at
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Retrieve the height in pixels the document was drawn at
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Draw the document stretched over a texture of the given size in pixels
+    pub fn rasterize(&self, width: u32, height: u32) -> Result<Texture, Error> {
+        let tree = parse(&self.data)?;
+
+        let mut pixmap = match Pixmap::new(width, height) {
+            None => {
+                return Err(err_msg("A rasterized svg must be at least 1 pixel wide and high"));
+            }
+            Some(val) => val,
+        };
+
+        let transform = Transform::from_scale(width as f32 / self.width, height as f32 / self.height);
+        if resvg::render(&tree, FitTo::Original, transform, pixmap.as_mut()).is_none() {
+            return Err(err_msg("Failed to rasterize the svg"));
+        }
+
+        // Textures hold straight alpha
+        let mut pixels = Vec::with_capacity(pixmap.pixels().len() * 4);
+        for pixel in pixmap.pixels() {
+            let pixel = pixel.demultiply();
+            pixels.extend_from_slice(&[pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]);
+        }
+
+        Texture::new(width, height, pixels)
+    }
+}
+
+impl fmt::Debug for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Svg")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+fn parse(bytes: &[u8]) -> Result<Tree, Error> {
+    match Tree::from_data(bytes, &Options::default().to_ref()) {
+        Err(err) => Err(err_msg(format!("Failed to parse the svg: {}", err))),
+        Ok(val) => Ok(val),
+    }
+}
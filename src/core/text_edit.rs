@@ -0,0 +1,641 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! The editable text behind the text widgets, with a caret, a selection and an undo history
+
+use self::super::{
+    widgets::window::{
+        ModifiersState,
+        VirtualKeyCode,
+    },
+    Ui,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The number of edits that can be undone
+const HISTORY: usize = 100;
+
+/// The kinds of edits, consecutive edits of the same kind are undone together
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EditKind {
+    Typing,
+    Deleting,
+    Pasting,
+    Cutting,
+}
+
+/// The text, caret and anchor before an edit
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    text:   String,
+    caret:  usize,
+    anchor: usize,
+}
+
+/// The edits that can be undone and redone
+#[derive(Debug, Clone, Default, PartialEq)]
+struct History {
+    undo:  Vec<Snapshot>,
+    redo:  Vec<Snapshot>,
+    group: Option<EditKind>,
+}
+
+/// A text being edited, with the caret and the anchor of the selection as byte offsets. The caret
+/// moves and deletes by grapheme cluster, so that a letter and its accents or a composed emoji are
+/// edited as a single character, and the text composed by an input method is kept apart until it
+/// is committed.
+///
+/// # Examples
+///
+/// ```
+/// use o2tk::prelude::*;
+///
+/// let mut edit = TextEdit::new("hello world");
+/// edit.move_word_left(true);
+/// assert_eq!("world", edit.selected_text());
+/// edit.insert("there");
+/// assert_eq!("hello there", edit.text());
+/// edit.undo();
+/// assert_eq!("hello world", edit.text());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextEdit {
+    text:            String,
+    caret:           usize,
+    anchor:          usize,
+    max_length:      Option<usize>,
+    history:         History,
+    composing:       String,
+    composing_caret: usize,
+}
+
+impl TextEdit {
+    /// Start editing a text with the caret at its end
+    pub fn new<V: Into<String>>(text: V) -> Self {
+        let mut edit = Self::default();
+        edit.set_text(text);
+        edit
+    }
+
+    /// Retrieve the text
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Replace the text, moving the caret to its end and forgetting the edits to undo
+    pub fn set_text<V: Into<String>>(&mut self, text: V) {
+        self.text = text.into();
+        if let Some(max_length) = self.max_length {
+            self.text = self.text.graphemes(true).take(max_length).collect();
+        }
+        self.caret = self.text.len();
+        self.anchor = self.caret;
+        self.history = History::default();
+    }
+
+    /// Retrieve the maximum number of graphemes
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Set the maximum number of graphemes, cutting the text down to it
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+        if let Some(max_length) = max_length {
+            if self.text.graphemes(true).count() > max_length {
+                let text = self.text();
+                self.set_text(text);
+            }
+        }
+    }
+
+    /// Retrieve the position of the caret
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Retrieve the position the selection extends from to the caret
+    pub fn anchor(&self) -> usize {
+        self.anchor
+    }
+
+    /// Retrieve the start and end of the selection, if any text is selected
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        if self.caret == self.anchor {
+            None
+        } else {
+            Some((self.caret.min(self.anchor), self.caret.max(self.anchor)))
+        }
+    }
+
+    /// Retrieve the selected text
+    pub fn selected_text(&self) -> String {
+        match self.selection() {
+            None => String::new(),
+            Some((start, end)) => self.text[start..end].to_string(),
+        }
+    }
+
+    /// Move the caret to a position, extending the selection or starting over. The next edit won't
+    /// be undone along with the previous ones.
+    pub fn set_caret(&mut self, position: usize, select: bool) {
+        self.place(position, select);
+        self.history.group = None;
+    }
+
+    /// Retrieve the line and the column, counted in graphemes from 0, of a position
+    pub fn line_column(&self, position: usize) -> (usize, usize) {
+        let position = position.min(self.text.len());
+        let start = self.text[..position].rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        (
+            self.text[..start].matches('\n').count(),
+            self.text[start..position].graphemes(true).count(),
+        )
+    }
+
+    /// Retrieve the position at a line and a column, counted in graphemes from 0, the column being
+    /// limited to the end of the line and the line to the last one
+    pub fn position_of(&self, line: usize, column: usize) -> usize {
+        let start = if line == 0 {
+            0
+        } else {
+            match self.text.match_indices('\n').nth(line - 1) {
+                None => self.text.rfind('\n').map(|i| i + 1).unwrap_or(0),
+                Some((i, _)) => i + 1,
+            }
+        };
+        let end = self.text[start..]
+            .find('\n')
+            .map(|i| start + i)
+            .unwrap_or_else(|| self.text.len());
+
+        self.text[start..end]
+            .grapheme_indices(true)
+            .nth(column)
+            .map(|(i, _)| start + i)
+            .unwrap_or(end)
+    }
+
+    /// Retrieve the number of lines
+    pub fn line_count(&self) -> usize {
+        self.text.matches('\n').count() + 1
+    }
+
+    /// Move the caret one character to the left, or to the start of the selection
+    pub fn move_left(&mut self, select: bool) {
+        match self.selection() {
+            Some((start, _)) if !select => self.set_caret(start, false),
+            _ => {
+                let position = self.previous_boundary(self.caret);
+                self.set_caret(position, select);
+            }
+        }
+    }
+
+    /// Move the caret one character to the right, or to the end of the selection
+    pub fn move_right(&mut self, select: bool) {
+        match self.selection() {
+            Some((_, end)) if !select => self.set_caret(end, false),
+            _ => {
+                let position = self.next_boundary(self.caret);
+                self.set_caret(position, select);
+            }
+        }
+    }
+
+    /// Move the caret to the start of the word on its left
+    pub fn move_word_left(&mut self, select: bool) {
+        let position = self.previous_word(self.caret);
+        self.set_caret(position, select);
+    }
+
+    /// Move the caret to the end of the word on its right
+    pub fn move_word_right(&mut self, select: bool) {
+        let position = self.next_word(self.caret);
+        self.set_caret(position, select);
+    }
+
+    /// Move the caret to the start of the text
+    pub fn move_home(&mut self, select: bool) {
+        self.set_caret(0, select);
+    }
+
+    /// Move the caret to the end of the text
+    pub fn move_end(&mut self, select: bool) {
+        let position = self.text.len();
+        self.set_caret(position, select);
+    }
+
+    /// Select the whole text
+    pub fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.text.len();
+        self.history.group = None;
+    }
+
+    /// Replace the selection with a typed text, or insert it at the caret, returning whether the
+    /// text changed. Only the characters fitting under the maximum length are inserted.
+    pub fn insert(&mut self, text: &str) -> bool {
+        self.replace_selection(text, EditKind::Typing)
+    }
+
+    /// Replace the selection with a pasted text, or insert it at the caret, returning whether the
+    /// text changed. Unlike typing, every paste is undone on its own.
+    pub fn paste(&mut self, text: &str) -> bool {
+        self.replace_selection(text, EditKind::Pasting)
+    }
+
+    /// Retrieve the text being composed by an input method, shown in place of the selection until
+    /// it is committed
+    pub fn composition(&self) -> String {
+        self.composing.clone()
+    }
+
+    /// Retrieve the position of the caret in the text being composed
+    pub fn composition_caret(&self) -> usize {
+        self.composing_caret
+    }
+
+    /// Whether an input method is composing a text
+    pub fn composing(&self) -> bool {
+        !self.composing.is_empty()
+    }
+
+    /// Set the text being composed by an input method and the position of the caret in it, an
+    /// empty text cancelling the composition
+    pub fn set_composition<V: Into<String>>(&mut self, text: V, caret: usize) {
+        self.composing = text.into();
+
+        let mut caret = caret.min(self.composing.len());
+        while !self.composing.is_char_boundary(caret) {
+            caret -= 1;
+        }
+        self.composing_caret = caret;
+    }
+
+    /// Replace the selection with the text committed by an input method, ending the composition,
+    /// and retrieve whether the text changed
+    pub fn commit(&mut self, text: &str) -> bool {
+        self.set_composition("", 0);
+        self.insert(text)
+    }
+
+    /// Retrieve the text with the composition in place of the selection, along with the caret and
+    /// the start and end of the composition in it
+    pub(crate) fn composed(&self) -> (String, usize, Option<(usize, usize)>) {
+        if !self.composing() {
+            return (self.text(), self.caret, None);
+        }
+
+        let (start, end) = self.selection().unwrap_or((self.caret, self.caret));
+        let mut text = self.text.clone();
+        text.replace_range(start..end, &self.composing);
+
+        (
+            text,
+            start + self.composing_caret,
+            Some((start, start + self.composing.len())),
+        )
+    }
+
+    /// Whether an edit can be undone
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    /// Whether an undone edit can be redone
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    /// Undo the last edit, or the last run of edits of the same kind, returning whether the text
+    /// changed
+    pub fn undo(&mut self) -> bool {
+        match self.history.undo.pop() {
+            None => false,
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.history.redo.push(current);
+                true
+            }
+        }
+    }
+
+    /// Redo the last undone edit, returning whether the text changed
+    pub fn redo(&mut self) -> bool {
+        match self.history.redo.pop() {
+            None => false,
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.history.undo.push(current);
+                true
+            }
+        }
+    }
+
+    /// Delete the selection or the character before the caret, returning whether the text
+    /// changed
+    pub fn delete_backward(&mut self) -> bool {
+        let position = self.previous_boundary(self.caret);
+        self.delete_to(position)
+    }
+
+    /// Delete the selection or the character after the caret, returning whether the text changed
+    pub fn delete_forward(&mut self) -> bool {
+        let position = self.next_boundary(self.caret);
+        self.delete_to(position)
+    }
+
+    /// Delete the selection or the word before the caret, returning whether the text changed
+    pub fn delete_word_backward(&mut self) -> bool {
+        let position = self.previous_word(self.caret);
+        self.delete_to(position)
+    }
+
+    /// Delete the selection or the word after the caret, returning whether the text changed
+    pub fn delete_word_forward(&mut self) -> bool {
+        let position = self.next_word(self.caret);
+        self.delete_to(position)
+    }
+
+    /// Remove the selected text and retrieve it, if any text is selected
+    pub fn cut(&mut self) -> Option<String> {
+        let text = self.selected_text();
+        if text.is_empty() {
+            None
+        } else {
+            self.replace_selection("", EditKind::Cutting);
+            Some(text)
+        }
+    }
+
+    /// Apply the editing keys shared by the text widgets, returning whether the text changed or
+    /// nothing if the key isn't one of them. New lines are only pasted in multi-line texts.
+    pub(crate) fn handle_key(
+        &mut self,
+        ui: &Ui,
+        key: VirtualKeyCode,
+        modifiers: ModifiersState,
+        multiline: bool,
+    ) -> Option<bool> {
+        let select = modifiers.shift;
+        let command = modifiers.ctrl | modifiers.logo;
+        let changed = match key {
+            VirtualKeyCode::Left if command => {
+                self.move_word_left(select);
+                false
+            }
+            VirtualKeyCode::Left => {
+                self.move_left(select);
+                false
+            }
+            VirtualKeyCode::Right if command => {
+                self.move_word_right(select);
+                false
+            }
+            VirtualKeyCode::Right => {
+                self.move_right(select);
+                false
+            }
+            VirtualKeyCode::Home => {
+                self.move_home(select);
+                false
+            }
+            VirtualKeyCode::End => {
+                self.move_end(select);
+                false
+            }
+            VirtualKeyCode::A if command => {
+                self.select_all();
+                false
+            }
+            VirtualKeyCode::Back if command => self.delete_word_backward(),
+            VirtualKeyCode::Back => self.delete_backward(),
+            VirtualKeyCode::Delete if command => self.delete_word_forward(),
+            VirtualKeyCode::Delete => self.delete_forward(),
+            VirtualKeyCode::C if command => {
+                if self.selection().is_some() {
+                    ui.set_clipboard(self.selected_text());
+                }
+                false
+            }
+            VirtualKeyCode::X if command => match self.cut() {
+                None => false,
+                Some(text) => {
+                    ui.set_clipboard(text);
+                    true
+                }
+            },
+            VirtualKeyCode::V if command => {
+                let text: String = ui
+                    .clipboard()
+                    .replace("\r\n", "\n")
+                    .chars()
+                    .filter(|c| !c.is_control() || (multiline && ((*c == '\n') | (*c == '\t'))))
+                    .collect();
+                self.paste(&text)
+            }
+            VirtualKeyCode::Z if command && select => self.redo(),
+            VirtualKeyCode::Z if command => self.undo(),
+            VirtualKeyCode::Y if command => self.redo(),
+            _ => {
+                return None;
+            }
+        };
+
+        Some(changed)
+    }
+
+    /// Delete the selection, or the text between the caret and a position
+    fn delete_to(&mut self, position: usize) -> bool {
+        let (start, end) = self
+            .selection()
+            .unwrap_or((position.min(self.caret), position.max(self.caret)));
+        self.replace(start, end, "", EditKind::Deleting)
+    }
+
+    /// Replace the selection with a text, or insert it at the caret, as an edit of a kind
+    fn replace_selection(&mut self, text: &str, kind: EditKind) -> bool {
+        let (start, end) = self.selection().unwrap_or((self.caret, self.caret));
+        self.replace(start, end, text, kind)
+    }
+
+    /// Replace the text between two positions, returning whether the text changed
+    fn replace(&mut self, start: usize, end: usize, text: &str, kind: EditKind) -> bool {
+        let text: String = match self.max_length {
+            None => text.to_string(),
+            Some(max_length) => {
+                let kept = self.text.graphemes(true).count() - self.text[start..end].graphemes(true).count();
+                text.graphemes(true).take(max_length.saturating_sub(kept)).collect()
+            }
+        };
+
+        if text.is_empty() && (start == end) {
+            return false;
+        }
+
+        self.record(kind);
+        self.text.replace_range(start..end, &text);
+        self.place(start + text.len(), false);
+        true
+    }
+
+    /// Move the caret without ending the run of edits to undo together
+    fn place(&mut self, position: usize, select: bool) {
+        let mut position = position.min(self.text.len());
+        while !self.text.is_char_boundary(position) {
+            position -= 1;
+        }
+        let position = grapheme_floor(&self.text, position);
+
+        self.caret = position;
+        if !select {
+            self.anchor = position;
+        }
+    }
+
+    /// Remember the text before an edit, unless it continues a run of edits of the same kind
+    fn record(&mut self, kind: EditKind) {
+        self.history.redo.clear();
+        if (self.history.group == Some(kind)) & ((kind == EditKind::Typing) | (kind == EditKind::Deleting)) {
+            return;
+        }
+
+        self.history.undo.push(Snapshot {
+            text:   self.text.clone(),
+            caret:  self.caret,
+            anchor: self.anchor,
+        });
+        if self.history.undo.len() > HISTORY {
+            self.history.undo.remove(0);
+        }
+        self.history.group = Some(kind);
+    }
+
+    /// Go back to a snapshot, retrieving the current one
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let current = Snapshot {
+            text:   self.text.clone(),
+            caret:  self.caret,
+            anchor: self.anchor,
+        };
+
+        self.text = snapshot.text;
+        self.caret = snapshot.caret;
+        self.anchor = snapshot.anchor;
+        self.history.group = None;
+        current
+    }
+
+    fn previous_boundary(&self, position: usize) -> usize {
+        previous_grapheme(&self.text, position)
+    }
+
+    fn next_boundary(&self, position: usize) -> usize {
+        next_grapheme(&self.text, position)
+    }
+
+    /// The clusters of the text as their start, end and first character
+    fn clusters(&self) -> Vec<(usize, usize, char)> {
+        let boundaries: Vec<usize> = self.text.grapheme_indices(true).map(|(i, _)| i).collect();
+        boundaries
+            .iter()
+            .enumerate()
+            .map(|(n, &start)| {
+                let end = boundaries.get(n + 1).cloned().unwrap_or(self.text.len());
+                (start, end, self.text[start..].chars().next().unwrap())
+            })
+            .collect()
+    }
+
+    /// The whitespace before the caret is skipped, then the clusters of the same kind
+    fn previous_word(&self, position: usize) -> usize {
+        let mut clusters = self
+            .clusters()
+            .into_iter()
+            .rev()
+            .skip_while(|(start, ..)| *start >= position)
+            .skip_while(|(_, _, c)| c.is_whitespace());
+        let kind = match clusters.next() {
+            None => {
+                return 0;
+            }
+            Some((start, _, c)) => (start, word_kind(c)),
+        };
+
+        clusters
+            .take_while(|(_, _, c)| word_kind(*c) == kind.1)
+            .last()
+            .map(|(start, ..)| start)
+            .unwrap_or(kind.0)
+    }
+
+    /// The whitespace after the caret is skipped, then the clusters of the same kind
+    fn next_word(&self, position: usize) -> usize {
+        let mut clusters = self
+            .clusters()
+            .into_iter()
+            .skip_while(|(start, ..)| *start < position)
+            .skip_while(|(_, _, c)| c.is_whitespace());
+        let kind = match clusters.next() {
+            None => {
+                return self.text.len();
+            }
+            Some((_, end, c)) => (end, word_kind(c)),
+        };
+
+        clusters
+            .take_while(|(_, _, c)| word_kind(*c) == kind.1)
+            .last()
+            .map(|(_, end, _)| end)
+            .unwrap_or(kind.0)
+    }
+}
+
+/// Words are made of letters, digits and underscores, runs of any other characters but whitespace
+/// make words of their own
+fn word_kind(c: char) -> u8 {
+    if c.is_alphanumeric() | (c == '_') {
+        0
+    } else if c.is_whitespace() {
+        1
+    } else {
+        2
+    }
+}
+
+/// Retrieve the start of the grapheme cluster a position is in
+fn grapheme_floor(text: &str, position: usize) -> usize {
+    text.grapheme_indices(true)
+        .map(|(i, _)| i)
+        .chain(Some(text.len()))
+        .take_while(|i| *i <= position)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Retrieve the start of the grapheme cluster before a position
+pub(crate) fn previous_grapheme(text: &str, position: usize) -> usize {
+    let start = grapheme_floor(text, position);
+    text.grapheme_indices(true)
+        .map(|(i, _)| i)
+        .take_while(|i| *i < start)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Retrieve the end of the grapheme cluster a position is in
+fn next_grapheme(text: &str, position: usize) -> usize {
+    text.grapheme_indices(true)
+        .map(|(i, _)| i)
+        .find(|i| *i > position)
+        .unwrap_or(text.len())
+}
@@ -0,0 +1,182 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! The decoded pixels of an image, uploaded once by the renderer and drawn by any widget showing
+//! it.
+
+use self::super::{
+    err_msg,
+    Error,
+};
+use image::{
+    self,
+    DynamicImage,
+};
+use std::{
+    fmt,
+    path::Path,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+static NEXT_TEXTURE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The pixels of an image in 8 bit RGBA, rows from top to bottom
+#[derive(Clone)]
+pub struct Texture {
+    id:     usize,
+    width:  u32,
+    height: u32,
+    pixels: Arc<Vec<u8>>,
+}
+
+impl Texture {
+    /// Create a texture from its width and height in pixels along with 4 bytes (RGBA) per pixel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::Texture;
+    ///
+    /// let texture = Texture::new(2, 1, vec![255; 8]).unwrap();
+    /// assert_eq!((texture.width(), texture.height()), (2, 1));
+    /// assert!(Texture::new(2, 2, vec![255; 8]).is_err());
+    /// ```
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Result<Self, Error> {
+        if (width == 0) | (height == 0) {
+            return Err(err_msg("A texture must be at least 1 pixel wide and high"));
+        }
+
+        if pixels.len() != (width as usize * height as usize * 4) {
+            return Err(err_msg("A texture needs 4 bytes (RGBA) for each of its pixels"));
+        }
+
+        Ok(Self {
+            id: NEXT_TEXTURE_ID.fetch_add(1, Ordering::Relaxed),
+            width,
+            height,
+            pixels: Arc::new(pixels),
+        })
+    }
+
+    /// Decode a PNG or JPEG image from its bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match image::load_from_memory(bytes) {
+            Err(err) => Err(err_msg(format!("Failed to decode the image: {}", err))),
+            Ok(val) => Self::from_image(val),
+        }
+    }
+
+    /// Decode a PNG or JPEG image from a file
+    pub fn from_path<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        match image::open(path.as_ref()) {
+            Err(err) => Err(err_msg(format!(
+                "Failed to load the image {}: {}",
+                path.as_ref().display(),
+                err
+            ))),
+            Ok(val) => Self::from_image(val),
+        }
+    }
+
+    fn from_image(image: DynamicImage) -> Result<Self, Error> {
+        let image = image.to_rgba();
+        let (width, height) = image.dimensions();
+        Self::new(width, height, image.into_raw())
+    }
+
+    /// Retrieve the identifier the renderer uses to upload the texture once
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Retrieve the width in pixels
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Retrieve the height in pixels
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Retrieve the RGBA bytes of every pixel, rows from top to bottom
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Sample the color at the uv coordinates from (0.0, 0.0) for the top left to (1.0, 1.0) for
+    /// the bottom right, blending the four nearest pixels and clamping to the edges the way the
+    /// renderer does. The color is in RGBA from 0.0 to 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use o2tk::prelude::Texture;
+    ///
+    /// let texture = Texture::new(2, 1, vec![0, 0, 0, 255, 255, 255, 255, 255]).unwrap();
+    /// assert_eq!(texture.sample(0.0, 0.5), [0.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(texture.sample(0.5, 0.5), [0.5, 0.5, 0.5, 1.0]);
+    /// ```
+    pub fn sample(&self, u: f32, v: f32) -> [f32; 4] {
+        let x = ((u * self.width as f32) - 0.5).max(0.0).min((self.width - 1) as f32);
+        let y = ((v * self.height as f32) - 0.5).max(0.0).min((self.height - 1) as f32);
+        let (x0, y0) = (x.floor() as u32, y.floor() as u32);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+
+        let pixel =
+            |x: u32, y: u32, c: usize| f32::from(self.pixels[(((y * self.width) + x) as usize * 4) + c]) / 255.0;
+        let mut color = [0.0; 4];
+        for (c, value) in color.iter_mut().enumerate() {
+            let top = pixel(x0, y0, c) + ((pixel(x1, y0, c) - pixel(x0, y0, c)) * tx);
+            let bottom = pixel(x0, y1, c) + ((pixel(x1, y1, c) - pixel(x0, y1, c)) * tx);
+            *value = top + ((bottom - top) * ty);
+        }
+
+        color
+    }
+}
+
+impl Default for Texture {
+    /// A single white pixel, drawn as the color it is tinted with
+    fn default() -> Self {
+        Self {
+            id:     usize::max_value(),
+            width:  1,
+            height: 1,
+            pixels: Arc::new(vec![255; 4]),
+        }
+    }
+}
+
+impl PartialEq for Texture {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl fmt::Debug for Texture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Texture")
+            .field("id", &self.id)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
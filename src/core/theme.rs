@@ -13,7 +13,12 @@
 use self::super::{
     err_msg,
     Color,
+    CornerRadius,
     Error,
+    Fill,
+    Font,
+    NineSlice,
+    Shadow,
 };
 use serde::de::DeserializeOwned;
 use serde_derive::Deserialize;
@@ -38,27 +43,102 @@ where
     }
 }
 
+/// The shadow cast by a type of widget, starting from its elevation when one is given
+#[derive(Debug, Default, Deserialize, Clone)]
+struct ShadowTheme {
+    elevation:   Option<f32>,
+    offset:      Option<(f32, f32)>,
+    blur_radius: Option<f32>,
+    color:       Option<String>,
+}
+
 /// The object containing the api to theme all widgets
 #[derive(Debug, Deserialize, Clone)]
 pub struct Theme {
-    bar:              String,
-    container:        String,
-    dock:             String,
-    label_text:       String,
-    label_background: String,
-    window_container: String,
+    bar:                    String,
+    container:              String,
+    dock:                   String,
+    label_text:             String,
+    label_background:       String,
+    window_container:       String,
+    border:                 Option<String>,
+    border_width:           Option<f32>,
+    corner_radius:          Option<f32>,
+    shadow:                 Option<String>,
+    bar_shadow:             Option<ShadowTheme>,
+    container_shadow:       Option<ShadowTheme>,
+    dock_shadow:            Option<ShadowTheme>,
+    label_shadow:           Option<ShadowTheme>,
+    menu_shadow:            Option<ShadowTheme>,
+    dialog_shadow:          Option<ShadowTheme>,
+    tooltip_shadow:         Option<ShadowTheme>,
+    button_image:           Option<String>,
+    button_image_insets:    Option<[u32; 4]>,
+    container_image:        Option<String>,
+    container_image_insets: Option<[u32; 4]>,
+    dock_image:             Option<String>,
+    dock_image_insets:      Option<[u32; 4]>,
+    font:                   Option<String>,
+    text_input:             Option<String>,
+    text_input_text:        Option<String>,
+    placeholder:            Option<String>,
+    selection:              Option<String>,
+    check:                  Option<String>,
+    checked:                Option<String>,
+    unchecked:              Option<String>,
+    disabled:               Option<String>,
+    dim:                    Option<String>,
 }
 
 impl Theme {
     /// Use default colors
     pub fn default() -> Self {
         Self {
-            bar:              String::from("#161B6DFF"),
-            container:        String::from("#161B1DFF"),
-            dock:             String::from("#161B6DFF"),
-            label_text:       String::from("#FFFFFFFF"),
-            label_background: String::from("#161B1DFF"),
-            window_container: String::from("#161B3DFF"),
+            bar:                    String::from("#161B6DFF"),
+            container:              String::from("#161B1DFF"),
+            dock:                   String::from("#161B6DFF"),
+            label_text:             String::from("#FFFFFFFF"),
+            label_background:       String::from("#161B1DFF"),
+            window_container:       String::from("#161B3DFF"),
+            border:                 Some(String::from("#00000000")),
+            border_width:           Some(0.0),
+            corner_radius:          Some(0.0),
+            shadow:                 Some(String::from("#00000066")),
+            bar_shadow:             None,
+            container_shadow:       None,
+            dock_shadow:            Some(ShadowTheme {
+                elevation: Some(4.0),
+                ..ShadowTheme::default()
+            }),
+            label_shadow:           None,
+            menu_shadow:            Some(ShadowTheme {
+                elevation: Some(8.0),
+                ..ShadowTheme::default()
+            }),
+            dialog_shadow:          Some(ShadowTheme {
+                elevation: Some(24.0),
+                ..ShadowTheme::default()
+            }),
+            tooltip_shadow:         Some(ShadowTheme {
+                elevation: Some(4.0),
+                ..ShadowTheme::default()
+            }),
+            button_image:           None,
+            button_image_insets:    None,
+            container_image:        None,
+            container_image_insets: None,
+            dock_image:             None,
+            dock_image_insets:      None,
+            font:                   None,
+            text_input:             Some(String::from("#0E1113FF")),
+            text_input_text:        Some(String::from("#FFFFFFFF")),
+            placeholder:            Some(String::from("#FFFFFF80")),
+            selection:              Some(String::from("#3D6DCC99")),
+            check:                  Some(String::from("#00000000")),
+            checked:                Some(String::from("#3D6DCCFF")),
+            unchecked:              Some(String::from("#0E1113FF")),
+            disabled:               Some(String::from("#5A5F66FF")),
+            dim:                    Some(String::from("#00000080")),
         }
     }
 
@@ -101,17 +181,32 @@ impl Theme {
 
     /// Retrieve the color for the bar color
     pub fn bar_color(&self) -> Result<Color, Error> {
-        Color::from_hex(self.clone().bar)
+        Ok(self.bar_fill()?.color())
+    }
+
+    /// Retrieve the fill for the bar
+    pub fn bar_fill(&self) -> Result<Fill, Error> {
+        Fill::parse(self.clone().bar)
     }
 
     /// Retrieve the color for the container color
     pub fn container_color(&self) -> Result<Color, Error> {
-        Color::from_hex(self.clone().container)
+        Ok(self.container_fill()?.color())
+    }
+
+    /// Retrieve the fill for the container
+    pub fn container_fill(&self) -> Result<Fill, Error> {
+        Fill::parse(self.clone().container)
     }
 
     /// Retrieve the color for the dock color
     pub fn dock_color(&self) -> Result<Color, Error> {
-        Color::from_hex(self.clone().dock)
+        Ok(self.dock_fill()?.color())
+    }
+
+    /// Retrieve the fill for the dock
+    pub fn dock_fill(&self) -> Result<Fill, Error> {
+        Fill::parse(self.clone().dock)
     }
 
     /// Retrieve the color for the label text
@@ -121,11 +216,210 @@ impl Theme {
 
     /// Retrieve the color for the label background
     pub fn label_background_color(&self) -> Result<Color, Error> {
-        Color::from_hex(self.clone().label_background)
+        Ok(self.label_background_fill()?.color())
+    }
+
+    /// Retrieve the fill for the label background
+    pub fn label_background_fill(&self) -> Result<Fill, Error> {
+        Fill::parse(self.clone().label_background)
     }
 
     /// Retrieve the color for the window container color
     pub fn window_container_color(&self) -> Result<Color, Error> {
-        Color::from_hex(self.clone().window_container)
+        Ok(self.window_container_fill()?.color())
+    }
+
+    /// Retrieve the fill for the window container
+    pub fn window_container_fill(&self) -> Result<Fill, Error> {
+        Fill::parse(self.clone().window_container)
+    }
+
+    /// Retrieve the color for the widget borders
+    pub fn border_color(&self) -> Result<Color, Error> {
+        match self.clone().border {
+            None => Color::from_hex("#00000000"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the width in pixels for the widget borders
+    pub fn border_width(&self) -> f32 {
+        self.border_width.unwrap_or(0.0)
+    }
+
+    /// Retrieve the radius in pixels for the widget corners
+    pub fn corner_radius(&self) -> Result<CornerRadius, Error> {
+        CornerRadius::uniform(self.corner_radius.unwrap_or(0.0))
+    }
+
+    /// Retrieve the color for the widget shadows
+    pub fn shadow_color(&self) -> Result<Color, Error> {
+        match self.clone().shadow {
+            None => Color::from_hex("#00000066"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the shadow for the bar
+    pub fn bar_shadow(&self) -> Result<Shadow, Error> {
+        self.shadow(self.clone().bar_shadow)
+    }
+
+    /// Retrieve the shadow for the container
+    pub fn container_shadow(&self) -> Result<Shadow, Error> {
+        self.shadow(self.clone().container_shadow)
+    }
+
+    /// Retrieve the shadow for the dock
+    pub fn dock_shadow(&self) -> Result<Shadow, Error> {
+        self.shadow(self.clone().dock_shadow)
+    }
+
+    /// Retrieve the shadow for the label
+    pub fn label_shadow(&self) -> Result<Shadow, Error> {
+        self.shadow(self.clone().label_shadow)
+    }
+
+    /// Retrieve the shadow for the menu
+    pub fn menu_shadow(&self) -> Result<Shadow, Error> {
+        self.shadow(self.clone().menu_shadow)
+    }
+
+    /// Retrieve the shadow for the dialog
+    pub fn dialog_shadow(&self) -> Result<Shadow, Error> {
+        self.shadow(self.clone().dialog_shadow)
+    }
+
+    /// Retrieve the shadow for the tooltip
+    pub fn tooltip_shadow(&self) -> Result<Shadow, Error> {
+        self.shadow(self.clone().tooltip_shadow)
+    }
+
+    /// Retrieve the nine slice background image for the button
+    pub fn button_background(&self) -> Result<Option<NineSlice>, Error> {
+        background(self.clone().button_image, self.button_image_insets)
+    }
+
+    /// Retrieve the nine slice background image for the container
+    pub fn container_background(&self) -> Result<Option<NineSlice>, Error> {
+        background(self.clone().container_image, self.container_image_insets)
+    }
+
+    /// Retrieve the nine slice background image for the dock
+    pub fn dock_background(&self) -> Result<Option<NineSlice>, Error> {
+        background(self.clone().dock_image, self.dock_image_insets)
+    }
+
+    /// Retrieve the font for the text of the widgets, if one is set
+    pub fn font(&self) -> Result<Option<Font>, Error> {
+        match self.clone().font {
+            None => Ok(None),
+            Some(val) => Ok(Some(Font::from_path(val)?)),
+        }
+    }
+
+    /// Retrieve the fill for the text input
+    pub fn text_input_fill(&self) -> Result<Fill, Error> {
+        match self.clone().text_input {
+            None => Fill::parse("#0E1113FF"),
+            Some(val) => Fill::parse(val),
+        }
+    }
+
+    /// Retrieve the color for the text of the text input
+    pub fn text_input_text_color(&self) -> Result<Color, Error> {
+        match self.clone().text_input_text {
+            None => Color::from_hex("#FFFFFFFF"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the color for the placeholder text shown while a text widget is empty
+    pub fn placeholder_color(&self) -> Result<Color, Error> {
+        match self.clone().placeholder {
+            None => Color::from_hex("#FFFFFF80"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the color for the background of selected text
+    pub fn selection_color(&self) -> Result<Color, Error> {
+        match self.clone().selection {
+            None => Color::from_hex("#3D6DCC99"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the fill behind the checkboxes, radio buttons, toggles and sliders, only their
+    /// indicator, label or track are drawn by default
+    pub fn check_fill(&self) -> Result<Fill, Error> {
+        match self.clone().check {
+            None => Fill::parse("#00000000"),
+            Some(val) => Fill::parse(val),
+        }
+    }
+
+    /// Retrieve the color of the checked checkboxes, radio buttons and toggles, and of the value of
+    /// the sliders and progress bars
+    pub fn checked_color(&self) -> Result<Color, Error> {
+        match self.clone().checked {
+            None => Color::from_hex("#3D6DCCFF"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the color of the unchecked checkboxes, radio buttons and toggles, and of the track
+    /// of the sliders and progress bars
+    pub fn unchecked_color(&self) -> Result<Color, Error> {
+        match self.clone().unchecked {
+            None => Color::from_hex("#0E1113FF"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the color of the disabled checkboxes, radio buttons, toggles and sliders
+    pub fn disabled_color(&self) -> Result<Color, Error> {
+        match self.clone().disabled {
+            None => Color::from_hex("#5A5F66FF"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    /// Retrieve the color laid over the window below the modal dialogs
+    pub fn dim_color(&self) -> Result<Color, Error> {
+        match self.clone().dim {
+            None => Color::from_hex("#00000080"),
+            Some(val) => Color::from_hex(val),
+        }
+    }
+
+    fn shadow(&self, shadow: Option<ShadowTheme>) -> Result<Shadow, Error> {
+        let shadow = shadow.unwrap_or_default();
+
+        let color = match shadow.color {
+            None => self.shadow_color()?,
+            Some(val) => Color::from_hex(val)?,
+        };
+
+        let elevated = Shadow::from_elevation(shadow.elevation.unwrap_or(0.0), color)?;
+
+        Shadow::new(
+            shadow.offset.unwrap_or_else(|| elevated.offset()),
+            shadow.blur_radius.unwrap_or_else(|| elevated.blur_radius()),
+            color,
+        )
+    }
+}
+
+fn background(path: Option<String>, insets: Option<[u32; 4]>) -> Result<Option<NineSlice>, Error> {
+    match path {
+        None => Ok(None),
+        Some(path) => match NineSlice::from_path(&path, insets.unwrap_or_default()) {
+            Err(err) => Err(err_msg(format!(
+                "Failed to load the background image {}: {}",
+                path, err
+            ))),
+            Ok(val) => Ok(Some(val)),
+        },
     }
 }
@@ -12,12 +12,18 @@
 
 mod button;
 mod dock;
+mod list_model;
+mod table_model;
 mod text;
+mod tree_model;
 mod widget;
 
 pub use self::{
     button::*,
     dock::*,
+    list_model::*,
+    table_model::*,
     text::*,
+    tree_model::*,
     widget::*,
 };
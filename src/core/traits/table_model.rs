@@ -0,0 +1,32 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+/// The rows of cells shown by a table. The table only asks for the cells of the rows it shows,
+/// except to sort the rows without a comparator, when it asks for every cell of the sorted column.
+pub trait TableModel: Send + Sync {
+    /// The number of rows
+    fn len(&self) -> usize;
+    /// Whether there are no rows
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The text shown for the cell of a row in a column, from 0 to the number of rows and columns
+    fn text(&self, row: usize, column: usize) -> String;
+}
+
+impl TableModel for Vec<Vec<String>> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn text(&self, row: usize, column: usize) -> String {
+        self[row].get(column).cloned().unwrap_or_default()
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+/// The nodes shown by a tree view. A node is given by its path, the positions of the node and of
+/// each of its parents among their siblings, starting from the top level. The tree view only asks
+/// for the children of a node once it is expanded, so the children can be loaded on demand.
+pub trait TreeModel: Send + Sync {
+    /// The number of children of a node, the empty path giving the number of nodes at the top
+    /// level
+    fn children(&self, path: &[usize]) -> usize;
+    /// Whether a node has children to expand, asked for the nodes shown without loading their
+    /// children. By default whether the node has more than 0 children.
+    fn has_children(&self, path: &[usize]) -> bool {
+        self.children(path) > 0
+    }
+    /// The text shown for a node
+    fn text(&self, path: &[usize]) -> String;
+}
@@ -10,12 +10,21 @@
 
 use self::super::super::prelude::{
     err_msg,
+    Border,
     Color,
+    CornerRadius,
     DrawVertex,
     Error,
+    Fill,
     Id,
+    InputEvent,
+    Layer,
+    NineSlice,
+    Overflow,
     Position,
+    Shadow,
     Size,
+    Texture,
     Ui,
     Vertex,
     WidgetType,
@@ -25,40 +34,30 @@ use downcast_rs::{
     Downcast,
 };
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 struct DObject {
-    color:     Color,
+    fill:      Fill,
     tr_vertex: Vertex,
-    tl_vertex: Vertex,
     bl_vertex: Vertex,
-    br_vertex: Vertex,
 }
 
 impl DObject {
-    fn color(&self) -> Color {
-        self.color
+    fn fill(&self) -> Fill {
+        self.fill.clone()
     }
 
     fn tr_vertex(&self) -> Vertex {
         self.tr_vertex
     }
 
-    fn tl_vertex(&self) -> Vertex {
-        self.tl_vertex
-    }
-
     fn bl_vertex(&self) -> Vertex {
         self.bl_vertex
     }
-
-    fn br_vertex(&self) -> Vertex {
-        self.br_vertex
-    }
 }
 
 #[derive(Debug, Default, Clone)]
 struct DObjectBuilder {
-    color:     Color,
+    fill:      Fill,
     tr_vertex: Vertex,
     bl_vertex: Vertex,
 }
@@ -68,8 +67,8 @@ impl DObjectBuilder {
         Self::default()
     }
 
-    fn with_color(&mut self, color: Color) -> &mut Self {
-        self.color = color;
+    fn with_fill(&mut self, fill: Fill) -> &mut Self {
+        self.fill = fill;
         self
     }
 
@@ -84,17 +83,13 @@ impl DObjectBuilder {
     }
 
     fn build(&self) -> DObject {
-        let color = self.clone().color;
+        let fill = self.clone().fill;
         let bl_vertex = self.bl_vertex;
         let tr_vertex = self.tr_vertex;
-        let br_vertex = VertexBuilder::new().with_x(tr_vertex.x()).with_y(bl_vertex.y()).build();
-        let tl_vertex = VertexBuilder::new().with_x(bl_vertex.x()).with_y(tr_vertex.y()).build();
         DObject {
-            color,
+            fill,
             tr_vertex,
-            tl_vertex,
             bl_vertex,
-            br_vertex,
         }
     }
 }
@@ -133,89 +128,91 @@ fn calc_vertices(bl_vertex: Vertex, tr_vertex: Vertex, size: Size, position: Pos
         Size::Size(xp, yp) => {
             let x = xp / 100.0;
             let y = yp / 100.0;
+            let width = tr_vertex.x() - bl_vertex.x();
+            let height = tr_vertex.y() - bl_vertex.y();
             match position {
                 Position::BottomLeft => (
                     VertexBuilder::new()
                         .with_x(bl_vertex.x())
-                        .with_y(tr_vertex.y() - ((tr_vertex.y() - bl_vertex.y()) * y))
+                        .with_y(tr_vertex.y() - (height * y))
                         .build(),
                     VertexBuilder::new()
-                        .with_x((tr_vertex.x() - bl_vertex.x()) * x)
+                        .with_x(bl_vertex.x() + (width * x))
                         .with_y(tr_vertex.y())
                         .build(),
                 ),
                 Position::Bottom => (
                     VertexBuilder::new()
-                        .with_x(((tr_vertex.x() - bl_vertex.x()) - ((tr_vertex.x() - bl_vertex.x()) * x)) / 2.0)
-                        .with_y(tr_vertex.y() - ((tr_vertex.y() - bl_vertex.y()) * y))
+                        .with_x(bl_vertex.x() + ((width - (width * x)) / 2.0))
+                        .with_y(tr_vertex.y() - (height * y))
                         .build(),
                     VertexBuilder::new()
-                        .with_x(((tr_vertex.x() - bl_vertex.x()) + ((tr_vertex.x() - bl_vertex.x()) * x)) / 2.0)
+                        .with_x(bl_vertex.x() + ((width + (width * x)) / 2.0))
                         .with_y(tr_vertex.y())
                         .build(),
                 ),
                 Position::BottomRight => (
                     VertexBuilder::new()
-                        .with_x(tr_vertex.x() - ((tr_vertex.x() - bl_vertex.x()) * x))
-                        .with_y(tr_vertex.y() - ((tr_vertex.y() - bl_vertex.y()) * y))
+                        .with_x(tr_vertex.x() - (width * x))
+                        .with_y(tr_vertex.y() - (height * y))
                         .build(),
                     tr_vertex,
                 ),
                 Position::Left => (
                     VertexBuilder::new()
                         .with_x(bl_vertex.x())
-                        .with_y(((tr_vertex.y() - bl_vertex.y()) - ((tr_vertex.y() - bl_vertex.y()) * y)) / 2.0)
+                        .with_y(bl_vertex.y() + ((height - (height * y)) / 2.0))
                         .build(),
                     VertexBuilder::new()
-                        .with_x((tr_vertex.x() - bl_vertex.x()) * x)
-                        .with_y(((tr_vertex.y() - bl_vertex.y()) + ((tr_vertex.y() - bl_vertex.y()) * y)) / 2.0)
+                        .with_x(bl_vertex.x() + (width * x))
+                        .with_y(bl_vertex.y() + ((height + (height * y)) / 2.0))
                         .build(),
                 ),
                 Position::Center => (
                     VertexBuilder::new()
-                        .with_x(((tr_vertex.x() - bl_vertex.x()) - ((tr_vertex.x() - bl_vertex.x()) * x)) / 2.0)
-                        .with_y(((tr_vertex.y() - bl_vertex.y()) - ((tr_vertex.y() - bl_vertex.y()) * y)) / 2.0)
+                        .with_x(bl_vertex.x() + ((width - (width * x)) / 2.0))
+                        .with_y(bl_vertex.y() + ((height - (height * y)) / 2.0))
                         .build(),
                     VertexBuilder::new()
-                        .with_x(((tr_vertex.x() - bl_vertex.x()) + ((tr_vertex.x() - bl_vertex.x()) * x)) / 2.0)
-                        .with_y(((tr_vertex.y() - bl_vertex.y()) + ((tr_vertex.y() - bl_vertex.y()) * y)) / 2.0)
+                        .with_x(bl_vertex.x() + ((width + (width * x)) / 2.0))
+                        .with_y(bl_vertex.y() + ((height + (height * y)) / 2.0))
                         .build(),
                 ),
                 Position::Right => (
                     VertexBuilder::new()
-                        .with_x(tr_vertex.x() - ((tr_vertex.x() - bl_vertex.x()) * x))
-                        .with_y(((tr_vertex.y() - bl_vertex.y()) - ((tr_vertex.y() - bl_vertex.y()) * y)) / 2.0)
+                        .with_x(tr_vertex.x() - (width * x))
+                        .with_y(bl_vertex.y() + ((height - (height * y)) / 2.0))
                         .build(),
                     VertexBuilder::new()
                         .with_x(tr_vertex.x())
-                        .with_y(((tr_vertex.y() - bl_vertex.y()) + ((tr_vertex.y() - bl_vertex.y()) * y)) / 2.0)
+                        .with_y(bl_vertex.y() + ((height + (height * y)) / 2.0))
                         .build(),
                 ),
                 Position::TopLeft => (
                     bl_vertex,
                     VertexBuilder::new()
-                        .with_x((tr_vertex.x() - bl_vertex.x()) * x)
-                        .with_y((tr_vertex.y() - bl_vertex.y()) * y)
+                        .with_x(bl_vertex.x() + (width * x))
+                        .with_y(bl_vertex.y() + (height * y))
                         .build(),
                 ),
                 Position::Top => (
                     VertexBuilder::new()
-                        .with_x(((tr_vertex.x() - bl_vertex.x()) - ((tr_vertex.x() - bl_vertex.x()) * x)) / 2.0)
+                        .with_x(bl_vertex.x() + ((width - (width * x)) / 2.0))
                         .with_y(bl_vertex.y())
                         .build(),
                     VertexBuilder::new()
-                        .with_x(((tr_vertex.x() - bl_vertex.x()) + ((tr_vertex.x() - bl_vertex.x()) * x)) / 2.0)
-                        .with_y((tr_vertex.y() - bl_vertex.y()) * y)
+                        .with_x(bl_vertex.x() + ((width + (width * x)) / 2.0))
+                        .with_y(bl_vertex.y() + (height * y))
                         .build(),
                 ),
                 Position::TopRight => (
                     VertexBuilder::new()
-                        .with_x(tr_vertex.x() - ((tr_vertex.x() - bl_vertex.x()) * x))
+                        .with_x(tr_vertex.x() - (width * x))
                         .with_y(bl_vertex.y())
                         .build(),
                     VertexBuilder::new()
                         .with_x(tr_vertex.x())
-                        .with_y((tr_vertex.y() - bl_vertex.y()) * y)
+                        .with_y(bl_vertex.y() + (height * y))
                         .build(),
                 ),
             }
@@ -223,6 +220,17 @@ fn calc_vertices(bl_vertex: Vertex, tr_vertex: Vertex, size: Size, position: Pos
     }
 }
 
+fn intersect_vertices(a: (Vertex, Vertex), b: (Vertex, Vertex)) -> (Vertex, Vertex) {
+    let bl_x = a.0.x().max(b.0.x());
+    let bl_y = a.0.y().max(b.0.y());
+    let tr_x = a.1.x().min(b.1.x()).max(bl_x);
+    let tr_y = a.1.y().min(b.1.y()).max(bl_y);
+    (
+        VertexBuilder::new().with_x(bl_x).with_y(bl_y).build(),
+        VertexBuilder::new().with_x(tr_x).with_y(tr_y).build(),
+    )
+}
+
 /// The Trait that all widgets must implement
 pub trait WidgetTrait: WidgetClone + Downcast {
     /// The type of this widget
@@ -239,27 +247,94 @@ pub trait WidgetTrait: WidgetClone + Downcast {
     fn position(&self) -> Position;
     /// Retrieve the color of this widget
     fn color(&self) -> Color;
+    /// Retrieve how the background of this widget is filled
+    fn fill(&self) -> Fill {
+        Fill::Solid(self.color())
+    }
     /// The visibility of thi widget
     fn visible(&self) -> bool;
     /// Make this widget visible
     fn show(&mut self);
     /// Hide this widget
     fn hide(&mut self);
-    /// Calculate and retrieve the vertices of this widget
-    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
-        let mut vertices = Vec::new();
+    /// Retrieve how the children of this widget are clipped
+    fn overflow(&self) -> Overflow;
+    /// Retrieve the opacity of this widget, from 0.0 (invisible) to 1.0 (opaque)
+    fn opacity(&self) -> f32;
+    /// Set the opacity of this widget, from 0.0 (invisible) to 1.0 (opaque)
+    fn set_opacity(&mut self, opacity: f32);
+    /// Retrieve the z-index of this widget, it is drawn over its siblings with a lower z-index
+    fn z_index(&self) -> i32;
+    /// Set the z-index of this widget
+    fn set_z_index(&mut self, z_index: i32);
+    /// Retrieve the layer this widget is drawn in
+    fn layer(&self) -> Layer;
+    /// Retrieve the border of this widget
+    fn border(&self) -> Border;
+    /// Retrieve the radius of the corners of this widget
+    fn corner_radius(&self) -> CornerRadius;
+    /// Retrieve the shadow cast by this widget
+    fn shadow(&self) -> Shadow;
+    /// Whether this widget takes the keyboard focus when it is clicked
+    fn focusable(&self) -> bool {
+        false
+    }
+    /// Handle an input event, returning whether it was handled or should bubble up to the parent
+    fn handle_event(&mut self, _ui: &Ui, _event: &InputEvent) -> Result<bool, Error> {
+        Ok(false)
+    }
+    /// Retrieve the nine slice image drawn over the background of this widget, if any
+    fn background(&self) -> Option<NineSlice> {
+        None
+    }
+    /// Calculate the bottom left and top right vertices of the area a child of this widget is laid
+    /// out in from the vertices of this widget, this will be None if the child is hidden. Widgets
+    /// that arrange their children, like the tabs showing only their active page, override it.
+    fn child_area(&self, _ui: &Ui, _child: &Id, bl_vertex: Vertex, tr_vertex: Vertex) -> Option<(Vertex, Vertex)> {
+        Some((bl_vertex, tr_vertex))
+    }
+    /// Whether the area of a child of this widget is placed by `child_area` alone, leaving the
+    /// child out of the room its siblings share when their sizes are normalized
+    fn places_child(&self, _child: &Id) -> bool {
+        false
+    }
+    /// Retrieve the texture this widget draws, if any
+    fn texture(&self) -> Option<Texture> {
+        self.background().map(|background| background.texture())
+    }
+    /// Calculate the opacity of this widget multiplied by the opacity of all its parents
+    fn inherited_opacity(&self, ui: &Ui) -> Result<f32, Error> {
+        let mut opacity = self.opacity();
+        let mut parent_id = self.parent_id();
+
+        while let Some(pid) = parent_id {
+            match ui.widgets().get(&pid) {
+                None => {
+                    return Err(err_msg("OPError: Attempted to use an id that does not exist"));
+                }
+                Some(widget) => {
+                    let widget = widget.lock();
+                    opacity *= widget.opacity();
+                    parent_id = widget.parent_id();
+                }
+            }
+        }
+
+        Ok(opacity)
+    }
+    /// Calculate and retrieve the bottom left and top right vertices of this widget, this will be
+    /// None if the widget or one of its parents is hidden
+    fn bounds(&self, ui: &Ui) -> Result<Option<(Vertex, Vertex)>, Error> {
         if !self.visible() {
-            return Ok(vertices);
+            return Ok(None);
         }
 
-        let mut bl_vertex = VertexBuilder::new().with_x(0.0).with_y(0.0).build();
-        let mut tr_vertex = VertexBuilder::new().with_x(2.0).with_y(2.0).build();
+        let bl_vertex = VertexBuilder::new().with_x(0.0).with_y(0.0).build();
+        let tr_vertex = VertexBuilder::new().with_x(2.0).with_y(2.0).build();
 
         let mut parent_id = self.parent_id();
         let size = self.size();
-
         let position = self.position();
-        let color = self.color();
         let mut parents: Vec<Id> = Vec::new();
 
         loop {
@@ -281,9 +356,13 @@ pub trait WidgetTrait: WidgetClone + Downcast {
         }
 
         let mut parent_vertices = (bl_vertex, tr_vertex);
-        for parent in parents.iter().rev() {
+        for (i, parent) in parents.iter().enumerate().rev() {
             let bl_vertex = parent_vertices.0;
             let tr_vertex = parent_vertices.1;
+            let child = match i {
+                0 => self.id(),
+                _ => parents[i - 1].clone(),
+            };
 
             match ui.widgets().get(parent) {
                 None => {
@@ -292,56 +371,140 @@ pub trait WidgetTrait: WidgetClone + Downcast {
                 Some(widget) => {
                     let widget = widget.lock();
                     if !widget.visible() {
-                        return Ok(vertices);
+                        return Ok(None);
                     }
-                    parent_vertices = calc_vertices(bl_vertex, tr_vertex, widget.size(), widget.position());
+                    let (bl, tr) = calc_vertices(bl_vertex, tr_vertex, widget.size(), widget.position());
+                    parent_vertices = match widget.child_area(ui, &child, bl, tr) {
+                        None => {
+                            return Ok(None);
+                        }
+                        Some(val) => val,
+                    };
                 }
             }
         }
 
         let bl = parent_vertices.0;
         let tr = parent_vertices.1;
-        parent_vertices = calc_vertices(bl, tr, size, position);
 
-        bl_vertex = parent_vertices.0;
-        tr_vertex = parent_vertices.1;
+        Ok(Some(calc_vertices(bl, tr, size, position)))
+    }
+    /// Calculate and retrieve the clipping rectangle of this widget as the intersection of the
+    /// bounds of every parent that hides its overflow, this will be None if nothing clips it.
+    /// Widgets in the overlay layer are not clipped by the parents of the overlay.
+    fn clip(&self, ui: &Ui) -> Result<Option<(Vertex, Vertex)>, Error> {
+        let mut clip: Option<(Vertex, Vertex)> = None;
+        let mut parent_id = self.parent_id();
+        let mut layer = self.layer();
+
+        while let Some(pid) = parent_id {
+            if let Layer::Overlay = layer {
+                break;
+            }
+            match ui.widgets().get(&pid) {
+                None => {
+                    return Err(err_msg("CLError: Attempted to use an id that does not exist"));
+                }
+                Some(widget) => {
+                    let widget = widget.lock();
+                    if let Overflow::Hidden = widget.overflow() {
+                        if let Some(bounds) = widget.bounds(ui)? {
+                            clip = match clip {
+                                None => Some(bounds),
+                                Some(val) => Some(intersect_vertices(val, bounds)),
+                            };
+                        }
+                    }
+                    parent_id = widget.parent_id();
+                    layer = widget.layer();
+                }
+            }
+        }
+
+        Ok(clip)
+    }
+    /// Calculate and retrieve the vertices of this widget
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let fill = self.fill();
 
         let dobject = DObjectBuilder::new()
             .with_bl_vertex(bl_vertex)
             .with_tr_vertex(tr_vertex)
-            .with_color(color)
+            .with_fill(fill)
             .build();
 
         let tr_vertex = dobject.tr_vertex();
-        let tl_vertex = dobject.tl_vertex();
         let bl_vertex = dobject.bl_vertex();
-        let br_vertex = dobject.br_vertex();
-        let color = dobject.color().into_scaled_rgba_float();
-
-        vertices.push(DrawVertex {
-            position: tr_vertex,
-            color,
-        });
-        vertices.push(DrawVertex {
-            position: tl_vertex,
-            color,
-        });
-        vertices.push(DrawVertex {
-            position: bl_vertex,
-            color,
-        });
-        vertices.push(DrawVertex {
-            position: tr_vertex,
-            color,
-        });
-        vertices.push(DrawVertex {
-            position: br_vertex,
-            color,
-        });
-        vertices.push(DrawVertex {
-            position: bl_vertex,
-            color,
-        });
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let border = self.border();
+        let mut border_color = border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let corner_radius = self.corner_radius();
+        let bounds = (bl_vertex, tr_vertex);
+
+        for (position, mut color) in dobject.fill().triangles(bl_vertex, tr_vertex) {
+            color[3] *= opacity;
+            vertices.push(DrawVertex::new(position, color).with_shape(
+                bounds,
+                corner_radius,
+                border.width(),
+                border_color,
+            ));
+        }
+
+        if let Some(background) = self.background() {
+            let texture = background.texture();
+            for (position, uv) in background.triangles(bl_vertex, tr_vertex, ui.window_size()) {
+                vertices.push(
+                    DrawVertex::new(position, [1.0, 1.0, 1.0, opacity])
+                        .with_shape(bounds, corner_radius, border.width(), border_color)
+                        .with_texture(texture.id(), uv),
+                );
+            }
+        }
+
+        Ok(vertices)
+    }
+    /// Calculate and retrieve the vertices of the shadow drawn behind this widget
+    fn draw_shadow(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let shadow = self.shadow();
+        if !shadow.visible() {
+            return Ok(vertices);
+        }
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+        let corner_radius = self.corner_radius();
+        let bounds = (bl_vertex, tr_vertex);
+
+        for (position, mut color) in Fill::Solid(shadow.color()).triangles(bl_vertex, tr_vertex) {
+            color[3] *= opacity;
+            vertices.push(
+                DrawVertex::new(position, color)
+                    .with_shape(bounds, corner_radius, 0.0, [0.0; 4])
+                    .with_blur(shadow.offset(), shadow.blur_radius()),
+            );
+        }
 
         Ok(vertices)
     }
@@ -11,18 +11,71 @@
 //! All Widgets, utilities, traits, and enumerations
 
 mod button;
+mod canvas;
+mod checkbox;
+mod combo_box;
 mod container;
+mod dialog;
 mod dock;
+mod dock_area;
+mod dock_panel;
+mod image;
 mod label;
+mod list_view;
+mod menu;
+mod menu_bar;
+mod progress_bar;
+mod radio;
+mod slider;
+mod spin_box;
+mod splitter;
+mod table;
+mod tabs;
+mod text_area;
+mod text_input;
+mod toggle;
 mod toolbar;
+mod tooltip;
+mod tree_view;
 pub mod window;
 mod window_container;
 
 pub use self::{
     button::*,
+    canvas::*,
+    checkbox::*,
+    combo_box::*,
     container::*,
+    dialog::*,
     dock::*,
+    dock_area::*,
+    dock_panel::*,
+    image::*,
     label::*,
+    list_view::*,
+    menu::*,
+    menu_bar::*,
+    progress_bar::*,
+    radio::*,
+    slider::*,
+    spin_box::*,
+    splitter::*,
+    table::*,
+    tabs::*,
+    text_area::*,
+    text_input::*,
+    toggle::*,
     toolbar::*,
+    tooltip::*,
+    tree_view::*,
     window_container::*,
 };
+
+/// The space in pixels between the border of a widget and its content
+pub(crate) const PADDING: f32 = 4.0;
+/// The size in pixels of the text when none is set
+pub(crate) const TEXT_SIZE: f32 = 16.0;
+/// The space in pixels on each side of the title of a tab
+pub(crate) const TAB_PADDING: f32 = 12.0;
+/// The narrowest a tab gets in pixels
+pub(crate) const MIN_TAB_WIDTH: f32 = 48.0;
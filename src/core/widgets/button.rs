@@ -22,6 +22,14 @@ pub struct Button {
     text_size:      f32,
     selected_color: Color,
     visible:        bool,
+    opacity:        f32,
+    overflow:       Overflow,
+    z_index:        i32,
+    layer:          Layer,
+    border:         Border,
+    corner_radius:  CornerRadius,
+    shadow:         Shadow,
+    background:     Option<NineSlice>,
     label:          String,
 }
 
@@ -65,6 +73,46 @@ impl WidgetTrait for Button {
     fn hide(&mut self) {
         self.visible = false;
     }
+
+    fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn background(&self) -> Option<NineSlice> {
+        self.background.clone()
+    }
 }
 
 impl TextTrait for Button {
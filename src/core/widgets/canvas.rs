@@ -0,0 +1,354 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+};
+use std::sync::Arc;
+
+/// The callback drawing the content of a Canvas every frame
+pub type DrawCallback = Arc<Fn(&mut Painter) + Send + Sync>;
+
+/// The Canvas Widget
+#[derive(Clone)]
+pub struct Canvas {
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    on_draw:       Option<DrawCallback>,
+    text:          Arc<TextureCache>,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+}
+
+impl WidgetTrait for Canvas {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Canvas
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        Shadow::default()
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        // The callback draws in pixels, so nothing can be drawn until the size of the window is known
+        let window_size = ui.window_size();
+        let on_draw = match self.on_draw {
+            Some(ref val) if (window_size[0] != 0) & (window_size[1] != 0) => val,
+            _ => {
+                self.text.clear();
+                return Ok(vertices);
+            }
+        };
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+        on_draw(&mut painter);
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Canvas {
+    /// Set the callback drawing the content of the canvas every frame
+    pub fn set_on_draw<F>(&mut self, on_draw: F)
+    where
+        F: Fn(&mut Painter) + Send + Sync + 'static,
+    {
+        self.on_draw = Some(Arc::new(on_draw));
+    }
+}
+
+/// The builder for the Canvas widget
+#[derive(Clone, Default)]
+pub struct CanvasBuilder {
+    id:            Id,
+    size:          Size,
+    position:      Position,
+    color:         String,
+    parent_id:     Id,
+    on_draw:       Option<DrawCallback>,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border_color:  String,
+    border_width:  Option<f32>,
+    corner_radius: Option<[f32; 4]>,
+}
+
+impl CanvasBuilder {
+    /// Initialize the builder for the Canvas widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Canvas widget from another Canvas widget
+    pub fn new_from_canvas(canvas: &Canvas) -> Self {
+        Self {
+            id:            canvas.id(),
+            size:          canvas.size(),
+            position:      canvas.position(),
+            color:         canvas.fill().into_string(),
+            parent_id:     canvas.parent_id().unwrap(),
+            on_draw:       canvas.on_draw.clone(),
+            visible:       canvas.visible(),
+            opacity:       canvas.opacity(),
+            z_index:       canvas.z_index(),
+            layer:         canvas.layer(),
+            border_color:  canvas.border().color().into_hex(),
+            border_width:  Some(canvas.border().width()),
+            corner_radius: Some(canvas.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill drawn behind the content, either a hex color or a gradient as parsed by
+    /// `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the callback drawing the content every frame
+    pub fn with_on_draw<F>(&mut self, on_draw: F) -> &mut Self
+    where
+        F: Fn(&mut Painter) + Send + Sync + 'static,
+    {
+        self.on_draw = Some(Arc::new(on_draw));
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the Canvas widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Canvas>, Error> {
+        let fill = if self.color.is_empty() {
+            Fill::parse("#00000000")?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Canvas {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            on_draw: self.clone().on_draw,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
@@ -0,0 +1,752 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The size in pixels of the box, the radio circle or the toggle knob
+pub(crate) const INDICATOR: f32 = 16.0;
+/// The space in pixels between the indicator and the label
+pub(crate) const SPACING: f32 = 6.0;
+
+/// The callback given the state of a checkbox
+pub type CheckCallback = Arc<Fn(CheckState) + Send + Sync>;
+
+/// The Checkbox Widget, a box checked or unchecked when clicked, with an optional third state
+/// standing for options only some of which are checked
+#[derive(Clone)]
+pub struct Checkbox {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    text_color:      Color,
+    checked_color:   Color,
+    unchecked_color: Color,
+    disabled_color:  Color,
+    focus_color:     Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    label:           String,
+    state:           CheckState,
+    tri_state:       bool,
+    enabled:         bool,
+    pressed:         bool,
+    on_change:       Option<CheckCallback>,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for Checkbox {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Checkbox
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        if !self.enabled {
+            return Ok(false);
+        }
+
+        match *event {
+            InputEvent::MousePressed {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.pressed = true;
+            }
+            InputEvent::MouseReleased {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                // The press is cancelled by releasing the button away from the checkbox
+                if self.pressed & contains(ui, self.bounds(ui)?, x, y) {
+                    self.toggle();
+                }
+                self.pressed = false;
+            }
+            InputEvent::KeyPressed {
+                key: VirtualKeyCode::Space,
+                ..
+            } => {
+                self.toggle();
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let size = indicator_size(painter.size());
+        let (left, top) = (PADDING, (painter.size().1 - size) / 2.0);
+
+        let box_color = match (self.enabled, self.state) {
+            (false, _) => self.disabled_color,
+            (true, CheckState::Unchecked) => self.unchecked_color,
+            (true, _) => self.checked_color,
+        };
+
+        painter.rect((left, top), (size, size), box_color);
+        painter.stroke_rect((left, top), (size, size), 1.0, self.border_color());
+
+        match self.state {
+            CheckState::Unchecked => {}
+            CheckState::Checked => {
+                painter.polyline(
+                    &[
+                        (left + size * 0.2, top + size * 0.5),
+                        (left + size * 0.42, top + size * 0.72),
+                        (left + size * 0.8, top + size * 0.28),
+                    ],
+                    (size / 8.0).max(1.0),
+                    self.text_color,
+                );
+            }
+            CheckState::Indeterminate => {
+                painter.rect(
+                    (left + size * 0.25, top + size * 0.45),
+                    (size * 0.5, (size * 0.1).max(1.0)),
+                    self.text_color,
+                );
+            }
+        }
+
+        if ui.focused() == Some(self.id()) {
+            painter.stroke_rect((left - 2.0, top - 2.0), (size + 4.0, size + 4.0), 1.0, self.focus_color);
+        }
+
+        if let Some(ref font) = self.font {
+            let line_height = font.measure("", self.text_size).1;
+            painter.text(
+                (left + size + SPACING, (painter.size().1 - line_height) / 2.0),
+                &self.label,
+                self.text_size,
+                self.label_color(),
+                font,
+            );
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Checkbox {
+    /// Retrieve the state
+    pub fn state(&self) -> CheckState {
+        self.state
+    }
+
+    /// Set the state without calling the change callback
+    pub fn set_state(&mut self, state: CheckState) {
+        self.state = state;
+    }
+
+    /// Whether the checkbox is checked
+    pub fn checked(&self) -> bool {
+        self.state == CheckState::Checked
+    }
+
+    /// Check or uncheck the checkbox without calling the change callback
+    pub fn set_checked(&mut self, checked: bool) {
+        self.state = if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        };
+    }
+
+    /// Whether clicking the checkbox cycles through the indeterminate state
+    pub fn tri_state(&self) -> bool {
+        self.tri_state
+    }
+
+    /// Retrieve the label
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    /// Set the label
+    pub fn set_label<V: Into<String>>(&mut self, label: V) {
+        self.label = label.into();
+    }
+
+    /// Whether the checkbox can be focused and clicked
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable the checkbox
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.pressed = false;
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the state whenever the checkbox is clicked
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(CheckState) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    /// Move to the next state: unchecked, checked then indeterminate for tri-state checkboxes
+    fn toggle(&mut self) {
+        self.state = match self.state {
+            CheckState::Unchecked => CheckState::Checked,
+            CheckState::Checked if self.tri_state => CheckState::Indeterminate,
+            CheckState::Checked => CheckState::Unchecked,
+            CheckState::Indeterminate if self.tri_state => CheckState::Unchecked,
+            CheckState::Indeterminate => CheckState::Checked,
+        };
+
+        if let Some(ref on_change) = self.on_change {
+            on_change(self.state);
+        }
+    }
+
+    fn label_color(&self) -> Color {
+        if self.enabled {
+            self.text_color
+        } else {
+            self.disabled_color
+        }
+    }
+
+    fn border_color(&self) -> Color {
+        if self.enabled {
+            self.checked_color
+        } else {
+            self.disabled_color
+        }
+    }
+}
+
+/// The size in pixels of the indicator fitting in a widget of a size in pixels
+pub(crate) fn indicator_size(size: (f32, f32)) -> f32 {
+    INDICATOR.min(size.1 - (2.0 * PADDING)).max(0.0)
+}
+
+/// Whether a position in pixels in the window is within the bounds of a widget
+pub(crate) fn contains(ui: &Ui, bounds: Option<(Vertex, Vertex)>, x: f32, y: f32) -> bool {
+    let (bl_vertex, tr_vertex) = match bounds {
+        None => {
+            return false;
+        }
+        Some(val) => val,
+    };
+
+    let (x, y) = (
+        (x * 2.0) / ui.window_size()[0] as f32,
+        (y * 2.0) / ui.window_size()[1] as f32,
+    );
+    (x >= bl_vertex.x()) & (x <= tr_vertex.x()) & (y >= bl_vertex.y()) & (y <= tr_vertex.y())
+}
+
+/// The builder for the Checkbox widget
+#[derive(Clone, Default)]
+pub struct CheckboxBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    text_color:      String,
+    checked_color:   String,
+    unchecked_color: String,
+    disabled_color:  String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    label:           String,
+    state:           CheckState,
+    tri_state:       bool,
+    disabled:        bool,
+    on_change:       Option<CheckCallback>,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+    elevation:       Option<f32>,
+}
+
+impl CheckboxBuilder {
+    /// Initialize the builder for the Checkbox widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Checkbox widget from another Checkbox widget
+    pub fn new_from_checkbox(checkbox: &Checkbox) -> Self {
+        Self {
+            id:              checkbox.id(),
+            size:            checkbox.size(),
+            position:        checkbox.position(),
+            color:           checkbox.fill().into_string(),
+            text_color:      checkbox.text_color.into_hex(),
+            checked_color:   checkbox.checked_color.into_hex(),
+            unchecked_color: checkbox.unchecked_color.into_hex(),
+            disabled_color:  checkbox.disabled_color.into_hex(),
+            font:            checkbox.font(),
+            text_size:       Some(checkbox.text_size()),
+            label:           checkbox.label(),
+            state:           checkbox.state(),
+            tri_state:       checkbox.tri_state(),
+            disabled:        !checkbox.enabled(),
+            on_change:       checkbox.on_change.clone(),
+            parent_id:       checkbox.parent_id().unwrap(),
+            visible:         checkbox.visible(),
+            opacity:         checkbox.opacity(),
+            z_index:         checkbox.z_index(),
+            layer:           checkbox.layer(),
+            border_color:    checkbox.border().color().into_hex(),
+            border_width:    Some(checkbox.border().width()),
+            corner_radius:   Some(checkbox.corner_radius().as_array()),
+            elevation:       Some(checkbox.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the label and the check mark
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the box while checked
+    pub fn with_checked_color<V: Into<String>>(&mut self, checked_color: V) -> &mut Self {
+        self.checked_color = checked_color.into();
+        self
+    }
+
+    /// Set the color of the box while unchecked
+    pub fn with_unchecked_color<V: Into<String>>(&mut self, unchecked_color: V) -> &mut Self {
+        self.unchecked_color = unchecked_color.into();
+        self
+    }
+
+    /// Set the color of the box and the label while disabled
+    pub fn with_disabled_color<V: Into<String>>(&mut self, disabled_color: V) -> &mut Self {
+        self.disabled_color = disabled_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the label drawn next to the box
+    pub fn with_label<V: Into<String>>(&mut self, label: V) -> &mut Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set the state
+    pub fn with_state(&mut self, state: CheckState) -> &mut Self {
+        self.state = state;
+        self
+    }
+
+    /// Check or uncheck the checkbox
+    pub fn with_checked(&mut self, checked: bool) -> &mut Self {
+        self.state = if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        };
+        self
+    }
+
+    /// Cycle through the indeterminate state when clicked
+    pub fn with_tri_state(&mut self, tri_state: bool) -> &mut Self {
+        self.tri_state = tri_state;
+        self
+    }
+
+    /// Disable the checkbox, it is then neither focused nor clicked
+    pub fn with_disabled(&mut self, disabled: bool) -> &mut Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the callback called with the state whenever the checkbox is clicked
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(CheckState) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the Checkbox widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Checkbox>, Error> {
+        let colors = CheckColors::resolve(
+            ui,
+            &self.text_color,
+            &self.checked_color,
+            &self.unchecked_color,
+            &self.disabled_color,
+        )?;
+
+        let fill = if self.color.is_empty() {
+            ui.theme().check_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let font = match self.font {
+            None => ui.theme().font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        if (self.state == CheckState::Indeterminate) & !self.tri_state {
+            return Err(err_msg("Only a tri-state checkbox can be indeterminate"));
+        }
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, ui.theme().shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Checkbox {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color: colors.text,
+            checked_color: colors.checked,
+            unchecked_color: colors.unchecked,
+            disabled_color: colors.disabled,
+            focus_color: colors.focus,
+            font,
+            text_size,
+            label: self.clone().label,
+            state: self.state,
+            tri_state: self.tri_state,
+            enabled: !self.disabled,
+            pressed: false,
+            on_change: self.clone().on_change,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
+
+/// The colors of the checkboxes, radio buttons and toggles, taken from the theme when not set
+pub(crate) struct CheckColors {
+    pub(crate) text:      Color,
+    pub(crate) checked:   Color,
+    pub(crate) unchecked: Color,
+    pub(crate) disabled:  Color,
+    pub(crate) focus:     Color,
+}
+
+impl CheckColors {
+    pub(crate) fn resolve(ui: &Ui, text: &str, checked: &str, unchecked: &str, disabled: &str) -> Result<Self, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        Ok(Self {
+            text:      color(text, theme.label_text_color())?,
+            checked:   color(checked, theme.checked_color())?,
+            unchecked: color(unchecked, theme.unchecked_color())?,
+            disabled:  color(disabled, theme.disabled_color())?,
+            focus:     theme.selection_color()?,
+        })
+    }
+}
@@ -0,0 +1,1393 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    text_input::char_index,
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The width in pixels of the button opening the list
+const BUTTON: f32 = 20.0;
+/// The number of choices shown in the list before it scrolls, when none is set
+const VISIBLE_CHOICES: usize = 8;
+/// The time in milliseconds after which the characters typed start a new search
+const TYPE_AHEAD: u64 = 1000;
+
+/// The callback given the index of the choice picked, if the text is one of the choices, and the
+/// text shown
+pub type ComboCallback = Arc<Fn(Option<usize>, &str) + Send + Sync>;
+
+/// The ComboBox Widget, showing the current choice and opening the list of the choices below it
+/// in the overlay layer. Typing the first characters of a choice picks it, or when the combo box is
+/// editable any text can be typed in, picking the choice with the same text.
+#[derive(Clone)]
+pub struct ComboBox {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    list_color:      Color,
+    text_color:      Color,
+    highlight_color: Color,
+    focus_color:     Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    row_height:      f32,
+    choices:         Vec<String>,
+    selected:        Option<usize>,
+    editable:        bool,
+    edit:            TextEdit,
+    committed:       String,
+    popup:           Id,
+    open:            bool,
+    highlighted:     Option<usize>,
+    scroll:          usize,
+    visible_choices: usize,
+    typed:           String,
+    last_typed:      Option<Instant>,
+    on_change:       Option<ComboCallback>,
+    dragging:        bool,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for ComboBox {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::ComboBox
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+        self.open = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                button: MouseButton::Left,
+                modifiers,
+                ..
+            } => {
+                if !self.editable || self.on_button(ui, x)? {
+                    let open = !self.open;
+                    self.show_list(open);
+                } else {
+                    let position = self.position_at(ui, x)?;
+                    self.edit.set_caret(position, modifiers.shift);
+                    self.dragging = true;
+                }
+            }
+            InputEvent::CursorMoved { x, .. } if self.dragging => {
+                let position = self.position_at(ui, x)?;
+                self.edit.set_caret(position, true);
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.dragging = false;
+            }
+            InputEvent::Character(c) if !c.is_control() => {
+                if self.editable {
+                    if self.edit.insert(&c.to_string()) {
+                        self.text_changed();
+                    }
+                } else if (c != ' ') | !self.typed.is_empty() {
+                    self.type_ahead(c);
+                } else {
+                    return Ok(false);
+                }
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                let page = self.visible_choices as isize;
+                let last = self.choices.len().saturating_sub(1) as isize;
+                if self.open {
+                    match key {
+                        VirtualKeyCode::Up if modifiers.alt => self.show_list(false),
+                        VirtualKeyCode::Up => self.move_highlight(-1),
+                        VirtualKeyCode::Down => self.move_highlight(1),
+                        VirtualKeyCode::PageUp => self.move_highlight(-page),
+                        VirtualKeyCode::PageDown => self.move_highlight(page),
+                        VirtualKeyCode::Home if !self.editable => self.move_highlight(-last - 1),
+                        VirtualKeyCode::End if !self.editable => self.move_highlight(last + 1),
+                        VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => match self.highlighted {
+                            Some(index) => self.pick(index),
+                            None => {
+                                self.show_list(false);
+                                self.commit();
+                            }
+                        },
+                        VirtualKeyCode::Escape | VirtualKeyCode::F4 => self.show_list(false),
+                        _ => {
+                            return Ok(self.edit_key(ui, key, modifiers));
+                        }
+                    }
+                } else {
+                    match key {
+                        VirtualKeyCode::Down | VirtualKeyCode::Up if modifiers.alt => self.show_list(true),
+                        VirtualKeyCode::F4 => self.show_list(true),
+                        VirtualKeyCode::Space if !self.editable => self.show_list(true),
+                        VirtualKeyCode::Up => self.select_by(-1),
+                        VirtualKeyCode::Down => self.select_by(1),
+                        VirtualKeyCode::Home if !self.editable => self.select_by(-last - 1),
+                        VirtualKeyCode::End if !self.editable => self.select_by(last + 1),
+                        VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter if self.editable => self.commit(),
+                        // Escape only goes to the combo box while the text typed isn't committed
+                        VirtualKeyCode::Escape if self.editable & (self.edit.text() != self.committed) => {
+                            self.edit.set_text(self.committed.as_str());
+                            self.text_changed();
+                        }
+                        _ => {
+                            return Ok(self.edit_key(ui, key, modifiers));
+                        }
+                    }
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        // The button, split by a line from the text, with an arrow pointing down
+        let (width, height) = painter.size();
+        let button = button_width(width);
+        let left = width - button;
+        let line_color = self.text_color.with_alpha(self.text_color.alpha() / 4);
+        painter.line((left, 0.0), (left, height), 1.0, line_color);
+
+        let arrow = (button.min(height) / 6.0).max(1.0);
+        let center = (left + (button / 2.0), height / 2.0);
+        painter.polygon(
+            &[
+                (center.0 - arrow, center.1 - (arrow / 2.0)),
+                (center.0 + arrow, center.1 - (arrow / 2.0)),
+                (center.0, center.1 + (arrow / 2.0)),
+            ],
+            self.text_color,
+        );
+
+        let focused = ui.focused() == Some(self.id());
+        if focused & !self.editable {
+            painter.stroke_rect((0.5, 0.5), (left - 1.0, height - 1.0), 1.0, self.focus_color);
+        }
+
+        if let Some(ref font) = self.font {
+            let line_height = font.measure("", self.text_size).1;
+            let top = (height - line_height) / 2.0;
+            let text = self.edit.text();
+            let offsets = self.offsets(&text);
+
+            if let (true, true, Some((start, end))) = (self.editable, focused, self.edit.selection()) {
+                let start = offsets[char_index(&text, start)];
+                let end = offsets[char_index(&text, end)];
+                painter.rect((PADDING + start, top), (end - start, line_height), self.highlight_color);
+            }
+
+            painter.text((PADDING, top), &text, self.text_size, self.text_color, font);
+
+            if self.editable & focused {
+                let caret = offsets[char_index(&text, self.edit.caret())];
+                painter.rect((PADDING + caret, top), (1.0, line_height), self.text_color);
+            }
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl ComboBox {
+    /// Retrieve the choices
+    pub fn choices(&self) -> &[String] {
+        &self.choices
+    }
+
+    /// Add a choice after the others
+    pub fn add_choice<V: Into<String>>(&mut self, choice: V) {
+        self.choices.push(choice.into());
+    }
+
+    /// Replace the choices, leaving none picked and closing the list
+    pub fn set_choices(&mut self, choices: Vec<String>) {
+        self.choices = choices;
+        self.open = false;
+        self.set_selected(None);
+    }
+
+    /// Retrieve the index of the choice picked, if the text shown is one of the choices
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Pick a choice, or none, without calling the change callback
+    pub fn set_selected(&mut self, selected: Option<usize>) {
+        self.selected = selected.filter(|&index| index < self.choices.len());
+        let text = self
+            .selected
+            .map(|index| self.choices[index].clone())
+            .unwrap_or_default();
+        self.edit.set_text(text.as_str());
+        self.committed = text;
+    }
+
+    /// Retrieve the text shown, the choice picked or the text typed in an editable combo box
+    pub fn text(&self) -> String {
+        self.edit.text()
+    }
+
+    /// Set the text of an editable combo box, picking the choice with the same text if any,
+    /// without calling the change callback
+    pub fn set_text<V: Into<String>>(&mut self, text: V) {
+        if !self.editable {
+            return;
+        }
+
+        let text = text.into();
+        self.edit.set_text(text.as_str());
+        self.selected = self.choices.iter().position(|choice| *choice == text);
+        self.committed = text;
+    }
+
+    /// Retrieve the text being edited, along with the caret and the selection
+    pub fn edit(&self) -> &TextEdit {
+        &self.edit
+    }
+
+    /// Retrieve whether any text can be typed in
+    pub fn editable(&self) -> bool {
+        self.editable
+    }
+
+    /// Retrieve whether the list of the choices is open
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Open the list of the choices
+    pub fn open(&mut self) {
+        self.show_list(true);
+    }
+
+    /// Close the list of the choices
+    pub fn close(&mut self) {
+        self.show_list(false);
+    }
+
+    /// Retrieve the index of the choice highlighted in the list
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Retrieve the id of the widget drawing the list of the choices in the overlay layer
+    pub fn popup_id(&self) -> Id {
+        self.popup.clone()
+    }
+
+    /// Retrieve the number of choices shown in the list before it scrolls
+    pub fn visible_choices(&self) -> usize {
+        self.visible_choices
+    }
+
+    /// Retrieve the height in pixels of the rows of the list
+    pub fn row_height(&self) -> f32 {
+        self.row_height
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the choice picked and the text whenever the user changes them
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(Option<usize>, &str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    /// Handle an event delivered to the list in the overlay layer, which closes when pressed
+    /// anywhere else
+    fn handle_list_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::CursorMoved { x, y } => {
+                if let Some(index) = self.choice_at(ui, x, y)? {
+                    self.highlighted = Some(index);
+                }
+            }
+            InputEvent::MousePressed { x, y, button, .. } => match self.choice_at(ui, x, y)? {
+                Some(index) if button == MouseButton::Left => self.pick(index),
+                Some(_) => {}
+                None => {
+                    let inside = match self.list_area(ui)? {
+                        None => false,
+                        Some((left, top, width, height)) => {
+                            (x >= left) & (x < left + width) & (y >= top) & (y < top + height)
+                        }
+                    };
+                    if !inside {
+                        self.show_list(false);
+                    }
+                }
+            },
+            InputEvent::MouseWheel { delta_y, .. } => {
+                let rows = (-delta_y / self.row_height).round() as isize;
+                self.scroll_by(rows);
+            }
+            InputEvent::MouseReleased { .. } => {}
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Draw the list of the choices below the combo box, or above it when there is no room left
+    /// below, caching its texture in the texture of the list
+    fn draw_list(&self, ui: &Ui, texture: &TextureCache) -> Result<Vec<DrawVertex>, Error> {
+        let window_size = ui.window_size();
+        let (left, top, width, height) = match self.list_area(ui)? {
+            Some(val) if self.open => val,
+            _ => {
+                texture.clear();
+                return Ok(Vec::new());
+            }
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+        let mut vertices = Vec::new();
+
+        // The list is rounded and bordered like the combo box, under the painted choices
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+        let (bl_vertex, tr_vertex) = list_vertices((left, top, width, height), window_size);
+        for (position, mut color) in Fill::Solid(self.list_color).triangles(bl_vertex, tr_vertex) {
+            color[3] *= opacity;
+            vertices.push(DrawVertex::new(position, color).with_shape(
+                (bl_vertex, tr_vertex),
+                self.corner_radius,
+                self.border.width(),
+                border_color,
+            ));
+        }
+
+        let mut painter = Painter::new(width, height);
+
+        let rows = self.visible_choices.min(self.choices.len());
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+        for (row, index) in (self.scroll..self.scroll + rows).enumerate() {
+            let y = PADDING + (row as f32 * self.row_height);
+            if self.highlighted == Some(index) {
+                painter.rect((0.0, y), (width, self.row_height), self.highlight_color);
+            }
+            if let Some(ref font) = self.font {
+                painter.text(
+                    (PADDING, y + ((self.row_height - line_height) / 2.0)),
+                    &self.choices[index],
+                    self.text_size,
+                    self.text_color,
+                    font,
+                );
+            }
+        }
+
+        // The scroll bar, only when some choices aren't shown
+        if rows < self.choices.len() {
+            let track = height - (2.0 * PADDING);
+            let thumb = (track * rows as f32) / self.choices.len() as f32;
+            let offset = (track * self.scroll as f32) / self.choices.len() as f32;
+            painter.rect(
+                (width - PADDING - 2.0, PADDING + offset),
+                (2.0, thumb),
+                self.text_color.with_alpha(self.text_color.alpha() / 2),
+            );
+        }
+
+        let list_texture = painter.texture(texture);
+        vertices.extend(
+            painter.draw_vertices(bl_vertex, window_size, list_texture.as_ref(), |position, mut color| {
+                color[3] *= opacity;
+                DrawVertex::new(position, color)
+            }),
+        );
+
+        Ok(vertices)
+    }
+
+    /// Draw the shadow of the list of the choices, cast like the shadow of the combo box
+    fn draw_list_shadow(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let area = match self.list_area(ui)? {
+            Some(val) if self.open & self.shadow.visible() => val,
+            _ => {
+                return Ok(vertices);
+            }
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+        let (bl_vertex, tr_vertex) = list_vertices(area, ui.window_size());
+        for (position, mut color) in Fill::Solid(self.shadow.color()).triangles(bl_vertex, tr_vertex) {
+            color[3] *= opacity;
+            vertices.push(
+                DrawVertex::new(position, color)
+                    .with_shape((bl_vertex, tr_vertex), self.corner_radius, 0.0, [0.0; 4])
+                    .with_blur(self.shadow.offset(), self.shadow.blur_radius()),
+            );
+        }
+
+        Ok(vertices)
+    }
+
+    fn show_list(&mut self, open: bool) {
+        self.open = open & !self.choices.is_empty();
+        self.typed.clear();
+        if self.open {
+            self.highlighted = self.selected;
+            self.scroll = 0;
+            self.scroll_to_highlighted();
+        }
+    }
+
+    /// Pick a choice from the list and close it
+    fn pick(&mut self, index: usize) {
+        self.show_list(false);
+        self.select(index);
+    }
+
+    /// Pick a choice, calling the change callback if it changed
+    fn select(&mut self, index: usize) {
+        let text = self.choices[index].clone();
+        self.selected = Some(index);
+        self.edit.set_text(text.as_str());
+        self.edit.select_all();
+        if text != self.committed {
+            self.committed = text;
+            self.changed();
+        }
+    }
+
+    /// Pick the choice some choices after the one picked, or before it
+    fn select_by(&mut self, delta: isize) {
+        if self.choices.is_empty() {
+            return;
+        }
+
+        let index = match self.selected {
+            None if delta < 0 => self.choices.len() as isize - 1,
+            None => 0,
+            Some(val) => val as isize + delta,
+        };
+        self.select(index.max(0).min(self.choices.len() as isize - 1) as usize);
+    }
+
+    /// Highlight the choice some choices after the one highlighted, or before it
+    fn move_highlight(&mut self, delta: isize) {
+        if self.choices.is_empty() {
+            return;
+        }
+
+        let index = match self.highlighted {
+            None if delta < 0 => self.choices.len() as isize - 1,
+            None => 0,
+            Some(val) => val as isize + delta,
+        };
+        self.highlighted = Some(index.max(0).min(self.choices.len() as isize - 1) as usize);
+        self.scroll_to_highlighted();
+    }
+
+    /// Commit the text typed in an editable combo box, calling the change callback if it changed
+    fn commit(&mut self) {
+        let text = self.edit.text();
+        if text != self.committed {
+            self.committed = text;
+            self.changed();
+        }
+    }
+
+    fn changed(&self) {
+        if let Some(ref on_change) = self.on_change {
+            on_change(self.selected, &self.edit.text());
+        }
+    }
+
+    /// Pick the choice with the text typed in an editable combo box, and highlight the first
+    /// choice starting with it
+    fn text_changed(&mut self) {
+        let text = self.edit.text();
+        self.selected = self.choices.iter().position(|choice| *choice == text);
+
+        let prefix = text.to_lowercase();
+        if let (false, Some(index)) = (
+            prefix.is_empty(),
+            self.choices
+                .iter()
+                .position(|choice| choice.to_lowercase().starts_with(&prefix)),
+        ) {
+            self.highlighted = Some(index);
+            self.scroll_to_highlighted();
+        }
+    }
+
+    /// Apply an editing key to the text of an editable combo box
+    fn edit_key(&mut self, ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> bool {
+        if !self.editable {
+            return false;
+        }
+
+        match self.edit.handle_key(ui, key, modifiers, false) {
+            None => false,
+            Some(changed) => {
+                if changed {
+                    self.text_changed();
+                }
+                true
+            }
+        }
+    }
+
+    /// Add a character to the search of the characters typed shortly one after the other, moving
+    /// to the next choice starting with them. Typing the same character cycles through the
+    /// choices starting with it.
+    fn type_ahead(&mut self, c: char) {
+        let expired = match self.last_typed {
+            None => true,
+            Some(time) => time.elapsed() >= Duration::from_millis(TYPE_AHEAD),
+        };
+        if expired {
+            self.typed.clear();
+        }
+        self.typed.extend(c.to_lowercase());
+        self.last_typed = Some(Instant::now());
+
+        let len = self.choices.len();
+        let current = if self.open { self.highlighted } else { self.selected };
+        let first = self.typed.chars().next().unwrap_or(c);
+        let repeated = self.typed.chars().all(|typed| typed == first);
+        let (prefix, start) = match current {
+            None => (self.typed.clone(), 0),
+            Some(val) if repeated => (first.to_string(), val + 1),
+            Some(val) => (self.typed.clone(), val),
+        };
+
+        let found = (0..len)
+            .map(|step| (start + step) % len)
+            .find(|&index| self.choices[index].to_lowercase().starts_with(&prefix));
+        if let Some(index) = found {
+            if self.open {
+                self.highlighted = Some(index);
+                self.scroll_to_highlighted();
+            } else {
+                let typed = self.typed.clone();
+                self.select(index);
+                self.typed = typed;
+            }
+        }
+    }
+
+    fn scroll_by(&mut self, rows: isize) {
+        let max = self.choices.len().saturating_sub(self.visible_choices) as isize;
+        self.scroll = (self.scroll as isize + rows).max(0).min(max) as usize;
+    }
+
+    /// Scroll the list so that the choice highlighted is shown
+    fn scroll_to_highlighted(&mut self) {
+        if let Some(index) = self.highlighted {
+            if index < self.scroll {
+                self.scroll = index;
+            } else if index >= self.scroll + self.visible_choices {
+                self.scroll = index + 1 - self.visible_choices;
+            }
+        }
+    }
+
+    /// The distance in pixels from the start of a text to each character, followed by its width
+    fn offsets(&self, text: &str) -> Vec<f32> {
+        match self.font {
+            None => vec![0.0; text.chars().count() + 1],
+            Some(ref font) => font.offsets(text, self.text_size),
+        }
+    }
+
+    /// The left, top, width and height in pixels of the combo box in the window
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+
+    /// The left, top, width and height in pixels of the list in the window, as wide as the combo
+    /// box and below it, or above it when there is no room left below
+    fn list_area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (left, top, width, height) = match self.area(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        let (window_width, window_height) = (window_size[0] as f32, window_size[1] as f32);
+        let rows = self.visible_choices.min(self.choices.len());
+        let list_height = (rows as f32 * self.row_height) + (2.0 * PADDING);
+
+        let below = top + height;
+        let list_top = if (below + list_height > window_height) & (top - list_height >= 0.0) {
+            top - list_height
+        } else {
+            below
+        };
+
+        Ok(Some((
+            left.min(window_width - width).max(0.0),
+            list_top,
+            width,
+            list_height,
+        )))
+    }
+
+    /// The index of the choice in the list under a position in pixels in the window
+    fn choice_at(&self, ui: &Ui, x: f32, y: f32) -> Result<Option<usize>, Error> {
+        let (left, top, width, height) = match self.list_area(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        if (x < left) | (x >= left + width) | (y < top + PADDING) | (y >= top + height - PADDING) {
+            return Ok(None);
+        }
+
+        let index = self.scroll + ((y - top - PADDING) / self.row_height) as usize;
+        Ok(if index < self.choices.len() { Some(index) } else { None })
+    }
+
+    /// Whether a horizontal position in pixels in the window is on the button opening the list
+    fn on_button(&self, ui: &Ui, x: f32) -> Result<bool, Error> {
+        Ok(match self.area(ui)? {
+            None => false,
+            Some((left, _, width, _)) => x - left >= width - button_width(width),
+        })
+    }
+
+    /// The position in the text closest to a horizontal position in pixels in the window
+    fn position_at(&self, ui: &Ui, x: f32) -> Result<usize, Error> {
+        let left = match self.area(ui)? {
+            None => {
+                return Ok(self.edit.caret());
+            }
+            Some((val, ..)) => val,
+        };
+
+        let x = x - left - PADDING;
+        let text = self.edit.text();
+        let index = self
+            .offsets(&text)
+            .iter()
+            .enumerate()
+            .min_by(|a, b| (a.1 - x).abs().partial_cmp(&(b.1 - x).abs()).unwrap())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        Ok(text
+            .char_indices()
+            .nth(index)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| text.len()))
+    }
+}
+
+/// The width in pixels of the button of a combo box of a width in pixels
+fn button_width(width: f32) -> f32 {
+    BUTTON.min(width / 2.0)
+}
+
+/// The list of the choices of a ComboBox widget, built along with it in the overlay layer. It
+/// covers the window while it is open so that pressing anywhere else closes it.
+#[derive(Clone)]
+pub struct ComboPopup {
+    id:        Id,
+    parent_id: Id,
+    text:      Arc<TextureCache>,
+}
+
+impl ComboPopup {
+    /// Run a function on the combo box of the list, locked while the list handles an event or
+    /// draws itself
+    fn with_combo_box<F, T>(&self, ui: &Ui, default: T, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut ComboBox) -> Result<T, Error>,
+    {
+        match ui.widgets().get(&self.parent_id) {
+            None => Ok(default),
+            Some(widget) => match widget.lock().downcast_mut::<ComboBox>() {
+                None => Ok(default),
+                Some(combo_box) => f(combo_box),
+            },
+        }
+    }
+}
+
+impl WidgetTrait for ComboPopup {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::ComboPopup
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        Size::Full
+    }
+
+    fn set_size(&mut self, _size: Size) {}
+
+    fn position(&self) -> Position {
+        Position::default()
+    }
+
+    fn color(&self) -> Color {
+        Color::default()
+    }
+
+    fn fill(&self) -> Fill {
+        Fill::default()
+    }
+
+    fn visible(&self) -> bool {
+        true
+    }
+
+    fn show(&mut self) {}
+
+    fn hide(&mut self) {}
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        1.0
+    }
+
+    fn set_opacity(&mut self, _opacity: f32) {}
+
+    fn z_index(&self) -> i32 {
+        0
+    }
+
+    fn set_z_index(&mut self, _z_index: i32) {}
+
+    fn layer(&self) -> Layer {
+        Layer::Overlay
+    }
+
+    fn border(&self) -> Border {
+        Border::default()
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        CornerRadius::default()
+    }
+
+    fn shadow(&self) -> Shadow {
+        Shadow::default()
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn bounds(&self, ui: &Ui) -> Result<Option<(Vertex, Vertex)>, Error> {
+        let open = self.with_combo_box(ui, false, |combo_box| {
+            Ok(combo_box.open & combo_box.bounds(ui)?.is_some())
+        })?;
+        Ok(if open {
+            Some((Vertex { x: 0.0, y: 0.0 }, Vertex { x: 2.0, y: 2.0 }))
+        } else {
+            None
+        })
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        self.with_combo_box(ui, false, |combo_box| {
+            if !combo_box.open {
+                return Ok(false);
+            }
+            combo_box.handle_list_event(ui, event)
+        })
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        if (ui.window_size()[0] == 0) | (ui.window_size()[1] == 0) {
+            self.text.clear();
+            return Ok(Vec::new());
+        }
+
+        self.with_combo_box(ui, Vec::new(), |combo_box| combo_box.draw_list(ui, &self.text))
+    }
+
+    fn draw_shadow(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        // The popup covers the whole window while open, so only the list casts a shadow
+        if (ui.window_size()[0] == 0) | (ui.window_size()[1] == 0) {
+            return Ok(Vec::new());
+        }
+
+        self.with_combo_box(ui, Vec::new(), |combo_box| combo_box.draw_list_shadow(ui))
+    }
+}
+
+/// The corners of the list of the choices in pixels as vertices
+fn list_vertices((x, y, width, height): (f32, f32, f32, f32), window_size: [u32; 2]) -> (Vertex, Vertex) {
+    let (window_width, window_height) = (window_size[0] as f32, window_size[1] as f32);
+    (
+        Vertex {
+            x: (x * 2.0) / window_width,
+            y: (y * 2.0) / window_height,
+        },
+        Vertex {
+            x: ((x + width) * 2.0) / window_width,
+            y: ((y + height) * 2.0) / window_height,
+        },
+    )
+}
+
+/// The builder for the ComboBox widget
+#[derive(Clone, Default)]
+pub struct ComboBoxBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    list_color:      String,
+    text_color:      String,
+    highlight_color: String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    row_height:      Option<f32>,
+    choices:         Vec<String>,
+    selected:        Option<usize>,
+    editable:        bool,
+    text:            Option<String>,
+    visible_choices: Option<usize>,
+    on_change:       Option<ComboCallback>,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+    elevation:       Option<f32>,
+}
+
+impl ComboBoxBuilder {
+    /// Initialize the builder for the ComboBox widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the ComboBox widget from another ComboBox widget
+    pub fn new_from_combo_box(combo_box: &ComboBox) -> Self {
+        Self {
+            id:              combo_box.id(),
+            size:            combo_box.size(),
+            position:        combo_box.position(),
+            color:           combo_box.fill().into_string(),
+            list_color:      combo_box.list_color.into_hex(),
+            text_color:      combo_box.text_color.into_hex(),
+            highlight_color: combo_box.highlight_color.into_hex(),
+            font:            combo_box.font(),
+            text_size:       Some(combo_box.text_size()),
+            row_height:      Some(combo_box.row_height()),
+            choices:         combo_box.choices().to_vec(),
+            selected:        combo_box.selected(),
+            editable:        combo_box.editable(),
+            text:            Some(combo_box.text()),
+            visible_choices: Some(combo_box.visible_choices()),
+            on_change:       combo_box.on_change.clone(),
+            parent_id:       combo_box.parent_id().unwrap(),
+            visible:         combo_box.visible(),
+            opacity:         combo_box.opacity(),
+            z_index:         combo_box.z_index(),
+            layer:           combo_box.layer(),
+            border_color:    combo_box.border().color().into_hex(),
+            border_width:    Some(combo_box.border().width()),
+            corner_radius:   Some(combo_box.corner_radius().as_array()),
+            elevation:       Some(combo_box.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color behind the choices of the list
+    pub fn with_list_color<V: Into<String>>(&mut self, list_color: V) -> &mut Self {
+        self.list_color = list_color.into();
+        self
+    }
+
+    /// Set the color of the text and the arrow of the button
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color behind the choice highlighted in the list and the selected text
+    pub fn with_highlight_color<V: Into<String>>(&mut self, highlight_color: V) -> &mut Self {
+        self.highlight_color = highlight_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the height in pixels of the rows of the list, the size of the text and some padding
+    /// by default
+    pub fn with_row_height(&mut self, row_height: f32) -> &mut Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Add a choice after the others
+    pub fn with_choice<V: Into<String>>(&mut self, choice: V) -> &mut Self {
+        self.choices.push(choice.into());
+        self
+    }
+
+    /// Add choices after the others
+    pub fn with_choices<I, V>(&mut self, choices: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.choices.extend(choices.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the index of the choice picked, none by default
+    pub fn with_selected(&mut self, selected: usize) -> &mut Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Set whether any text can be typed in
+    pub fn with_editable(&mut self, editable: bool) -> &mut Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Set the text of an editable combo box, picking the choice with the same text if any
+    pub fn with_text<V: Into<String>>(&mut self, text: V) -> &mut Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Set the number of choices shown in the list before it scrolls, 8 by default
+    pub fn with_visible_choices(&mut self, visible_choices: usize) -> &mut Self {
+        self.visible_choices = Some(visible_choices);
+        self
+    }
+
+    /// Set the callback called with the choice picked and the text whenever the user changes them
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(Option<usize>, &str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the ComboBox widget, along with the widget drawing its list in the overlay layer
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<ComboBox>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.text_input_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let list_color = color(&self.list_color, theme.container_color())?;
+        let text_color = color(&self.text_color, theme.text_input_text_color())?;
+        let highlight_color = color(&self.highlight_color, theme.selection_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let row_height = self.row_height.unwrap_or(text_size + (2.0 * PADDING));
+        if row_height <= 0.0 {
+            return Err(err_msg("The row height must be greater than 0.0"));
+        }
+
+        let visible_choices = self.visible_choices.unwrap_or(VISIBLE_CHOICES);
+        if visible_choices == 0 {
+            return Err(err_msg("The number of visible choices must be greater than 0"));
+        }
+
+        if let Some(val) = self.selected {
+            if val >= self.choices.len() {
+                return Err(err_msg(format!(
+                    "The selected choice {} is not one of the choices",
+                    val
+                )));
+            }
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, theme.shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let popup = format!("{}_popup", self.id);
+        let mut widget = Box::new(ComboBox {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            list_color,
+            text_color,
+            highlight_color,
+            focus_color: theme.checked_color()?,
+            font,
+            text_size,
+            row_height,
+            choices: self.choices.clone(),
+            selected: None,
+            editable: self.editable,
+            edit: TextEdit::default(),
+            committed: String::new(),
+            popup: popup.clone(),
+            open: false,
+            highlighted: None,
+            scroll: 0,
+            visible_choices,
+            typed: String::new(),
+            last_typed: None,
+            on_change: self.clone().on_change,
+            dragging: false,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+        widget.set_selected(self.selected);
+        if let Some(ref val) = self.text {
+            widget.set_text(val.as_str());
+        }
+
+        ui.add_widget(widget.clone());
+        ui.add_widget(Box::new(ComboPopup {
+            id:        popup,
+            parent_id: self.clone().id,
+            text:      Arc::new(TextureCache::default()),
+        }));
+
+        Ok(widget)
+    }
+}
@@ -13,12 +13,20 @@ use crate::prelude::*;
 /// The Container Widget
 #[derive(Clone)]
 pub struct Container {
-    id:        Id,
-    parent_id: Id,
-    size:      Size,
-    position:  Position,
-    color:     Color,
-    visible:   bool,
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    visible:       bool,
+    opacity:       f32,
+    overflow:      Overflow,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+    background:    Option<NineSlice>,
 }
 
 impl WidgetTrait for Container {
@@ -47,7 +55,11 @@ impl WidgetTrait for Container {
     }
 
     fn color(&self) -> Color {
-        self.color
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
     }
 
     fn visible(&self) -> bool {
@@ -61,17 +73,71 @@ impl WidgetTrait for Container {
     fn hide(&mut self) {
         self.visible = false;
     }
+
+    fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn background(&self) -> Option<NineSlice> {
+        self.background.clone()
+    }
 }
 
 /// The builder for the Container widget
 #[derive(Clone, Default)]
 pub struct ContainerBuilder {
-    id:        Id,
-    size:      Size,
-    position:  Position,
-    color:     String,
-    parent_id: Id,
-    visible:   bool,
+    id:                 Id,
+    size:               Size,
+    position:           Position,
+    color:              String,
+    parent_id:          Id,
+    visible:            bool,
+    opacity:            f32,
+    overflow:           Overflow,
+    z_index:            i32,
+    layer:              Layer,
+    border_color:       String,
+    border_width:       Option<f32>,
+    corner_radius:      Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<(f32, f32)>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       String,
+    background_image:   String,
+    background_texture: Option<Texture>,
+    background_insets:  Option<[u32; 4]>,
 }
 
 impl ContainerBuilder {
@@ -85,6 +151,7 @@ impl ContainerBuilder {
             position,
             parent_id: parent_id.into(),
             visible: true,
+            opacity: 1.0,
             ..Self::default()
         }
     }
@@ -92,16 +159,30 @@ impl ContainerBuilder {
     /// Initialize the builder for the Container widget from another Container widget
     pub fn new_from_container(container: &Container) -> Self {
         Self {
-            id:        container.id(),
-            size:      container.size(),
-            position:  container.position(),
-            color:     container.color().into_hex(),
-            parent_id: container.parent_id().unwrap(),
-            visible:   container.visible(),
+            id:                 container.id(),
+            size:               container.size(),
+            position:           container.position(),
+            color:              container.fill().into_string(),
+            parent_id:          container.parent_id().unwrap(),
+            visible:            container.visible(),
+            opacity:            container.opacity(),
+            overflow:           container.overflow(),
+            z_index:            container.z_index(),
+            layer:              container.layer(),
+            border_color:       container.border().color().into_hex(),
+            border_width:       Some(container.border().width()),
+            corner_radius:      Some(container.corner_radius().as_array()),
+            elevation:          None,
+            shadow_offset:      Some(container.shadow().offset()),
+            shadow_blur_radius: Some(container.shadow().blur_radius()),
+            shadow_color:       container.shadow().color().into_hex(),
+            background_image:   String::new(),
+            background_texture: container.background().map(|background| background.texture()),
+            background_insets:  container.background().map(|background| background.insets()),
         }
     }
 
-    /// Set the color
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
     pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
         self.color = color.into();
         self
@@ -113,21 +194,181 @@ impl ContainerBuilder {
         self
     }
 
+    /// Set how the children are clipped
+    pub fn with_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Set the offset in pixels of the shadow
+    pub fn with_shadow_offset(&mut self, x: f32, y: f32) -> &mut Self {
+        self.shadow_offset = Some((x, y));
+        self
+    }
+
+    /// Set the blur radius in pixels of the shadow
+    pub fn with_shadow_blur_radius(&mut self, blur_radius: f32) -> &mut Self {
+        self.shadow_blur_radius = Some(blur_radius);
+        self
+    }
+
+    /// Set the shadow color
+    pub fn with_shadow_color<V: Into<String>>(&mut self, shadow_color: V) -> &mut Self {
+        self.shadow_color = shadow_color.into();
+        self
+    }
+
+    /// Set the path of the PNG or JPEG image drawn over the background
+    pub fn with_background_image<V: Into<String>>(&mut self, background_image: V) -> &mut Self {
+        self.background_image = background_image.into();
+        self
+    }
+
+    /// Set the texture drawn over the background
+    pub fn with_background_texture(&mut self, background_texture: Texture) -> &mut Self {
+        self.background_texture = Some(background_texture);
+        self
+    }
+
+    /// Set the insets in pixels splitting the background image into nine slices, the corners
+    /// keep their size while the edges and the center stretch
+    pub fn with_background_insets(&mut self, top: u32, right: u32, bottom: u32, left: u32) -> &mut Self {
+        self.background_insets = Some([top, right, bottom, left]);
+        self
+    }
+
     /// Build the Container widget
     pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Container>, Error> {
-        let color = if self.color.is_empty() {
-            ui.theme().container_color()?
+        let fill = if self.color.is_empty() {
+            ui.theme().container_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => ui.theme().container_shadow()?,
+            Some(val) => Shadow::from_elevation(val, ui.theme().container_shadow()?.color())?,
+        };
+
+        let shadow_color = if self.shadow_color.is_empty() {
+            shadow.color()
         } else {
-            Color::from_hex(self.clone().color)?
+            Color::from_hex(self.clone().shadow_color)?
         };
 
+        let shadow = Shadow::new(
+            self.shadow_offset.unwrap_or_else(|| shadow.offset()),
+            self.shadow_blur_radius.unwrap_or_else(|| shadow.blur_radius()),
+            shadow_color,
+        )?;
+
+        let insets = self.background_insets.unwrap_or_default();
+        let background = match self.background_texture {
+            Some(ref texture) => Some(NineSlice::new(texture.clone(), insets)?),
+            None if !self.background_image.is_empty() => Some(NineSlice::from_path(&self.background_image, insets)?),
+            None => match (ui.theme().container_background()?, self.background_insets) {
+                (Some(background), Some(insets)) => Some(NineSlice::new(background.texture(), insets)?),
+                (background, _) => background,
+            },
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
         let widget = Box::new(Container {
             id: self.clone().id,
             parent_id: self.clone().parent_id,
             position: self.position,
             size: self.size,
-            color,
+            fill,
             visible: self.visible,
+            opacity: self.opacity,
+            overflow: self.overflow,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+            background,
         });
 
         ui.add_widget(widget.clone());
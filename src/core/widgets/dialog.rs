@@ -0,0 +1,950 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The space in pixels on each side of the title, the text and the label of a button
+const BUTTON_PADDING: f32 = 12.0;
+/// The narrowest a button gets in pixels
+const MIN_BUTTON_WIDTH: f32 = 80.0;
+
+/// The callback given the label of the button chosen, or None when the dialog is dismissed
+pub type DialogCallback = Arc<Fn(Option<&str>) + Send + Sync>;
+
+/// The Dialog Widget, a modal panel with a title, an optional text and a row of buttons over the
+/// rest of the window. It is drawn in the overlay layer and dims the content below it while it is
+/// open, the input only reaching the dialog and its children, with the keyboard focus going
+/// around them on tab. Its children are laid out between the title and the buttons.
+#[derive(Clone)]
+pub struct Dialog {
+    id:                   Id,
+    parent_id:            Id,
+    size:                 Size,
+    position:             Position,
+    fill:                 Fill,
+    title_color:          Color,
+    text_color:           Color,
+    button_color:         Color,
+    default_button_color: Color,
+    dim_color:            Color,
+    font:                 Option<Font>,
+    text_size:            f32,
+    title:                String,
+    message:              String,
+    buttons:              Vec<String>,
+    default_button:       usize,
+    pressed:              Option<usize>,
+    open:                 bool,
+    return_focus:         Option<Id>,
+    on_close:             Option<DialogCallback>,
+    text:                 Arc<TextureCache>,
+    opacity:              f32,
+    z_index:              i32,
+    border:               Border,
+    corner_radius:        CornerRadius,
+    shadow:               Shadow,
+}
+
+impl WidgetTrait for Dialog {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Dialog
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.open
+    }
+
+    fn show(&mut self) {}
+
+    fn hide(&mut self) {
+        self.open = false;
+        self.pressed = None;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        Layer::Overlay
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        if !self.open {
+            return Ok(false);
+        }
+
+        // Every mouse event reaching the dialog is handled, the content below it is out of reach
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.pressed = self.button_at(ui, x, y)?;
+                if let Some(index) = self.pressed {
+                    self.default_button = index;
+                }
+            }
+            InputEvent::MouseReleased {
+                x,
+                y,
+                button: MouseButton::Left,
+            } => {
+                let pressed = self.pressed.take();
+                if pressed.is_some() & (pressed == self.button_at(ui, x, y)?) {
+                    self.choose(ui, pressed);
+                }
+            }
+            InputEvent::CursorMoved { .. }
+            | InputEvent::MousePressed { .. }
+            | InputEvent::MouseReleased { .. }
+            | InputEvent::MouseWheel { .. } => {}
+            InputEvent::KeyPressed { key, modifiers } => {
+                let focused = ui.focused() == Some(self.id());
+                let len = self.buttons.len();
+                match key {
+                    VirtualKeyCode::Escape => self.choose(ui, None),
+                    VirtualKeyCode::Tab if !(modifiers.ctrl | modifiers.logo) => {
+                        self.cycle_focus(ui, !modifiers.shift);
+                    }
+                    VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter if len > 0 => {
+                        self.choose(ui, Some(self.default_button));
+                    }
+                    VirtualKeyCode::Space if focused & (len > 0) => {
+                        self.choose(ui, Some(self.default_button));
+                    }
+                    VirtualKeyCode::Left if focused & (len > 0) => {
+                        self.default_button = self.default_button.saturating_sub(1);
+                    }
+                    VirtualKeyCode::Right if focused & (len > 0) => {
+                        self.default_button = (self.default_button + 1).min(len - 1);
+                    }
+                    _ => {
+                        return Ok(false);
+                    }
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn child_area(&self, ui: &Ui, _child: &Id, bl_vertex: Vertex, tr_vertex: Vertex) -> Option<(Vertex, Vertex)> {
+        // The children are laid out between the title and the row of buttons
+        let window_size = ui.window_size();
+        let (top, bottom) = if window_size[1] == 0 {
+            (0.0, 0.0)
+        } else {
+            (
+                (self.title_height() * 2.0) / window_size[1] as f32,
+                (self.button_row_height() * 2.0) / window_size[1] as f32,
+            )
+        };
+
+        let top = (bl_vertex.y() + top).min(tr_vertex.y());
+        Some((
+            Vertex {
+                x: bl_vertex.x(),
+                y: top,
+            },
+            Vertex {
+                x: tr_vertex.x(),
+                y: (tr_vertex.y() - bottom).max(top),
+            },
+        ))
+    }
+
+    fn draw_shadow(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        // The whole window is dimmed first, so that the shadow of the panel falls on it
+        let window = (Vertex { x: 0.0, y: 0.0 }, Vertex { x: 2.0, y: 2.0 });
+        for (position, mut color) in Fill::Solid(self.dim_color).triangles(window.0, window.1) {
+            color[3] *= opacity;
+            vertices.push(DrawVertex::new(position, color));
+        }
+
+        if !self.shadow.visible() {
+            return Ok(vertices);
+        }
+
+        for (position, mut color) in Fill::Solid(self.shadow.color()).triangles(bl_vertex, tr_vertex) {
+            color[3] *= opacity;
+            vertices.push(
+                DrawVertex::new(position, color)
+                    .with_shape((bl_vertex, tr_vertex), self.corner_radius, 0.0, [0.0; 4])
+                    .with_blur(self.shadow.offset(), self.shadow.blur_radius()),
+            );
+        }
+
+        Ok(vertices)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                self.text.clear();
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let width = ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0;
+        let height = ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0;
+        let mut painter = Painter::new(width, height);
+
+        let title_height = self.title_height();
+        painter.rect((0.0, 0.0), (width, title_height), self.title_color);
+
+        if let Some(ref font) = self.font {
+            let line_height = font.measure("", self.text_size).1;
+            painter.text(
+                (BUTTON_PADDING, (title_height - line_height) / 2.0),
+                &self.title,
+                self.text_size,
+                self.text_color,
+                font,
+            );
+
+            let mut y = title_height + BUTTON_PADDING;
+            for line in self.message.lines() {
+                for (start, end) in font.wrap(line, self.text_size, width - (2.0 * BUTTON_PADDING)) {
+                    painter.text(
+                        (BUTTON_PADDING, y),
+                        &line[start..end],
+                        self.text_size,
+                        self.text_color,
+                        font,
+                    );
+                    y += line_height;
+                }
+            }
+        }
+
+        let focused = ui.focused() == Some(self.id());
+        for (index, (x, y, button_width, button_height)) in self.button_rects(width, height).into_iter().enumerate() {
+            let color = if index == self.default_button {
+                self.default_button_color
+            } else {
+                self.button_color
+            };
+            painter.rect((x, y), (button_width, button_height), color);
+
+            if let Some(ref font) = self.font {
+                let (text_width, line_height) = font.measure(&self.buttons[index], self.text_size);
+                painter.text(
+                    (
+                        x + ((button_width - text_width) / 2.0),
+                        y + ((button_height - line_height) / 2.0),
+                    ),
+                    &self.buttons[index],
+                    self.text_size,
+                    self.text_color,
+                    font,
+                );
+            }
+
+            if focused & (index == self.default_button) {
+                painter.stroke_rect(
+                    (x + 0.5, y + 0.5),
+                    (button_width - 1.0, button_height - 1.0),
+                    1.0,
+                    self.text_color,
+                );
+            }
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Dialog {
+    /// Open the dialog, blocking the input to the rest of the window, and give the keyboard focus
+    /// to the first focusable widget inside it, or to the dialog itself
+    pub fn open(&mut self, ui: &Ui) {
+        if self.open {
+            return;
+        }
+
+        self.open = true;
+        self.pressed = None;
+        self.return_focus = ui.focused();
+        ui.open_modal(self.id());
+
+        let focus = match self.focusable(ui).first() {
+            None => self.id(),
+            Some(val) => val.clone(),
+        };
+        ui.set_focus(Some(focus));
+    }
+
+    /// Close the dialog without calling the close callback, giving the keyboard focus back to the
+    /// widget that had it when the dialog was opened
+    pub fn close(&mut self, ui: &Ui) {
+        if !self.open {
+            return;
+        }
+
+        self.open = false;
+        self.pressed = None;
+        ui.close_modal(&self.id);
+        ui.set_focus(self.return_focus.take());
+    }
+
+    /// Retrieve whether the dialog is open
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Retrieve the title
+    pub fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    /// Set the title
+    pub fn set_title<V: Into<String>>(&mut self, title: V) {
+        self.title = title.into();
+    }
+
+    /// Retrieve the text shown below the title
+    pub fn text(&self) -> String {
+        self.message.clone()
+    }
+
+    /// Set the text shown below the title, wrapped at the width of the dialog
+    pub fn set_text<V: Into<String>>(&mut self, text: V) {
+        self.message = text.into();
+    }
+
+    /// Retrieve the labels of the buttons, from left to right
+    pub fn buttons(&self) -> &[String] {
+        &self.buttons
+    }
+
+    /// Set the labels of the buttons, from left to right, the first one becoming the default button
+    pub fn set_buttons(&mut self, buttons: Vec<String>) {
+        self.buttons = buttons;
+        self.default_button = 0;
+        self.pressed = None;
+    }
+
+    /// Retrieve the index of the default button, chosen by pressing return
+    pub fn default_button(&self) -> Option<usize> {
+        if self.buttons.is_empty() {
+            None
+        } else {
+            Some(self.default_button)
+        }
+    }
+
+    /// Set the default button, chosen by pressing return, out of range indices are ignored
+    pub fn set_default_button(&mut self, index: usize) {
+        if index < self.buttons.len() {
+            self.default_button = index;
+        }
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the label of the button chosen, or None when the dialog is
+    /// dismissed with escape, whenever the user closes the dialog
+    pub fn set_on_close<F>(&mut self, on_close: F)
+    where
+        F: Fn(Option<&str>) + Send + Sync + 'static,
+    {
+        self.on_close = Some(Arc::new(on_close));
+    }
+
+    /// Close the dialog as asked by the user, with a button or dismissing it
+    fn choose(&mut self, ui: &Ui, index: Option<usize>) {
+        self.close(ui);
+        if let Some(ref on_close) = self.on_close {
+            on_close(index.map(|index| self.buttons[index].as_str()));
+        }
+    }
+
+    /// Move the keyboard focus to the next or the previous focusable widget inside the dialog, the
+    /// dialog itself taking it when it has buttons
+    fn cycle_focus(&self, ui: &Ui, forward: bool) {
+        let mut focusable = self.focusable(ui);
+        if focusable.is_empty() | !self.buttons.is_empty() {
+            focusable.push(self.id());
+        }
+
+        let len = focusable.len();
+        let index = match ui.focused().and_then(|id| focusable.iter().position(|val| *val == id)) {
+            None if forward => 0,
+            None => len - 1,
+            Some(val) if forward => (val + 1) % len,
+            Some(val) => (val + len - 1) % len,
+        };
+        ui.set_focus(Some(focusable[index].clone()));
+    }
+
+    /// The ids of the focusable widgets shown inside the dialog, in the order they were added
+    fn focusable(&self, ui: &Ui) -> Vec<Id> {
+        let mut focusable = Vec::new();
+        if let Some(children) = ui.heirarchy().get(&self.id) {
+            collect_focusable(ui, children.clone(), &mut focusable);
+        }
+        focusable
+    }
+
+    /// The height in pixels of the title
+    fn title_height(&self) -> f32 {
+        2.0 * self.text_size
+    }
+
+    /// The height in pixels of the row of buttons, none when there are no buttons
+    fn button_row_height(&self) -> f32 {
+        if self.buttons.is_empty() {
+            0.0
+        } else {
+            (2.0 * self.text_size) + (2.0 * PADDING)
+        }
+    }
+
+    /// The position and size in pixels of each button in a dialog of a size in pixels, aligned to
+    /// the right of the row of buttons
+    fn button_rects(&self, width: f32, height: f32) -> Vec<(f32, f32, f32, f32)> {
+        let widths: Vec<f32> = self
+            .buttons
+            .iter()
+            .map(|button| {
+                let text_width = match self.font {
+                    None => 0.0,
+                    Some(ref font) => font.measure(button, self.text_size).0,
+                };
+                (text_width + (2.0 * BUTTON_PADDING)).max(MIN_BUTTON_WIDTH)
+            })
+            .collect();
+
+        let button_height = 2.0 * self.text_size;
+        let y = height - PADDING - button_height;
+        let mut x =
+            width - BUTTON_PADDING - widths.iter().sum::<f32>() - (PADDING * widths.len().saturating_sub(1) as f32);
+
+        let mut rects = Vec::new();
+        for button_width in widths {
+            rects.push((x, y, button_width, button_height));
+            x += button_width + PADDING;
+        }
+        rects
+    }
+
+    /// The button at a position in pixels in the window
+    fn button_at(&self, ui: &Ui, x: f32, y: f32) -> Result<Option<usize>, Error> {
+        let (left, top, width, height) = match self.area(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let (x, y) = (x - left, y - top);
+        Ok(self
+            .button_rects(width, height)
+            .into_iter()
+            .position(|(bx, by, bw, bh)| (x >= bx) & (x < bx + bw) & (y >= by) & (y < by + bh)))
+    }
+
+    /// The position in pixels of the top left corner of the dialog in the window, and its size
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+}
+
+/// Collect the focusable widgets among some widgets and their children, skipping the hidden ones.
+/// The dialog is locked while it walks its children, so they are locked one at a time.
+fn collect_focusable(ui: &Ui, ids: Vec<Id>, focusable: &mut Vec<Id>) {
+    for id in ids {
+        let children: Vec<Id> = match ui.widgets().get(&id) {
+            None => continue,
+            Some(widget) => {
+                let widget = widget.lock();
+                if !widget.visible() {
+                    continue;
+                }
+                if widget.focusable() {
+                    focusable.push(id.clone());
+                }
+                match ui.heirarchy().get(&id) {
+                    None => Vec::new(),
+                    Some(children) => children
+                        .iter()
+                        .filter(|child| {
+                            widget
+                                .child_area(ui, child, Vertex::default(), Vertex::default())
+                                .is_some()
+                        })
+                        .cloned()
+                        .collect(),
+                }
+            }
+        };
+        collect_focusable(ui, children, focusable);
+    }
+}
+
+/// The builder for the Dialog widget
+#[derive(Clone, Default)]
+pub struct DialogBuilder {
+    id:                   Id,
+    size:                 Size,
+    position:             Position,
+    color:                String,
+    title_color:          String,
+    text_color:           String,
+    button_color:         String,
+    default_button_color: String,
+    dim_color:            String,
+    font:                 Option<Font>,
+    text_size:            Option<f32>,
+    title:                String,
+    text:                 String,
+    buttons:              Vec<String>,
+    default_button:       usize,
+    on_close:             Option<DialogCallback>,
+    parent_id:            Id,
+    opacity:              f32,
+    z_index:              i32,
+    border_color:         String,
+    border_width:         Option<f32>,
+    corner_radius:        Option<[f32; 4]>,
+    elevation:            Option<f32>,
+}
+
+impl DialogBuilder {
+    /// Initialize the builder for the Dialog widget, closed until it is opened
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            size: Size::Size(50.0, 30.0),
+            position,
+            parent_id: parent_id.into(),
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Dialog widget from another Dialog widget
+    pub fn new_from_dialog(dialog: &Dialog) -> Self {
+        Self {
+            id:                   dialog.id(),
+            size:                 dialog.size(),
+            position:             dialog.position(),
+            color:                dialog.fill().into_string(),
+            title_color:          dialog.title_color.into_hex(),
+            text_color:           dialog.text_color.into_hex(),
+            button_color:         dialog.button_color.into_hex(),
+            default_button_color: dialog.default_button_color.into_hex(),
+            dim_color:            dialog.dim_color.into_hex(),
+            font:                 dialog.font(),
+            text_size:            Some(dialog.text_size()),
+            title:                dialog.title(),
+            text:                 dialog.text(),
+            buttons:              dialog.buttons().to_vec(),
+            default_button:       dialog.default_button,
+            on_close:             dialog.on_close.clone(),
+            parent_id:            dialog.parent_id().unwrap(),
+            opacity:              dialog.opacity(),
+            z_index:              dialog.z_index(),
+            border_color:         dialog.border().color().into_hex(),
+            border_width:         Some(dialog.border().width()),
+            corner_radius:        Some(dialog.corner_radius().as_array()),
+            elevation:            Some(dialog.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill of the panel, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color behind the title
+    pub fn with_title_color<V: Into<String>>(&mut self, title_color: V) -> &mut Self {
+        self.title_color = title_color.into();
+        self
+    }
+
+    /// Set the color of the title, the text and the labels of the buttons
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the buttons
+    pub fn with_button_color<V: Into<String>>(&mut self, button_color: V) -> &mut Self {
+        self.button_color = button_color.into();
+        self
+    }
+
+    /// Set the color of the default button
+    pub fn with_default_button_color<V: Into<String>>(&mut self, default_button_color: V) -> &mut Self {
+        self.default_button_color = default_button_color.into();
+        self
+    }
+
+    /// Set the color laid over the rest of the window while the dialog is open
+    pub fn with_dim_color<V: Into<String>>(&mut self, dim_color: V) -> &mut Self {
+        self.dim_color = dim_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the title
+    pub fn with_title<V: Into<String>>(&mut self, title: V) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the text shown below the title
+    pub fn with_text<V: Into<String>>(&mut self, text: V) -> &mut Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Add a button to the right of the others
+    pub fn with_button<V: Into<String>>(&mut self, button: V) -> &mut Self {
+        self.buttons.push(button.into());
+        self
+    }
+
+    /// Add buttons to the right of the others
+    pub fn with_buttons<I, V>(&mut self, buttons: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.buttons.extend(buttons.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the default button, chosen by pressing return, the first one by default
+    pub fn with_default_button(&mut self, default_button: usize) -> &mut Self {
+        self.default_button = default_button;
+        self
+    }
+
+    /// Set the callback called with the label of the button chosen, or None when the dialog is
+    /// dismissed with escape, whenever the user closes the dialog
+    pub fn with_on_close<F>(&mut self, on_close: F) -> &mut Self
+    where
+        F: Fn(Option<&str>) + Send + Sync + 'static,
+    {
+        self.on_close = Some(Arc::new(on_close));
+        self
+    }
+
+    /// Set the size of the panel
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a dialog is drawn over the other widgets of the overlay layer with a lower
+    /// z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the Dialog widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Dialog>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.container_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let title_color = color(&self.title_color, theme.bar_color())?;
+        let text_color = color(&self.text_color, theme.label_text_color())?;
+        let button_color = color(&self.button_color, theme.bar_color())?;
+        let default_button_color = color(&self.default_button_color, theme.checked_color())?;
+        let dim_color = color(&self.dim_color, theme.dim_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        if (self.default_button > 0) & (self.default_button >= self.buttons.len()) {
+            return Err(err_msg(format!(
+                "The default button {} is not one of the {} buttons",
+                self.default_button,
+                self.buttons.len()
+            )));
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => theme.dialog_shadow()?,
+            Some(val) => Shadow::from_elevation(val, theme.dialog_shadow()?.color())?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Dialog {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            title_color,
+            text_color,
+            button_color,
+            default_button_color,
+            dim_color,
+            font,
+            text_size,
+            title: self.clone().title,
+            message: self.clone().text,
+            buttons: self.clone().buttons,
+            default_button: self.default_button,
+            pressed: None,
+            open: false,
+            return_focus: None,
+            on_close: self.clone().on_close,
+            text: Arc::new(TextureCache::default()),
+            opacity: self.opacity,
+            z_index: self.z_index,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
@@ -10,18 +10,27 @@
 
 use crate::*;
 
-/// The Dock Widget
+/// The Dock Widget, a fixed rectangle along an edge. Panels dragged between the edges, grouped
+/// as tabs or floating are arranged by the DockArea widget instead.
 #[derive(Clone)]
 pub struct Dock {
-    id:          Id,
-    parent_id:   Id,
-    size:        Size,
-    position:    Position,
-    color:       Color,
-    visible:     bool,
-    thickness:   DockSize,
-    length:      f32,
-    orientation: Orientation,
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    visible:       bool,
+    opacity:       f32,
+    overflow:      Overflow,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+    background:    Option<NineSlice>,
+    thickness:     DockSize,
+    length:        f32,
+    orientation:   Orientation,
 }
 
 impl WidgetTrait for Dock {
@@ -50,7 +59,11 @@ impl WidgetTrait for Dock {
     }
 
     fn color(&self) -> Color {
-        self.color
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
     }
 
     fn visible(&self) -> bool {
@@ -64,6 +77,46 @@ impl WidgetTrait for Dock {
     fn hide(&mut self) {
         self.visible = false;
     }
+
+    fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn background(&self) -> Option<NineSlice> {
+        self.background.clone()
+    }
 }
 
 impl DockTrait for Dock {
@@ -83,13 +136,27 @@ impl DockTrait for Dock {
 /// The builder for the Dock widget
 #[derive(Clone, Default)]
 pub struct DockBuilder {
-    id:          Id,
-    thickness:   DockSize,
-    length:      f32,
-    orientation: Orientation,
-    color:       String,
-    parent_id:   Id,
-    visible:     bool,
+    id:                 Id,
+    thickness:          DockSize,
+    length:             f32,
+    orientation:        Orientation,
+    color:              String,
+    parent_id:          Id,
+    visible:            bool,
+    opacity:            f32,
+    overflow:           Overflow,
+    z_index:            i32,
+    layer:              Layer,
+    border_color:       String,
+    border_width:       Option<f32>,
+    corner_radius:      Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<(f32, f32)>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       String,
+    background_image:   String,
+    background_texture: Option<Texture>,
+    background_insets:  Option<[u32; 4]>,
 }
 
 impl DockBuilder {
@@ -104,6 +171,7 @@ impl DockBuilder {
             parent_id: parent_id.into(),
             length,
             visible: true,
+            opacity: 1.0,
             ..Self::default()
         }
     }
@@ -111,13 +179,27 @@ impl DockBuilder {
     /// Initialize the builder for the Dock widget from another Dock widget
     pub fn new_from_dock(dock: &Dock) -> Self {
         Self {
-            id:          dock.id(),
-            thickness:   dock.thickness(),
-            length:      dock.length(),
-            orientation: dock.orientation(),
-            color:       dock.color().into_hex(),
-            parent_id:   dock.parent_id().unwrap(),
-            visible:     dock.visible(),
+            id:                 dock.id(),
+            thickness:          dock.thickness(),
+            length:             dock.length(),
+            orientation:        dock.orientation(),
+            color:              dock.fill().into_string(),
+            parent_id:          dock.parent_id().unwrap(),
+            visible:            dock.visible(),
+            opacity:            dock.opacity(),
+            overflow:           dock.overflow(),
+            z_index:            dock.z_index(),
+            layer:              dock.layer(),
+            border_color:       dock.border().color().into_hex(),
+            border_width:       Some(dock.border().width()),
+            corner_radius:      Some(dock.corner_radius().as_array()),
+            elevation:          None,
+            shadow_offset:      Some(dock.shadow().offset()),
+            shadow_blur_radius: Some(dock.shadow().blur_radius()),
+            shadow_color:       dock.shadow().color().into_hex(),
+            background_image:   String::new(),
+            background_texture: dock.background().map(|background| background.texture()),
+            background_insets:  dock.background().map(|background| background.insets()),
         }
     }
 
@@ -127,7 +209,7 @@ impl DockBuilder {
         self
     }
 
-    /// Set the color
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
     pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
         self.color = color.into();
         self
@@ -145,6 +227,109 @@ impl DockBuilder {
         self
     }
 
+    /// Set how the children are clipped
+    pub fn with_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Set the offset in pixels of the shadow
+    pub fn with_shadow_offset(&mut self, x: f32, y: f32) -> &mut Self {
+        self.shadow_offset = Some((x, y));
+        self
+    }
+
+    /// Set the blur radius in pixels of the shadow
+    pub fn with_shadow_blur_radius(&mut self, blur_radius: f32) -> &mut Self {
+        self.shadow_blur_radius = Some(blur_radius);
+        self
+    }
+
+    /// Set the shadow color
+    pub fn with_shadow_color<V: Into<String>>(&mut self, shadow_color: V) -> &mut Self {
+        self.shadow_color = shadow_color.into();
+        self
+    }
+
+    /// Set the path of the PNG or JPEG image drawn over the background
+    pub fn with_background_image<V: Into<String>>(&mut self, background_image: V) -> &mut Self {
+        self.background_image = background_image.into();
+        self
+    }
+
+    /// Set the texture drawn over the background
+    pub fn with_background_texture(&mut self, background_texture: Texture) -> &mut Self {
+        self.background_texture = Some(background_texture);
+        self
+    }
+
+    /// Set the insets in pixels splitting the background image into nine slices, the corners
+    /// keep their size while the edges and the center stretch
+    pub fn with_background_insets(&mut self, top: u32, right: u32, bottom: u32, left: u32) -> &mut Self {
+        self.background_insets = Some([top, right, bottom, left]);
+        self
+    }
+
     /// Build the Dock widget
     pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Dock>, Error> {
         let thickness = self.thickness;
@@ -157,19 +342,76 @@ impl DockBuilder {
             Orientation::Right => (Size::Size(thickness.into_f32(), length), Position::Right),
         };
 
-        let color = if self.color.is_empty() {
-            ui.theme().dock_color()?
+        let fill = if self.color.is_empty() {
+            ui.theme().dock_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => ui.theme().dock_shadow()?,
+            Some(val) => Shadow::from_elevation(val, ui.theme().dock_shadow()?.color())?,
+        };
+
+        let shadow_color = if self.shadow_color.is_empty() {
+            shadow.color()
         } else {
-            Color::from_hex(self.clone().color)?
+            Color::from_hex(self.clone().shadow_color)?
         };
 
+        let shadow = Shadow::new(
+            self.shadow_offset.unwrap_or_else(|| shadow.offset()),
+            self.shadow_blur_radius.unwrap_or_else(|| shadow.blur_radius()),
+            shadow_color,
+        )?;
+
+        let insets = self.background_insets.unwrap_or_default();
+        let background = match self.background_texture {
+            Some(ref texture) => Some(NineSlice::new(texture.clone(), insets)?),
+            None if !self.background_image.is_empty() => Some(NineSlice::from_path(&self.background_image, insets)?),
+            None => match (ui.theme().dock_background()?, self.background_insets) {
+                (Some(background), Some(insets)) => Some(NineSlice::new(background.texture(), insets)?),
+                (background, _) => background,
+            },
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
         let widget = Box::new(Dock {
             id: self.clone().id,
             parent_id: self.clone().parent_id,
             position,
             size,
-            color,
+            fill,
             visible: self.visible,
+            opacity: self.opacity,
+            overflow: self.overflow,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+            background,
             thickness,
             length,
             orientation,
@@ -0,0 +1,1170 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    dock_panel::{
+        DockPanel,
+        PanelState,
+    },
+    TEXT_SIZE,
+};
+use crate::{
+    prelude::*,
+    window::MouseButton,
+};
+use serde_derive::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// The widest a tab of a group of panels gets in pixels
+pub(crate) const MAX_TAB_WIDTH: f32 = 160.0;
+/// The distance in pixels from an edge of the dock area within which a panel dropped docks there
+const EDGE: f32 = 32.0;
+/// The thickness in pixels of the band along the inner side of a docked group dragged to resize
+/// it, and of the square in the bottom right corner of a floating group
+const GRIP: f32 = 8.0;
+/// The distance in pixels the cursor moves with a tab pressed before its panel is dragged
+const DRAG: f32 = 4.0;
+/// The smallest size in pixels of a docked group, and of either side of a floating group
+const MIN_SIZE: f32 = 48.0;
+/// The size in pixels of a group docked at an edge when a panel is dropped there
+const DOCK_SIZE: f32 = 200.0;
+/// The largest size in pixels of a docked panel dragged out of its group
+const FLOAT_SIZE: (f32, f32) = (320.0, 240.0);
+
+/// The callback given the arrangement of the panels of a dock area
+pub type LayoutCallback = Arc<Fn(&DockLayout) + Send + Sync>;
+
+/// A rectangle in pixels from the top left corner of the dock area: left, top, width and height
+type Rect = (f32, f32, f32, f32);
+
+/// The arrangement of the panels of a dock area, serializable to restore it later
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DockLayout {
+    /// The groups docked at the edges, each taking a strip along its edge of the space left by
+    /// the groups before it
+    #[serde(default)]
+    pub docked:   Vec<DockedGroup>,
+    /// The groups floating over the content, from the bottom to the top
+    #[serde(default)]
+    pub floating: Vec<FloatingGroup>,
+}
+
+/// The panels docked at an edge, shown as tabs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DockedGroup {
+    /// The edge of the dock area
+    pub edge:   Orientation,
+    /// The thickness in pixels of the group
+    pub size:   f32,
+    /// The ids of the panels, in the order of their tabs
+    pub panels: Vec<Id>,
+    /// The id of the panel shown, the first one if missing
+    pub active: Option<Id>,
+}
+
+/// The panels floating over the content, shown as tabs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FloatingGroup {
+    /// The distance in pixels from the left of the dock area
+    pub x:      f32,
+    /// The distance in pixels from the top of the dock area
+    pub y:      f32,
+    /// The width in pixels
+    pub width:  f32,
+    /// The height in pixels
+    pub height: f32,
+    /// The ids of the panels, in the order of their tabs
+    pub panels: Vec<Id>,
+    /// The id of the panel shown, the first one if missing
+    pub active: Option<Id>,
+}
+
+impl DockLayout {
+    /// Write the layout as toml
+    pub fn to_toml(&self) -> Result<String, Error> {
+        Ok(toml::to_string(self)?)
+    }
+
+    /// Read a layout written as toml
+    pub fn from_toml(text: &str) -> Result<Self, Error> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Retrieve the ids of the panels arranged, docked first
+    pub fn panels(&self) -> Vec<Id> {
+        self.docked
+            .iter()
+            .flat_map(|group| group.panels.iter())
+            .chain(self.floating.iter().flat_map(|group| group.panels.iter()))
+            .cloned()
+            .collect()
+    }
+
+    /// The rectangles of the docked groups in a dock area of a size in pixels, followed by the
+    /// rectangle of the content left between them
+    fn docked_rects(&self, width: f32, height: f32) -> (Vec<Rect>, Rect) {
+        let (mut left, mut top, mut right, mut bottom) = (0.0, 0.0, width, height);
+        let mut rects = Vec::new();
+        for group in &self.docked {
+            let rect = if group.edge.horizontal() {
+                let size = group.size.min(bottom - top).max(0.0);
+                if group.edge == Orientation::Top {
+                    top += size;
+                    (left, top - size, right - left, size)
+                } else {
+                    bottom -= size;
+                    (left, bottom, right - left, size)
+                }
+            } else {
+                let size = group.size.min(right - left).max(0.0);
+                if group.edge == Orientation::Left {
+                    left += size;
+                    (left - size, top, size, bottom - top)
+                } else {
+                    right -= size;
+                    (right, top, size, bottom - top)
+                }
+            };
+            rects.push(rect);
+        }
+
+        (rects, (left, top, right - left, bottom - top))
+    }
+
+    /// The rectangle of a floating group in a dock area of a size in pixels, kept inside it
+    fn floating_rect(&self, index: usize, width: f32, height: f32) -> Rect {
+        let group = &self.floating[index];
+        let (w, h) = (group.width.min(width).max(0.0), group.height.min(height).max(0.0));
+        (group.x.min(width - w).max(0.0), group.y.min(height - h).max(0.0), w, h)
+    }
+
+    /// Take a panel out of its group, removing the group once it is empty
+    fn remove(&mut self, panel: &str) {
+        let take = |panels: &mut Vec<Id>, active: &mut Option<Id>| {
+            if let Some(index) = panels.iter().position(|id| id == panel) {
+                panels.remove(index);
+                if active.as_ref().map(|id| id == panel).unwrap_or(false) {
+                    *active = panels.get(index.min(panels.len().saturating_sub(1))).cloned();
+                }
+            }
+        };
+        for group in &mut self.docked {
+            take(&mut group.panels, &mut group.active);
+        }
+        for group in &mut self.floating {
+            take(&mut group.panels, &mut group.active);
+        }
+        self.docked.retain(|group| !group.panels.is_empty());
+        self.floating.retain(|group| !group.panels.is_empty());
+    }
+
+    /// Add a panel to the group docked at an edge, docking a new group there if there is none
+    fn dock(&mut self, panel: &str, edge: Orientation, size: f32) {
+        self.remove(panel);
+        match self.docked.iter_mut().find(|group| group.edge == edge) {
+            Some(group) => {
+                group.panels.push(String::from(panel));
+                group.active = Some(String::from(panel));
+            }
+            None => self.docked.push(DockedGroup {
+                edge,
+                size,
+                panels: vec![String::from(panel)],
+                active: Some(String::from(panel)),
+            }),
+        }
+    }
+
+    /// Keep the panels of a dock area once each, removing the rest, the empty groups and the
+    /// active panels that are not in their group
+    fn retain(&mut self, panels: &[Id]) {
+        let mut seen: Vec<Id> = Vec::new();
+        let mut keep = |ids: &mut Vec<Id>, active: &mut Option<Id>| {
+            ids.retain(|id| {
+                let kept = panels.contains(id) & !seen.contains(id);
+                if kept {
+                    seen.push(id.clone());
+                }
+                kept
+            });
+            if !active.as_ref().map(|id| ids.contains(id)).unwrap_or(false) {
+                *active = ids.first().cloned();
+            }
+        };
+        for group in &mut self.docked {
+            keep(&mut group.panels, &mut group.active);
+        }
+        for group in &mut self.floating {
+            keep(&mut group.panels, &mut group.active);
+        }
+        self.docked.retain(|group| !group.panels.is_empty());
+        self.floating.retain(|group| !group.panels.is_empty());
+    }
+}
+
+/// The width in pixels of each tab of a group of panels, sharing the width of the group
+pub(crate) fn tab_width(width: f32, count: usize) -> f32 {
+    (width / count.max(1) as f32).min(MAX_TAB_WIDTH)
+}
+
+fn contains(rect: Rect, x: f32, y: f32) -> bool {
+    (x >= rect.0) & (x < rect.0 + rect.2) & (y >= rect.1) & (y < rect.1 + rect.3)
+}
+
+/// Where a panel dragged is dropped
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Docked(usize),
+    Floating(usize),
+    Edge(Orientation),
+}
+
+/// A panel dragged by its tab, or a floating group dragged by its tabs
+#[derive(Clone)]
+struct Drag {
+    panel: Option<Id>,
+    start: (f32, f32),
+    grab:  (f32, f32),
+    size:  (f32, f32),
+    moved: bool,
+}
+
+/// A group resized, docked from its outer side or floating from its top left corner
+#[derive(Clone, Copy)]
+enum Resize {
+    Docked(usize, f32),
+    Floating(usize),
+}
+
+/// The DockArea Widget, arranging DockPanel children docked at its edges or floating over the
+/// content. The panels docked at the same edge, or floating together, are grouped as tabs. A panel
+/// dragged by its tab docks at the edge it is dropped near, joins the group whose tabs it is
+/// dropped on, or floats where it is dropped. The docked groups are resized from their inner side
+/// and the floating groups from their bottom right corner. The other children are laid out in the
+/// space left between the docked groups.
+#[derive(Clone)]
+pub struct DockArea {
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    preview_color: Color,
+    tab_height:    f32,
+    panels:        Vec<Id>,
+    layout:        DockLayout,
+    drag:          Option<Drag>,
+    resize:        Option<Resize>,
+    preview:       Option<Rect>,
+    on_change:     Option<LayoutCallback>,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+}
+
+impl WidgetTrait for DockArea {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::DockArea
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        let (left, top, width, height) = match self.area(ui)? {
+            None => {
+                return Ok(false);
+            }
+            Some(val) => val,
+        };
+
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                let (x, y) = (x - left, y - top);
+                if !self.press(x, y, width, height) {
+                    return Ok(false);
+                }
+            }
+            InputEvent::CursorMoved { x, y } => {
+                let (x, y) = (x - left, y - top);
+                if let Some(resize) = self.resize {
+                    self.resize_to(resize, x, y, width, height);
+                } else if self.drag.is_some() {
+                    self.drag_to(x, y, width, height);
+                } else {
+                    return Ok(false);
+                }
+            }
+            InputEvent::MouseReleased {
+                x,
+                y,
+                button: MouseButton::Left,
+            } => {
+                let (x, y) = (x - left, y - top);
+                if self.resize.take().is_some() {
+                    self.changed();
+                } else if let Some(drag) = self.drag.take() {
+                    if drag.moved {
+                        self.drop(drag, x, y, width, height);
+                        self.changed();
+                    }
+                } else {
+                    return Ok(false);
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        self.sync(ui);
+
+        Ok(true)
+    }
+
+    fn child_area(&self, ui: &Ui, child: &Id, bl_vertex: Vertex, tr_vertex: Vertex) -> Option<(Vertex, Vertex)> {
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            return None;
+        }
+
+        // The panels are laid out where their group is if they are shown, the other children
+        // between the docked groups
+        let scale = (window_size[0] as f32 / 2.0, window_size[1] as f32 / 2.0);
+        let (width, height) = (
+            (tr_vertex.x() - bl_vertex.x()) * scale.0,
+            (tr_vertex.y() - bl_vertex.y()) * scale.1,
+        );
+        let rect = if self.panels.contains(child) {
+            self.panel_rect(child, width, height)?
+        } else {
+            self.layout.docked_rects(width, height).1
+        };
+
+        Some((
+            Vertex {
+                x: bl_vertex.x() + (rect.0 / scale.0),
+                y: bl_vertex.y() + (rect.1 / scale.1),
+            },
+            Vertex {
+                x: bl_vertex.x() + ((rect.0 + rect.2) / scale.0),
+                y: bl_vertex.y() + ((rect.1 + rect.3) / scale.1),
+            },
+        ))
+    }
+
+    fn places_child(&self, child: &Id) -> bool {
+        self.panels.contains(child)
+    }
+}
+
+impl DockArea {
+    /// Retrieve the ids of the panels, in the order they were added
+    pub fn panels(&self) -> Vec<Id> {
+        self.panels.clone()
+    }
+
+    /// Retrieve the arrangement of the panels
+    pub fn layout(&self) -> DockLayout {
+        self.layout.clone()
+    }
+
+    /// Arrange the panels, without calling the change callback. The ids that are not panels of
+    /// the dock area, or that were already arranged, are left out, and so are the groups left
+    /// empty. The panels left out of the layout are hidden.
+    pub fn set_layout(&mut self, ui: &Ui, layout: DockLayout) {
+        self.layout = layout;
+        self.layout.retain(&self.panels);
+        self.drag = None;
+        self.resize = None;
+        self.preview = None;
+        self.sync(ui);
+    }
+
+    /// Dock a panel at an edge, with the panels already docked there or in a new group, without
+    /// calling the change callback
+    pub fn dock(&mut self, ui: &Ui, panel: &str, edge: Orientation) {
+        if self.panels.iter().any(|id| id == panel) {
+            self.layout.dock(panel, edge, DOCK_SIZE);
+            self.sync(ui);
+        }
+    }
+
+    /// Float a panel on its own over the content, at a position and size in pixels from the top
+    /// left corner of the dock area, without calling the change callback
+    pub fn float(&mut self, ui: &Ui, panel: &str, x: f32, y: f32, width: f32, height: f32) {
+        if self.panels.iter().any(|id| id == panel) {
+            self.layout.remove(panel);
+            self.layout.floating.push(FloatingGroup {
+                x,
+                y,
+                width: width.max(MIN_SIZE),
+                height: height.max(MIN_SIZE),
+                panels: vec![String::from(panel)],
+                active: Some(String::from(panel)),
+            });
+            self.sync(ui);
+        }
+    }
+
+    /// Add a panel to the group of another panel as a tab, without calling the change callback
+    pub fn group(&mut self, ui: &Ui, panel: &str, with: &str) {
+        if (panel == with) | !self.panels.iter().any(|id| id == panel) {
+            return;
+        }
+
+        let mut layout = self.layout.clone();
+        layout.remove(panel);
+        let group = layout
+            .docked
+            .iter_mut()
+            .map(|group| (&mut group.panels, &mut group.active))
+            .chain(
+                layout
+                    .floating
+                    .iter_mut()
+                    .map(|group| (&mut group.panels, &mut group.active)),
+            )
+            .find(|(panels, _)| panels.iter().any(|id| id == with));
+        if let Some((panels, active)) = group {
+            panels.push(String::from(panel));
+            *active = Some(String::from(panel));
+            self.layout = layout;
+            self.sync(ui);
+        }
+    }
+
+    /// Show a panel in its group, without calling the change callback
+    pub fn set_active(&mut self, ui: &Ui, panel: &str) {
+        let groups = self
+            .layout
+            .docked
+            .iter_mut()
+            .map(|group| (&group.panels, &mut group.active))
+            .chain(
+                self.layout
+                    .floating
+                    .iter_mut()
+                    .map(|group| (&group.panels, &mut group.active)),
+            );
+        for (panels, active) in groups {
+            if panels.iter().any(|id| id == panel) {
+                *active = Some(String::from(panel));
+            }
+        }
+        self.sync(ui);
+    }
+
+    /// Retrieve the height in pixels of the tabs of the groups
+    pub fn tab_height(&self) -> f32 {
+        self.tab_height
+    }
+
+    /// Set the callback called with the arrangement of the panels whenever the user drops a panel
+    /// or resizes a group
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(&DockLayout) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    /// Add a panel, arranged by its builder
+    pub(crate) fn add_panel(&mut self, ui: &Ui, panel: &str, edge: Orientation, float: Option<Rect>, size: f32) {
+        if !self.panels.iter().any(|id| id == panel) {
+            self.panels.push(String::from(panel));
+        }
+        match float {
+            None => self.layout.dock(panel, edge, size),
+            Some((x, y, width, height)) => {
+                self.layout.remove(panel);
+                self.layout.floating.push(FloatingGroup {
+                    x,
+                    y,
+                    width,
+                    height,
+                    panels: vec![String::from(panel)],
+                    active: Some(String::from(panel)),
+                });
+            }
+        }
+        self.sync(ui);
+    }
+
+    fn changed(&self) {
+        if let Some(ref on_change) = self.on_change {
+            on_change(&self.layout);
+        }
+    }
+
+    /// Press the left button at a position in pixels in the dock area, over the floating groups
+    /// first. Returns whether a tab or a side of a group was pressed.
+    fn press(&mut self, x: f32, y: f32, width: f32, height: f32) -> bool {
+        for index in (0..self.layout.floating.len()).rev() {
+            let rect = self.layout.floating_rect(index, width, height);
+            if !contains(rect, x, y) {
+                continue;
+            }
+
+            // The group pressed is raised over the others
+            let group = self.layout.floating.remove(index);
+            self.layout.floating.push(group);
+            let index = self.layout.floating.len() - 1;
+
+            if (x >= rect.0 + rect.2 - GRIP) & (y >= rect.1 + rect.3 - GRIP) {
+                self.resize = Some(Resize::Floating(index));
+            } else if y < rect.1 + self.tab_height {
+                let panels = self.layout.floating[index].panels.clone();
+                let tab = ((x - rect.0) / tab_width(rect.2, panels.len())) as usize;
+                let panel = panels.get(tab).cloned();
+                if let Some(ref val) = panel {
+                    self.layout.floating[index].active = Some(val.clone());
+                }
+                self.drag = Some(Drag {
+                    panel,
+                    start: (x, y),
+                    grab: (x - rect.0, y - rect.1),
+                    size: (rect.2, rect.3),
+                    moved: false,
+                });
+            }
+            return true;
+        }
+
+        let (rects, _) = self.layout.docked_rects(width, height);
+        for (index, rect) in rects.into_iter().enumerate() {
+            if !contains(rect, x, y) {
+                continue;
+            }
+
+            // The inner side of the group is dragged from its outer side
+            let resize = match self.layout.docked[index].edge {
+                Orientation::Left if x >= rect.0 + rect.2 - GRIP => Some(rect.0),
+                Orientation::Right if x < rect.0 + GRIP => Some(rect.0 + rect.2),
+                Orientation::Top if y >= rect.1 + rect.3 - GRIP => Some(rect.1),
+                Orientation::Bottom if y < rect.1 + GRIP => Some(rect.1 + rect.3),
+                _ => None,
+            };
+            if let Some(anchor) = resize {
+                self.resize = Some(Resize::Docked(index, anchor));
+                return true;
+            }
+
+            if y >= rect.1 + self.tab_height {
+                return false;
+            }
+            let panels = self.layout.docked[index].panels.clone();
+            let tab = ((x - rect.0) / tab_width(rect.2, panels.len())) as usize;
+            let panel = match panels.get(tab) {
+                None => {
+                    return false;
+                }
+                Some(val) => val.clone(),
+            };
+            self.layout.docked[index].active = Some(panel.clone());
+
+            let size = (rect.2.min(FLOAT_SIZE.0), rect.3.min(FLOAT_SIZE.1));
+            self.drag = Some(Drag {
+                panel: Some(panel),
+                start: (x, y),
+                grab: ((x - rect.0).min(size.0 - 1.0).max(0.0), y - rect.1),
+                size,
+                moved: false,
+            });
+            return true;
+        }
+
+        false
+    }
+
+    /// Move the cursor to a position in pixels in the dock area while dragging a tab, taking its
+    /// panel out of its group to follow the cursor once it moved far enough
+    fn drag_to(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        let mut drag = match self.drag.take() {
+            None => {
+                return;
+            }
+            Some(val) => val,
+        };
+
+        if !drag.moved {
+            if (x - drag.start.0).hypot(y - drag.start.1) < DRAG {
+                self.drag = Some(drag);
+                return;
+            }
+            drag.moved = true;
+            if let Some(ref panel) = drag.panel {
+                self.layout.remove(panel);
+                self.layout.floating.push(FloatingGroup {
+                    x:      drag.start.0 - drag.grab.0,
+                    y:      drag.start.1 - drag.grab.1,
+                    width:  drag.size.0,
+                    height: drag.size.1,
+                    panels: vec![panel.clone()],
+                    active: Some(panel.clone()),
+                });
+            }
+        }
+
+        // The group dragged is the one at the top
+        if let Some(group) = self.layout.floating.last_mut() {
+            group.x = (x - drag.grab.0).min(width - group.width).max(0.0);
+            group.y = (y - drag.grab.1).min(height - group.height).max(0.0);
+        }
+
+        self.preview = match drag.panel {
+            None => None,
+            Some(_) => self
+                .target(x, y, width, height)
+                .map(|target| self.target_rect(target, width, height)),
+        };
+        self.drag = Some(drag);
+    }
+
+    /// Drop a panel dragged at a position in pixels in the dock area
+    fn drop(&mut self, drag: Drag, x: f32, y: f32, width: f32, height: f32) {
+        self.preview = None;
+        let panel = match drag.panel {
+            None => {
+                return;
+            }
+            Some(val) => val,
+        };
+
+        match self.target(x, y, width, height) {
+            None => {}
+            Some(Target::Edge(edge)) => self.layout.dock(&panel, edge, DOCK_SIZE),
+            Some(Target::Docked(index)) => {
+                self.layout.floating.pop();
+                let group = &mut self.layout.docked[index];
+                group.panels.push(panel.clone());
+                group.active = Some(panel);
+            }
+            Some(Target::Floating(index)) => {
+                self.layout.floating.pop();
+                let group = &mut self.layout.floating[index];
+                group.panels.push(panel.clone());
+                group.active = Some(panel);
+            }
+        }
+    }
+
+    /// Resize a group to the cursor at a position in pixels in the dock area
+    fn resize_to(&mut self, resize: Resize, x: f32, y: f32, width: f32, height: f32) {
+        match resize {
+            Resize::Docked(index, anchor) => {
+                let group = &mut self.layout.docked[index];
+                let (size, length) = match group.edge {
+                    Orientation::Left => (x - anchor, width),
+                    Orientation::Right => (anchor - x, width),
+                    Orientation::Top => (y - anchor, height),
+                    Orientation::Bottom => (anchor - y, height),
+                };
+                group.size = size.min(length - MIN_SIZE).max(MIN_SIZE);
+            }
+            Resize::Floating(index) => {
+                let group = &mut self.layout.floating[index];
+                group.width = (x - group.x).min(width - group.x).max(MIN_SIZE);
+                group.height = (y - group.y).min(height - group.y).max(MIN_SIZE);
+            }
+        }
+    }
+
+    /// Where a panel dragged would be dropped at a position in pixels in the dock area: on the
+    /// tabs of a group, near an edge, or floating where it is
+    fn target(&self, x: f32, y: f32, width: f32, height: f32) -> Option<Target> {
+        // The group dragged is the one at the top, left out
+        let dragged = self.layout.floating.len().saturating_sub(1);
+        for index in (0..dragged).rev() {
+            let rect = self.layout.floating_rect(index, width, height);
+            if contains(rect, x, y) {
+                if y < rect.1 + self.tab_height {
+                    return Some(Target::Floating(index));
+                }
+                return None;
+            }
+        }
+
+        let (rects, _) = self.layout.docked_rects(width, height);
+        for (index, rect) in rects.into_iter().enumerate() {
+            if contains(rect, x, y) & (y < rect.1 + self.tab_height) {
+                return Some(Target::Docked(index));
+            }
+        }
+
+        let edges = [
+            (Orientation::Left, x),
+            (Orientation::Right, width - x),
+            (Orientation::Top, y),
+            (Orientation::Bottom, height - y),
+        ];
+        edges
+            .iter()
+            .filter(|(_, distance)| *distance < EDGE)
+            .fold(
+                None,
+                |nearest: Option<(Orientation, f32)>, &(edge, distance)| match nearest {
+                    Some((_, val)) if val <= distance => nearest,
+                    _ => Some((edge, distance)),
+                },
+            )
+            .map(|(edge, _)| Target::Edge(edge))
+    }
+
+    /// The rectangle in pixels in the dock area a panel dropped on a target takes
+    fn target_rect(&self, target: Target, width: f32, height: f32) -> Rect {
+        match target {
+            Target::Docked(index) => self.layout.docked_rects(width, height).0[index],
+            Target::Floating(index) => self.layout.floating_rect(index, width, height),
+            Target::Edge(edge) => {
+                let mut layout = self.layout.clone();
+                layout.floating.clear();
+                if !layout.docked.iter().any(|group| group.edge == edge) {
+                    layout.docked.push(DockedGroup {
+                        edge,
+                        size: DOCK_SIZE,
+                        panels: Vec::new(),
+                        active: None,
+                    });
+                }
+                let index = layout.docked.iter().position(|group| group.edge == edge).unwrap();
+                layout.docked_rects(width, height).0[index]
+            }
+        }
+    }
+
+    /// The rectangle in pixels in the dock area of a panel, if it is shown
+    fn panel_rect(&self, panel: &str, width: f32, height: f32) -> Option<Rect> {
+        let shown = |panels: &[Id], active: &Option<Id>| match active {
+            Some(ref id) if panels.contains(id) => id == panel,
+            _ => panels.first().map(|id| id == panel).unwrap_or(false),
+        };
+
+        if let Some(index) = self
+            .layout
+            .docked
+            .iter()
+            .position(|group| shown(&group.panels, &group.active))
+        {
+            return Some(self.layout.docked_rects(width, height).0[index]);
+        }
+        self.layout
+            .floating
+            .iter()
+            .position(|group| shown(&group.panels, &group.active))
+            .map(|index| self.layout.floating_rect(index, width, height))
+    }
+
+    /// Give every panel the tabs of its group, whether it floats and the drop preview
+    fn sync(&self, ui: &Ui) {
+        let mut titles = HashMap::new();
+        for id in &self.panels {
+            if let Some(widget) = ui.widgets().get(id) {
+                if let Some(panel) = widget.lock().downcast_ref::<DockPanel>() {
+                    titles.insert(id.clone(), panel.title());
+                }
+            }
+        }
+
+        let window_size = ui.window_size();
+        let preview = match (self.preview, self.area(ui)) {
+            (Some(rect), Ok(Some((left, top, ..)))) if (window_size[0] > 0) & (window_size[1] > 0) => {
+                let scale = (window_size[0] as f32 / 2.0, window_size[1] as f32 / 2.0);
+                Some((
+                    Vertex {
+                        x: (left + rect.0) / scale.0,
+                        y: (top + rect.1) / scale.1,
+                    },
+                    Vertex {
+                        x: (left + rect.0 + rect.2) / scale.0,
+                        y: (top + rect.1 + rect.3) / scale.1,
+                    },
+                ))
+            }
+            _ => None,
+        };
+
+        let mut states: HashMap<Id, PanelState> = HashMap::new();
+        let groups = self
+            .layout
+            .docked
+            .iter()
+            .map(|group| (&group.panels, &group.active, None))
+            .chain(
+                self.layout
+                    .floating
+                    .iter()
+                    .enumerate()
+                    .map(|(index, group)| (&group.panels, &group.active, Some(index))),
+            );
+        for (panels, active, floating) in groups {
+            let tabs: Vec<String> = panels
+                .iter()
+                .map(|id| titles.get(id).cloned().unwrap_or_default())
+                .collect();
+            let active = active
+                .as_ref()
+                .and_then(|active| panels.iter().position(|id| id == active))
+                .unwrap_or(0);
+            let dragged = (floating == Some(self.layout.floating.len().saturating_sub(1)))
+                & self.drag.as_ref().map(|drag| drag.moved).unwrap_or(false);
+            for id in panels {
+                states.insert(
+                    id.clone(),
+                    PanelState {
+                        tabs: tabs.clone(),
+                        active,
+                        floating: floating.is_some(),
+                        z_index: floating.map(|index| index as i32 + 1).unwrap_or(0),
+                        tab_height: self.tab_height,
+                        preview: if dragged {
+                            preview.map(|preview| (preview, self.preview_color))
+                        } else {
+                            None
+                        },
+                    },
+                );
+            }
+        }
+
+        for id in &self.panels {
+            if let Some(widget) = ui.widgets().get(id) {
+                if let Some(panel) = widget.lock().downcast_mut::<DockPanel>() {
+                    panel.set_state(states.remove(id).unwrap_or(PanelState {
+                        tabs:       Vec::new(),
+                        active:     0,
+                        floating:   false,
+                        z_index:    0,
+                        tab_height: self.tab_height,
+                        preview:    None,
+                    }));
+                }
+            }
+        }
+    }
+
+    /// The left, top, width and height in pixels of the dock area
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+}
+
+/// The builder for the DockArea widget
+#[derive(Clone, Default)]
+pub struct DockAreaBuilder {
+    id:            Id,
+    size:          Size,
+    position:      Position,
+    color:         String,
+    preview_color: String,
+    tab_height:    Option<f32>,
+    on_change:     Option<LayoutCallback>,
+    parent_id:     Id,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border_color:  String,
+    border_width:  Option<f32>,
+    corner_radius: Option<[f32; 4]>,
+}
+
+impl DockAreaBuilder {
+    /// Initialize the builder for the DockArea widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the DockArea widget from another DockArea widget, without its
+    /// panels
+    pub fn new_from_dock_area(dock_area: &DockArea) -> Self {
+        Self {
+            id:            dock_area.id(),
+            size:          dock_area.size(),
+            position:      dock_area.position(),
+            color:         dock_area.fill().into_string(),
+            preview_color: dock_area.preview_color.into_hex(),
+            tab_height:    Some(dock_area.tab_height()),
+            on_change:     dock_area.on_change.clone(),
+            parent_id:     dock_area.parent_id().unwrap(),
+            visible:       dock_area.visible(),
+            opacity:       dock_area.opacity(),
+            z_index:       dock_area.z_index(),
+            layer:         dock_area.layer(),
+            border_color:  dock_area.border().color().into_hex(),
+            border_width:  Some(dock_area.border().width()),
+            corner_radius: Some(dock_area.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill behind the content, either a hex color or a gradient as parsed by
+    /// `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the preview of where a panel dragged is dropped
+    pub fn with_preview_color<V: Into<String>>(&mut self, preview_color: V) -> &mut Self {
+        self.preview_color = preview_color.into();
+        self
+    }
+
+    /// Set the height in pixels of the tabs of the groups
+    pub fn with_tab_height(&mut self, tab_height: f32) -> &mut Self {
+        self.tab_height = Some(tab_height);
+        self
+    }
+
+    /// Set the callback called with the arrangement of the panels whenever the user drops a panel
+    /// or resizes a group
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(&DockLayout) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the DockArea widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<DockArea>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.container_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let preview_color = color(&self.preview_color, theme.selection_color())?;
+
+        let tab_height = self.tab_height.unwrap_or(2.0 * TEXT_SIZE);
+        if tab_height <= 0.0 {
+            return Err(err_msg("The tab height must be greater than 0.0"));
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(DockArea {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            preview_color,
+            tab_height,
+            panels: Vec::new(),
+            layout: DockLayout::default(),
+            drag: None,
+            resize: None,
+            preview: None,
+            on_change: self.clone().on_change,
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
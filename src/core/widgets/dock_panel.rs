@@ -0,0 +1,529 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    dock_area::{
+        tab_width,
+        DockArea,
+    },
+    TAB_PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+};
+use std::sync::Arc;
+
+/// What a panel is given by its dock area to draw itself: the tabs of its group, whether it floats
+/// and where a panel dragged would be dropped
+#[derive(Clone, Default)]
+pub(crate) struct PanelState {
+    pub(crate) tabs:       Vec<String>,
+    pub(crate) active:     usize,
+    pub(crate) floating:   bool,
+    pub(crate) z_index:    i32,
+    pub(crate) tab_height: f32,
+    pub(crate) preview:    Option<((Vertex, Vertex), Color)>,
+}
+
+/// The DockPanel Widget, a panel with a title arranged by its parent DockArea: docked at an edge
+/// or floating over the content, with the panels of its group as tabs along its top. Its children
+/// are laid out below the tabs.
+#[derive(Clone)]
+pub struct DockPanel {
+    id:               Id,
+    parent_id:        Id,
+    fill:             Fill,
+    tab_color:        Color,
+    active_tab_color: Color,
+    text_color:       Color,
+    font:             Option<Font>,
+    text_size:        f32,
+    title:            String,
+    state:            PanelState,
+    text:             Arc<TextureCache>,
+    visible:          bool,
+    opacity:          f32,
+    z_index:          i32,
+    border:           Border,
+    corner_radius:    CornerRadius,
+    shadow:           Shadow,
+}
+
+impl WidgetTrait for DockPanel {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::DockPanel
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        Size::Full
+    }
+
+    fn set_size(&mut self, _size: Size) {}
+
+    fn position(&self) -> Position {
+        Position::Center
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index + self.state.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        if self.state.floating {
+            Layer::Overlay
+        } else {
+            Layer::Content
+        }
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn child_area(&self, ui: &Ui, _child: &Id, bl_vertex: Vertex, tr_vertex: Vertex) -> Option<(Vertex, Vertex)> {
+        // The children are laid out below the tabs of the group
+        let window_size = ui.window_size();
+        let strip = if window_size[1] == 0 {
+            0.0
+        } else {
+            (self.state.tab_height * 2.0) / window_size[1] as f32
+        };
+
+        Some((
+            Vertex {
+                x: bl_vertex.x(),
+                y: (bl_vertex.y() + strip).min(tr_vertex.y()),
+            },
+            tr_vertex,
+        ))
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        // The preview of where the panel dragged would be dropped is drawn under it
+        if let Some((preview, preview_color)) = self.state.preview {
+            for (position, mut color) in Fill::Solid(preview_color).triangles(preview.0, preview.1) {
+                color[3] *= opacity;
+                vertices.push(DrawVertex::new(position, color));
+            }
+        }
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let width = ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0;
+        let mut painter = Painter::new(width, ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0);
+
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+
+        let tab_height = self.state.tab_height;
+        let tab = tab_width(width, self.state.tabs.len());
+        for (index, title) in self.state.tabs.iter().enumerate() {
+            let x = index as f32 * tab;
+            let color = if index == self.state.active {
+                self.active_tab_color
+            } else {
+                self.tab_color
+            };
+            painter.rect((x, 0.0), (tab, tab_height), color);
+
+            if let Some(ref font) = self.font {
+                painter.text(
+                    (x + TAB_PADDING, (tab_height - line_height) / 2.0),
+                    font.fit(title, self.text_size, tab - (2.0 * TAB_PADDING)),
+                    self.text_size,
+                    self.text_color,
+                    font,
+                );
+            }
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl DockPanel {
+    /// Retrieve the title of the tab of the panel
+    pub fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    /// Set the title of the tab of the panel, shown once its dock area arranges its panels again
+    pub fn set_title<V: Into<String>>(&mut self, title: V) {
+        self.title = title.into();
+    }
+
+    /// Retrieve whether the panel floats over the content
+    pub fn floating(&self) -> bool {
+        self.state.floating
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set what the panel is given by its dock area to draw itself
+    pub(crate) fn set_state(&mut self, state: PanelState) {
+        self.state = state;
+    }
+}
+
+/// The builder for the DockPanel widget
+#[derive(Clone, Default)]
+pub struct DockPanelBuilder {
+    id:               Id,
+    color:            String,
+    tab_color:        String,
+    active_tab_color: String,
+    text_color:       String,
+    font:             Option<Font>,
+    text_size:        Option<f32>,
+    title:            String,
+    edge:             Option<Orientation>,
+    dock_size:        Option<f32>,
+    floating:         Option<(f32, f32, f32, f32)>,
+    parent_id:        Id,
+    visible:          bool,
+    opacity:          f32,
+    z_index:          i32,
+    border_color:     String,
+    border_width:     Option<f32>,
+    corner_radius:    Option<[f32; 4]>,
+    elevation:        Option<f32>,
+}
+
+impl DockPanelBuilder {
+    /// Initialize the builder for the DockPanel widget, a panel of a DockArea widget
+    pub fn new<V>(id: V, dock_area_id: V) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            parent_id: dock_area_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the tabs of the group not shown
+    pub fn with_tab_color<V: Into<String>>(&mut self, tab_color: V) -> &mut Self {
+        self.tab_color = tab_color.into();
+        self
+    }
+
+    /// Set the color of the tab of the panel shown
+    pub fn with_active_tab_color<V: Into<String>>(&mut self, active_tab_color: V) -> &mut Self {
+        self.active_tab_color = active_tab_color.into();
+        self
+    }
+
+    /// Set the color of the titles
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the title of the tab of the panel
+    pub fn with_title<V: Into<String>>(&mut self, title: V) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Dock the panel at an edge of the dock area, with the panels already docked there, the left
+    /// by default
+    pub fn with_edge(&mut self, edge: Orientation) -> &mut Self {
+        self.edge = Some(edge);
+        self
+    }
+
+    /// Set the thickness in pixels of the group the panel docks in, if it is the first one docked
+    /// at its edge
+    pub fn with_dock_size(&mut self, dock_size: f32) -> &mut Self {
+        self.dock_size = Some(dock_size);
+        self
+    }
+
+    /// Float the panel over the content instead of docking it, at a position and size in pixels
+    /// from the top left corner of the dock area
+    pub fn with_floating(&mut self, x: f32, y: f32, width: f32, height: f32) -> &mut Self {
+        self.floating = Some((x, y, width, height));
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the DockPanel widget and add it to its dock area
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<DockPanel>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.dock_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let tab_color = color(&self.tab_color, theme.bar_color())?;
+        let active_tab_color = color(&self.active_tab_color, theme.container_color())?;
+        let text_color = color(&self.text_color, theme.label_text_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let dock_size = self.dock_size.unwrap_or(200.0);
+        if dock_size <= 0.0 {
+            return Err(err_msg("The dock size must be greater than 0.0"));
+        }
+
+        if let Some((_, _, width, height)) = self.floating {
+            if (width <= 0.0) | (height <= 0.0) {
+                return Err(err_msg("The floating size must be greater than 0.0"));
+            }
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => theme.dock_shadow()?,
+            Some(val) => Shadow::from_elevation(val, theme.dock_shadow()?.color())?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let dock_area = match ui.widgets().get(&self.parent_id) {
+            None => {
+                return Err(err_msg(format!("The dock area {} does not exist", self.parent_id)));
+            }
+            Some(val) => val.clone(),
+        };
+        if dock_area.lock().downcast_ref::<DockArea>().is_none() {
+            return Err(err_msg(format!("The parent {} is not a dock area", self.parent_id)));
+        }
+
+        let widget = Box::new(DockPanel {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            fill,
+            tab_color,
+            active_tab_color,
+            text_color,
+            font,
+            text_size,
+            title: self.clone().title,
+            state: PanelState::default(),
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        let ui: &Ui = ui;
+        if let Some(dock_area) = dock_area.lock().downcast_mut::<DockArea>() {
+            let edge = self.edge.unwrap_or(Orientation::Left);
+            dock_area.add_panel(ui, &self.id, edge, self.floating, dock_size);
+        }
+
+        Ok(widget)
+    }
+}
@@ -0,0 +1,557 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// The Image Widget
+#[derive(Clone)]
+pub struct Image {
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    texture:       Texture,
+    svg:           Option<Svg>,
+    rasterized:    Arc<Mutex<Texture>>,
+    fit:           Fit,
+    tint:          Color,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+}
+
+impl WidgetTrait for Image {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Image
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.tint
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        Shadow::default()
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        match self.svg {
+            None => Some(self.texture.clone()),
+            Some(_) => Some(self.rasterized.lock().clone()),
+        }
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex, uv_min, uv_max) = match self.fitted(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let texture = self.texture_at(ui, (bl_vertex, tr_vertex, uv_min, uv_max))?;
+
+        let opacity = self.inherited_opacity(ui)?;
+        let mut tint = self.tint.into_scaled_rgba_float();
+        tint[3] *= opacity;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |x: f32, y: f32, u: f32, v: f32| {
+            DrawVertex::new(Vertex { x, y }, tint)
+                .with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+                .with_texture(texture.id(), [u, v])
+        };
+
+        let tr = shape(tr_vertex.x(), tr_vertex.y(), uv_max[0], uv_max[1]);
+        let tl = shape(bl_vertex.x(), tr_vertex.y(), uv_min[0], uv_max[1]);
+        let bl = shape(bl_vertex.x(), bl_vertex.y(), uv_min[0], uv_min[1]);
+        let br = shape(tr_vertex.x(), bl_vertex.y(), uv_max[0], uv_min[1]);
+
+        vertices.push(tr);
+        vertices.push(tl);
+        vertices.push(bl);
+        vertices.push(tr);
+        vertices.push(br);
+        vertices.push(bl);
+
+        Ok(vertices)
+    }
+}
+
+impl Image {
+    /// Retrieve how the image is scaled to fit the bounds of the widget
+    pub fn fit(&self) -> Fit {
+        self.fit
+    }
+
+    /// Replace the image shown by the widget with a texture
+    pub fn set_texture(&mut self, texture: Texture) {
+        self.texture = texture;
+        self.svg = None;
+    }
+
+    /// Replace the image shown by the widget with a vector image
+    pub fn set_svg(&mut self, svg: Svg) {
+        self.svg = Some(svg);
+        self.rasterized = Arc::new(Mutex::new(Texture::default()));
+    }
+
+    /// Retrieve the vector image shown by the widget, if any
+    pub fn svg(&self) -> Option<Svg> {
+        self.svg.clone()
+    }
+
+    /// Render the image on the CPU into a texture the size in pixels of the widget, fitted, tinted
+    /// and faded by its opacity the way the renderer draws it, for software backends and
+    /// screenshots. Returns None while the widget is not shown or the window has no size.
+    pub fn render(&self, ui: &Ui) -> Result<Option<Texture>, Error> {
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            return Ok(None);
+        }
+
+        let (bounds, fitted) = match (self.bounds(ui)?, self.fitted(ui)?) {
+            (Some(bounds), Some(fitted)) => (bounds, fitted),
+            _ => {
+                return Ok(None);
+            }
+        };
+        let texture = self.texture_at(ui, fitted)?;
+
+        let pixels = |vertex: Vertex| {
+            (
+                (vertex.x() * window_size[0] as f32) / 2.0,
+                (vertex.y() * window_size[1] as f32) / 2.0,
+            )
+        };
+        let (left, top) = pixels(bounds.0);
+        let (right, bottom) = pixels(bounds.1);
+        let (image_left, image_top) = pixels(fitted.0);
+        let (image_right, image_bottom) = pixels(fitted.1);
+
+        let opacity = self.inherited_opacity(ui)?;
+        let tint = self
+            .tint
+            .with_alpha((f32::from(self.tint.alpha()) * opacity).round() as u8);
+
+        let mut painter = Painter::new(right - left, bottom - top);
+        painter.image_part(
+            (image_left - left, image_top - top),
+            (image_right - image_left, image_bottom - image_top),
+            &texture,
+            (fitted.2, fitted.3),
+            tint,
+        );
+
+        Ok(painter.texture(&TextureCache::default()))
+    }
+
+    /// The texture drawn between vertices fitted by `fitted`, vector images are rasterized again
+    /// whenever the size they are drawn at changes
+    fn texture_at(&self, ui: &Ui, fitted: (Vertex, Vertex, [f32; 2], [f32; 2])) -> Result<Texture, Error> {
+        let (bl_vertex, tr_vertex, uv_min, uv_max) = fitted;
+        let svg = match self.svg {
+            None => {
+                return Ok(self.texture.clone());
+            }
+            Some(ref val) => val,
+        };
+
+        let window_size = ui.window_size();
+        let width = ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / (2.0 * (uv_max[0] - uv_min[0]));
+        let height = ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / (2.0 * (uv_max[1] - uv_min[1]));
+        let (width, height) = if (window_size[0] == 0) | (window_size[1] == 0) {
+            (svg.width(), svg.height())
+        } else {
+            (width, height)
+        };
+        let (width, height) = (width.ceil().max(1.0) as u32, height.ceil().max(1.0) as u32);
+
+        let mut rasterized = self.rasterized.lock();
+        if (rasterized.width(), rasterized.height()) != (width, height) {
+            *rasterized = svg.rasterize(width, height)?;
+        }
+        Ok(rasterized.clone())
+    }
+
+    /// Calculate the bottom left and top right vertices the image is drawn between, along with
+    /// the uv coordinates of the part of the texture shown in them
+    pub fn fitted(&self, ui: &Ui) -> Result<Option<(Vertex, Vertex, [f32; 2], [f32; 2])>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        // Until the size of the window is known the aspect ratio can't be kept
+        let window_size = ui.window_size();
+        let fit = if (window_size[0] == 0) | (window_size[1] == 0) {
+            Fit::Stretch
+        } else {
+            self.fit
+        };
+
+        let width = ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0;
+        let height = ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0;
+        let (image_width, image_height) = match self.svg {
+            None => (self.texture.width() as f32, self.texture.height() as f32),
+            Some(ref svg) => (svg.width(), svg.height()),
+        };
+
+        let (scale_x, scale_y) = match fit {
+            Fit::Stretch => {
+                return Ok(Some((bl_vertex, tr_vertex, [0.0, 0.0], [1.0, 1.0])));
+            }
+            Fit::Contain => {
+                let scale = (width / image_width).min(height / image_height);
+                (scale, scale)
+            }
+            Fit::Cover => {
+                let scale = (width / image_width).max(height / image_height);
+                (scale, scale)
+            }
+            Fit::None => (1.0, 1.0),
+        };
+
+        // The scaled image is centered, cropping the texture where it exceeds the bounds
+        let shown_width = width.min(image_width * scale_x);
+        let shown_height = height.min(image_height * scale_y);
+        let uv_width = shown_width / (image_width * scale_x);
+        let uv_height = shown_height / (image_height * scale_y);
+
+        let center_x = (bl_vertex.x() + tr_vertex.x()) / 2.0;
+        let center_y = (bl_vertex.y() + tr_vertex.y()) / 2.0;
+        let half_x = shown_width / window_size[0] as f32;
+        let half_y = shown_height / window_size[1] as f32;
+
+        Ok(Some((
+            Vertex {
+                x: center_x - half_x,
+                y: center_y - half_y,
+            },
+            Vertex {
+                x: center_x + half_x,
+                y: center_y + half_y,
+            },
+            [0.5 - (uv_width / 2.0), 0.5 - (uv_height / 2.0)],
+            [0.5 + (uv_width / 2.0), 0.5 + (uv_height / 2.0)],
+        )))
+    }
+}
+
+/// The builder for the Image widget
+#[derive(Clone, Default)]
+pub struct ImageBuilder {
+    id:            Id,
+    size:          Size,
+    position:      Position,
+    parent_id:     Id,
+    path:          String,
+    bytes:         Vec<u8>,
+    texture:       Option<Texture>,
+    svg:           Option<Svg>,
+    fit:           Fit,
+    tint:          String,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border_color:  String,
+    border_width:  Option<f32>,
+    corner_radius: Option<[f32; 4]>,
+}
+
+impl ImageBuilder {
+    /// Initialize the builder for the Image widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Image widget from another Image widget
+    pub fn new_from_image(image: &Image) -> Self {
+        Self {
+            id:            image.id(),
+            size:          image.size(),
+            position:      image.position(),
+            parent_id:     image.parent_id().unwrap(),
+            path:          String::new(),
+            bytes:         Vec::new(),
+            texture:       image.texture(),
+            svg:           image.svg(),
+            fit:           image.fit(),
+            tint:          image.color().into_hex(),
+            visible:       image.visible(),
+            opacity:       image.opacity(),
+            z_index:       image.z_index(),
+            layer:         image.layer(),
+            border_color:  image.border().color().into_hex(),
+            border_width:  Some(image.border().width()),
+            corner_radius: Some(image.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the path of the PNG, JPEG or SVG file to show
+    pub fn with_path<V: Into<String>>(&mut self, path: V) -> &mut Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Set the bytes of the PNG, JPEG or SVG image to show
+    pub fn with_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.bytes = bytes.to_vec();
+        self
+    }
+
+    /// Set the already decoded texture to show
+    pub fn with_texture(&mut self, texture: Texture) -> &mut Self {
+        self.texture = Some(texture);
+        self
+    }
+
+    /// Set the already parsed vector image to show
+    pub fn with_svg(&mut self, svg: Svg) -> &mut Self {
+        self.svg = Some(svg);
+        self
+    }
+
+    /// Set how the image is scaled to fit the bounds of the widget
+    pub fn with_fit(&mut self, fit: Fit) -> &mut Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Set the color the image is multiplied with
+    pub fn with_tint<V: Into<String>>(&mut self, tint: V) -> &mut Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the Image widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Image>, Error> {
+        let (texture, svg) = if let Some(val) = self.clone().svg {
+            (Texture::default(), Some(val))
+        } else if let Some(val) = self.clone().texture {
+            (val, None)
+        } else if !self.bytes.is_empty() {
+            match Texture::from_bytes(&self.bytes) {
+                Ok(val) => (val, None),
+                Err(err) => match Svg::from_bytes(&self.bytes) {
+                    Err(_) => {
+                        return Err(err);
+                    }
+                    Ok(val) => (Texture::default(), Some(val)),
+                },
+            }
+        } else if self.path.to_lowercase().ends_with(".svg") {
+            (Texture::default(), Some(Svg::from_path(&self.path)?))
+        } else if !self.path.is_empty() {
+            (Texture::from_path(&self.path)?, None)
+        } else {
+            return Err(err_msg("An image needs a path, bytes, a texture or an svg to show"));
+        };
+
+        let tint = if self.tint.is_empty() {
+            Color::from_hex("#FFFFFFFF")?
+        } else {
+            Color::from_hex(self.clone().tint)?
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Image {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            texture,
+            svg,
+            rasterized: Arc::new(Mutex::new(Texture::default())),
+            fit: self.fit,
+            tint,
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
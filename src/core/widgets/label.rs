@@ -13,15 +13,22 @@ use crate::prelude::*;
 /// The Label Widget
 #[derive(Clone)]
 pub struct Label {
-    id:         Id,
-    parent_id:  Id,
-    size:       Size,
-    position:   Position,
-    bg_color:   Color,
-    visible:    bool,
-    label:      String,
-    text_color: Color,
-    text_size:  f32,
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    background:    Fill,
+    visible:       bool,
+    opacity:       f32,
+    overflow:      Overflow,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+    label:         String,
+    text_color:    Color,
+    text_size:     f32,
 }
 
 impl WidgetTrait for Label {
@@ -50,7 +57,11 @@ impl WidgetTrait for Label {
     }
 
     fn color(&self) -> Color {
-        self.bg_color
+        self.background.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.background.clone()
     }
 
     fn visible(&self) -> bool {
@@ -64,6 +75,42 @@ impl WidgetTrait for Label {
     fn hide(&mut self) {
         self.visible = false;
     }
+
+    fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
 }
 
 impl TextTrait for Label {
@@ -83,15 +130,26 @@ impl TextTrait for Label {
 /// The builder for the Label widget
 #[derive(Clone, Default)]
 pub struct LabelBuilder {
-    id:         Id,
-    size:       Size,
-    position:   Position,
-    bg_color:   String,
-    text_color: String,
-    parent_id:  Id,
-    visible:    bool,
-    label:      String,
-    text_size:  f32,
+    id:                 Id,
+    size:               Size,
+    position:           Position,
+    bg_color:           String,
+    text_color:         String,
+    parent_id:          Id,
+    visible:            bool,
+    opacity:            f32,
+    overflow:           Overflow,
+    z_index:            i32,
+    layer:              Layer,
+    border_color:       String,
+    border_width:       Option<f32>,
+    corner_radius:      Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<(f32, f32)>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       String,
+    label:              String,
+    text_size:          f32,
 }
 
 impl LabelBuilder {
@@ -106,6 +164,7 @@ impl LabelBuilder {
             position,
             parent_id: parent_id.into(),
             visible: true,
+            opacity: 1.0,
             label: label.into(),
             text_size: 100.0,
             ..Self::default()
@@ -115,19 +174,30 @@ impl LabelBuilder {
     /// Initialize the builder for the Label widget from another Label widget
     pub fn new_from_label(label: &Label) -> Self {
         Self {
-            id:         label.id(),
-            size:       label.size(),
-            position:   label.position(),
-            bg_color:   label.color().into_hex(),
-            text_color: label.text_color().into_hex(),
-            parent_id:  label.parent_id().unwrap(),
-            visible:    label.visible(),
-            label:      label.label(),
-            text_size:  label.text_size(),
+            id:                 label.id(),
+            size:               label.size(),
+            position:           label.position(),
+            bg_color:           label.fill().into_string(),
+            text_color:         label.text_color().into_hex(),
+            parent_id:          label.parent_id().unwrap(),
+            visible:            label.visible(),
+            opacity:            label.opacity(),
+            overflow:           label.overflow(),
+            z_index:            label.z_index(),
+            layer:              label.layer(),
+            border_color:       label.border().color().into_hex(),
+            border_width:       Some(label.border().width()),
+            corner_radius:      Some(label.corner_radius().as_array()),
+            label:              label.label(),
+            text_size:          label.text_size(),
+            elevation:          None,
+            shadow_offset:      Some(label.shadow().offset()),
+            shadow_blur_radius: Some(label.shadow().blur_radius()),
+            shadow_color:       label.shadow().color().into_hex(),
         }
     }
 
-    /// Set the color
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
     pub fn with_background_color<V: Into<String>>(&mut self, bg_color: V) -> &mut Self {
         self.bg_color = bg_color.into();
         self
@@ -151,12 +221,96 @@ impl LabelBuilder {
         self
     }
 
+    /// Set how the children are clipped
+    pub fn with_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Set the offset in pixels of the shadow
+    pub fn with_shadow_offset(&mut self, x: f32, y: f32) -> &mut Self {
+        self.shadow_offset = Some((x, y));
+        self
+    }
+
+    /// Set the blur radius in pixels of the shadow
+    pub fn with_shadow_blur_radius(&mut self, blur_radius: f32) -> &mut Self {
+        self.shadow_blur_radius = Some(blur_radius);
+        self
+    }
+
+    /// Set the shadow color
+    pub fn with_shadow_color<V: Into<String>>(&mut self, shadow_color: V) -> &mut Self {
+        self.shadow_color = shadow_color.into();
+        self
+    }
+
     /// Build the Label widget
     pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Label>, Error> {
-        let bg_color = if self.bg_color.is_empty() {
-            ui.theme().label_background_color()?
+        let background = if self.bg_color.is_empty() {
+            ui.theme().label_background_fill()?
         } else {
-            Color::from_hex(self.clone().bg_color)?
+            Fill::parse(self.clone().bg_color)?
         };
 
         let text_color = if self.text_color.is_empty() {
@@ -171,14 +325,60 @@ impl LabelBuilder {
             return Err(err_msg("The size must be 100 or less"));
         }
 
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => ui.theme().label_shadow()?,
+            Some(val) => Shadow::from_elevation(val, ui.theme().label_shadow()?.color())?,
+        };
+
+        let shadow_color = if self.shadow_color.is_empty() {
+            shadow.color()
+        } else {
+            Color::from_hex(self.clone().shadow_color)?
+        };
+
+        let shadow = Shadow::new(
+            self.shadow_offset.unwrap_or_else(|| shadow.offset()),
+            self.shadow_blur_radius.unwrap_or_else(|| shadow.blur_radius()),
+            shadow_color,
+        )?;
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
         let widget = Box::new(Label {
             id: self.clone().id,
             parent_id: self.clone().parent_id,
             position: self.position,
             size: self.size,
-            bg_color,
+            background,
             text_color,
             visible: self.visible,
+            opacity: self.opacity,
+            overflow: self.overflow,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
             label: self.clone().label,
             text_size: self.text_size,
         });
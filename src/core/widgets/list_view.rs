@@ -0,0 +1,915 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    collections::BTreeSet,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The longest time in milliseconds between the two clicks of a double click
+pub(crate) const DOUBLE_CLICK: u64 = 500;
+
+/// The callback given the selected rows of a list
+pub type SelectionCallback = Arc<Fn(&[usize]) + Send + Sync>;
+
+/// The callback given a row of a list
+pub type RowCallback = Arc<Fn(usize) + Send + Sync>;
+
+/// A row shown by the list view, bound to another row of the model once scrolled out of view
+struct Row {
+    index: usize,
+    text:  String,
+}
+
+/// The ListView Widget, the rows of a model in a scrolled list. Only the rows in view are asked
+/// from the model, so a list view shows any number of rows.
+#[derive(Clone)]
+pub struct ListView {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    text_color:      Color,
+    selection_color: Color,
+    focus_color:     Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    row_height:      f32,
+    model:           Arc<ListModel>,
+    rows:            Arc<Mutex<Vec<Row>>>,
+    selection_mode:  SelectionMode,
+    selected:        BTreeSet<usize>,
+    current:         Option<usize>,
+    anchor:          Option<usize>,
+    scroll:          f32,
+    last_click:      Option<(usize, Instant)>,
+    on_select:       Option<SelectionCallback>,
+    on_activate:     Option<RowCallback>,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for ListView {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::ListView
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed {
+                y,
+                button: MouseButton::Left,
+                modifiers,
+                ..
+            } => {
+                if let Some(row) = self.row_at(ui, y)? {
+                    let double = match self.last_click {
+                        Some((last, time)) => (last == row) & (time.elapsed() < Duration::from_millis(DOUBLE_CLICK)),
+                        None => false,
+                    };
+                    self.click(row, modifiers);
+                    if double {
+                        self.last_click = None;
+                        self.activate(row);
+                    } else {
+                        self.last_click = Some((row, Instant::now()));
+                    }
+                }
+            }
+            InputEvent::MouseWheel { delta_y, .. } => {
+                return self.scroll_by(ui, -delta_y);
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                return self.handle_key(ui, key, modifiers);
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let (width, height) = painter.size();
+        let last = (((self.scroll + height) / self.row_height).ceil() as usize).min(self.model.len());
+        let first = ((self.scroll / self.row_height).floor() as usize).min(last);
+        self.recycle(first, last);
+
+        let focused = ui.focused() == Some(self.id());
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+
+        for row in self.rows.lock().iter() {
+            let y = (row.index as f32 * self.row_height) - self.scroll;
+            if self.selected.contains(&row.index) {
+                painter.rect((0.0, y), (width, self.row_height), self.selection_color);
+            }
+            if let Some(ref font) = self.font {
+                painter.text(
+                    (PADDING, y + ((self.row_height - line_height) / 2.0)),
+                    &row.text,
+                    self.text_size,
+                    self.text_color,
+                    font,
+                );
+            }
+            if focused & (self.current == Some(row.index)) {
+                painter.stroke_rect(
+                    (0.5, y + 0.5),
+                    (width - 1.0, self.row_height - 1.0),
+                    1.0,
+                    self.focus_color,
+                );
+            }
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl ListView {
+    /// Retrieve the model of the rows
+    pub fn model(&self) -> Arc<ListModel> {
+        Arc::clone(&self.model)
+    }
+
+    /// Show the rows of another model, clearing the selection and scrolling back to the top
+    pub fn set_model(&mut self, model: Arc<ListModel>) {
+        self.model = model;
+        self.rows.lock().clear();
+        self.selected.clear();
+        self.current = None;
+        self.anchor = None;
+        self.scroll = 0.0;
+    }
+
+    /// Ask the model again for the rows shown, after its rows changed. The selected rows past the
+    /// end of the model are unselected.
+    pub fn refresh(&mut self) {
+        let len = self.model.len();
+        self.rows.lock().clear();
+        self.selected = self.selected.iter().cloned().filter(|&row| row < len).collect();
+        self.current = self.current.filter(|&row| row < len);
+        self.anchor = self.anchor.filter(|&row| row < len);
+    }
+
+    /// Retrieve the rows of the model shown the last time the list view was drawn
+    pub fn rows(&self) -> Vec<usize> {
+        self.rows.lock().iter().map(|row| row.index).collect()
+    }
+
+    /// Retrieve how many rows can be selected at once
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection_mode
+    }
+
+    /// Retrieve the selected rows, in order
+    pub fn selected(&self) -> Vec<usize> {
+        self.selected.iter().cloned().collect()
+    }
+
+    /// Select rows, only the first one unless several rows can be selected, without calling the
+    /// selection callback. The rows past the end of the model are ignored.
+    pub fn set_selected(&mut self, rows: &[usize]) {
+        let len = self.model.len();
+        let mut rows = rows.iter().cloned().filter(|&row| row < len);
+        self.selected = match self.selection_mode {
+            SelectionMode::Single => rows.next().into_iter().collect(),
+            SelectionMode::Multiple => rows.collect(),
+        };
+        self.current = self.selected.iter().next().cloned();
+        self.anchor = self.current;
+    }
+
+    /// Retrieve the row moved to with the keyboard or clicked last
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Retrieve the distance in pixels the rows are scrolled down
+    pub fn scroll(&self) -> f32 {
+        self.scroll
+    }
+
+    /// Scroll just enough for a row to be shown
+    pub fn scroll_to(&mut self, ui: &Ui, row: usize) -> Result<(), Error> {
+        let height = match self.area(ui)? {
+            None => {
+                return Ok(());
+            }
+            Some((_, _, _, val)) => val,
+        };
+
+        let top = row as f32 * self.row_height;
+        if top < self.scroll {
+            self.scroll = top;
+        } else if (top + self.row_height) - self.scroll > height {
+            self.scroll = (top + self.row_height) - height;
+        }
+        self.scroll = self.scroll.min(self.max_scroll(height)).max(0.0);
+
+        Ok(())
+    }
+
+    /// Retrieve the height in pixels of the rows
+    pub fn row_height(&self) -> f32 {
+        self.row_height
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the selected rows whenever the user changes the selection
+    pub fn set_on_select<F>(&mut self, on_select: F)
+    where
+        F: Fn(&[usize]) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+    }
+
+    /// Set the callback called with a row when it is double clicked, or when enter is pressed on
+    /// the current row
+    pub fn set_on_activate<F>(&mut self, on_activate: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(on_activate));
+    }
+
+    fn handle_key(&mut self, ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> Result<bool, Error> {
+        let len = self.model.len();
+        if len == 0 {
+            return Ok(false);
+        }
+
+        let command = modifiers.ctrl | modifiers.logo;
+        let multiple = self.selection_mode == SelectionMode::Multiple;
+        let page = self.page_rows(ui)?;
+        let current = self.current.filter(|&row| row < len);
+
+        let target = match key {
+            VirtualKeyCode::Up => current.map_or(len - 1, |row| row.saturating_sub(1)),
+            VirtualKeyCode::Down => current.map_or(0, |row| (row + 1).min(len - 1)),
+            VirtualKeyCode::PageUp => current.map_or(0, |row| row.saturating_sub(page)),
+            VirtualKeyCode::PageDown => current.map_or(0, |row| (row + page).min(len - 1)),
+            VirtualKeyCode::Home => 0,
+            VirtualKeyCode::End => len - 1,
+            VirtualKeyCode::A if command & multiple => {
+                self.change((0..len).collect());
+                return Ok(true);
+            }
+            VirtualKeyCode::Space => {
+                let row = match current {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                self.click(row, modifiers);
+                return Ok(true);
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                return Ok(match current {
+                    None => false,
+                    Some(row) => {
+                        self.activate(row);
+                        true
+                    }
+                });
+            }
+            _ => {
+                return Ok(false);
+            }
+        };
+
+        self.current = Some(target);
+        if multiple & modifiers.shift {
+            let anchor = self.anchor.unwrap_or(target);
+            self.change(range(anchor, target));
+        } else if !(multiple & command) {
+            // The control key moves through the rows of a multiple selection without selecting
+            self.anchor = Some(target);
+            self.change(Some(target).into_iter().collect());
+        }
+        self.scroll_to(ui, target)?;
+
+        Ok(true)
+    }
+
+    /// Select a row clicked, or pressed space on, with the modifier keys held down
+    fn click(&mut self, row: usize, modifiers: ModifiersState) {
+        let command = modifiers.ctrl | modifiers.logo;
+        let selected = match self.selection_mode {
+            SelectionMode::Multiple if modifiers.shift => range(self.anchor.unwrap_or(row), row),
+            SelectionMode::Multiple if command => {
+                let mut selected = self.selected.clone();
+                if !selected.remove(&row) {
+                    selected.insert(row);
+                }
+                self.anchor = Some(row);
+                selected
+            }
+            _ => {
+                self.anchor = Some(row);
+                Some(row).into_iter().collect()
+            }
+        };
+
+        self.current = Some(row);
+        self.change(selected);
+    }
+
+    fn change(&mut self, selected: BTreeSet<usize>) {
+        if selected == self.selected {
+            return;
+        }
+
+        self.selected = selected;
+        if let Some(ref on_select) = self.on_select {
+            on_select(&self.selected());
+        }
+    }
+
+    fn activate(&self, row: usize) {
+        if let Some(ref on_activate) = self.on_activate {
+            on_activate(row);
+        }
+    }
+
+    /// Bind the rows shown to the rows of the model from first to last, reusing the rows scrolled
+    /// out of view so that only the rows coming into view are asked from the model
+    fn recycle(&self, first: usize, last: usize) {
+        let mut rows = self.rows.lock();
+        let shown = |index: usize| (index >= first) & (index < last);
+
+        let mut bound = vec![false; last - first];
+        let mut free = Vec::new();
+        for (i, row) in rows.iter().enumerate() {
+            if shown(row.index) {
+                bound[row.index - first] = true;
+            } else {
+                free.push(i);
+            }
+        }
+
+        for index in (first..last).filter(|index| !bound[index - first]) {
+            let text = self.model.text(index);
+            match free.pop() {
+                None => rows.push(Row { index, text }),
+                Some(i) => rows[i] = Row { index, text },
+            }
+        }
+
+        // The rows left over once fewer rows are shown
+        rows.retain(|row| shown(row.index));
+        rows.sort_by_key(|row| row.index);
+    }
+
+    /// The position in pixels of the top left corner of the list view in the window, and its size
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+
+    /// The row under a position in pixels in the window, if any
+    fn row_at(&self, ui: &Ui, y: f32) -> Result<Option<usize>, Error> {
+        let top = match self.area(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some((_, val, ..)) => val,
+        };
+
+        let row = ((y - top + self.scroll) / self.row_height).floor();
+        if (row < 0.0) | (row as usize >= self.model.len()) {
+            return Ok(None);
+        }
+
+        Ok(Some(row as usize))
+    }
+
+    /// The number of rows shown at once
+    fn page_rows(&self, ui: &Ui) -> Result<usize, Error> {
+        Ok(match self.area(ui)? {
+            None => 1,
+            Some((_, _, _, height)) => ((height / self.row_height).floor() as usize).max(1),
+        })
+    }
+
+    /// The largest distance in pixels the rows can be scrolled down in a list view of a height
+    fn max_scroll(&self, height: f32) -> f32 {
+        ((self.model.len() as f32 * self.row_height) - height).max(0.0)
+    }
+
+    /// Scroll the rows down by a distance in pixels, returning whether they moved
+    fn scroll_by(&mut self, ui: &Ui, y: f32) -> Result<bool, Error> {
+        let height = match self.area(ui)? {
+            None => {
+                return Ok(false);
+            }
+            Some((_, _, _, val)) => val,
+        };
+
+        let scroll = (self.scroll + y).min(self.max_scroll(height)).max(0.0);
+        let moved = scroll != self.scroll;
+        self.scroll = scroll;
+        Ok(moved)
+    }
+}
+
+/// The rows from one row to another, both included
+fn range(from: usize, to: usize) -> BTreeSet<usize> {
+    (from.min(to)..=from.max(to)).collect()
+}
+
+/// The builder for the ListView widget
+#[derive(Clone, Default)]
+pub struct ListViewBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    text_color:      String,
+    selection_color: String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    row_height:      Option<f32>,
+    model:           Option<Arc<ListModel>>,
+    selection_mode:  SelectionMode,
+    selected:        Vec<usize>,
+    on_select:       Option<SelectionCallback>,
+    on_activate:     Option<RowCallback>,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+}
+
+impl ListViewBuilder {
+    /// Initialize the builder for the ListView widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the ListView widget from another ListView widget
+    pub fn new_from_list_view(list_view: &ListView) -> Self {
+        Self {
+            id:              list_view.id(),
+            size:            list_view.size(),
+            position:        list_view.position(),
+            color:           list_view.fill().into_string(),
+            text_color:      list_view.text_color.into_hex(),
+            selection_color: list_view.selection_color.into_hex(),
+            font:            list_view.font(),
+            text_size:       Some(list_view.text_size()),
+            row_height:      Some(list_view.row_height()),
+            model:           Some(list_view.model()),
+            selection_mode:  list_view.selection_mode(),
+            selected:        list_view.selected(),
+            on_select:       list_view.on_select.clone(),
+            on_activate:     list_view.on_activate.clone(),
+            parent_id:       list_view.parent_id().unwrap(),
+            visible:         list_view.visible(),
+            opacity:         list_view.opacity(),
+            z_index:         list_view.z_index(),
+            layer:           list_view.layer(),
+            border_color:    list_view.border().color().into_hex(),
+            border_width:    Some(list_view.border().width()),
+            corner_radius:   Some(list_view.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the text color
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color behind the selected rows
+    pub fn with_selection_color<V: Into<String>>(&mut self, selection_color: V) -> &mut Self {
+        self.selection_color = selection_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the height in pixels of the rows, the size of the text and some padding by default
+    pub fn with_row_height(&mut self, row_height: f32) -> &mut Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Set the model of the rows
+    pub fn with_model(&mut self, model: Arc<ListModel>) -> &mut Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// Show a list of texts as the rows
+    pub fn with_items<I, V>(&mut self, items: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let items: Vec<String> = items.into_iter().map(Into::into).collect();
+        self.model = Some(Arc::new(items));
+        self
+    }
+
+    /// Set how many rows can be selected at once
+    pub fn with_selection_mode(&mut self, selection_mode: SelectionMode) -> &mut Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Set the selected rows
+    pub fn with_selected(&mut self, selected: &[usize]) -> &mut Self {
+        self.selected = selected.to_vec();
+        self
+    }
+
+    /// Set the callback called with the selected rows whenever the user changes the selection
+    pub fn with_on_select<F>(&mut self, on_select: F) -> &mut Self
+    where
+        F: Fn(&[usize]) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+
+    /// Set the callback called with a row when it is double clicked, or when enter is pressed on
+    /// the current row
+    pub fn with_on_activate<F>(&mut self, on_activate: F) -> &mut Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(on_activate));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the ListView widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<ListView>, Error> {
+        let fill = if self.color.is_empty() {
+            ui.theme().text_input_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = if self.text_color.is_empty() {
+            ui.theme().text_input_text_color()?
+        } else {
+            Color::from_hex(self.clone().text_color)?
+        };
+
+        let selection_color = if self.selection_color.is_empty() {
+            ui.theme().selection_color()?
+        } else {
+            Color::from_hex(self.clone().selection_color)?
+        };
+
+        let font = match self.font {
+            None => ui.theme().font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let row_height = self.row_height.unwrap_or(text_size + (2.0 * PADDING));
+        if row_height <= 0.0 {
+            return Err(err_msg("The row height must be greater than 0.0"));
+        }
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let model = match self.model {
+            None => Arc::new(Vec::<String>::new()),
+            Some(ref val) => Arc::clone(val),
+        };
+
+        let mut widget = Box::new(ListView {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color,
+            selection_color,
+            focus_color: ui.theme().checked_color()?,
+            font,
+            text_size,
+            row_height,
+            model,
+            rows: Arc::new(Mutex::new(Vec::new())),
+            selection_mode: self.selection_mode,
+            selected: BTreeSet::new(),
+            current: None,
+            anchor: None,
+            scroll: 0.0,
+            last_click: None,
+            on_select: self.clone().on_select,
+            on_activate: self.clone().on_activate,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        });
+        widget.set_selected(&self.selected);
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
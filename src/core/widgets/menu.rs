@@ -0,0 +1,1074 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    menu_bar::MenuBar,
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The height in pixels of a separator
+const SEPARATOR: f32 = 9.0;
+/// The width in pixels of the column of the checkmarks, and of the arrows of the submenus
+const CHECK: f32 = 24.0;
+/// The space in pixels between the text and the shortcut of an item
+const SHORTCUT_GAP: f32 = 24.0;
+/// The narrowest a menu gets in pixels
+const MIN_MENU_WIDTH: f32 = 160.0;
+
+/// The callback given the id of a menu item
+pub type MenuCallback = Arc<Fn(&str) + Send + Sync>;
+
+/// An item of a menu, either a command, possibly checked, a submenu or a separator
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MenuItem {
+    id:        Id,
+    text:      String,
+    shortcut:  String,
+    checked:   Option<bool>,
+    enabled:   bool,
+    separator: bool,
+    items:     Vec<MenuItem>,
+}
+
+impl MenuItem {
+    /// Create an item
+    pub fn new<V: Into<String>>(id: V, text: V) -> Self {
+        Self {
+            id: id.into(),
+            text: text.into(),
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Create a line between the items
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::default()
+        }
+    }
+
+    /// Set the label of the keyboard shortcut shown at the right of the item
+    pub fn with_shortcut<V: Into<String>>(mut self, shortcut: V) -> Self {
+        self.shortcut = shortcut.into();
+        self
+    }
+
+    /// Make the item show a checkmark, checked or not, toggled when the item is picked
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Set whether the item can be picked
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Add an item to the submenu opened by the item
+    pub fn with_item(mut self, item: MenuItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Retrieve the id
+    pub fn id(&self) -> Id {
+        self.id.clone()
+    }
+
+    /// Retrieve the text
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Retrieve the label of the keyboard shortcut
+    pub fn shortcut(&self) -> String {
+        self.shortcut.clone()
+    }
+
+    /// Retrieve whether the item is checked, this will be None if it shows no checkmark
+    pub fn checked(&self) -> Option<bool> {
+        self.checked
+    }
+
+    /// Retrieve whether the item can be picked
+    pub fn enabled(&self) -> bool {
+        self.enabled & !self.separator
+    }
+
+    /// Retrieve whether the item is a separator
+    pub fn is_separator(&self) -> bool {
+        self.separator
+    }
+
+    /// Retrieve the items of the submenu opened by the item
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    fn find_mut<'a>(items: &'a mut [MenuItem], id: &str) -> Option<&'a mut MenuItem> {
+        for item in items {
+            if item.id == id {
+                return Some(item);
+            }
+            if let Some(val) = MenuItem::find_mut(&mut item.items, id) {
+                return Some(val);
+            }
+        }
+        None
+    }
+}
+
+/// The Menu Widget, a popup list of items opened by a menu bar or by pressing the right button
+/// over the widgets it is attached to. It is drawn in the overlay layer, and covers the window
+/// while it is open so that clicking anywhere else closes it.
+#[derive(Clone)]
+pub struct Menu {
+    id:              Id,
+    parent_id:       Id,
+    fill:            Fill,
+    text_color:      Color,
+    highlight_color: Color,
+    disabled_color:  Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    item_height:     f32,
+    items:           Vec<MenuItem>,
+    open:            Option<(f32, f32)>,
+    levels:          usize,
+    path:            Vec<usize>,
+    bar:             Option<Id>,
+    return_focus:    Option<Id>,
+    on_select:       Option<MenuCallback>,
+    text:            Arc<TextureCache>,
+    opacity:         f32,
+    z_index:         i32,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for Menu {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Menu
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        Size::Full
+    }
+
+    fn set_size(&mut self, _size: Size) {}
+
+    fn position(&self) -> Position {
+        Position::default()
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.open.is_some()
+    }
+
+    fn show(&mut self) {}
+
+    fn hide(&mut self) {
+        self.open = None;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        Layer::Overlay
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn bounds(&self, _ui: &Ui) -> Result<Option<(Vertex, Vertex)>, Error> {
+        Ok(self
+            .open
+            .map(|_| (Vertex { x: 0.0, y: 0.0 }, Vertex { x: 2.0, y: 2.0 })))
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        if self.open.is_none() {
+            // The right button pressed over a widget the menu is attached to opens it
+            return Ok(match *event {
+                InputEvent::MousePressed {
+                    x,
+                    y,
+                    button: MouseButton::Right,
+                    ..
+                } => {
+                    self.open(ui, x, y);
+                    true
+                }
+                _ => false,
+            });
+        }
+
+        match *event {
+            InputEvent::CursorMoved { x, y } => {
+                if let Some((level, Some(index))) = self.item_at(ui, x, y) {
+                    self.highlight(level, index);
+                }
+            }
+            InputEvent::MousePressed { x, y, .. } => match self.item_at(ui, x, y) {
+                None => self.press_outside(ui, x, y)?,
+                Some((_, None)) => {}
+                Some((level, Some(index))) => {
+                    self.highlight(level, index);
+                    if self.items_at(level)[index].items.is_empty() {
+                        self.activate(ui, level, index);
+                    }
+                }
+            },
+            InputEvent::MouseReleased { .. } => {}
+            InputEvent::KeyPressed { key, .. } => {
+                return self.handle_key(ui, key);
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let window_size = ui.window_size();
+        if self.open.is_none() | (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(Vec::new());
+        }
+
+        // The painter only covers the panels open, not the whole window
+        let panels = self.panels(ui);
+        let left = panels.iter().map(|panel| panel.0).fold(f32::MAX, f32::min);
+        let top = panels.iter().map(|panel| panel.1).fold(f32::MAX, f32::min);
+        let right = panels.iter().map(|panel| panel.0 + panel.2).fold(0.0, f32::max);
+        let bottom = panels.iter().map(|panel| panel.1 + panel.3).fold(0.0, f32::max);
+        let mut painter = Painter::new(right - left, bottom - top);
+
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+        let border = self.border.color();
+        let opacity = self.inherited_opacity(ui)?;
+        let mut vertices = Vec::new();
+
+        // The panels are rounded and bordered like any other widget, under the painted items
+        let corner_radius = self.corner_radius();
+        let mut border_color = border.into_scaled_rgba_float();
+        border_color[3] *= opacity;
+        for &panel in panels.iter() {
+            let (bl_vertex, tr_vertex) = panel_vertices(panel, window_size);
+            for (position, mut color) in self.fill.triangles(bl_vertex, tr_vertex) {
+                color[3] *= opacity;
+                vertices.push(DrawVertex::new(position, color).with_shape(
+                    (bl_vertex, tr_vertex),
+                    corner_radius,
+                    self.border.width(),
+                    border_color,
+                ));
+            }
+        }
+
+        for (level, &(x, y, width, _)) in panels.iter().enumerate() {
+            let (x, y) = (x - left, y - top);
+            let mut item_y = y + PADDING;
+            for (index, item) in self.items_at(level).iter().enumerate() {
+                if item.separator {
+                    painter.line(
+                        (x + PADDING, item_y + (SEPARATOR / 2.0)),
+                        (x + width - PADDING, item_y + (SEPARATOR / 2.0)),
+                        1.0,
+                        border,
+                    );
+                    item_y += SEPARATOR;
+                    continue;
+                }
+
+                if self.path.get(level) == Some(&index) && item.enabled() {
+                    painter.rect((x, item_y), (width, self.item_height), self.highlight_color);
+                }
+
+                let color = if item.enabled() {
+                    self.text_color
+                } else {
+                    self.disabled_color
+                };
+                let middle = item_y + (self.item_height / 2.0);
+                if item.checked == Some(true) {
+                    let cx = x + (CHECK / 2.0);
+                    painter.polyline(
+                        &[(cx - 5.0, middle), (cx - 1.5, middle + 3.5), (cx + 5.0, middle - 4.0)],
+                        2.0,
+                        color,
+                    );
+                }
+
+                if let Some(ref font) = self.font {
+                    let text_y = item_y + ((self.item_height - line_height) / 2.0);
+                    painter.text((x + CHECK, text_y), &item.text, self.text_size, color, font);
+                    if !item.shortcut.is_empty() {
+                        let shortcut_width = font.measure(&item.shortcut, self.text_size).0;
+                        painter.text(
+                            (x + width - CHECK - shortcut_width, text_y),
+                            &item.shortcut,
+                            self.text_size,
+                            color,
+                            font,
+                        );
+                    }
+                }
+
+                if !item.items.is_empty() {
+                    let cx = x + width - (CHECK / 2.0);
+                    painter.polygon(
+                        &[(cx - 2.0, middle - 4.0), (cx - 2.0, middle + 4.0), (cx + 2.0, middle)],
+                        color,
+                    );
+                }
+
+                item_y += self.item_height;
+            }
+        }
+
+        let origin = Vertex {
+            x: (left * 2.0) / window_size[0] as f32,
+            y: (top * 2.0) / window_size[1] as f32,
+        };
+        let texture = painter.texture(&self.text);
+        vertices.extend(
+            painter.draw_vertices(origin, window_size, texture.as_ref(), |position, mut color| {
+                color[3] *= opacity;
+                DrawVertex::new(position, color)
+            }),
+        );
+
+        Ok(vertices)
+    }
+
+    fn draw_shadow(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        // The menu covers the whole window while open, so only its panels cast a shadow
+        let window_size = ui.window_size();
+        if self.open.is_none() | !self.shadow.visible() | (window_size[0] == 0) | (window_size[1] == 0) {
+            return Ok(vertices);
+        }
+
+        let opacity = self.inherited_opacity(ui)?;
+        for panel in self.panels(ui) {
+            let (bl_vertex, tr_vertex) = panel_vertices(panel, window_size);
+            for (position, mut color) in Fill::Solid(self.shadow.color()).triangles(bl_vertex, tr_vertex) {
+                color[3] *= opacity;
+                vertices.push(
+                    DrawVertex::new(position, color)
+                        .with_shape((bl_vertex, tr_vertex), self.corner_radius, 0.0, [0.0; 4])
+                        .with_blur(self.shadow.offset(), self.shadow.blur_radius()),
+                );
+            }
+        }
+
+        Ok(vertices)
+    }
+}
+
+/// The corners of a panel in pixels as vertices
+fn panel_vertices((x, y, width, height): (f32, f32, f32, f32), window_size: [u32; 2]) -> (Vertex, Vertex) {
+    let (window_width, window_height) = (window_size[0] as f32, window_size[1] as f32);
+    (
+        Vertex {
+            x: (x * 2.0) / window_width,
+            y: (y * 2.0) / window_height,
+        },
+        Vertex {
+            x: ((x + width) * 2.0) / window_width,
+            y: ((y + height) * 2.0) / window_height,
+        },
+    )
+}
+
+impl Menu {
+    /// Open the menu with its top left corner at a position in pixels from the top left corner of
+    /// the window, moved away from the edges of the window, and give it the keyboard focus
+    pub fn open(&mut self, ui: &Ui, x: f32, y: f32) {
+        if self.open.is_none() {
+            self.return_focus = ui.focused();
+        }
+        self.open = Some((x, y));
+        self.levels = 1;
+        self.path.clear();
+        ui.set_focus(Some(self.id()));
+    }
+
+    /// Close the menu and its submenus, giving the keyboard focus back to the widget that had it
+    pub fn close(&mut self, ui: &Ui) {
+        if self.open.take().is_none() {
+            return;
+        }
+
+        self.levels = 1;
+        self.path.clear();
+        self.bar = None;
+        if ui.focused() == Some(self.id()) {
+            ui.set_focus(self.return_focus.take());
+        }
+    }
+
+    /// Retrieve whether the menu is open
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Retrieve the indices of the items highlighted in the menu and in each submenu open
+    pub fn highlighted(&self) -> Vec<usize> {
+        self.path.clone()
+    }
+
+    /// Retrieve the number of menus open, the menu itself and its submenus
+    pub fn levels(&self) -> usize {
+        if self.open.is_some() {
+            self.levels
+        } else {
+            0
+        }
+    }
+
+    /// Retrieve the items
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    /// Replace the items, closing the menu
+    pub fn set_items(&mut self, ui: &Ui, items: Vec<MenuItem>) {
+        self.close(ui);
+        self.items = items;
+    }
+
+    /// Retrieve whether an item is checked, this will be None if it shows no checkmark
+    pub fn checked(&self, id: &str) -> Option<bool> {
+        self.find(&self.items, id).and_then(|item| item.checked)
+    }
+
+    /// Check an item or not, making it show a checkmark
+    pub fn set_checked(&mut self, id: &str, checked: bool) {
+        if let Some(item) = MenuItem::find_mut(&mut self.items, id) {
+            item.checked = Some(checked);
+        }
+    }
+
+    /// Set whether an item can be picked
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        if let Some(item) = MenuItem::find_mut(&mut self.items, id) {
+            item.enabled = enabled;
+        }
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Retrieve the height in pixels of the items
+    pub fn item_height(&self) -> f32 {
+        self.item_height
+    }
+
+    /// Set the callback called with the id of an item whenever the user picks it
+    pub fn set_on_select<F>(&mut self, on_select: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+    }
+
+    /// Remember the menu bar that opened the menu, which the left and right keys move along
+    pub(crate) fn set_bar(&mut self, bar: Option<Id>) {
+        self.bar = bar;
+    }
+
+    fn handle_key(&mut self, ui: &Ui, key: VirtualKeyCode) -> Result<bool, Error> {
+        let level = self.levels - 1;
+        let current = self.path.get(level).cloned();
+        let len = self.items_at(level).len();
+
+        match key {
+            VirtualKeyCode::Down | VirtualKeyCode::Up | VirtualKeyCode::Home | VirtualKeyCode::End => {
+                let forward = (key == VirtualKeyCode::Down) | (key == VirtualKeyCode::Home);
+                let start = match (key, current) {
+                    (VirtualKeyCode::Home, _) | (VirtualKeyCode::Down, None) => len.saturating_sub(1),
+                    (VirtualKeyCode::End, _) | (VirtualKeyCode::Up, None) => 0,
+                    (_, val) => val.unwrap_or(0),
+                };
+                if let Some(index) = self.next_enabled(level, start, forward) {
+                    self.path.truncate(level);
+                    self.path.push(index);
+                }
+            }
+            VirtualKeyCode::Right | VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter | VirtualKeyCode::Space => {
+                let index = match current {
+                    Some(val) if self.items_at(level)[val].enabled() => Some(val),
+                    _ => None,
+                };
+                match index {
+                    Some(index) if !self.items_at(level)[index].items.is_empty() => {
+                        self.levels += 1;
+                        if let Some(first) = self.next_enabled(level + 1, self.items_at(level + 1).len() - 1, true) {
+                            self.path.push(first);
+                        }
+                    }
+                    Some(index) if key != VirtualKeyCode::Right => self.activate(ui, level, index),
+                    _ if key == VirtualKeyCode::Right => self.move_along_bar(ui, 1)?,
+                    _ => {}
+                }
+            }
+            VirtualKeyCode::Left | VirtualKeyCode::Escape => {
+                if level > 0 {
+                    self.levels -= 1;
+                    self.path.truncate(level);
+                } else if key == VirtualKeyCode::Left {
+                    self.move_along_bar(ui, -1)?;
+                } else {
+                    self.close(ui);
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Highlight an item under the cursor, opening its submenu if it has one
+    fn highlight(&mut self, level: usize, index: usize) {
+        self.path.truncate(level);
+        self.levels = level + 1;
+
+        let item = &self.items_at(level)[index];
+        let (enabled, submenu) = (item.enabled(), !item.items.is_empty());
+        if !enabled {
+            return;
+        }
+        self.path.push(index);
+        if submenu {
+            self.levels += 1;
+        }
+    }
+
+    /// Pick an item, toggling its checkmark, and close the menu
+    fn activate(&mut self, ui: &Ui, level: usize, index: usize) {
+        let item = &self.items_at(level)[index];
+        if !item.enabled() || !item.items.is_empty() {
+            return;
+        }
+
+        let id = item.id.clone();
+        if let Some(item) = MenuItem::find_mut(&mut self.items, &id) {
+            item.checked = item.checked.map(|checked| !checked);
+        }
+        self.close(ui);
+
+        if let Some(ref on_select) = self.on_select {
+            on_select(&id);
+        }
+    }
+
+    /// Close the menu and open the menu before or after it in the menu bar that opened it
+    fn move_along_bar(&mut self, ui: &Ui, delta: isize) -> Result<(), Error> {
+        let widget = match self.bar.as_ref().and_then(|bar| ui.widgets().get(bar)) {
+            None => {
+                return Ok(());
+            }
+            Some(val) => val,
+        };
+
+        let mut widget = widget.lock();
+        if let Some(bar) = widget.downcast_mut::<MenuBar>() {
+            if let Some(index) = bar.adjacent(&self.id, delta) {
+                self.close(ui);
+                bar.open_menu(ui, index)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Close the menu after a press outside of it, opening another menu of the menu bar that
+    /// opened it if the press is on its title
+    fn press_outside(&mut self, ui: &Ui, x: f32, y: f32) -> Result<(), Error> {
+        let bar = self.bar.clone();
+        self.close(ui);
+
+        let widget = match bar.as_ref().and_then(|bar| ui.widgets().get(bar)) {
+            None => {
+                return Ok(());
+            }
+            Some(val) => val,
+        };
+
+        let mut widget = widget.lock();
+        if let Some(bar) = widget.downcast_mut::<MenuBar>() {
+            match bar.title_at(ui, x, y)? {
+                Some(index) if bar.menus()[index] != self.id => bar.open_menu(ui, index)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// The first item that can be picked after an item, or before it, wrapping around
+    fn next_enabled(&self, level: usize, from: usize, forward: bool) -> Option<usize> {
+        let items = self.items_at(level);
+        let len = items.len();
+        (1..=len)
+            .map(|step| {
+                if forward {
+                    (from + step) % len
+                } else {
+                    (from + len - step) % len
+                }
+            })
+            .find(|&index| items[index].enabled())
+    }
+
+    fn find<'a>(&self, items: &'a [MenuItem], id: &str) -> Option<&'a MenuItem> {
+        for item in items {
+            if item.id == id {
+                return Some(item);
+            }
+            if let Some(val) = self.find(&item.items, id) {
+                return Some(val);
+            }
+        }
+        None
+    }
+
+    /// The items of the menu open at a level, the menu itself being at level 0
+    fn items_at(&self, level: usize) -> &[MenuItem] {
+        let mut items = &self.items[..];
+        for &index in &self.path[..level.min(self.path.len())] {
+            items = &items[index].items;
+        }
+        items
+    }
+
+    /// The size in pixels of the menu open at a level
+    fn panel_size(&self, level: usize) -> (f32, f32) {
+        let mut width: f32 = MIN_MENU_WIDTH;
+        let mut height = 2.0 * PADDING;
+        for item in self.items_at(level) {
+            if item.separator {
+                height += SEPARATOR;
+                continue;
+            }
+            height += self.item_height;
+            if let Some(ref font) = self.font {
+                let mut item_width = (2.0 * CHECK) + font.measure(&item.text, self.text_size).0;
+                if !item.shortcut.is_empty() {
+                    item_width += SHORTCUT_GAP + font.measure(&item.shortcut, self.text_size).0;
+                }
+                width = width.max(item_width);
+            }
+        }
+        (width, height)
+    }
+
+    /// The distance in pixels from the top of the menu open at a level to the top of an item
+    fn item_top(&self, level: usize, index: usize) -> f32 {
+        PADDING
+            + self.items_at(level)[..index]
+                .iter()
+                .map(|item| if item.separator { SEPARATOR } else { self.item_height })
+                .sum::<f32>()
+    }
+
+    /// The position and size in pixels of the menu and of each submenu open, a submenu opening at
+    /// the right of its item, or at its left when there is no room left at the right
+    fn panels(&self, ui: &Ui) -> Vec<(f32, f32, f32, f32)> {
+        let window_size = ui.window_size();
+        let (window_width, window_height) = (window_size[0] as f32, window_size[1] as f32);
+        let (x, y) = self.open.unwrap_or((0.0, 0.0));
+
+        let mut panels: Vec<(f32, f32, f32, f32)> = Vec::new();
+        for level in 0..self.levels {
+            let (width, height) = self.panel_size(level);
+            let (x, y) = match panels.last() {
+                None => (x.min(window_width - width), y),
+                Some(&(px, py, pwidth, _)) => {
+                    let y = py + self.item_top(level - 1, self.path[level - 1]) - PADDING;
+                    if px + pwidth + width > window_width {
+                        (px - width, y)
+                    } else {
+                        (px + pwidth, y)
+                    }
+                }
+            };
+            panels.push((x.max(0.0), y.min(window_height - height).max(0.0), width, height));
+        }
+        panels
+    }
+
+    /// The level of the menu under a position in pixels and the item under it, if any
+    fn item_at(&self, ui: &Ui, x: f32, y: f32) -> Option<(usize, Option<usize>)> {
+        let panels = self.panels(ui);
+        for (level, &(px, py, width, height)) in panels.iter().enumerate().rev() {
+            if (x < px) | (x >= px + width) | (y < py) | (y >= py + height) {
+                continue;
+            }
+
+            let mut top = py + PADDING;
+            for (index, item) in self.items_at(level).iter().enumerate() {
+                let bottom = top + if item.separator { SEPARATOR } else { self.item_height };
+                if (y >= top) & (y < bottom) {
+                    return Some((level, if item.separator { None } else { Some(index) }));
+                }
+                top = bottom;
+            }
+            return Some((level, None));
+        }
+        None
+    }
+}
+
+/// The builder for the Menu widget
+#[derive(Clone, Default)]
+pub struct MenuBuilder {
+    id:              Id,
+    color:           String,
+    text_color:      String,
+    highlight_color: String,
+    disabled_color:  String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    item_height:     Option<f32>,
+    items:           Vec<MenuItem>,
+    targets:         Vec<Id>,
+    on_select:       Option<MenuCallback>,
+    parent_id:       Id,
+    opacity:         f32,
+    z_index:         i32,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+    elevation:       Option<f32>,
+}
+
+impl MenuBuilder {
+    /// Initialize the builder for the Menu widget
+    pub fn new<V>(id: V, parent_id: V) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            parent_id: parent_id.into(),
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Menu widget from another Menu widget
+    pub fn new_from_menu(menu: &Menu) -> Self {
+        Self {
+            id:              menu.id(),
+            color:           menu.fill().into_string(),
+            text_color:      menu.text_color.into_hex(),
+            highlight_color: menu.highlight_color.into_hex(),
+            disabled_color:  menu.disabled_color.into_hex(),
+            font:            menu.font(),
+            text_size:       Some(menu.text_size()),
+            item_height:     Some(menu.item_height()),
+            items:           menu.items().to_vec(),
+            targets:         Vec::new(),
+            on_select:       menu.on_select.clone(),
+            parent_id:       menu.parent_id().unwrap(),
+            opacity:         menu.opacity(),
+            z_index:         menu.z_index(),
+            border_color:    menu.border().color().into_hex(),
+            border_width:    Some(menu.border().width()),
+            corner_radius:   Some(menu.corner_radius().as_array()),
+            elevation:       Some(menu.shadow().offset().1),
+        }
+    }
+
+    /// Set the color behind the items
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the text and of the checkmarks
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color behind the item highlighted
+    pub fn with_highlight_color<V: Into<String>>(&mut self, highlight_color: V) -> &mut Self {
+        self.highlight_color = highlight_color.into();
+        self
+    }
+
+    /// Set the color of the items that can't be picked
+    pub fn with_disabled_color<V: Into<String>>(&mut self, disabled_color: V) -> &mut Self {
+        self.disabled_color = disabled_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the height in pixels of the items, the size of the text and some padding by default
+    pub fn with_item_height(&mut self, item_height: f32) -> &mut Self {
+        self.item_height = Some(item_height);
+        self
+    }
+
+    /// Add an item after the others
+    pub fn with_item(&mut self, item: MenuItem) -> &mut Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Attach the menu to a widget, opening it when the right button is pressed over the widget
+    /// or its children and they don't handle it themselves
+    pub fn with_target<V: Into<Id>>(&mut self, target: V) -> &mut Self {
+        self.targets.push(target.into());
+        self
+    }
+
+    /// Set the callback called with the id of an item whenever the user picks it
+    pub fn with_on_select<F>(&mut self, on_select: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a menu is drawn over the other widgets of the overlay with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the Menu widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Menu>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.container_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = color(&self.text_color, theme.label_text_color())?;
+        let highlight_color = color(&self.highlight_color, theme.selection_color())?;
+        let disabled_color = color(&self.disabled_color, theme.disabled_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let item_height = self.item_height.unwrap_or(text_size + (2.0 * PADDING));
+        if item_height <= 0.0 {
+            return Err(err_msg("The item height must be greater than 0.0"));
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => theme.menu_shadow()?,
+            Some(val) => Shadow::from_elevation(val, theme.menu_shadow()?.color())?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Menu {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            fill,
+            text_color,
+            highlight_color,
+            disabled_color,
+            font,
+            text_size,
+            item_height,
+            items: self.items.clone(),
+            open: None,
+            levels: 1,
+            path: Vec::new(),
+            bar: None,
+            return_focus: None,
+            on_select: self.clone().on_select,
+            text: Arc::new(TextureCache::default()),
+            opacity: self.opacity,
+            z_index: self.z_index,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+        for target in &self.targets {
+            ui.set_context_menu(target.clone(), Some(self.id.clone()));
+        }
+
+        Ok(widget)
+    }
+}
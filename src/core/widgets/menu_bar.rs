@@ -0,0 +1,651 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    menu::Menu,
+    MIN_TAB_WIDTH,
+    TAB_PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The MenuBar Widget, a row of titles each opening a menu below it
+#[derive(Clone)]
+pub struct MenuBar {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    text_color:      Color,
+    highlight_color: Color,
+    focus_color:     Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    menus:           Vec<(Id, String)>,
+    current:         usize,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for MenuBar {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::MenuBar
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                let index = match self.title_at(ui, x, y)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                self.open_menu(ui, index)?;
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {}
+            InputEvent::KeyPressed { key, .. } if !self.menus.is_empty() => {
+                let len = self.menus.len();
+                match key {
+                    VirtualKeyCode::Left => self.current = (self.current + len - 1) % len,
+                    VirtualKeyCode::Right => self.current = (self.current + 1) % len,
+                    VirtualKeyCode::Home => self.current = 0,
+                    VirtualKeyCode::End => self.current = len - 1,
+                    VirtualKeyCode::Down
+                    | VirtualKeyCode::Return
+                    | VirtualKeyCode::NumpadEnter
+                    | VirtualKeyCode::Space => {
+                        self.open_menu(ui, self.current)?;
+                    }
+                    _ => {
+                        return Ok(false);
+                    }
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let height = ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0;
+        let mut painter = Painter::new(((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0, height);
+
+        let focused = ui.focused() == Some(self.id());
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+
+        let mut left = 0.0;
+        for (index, width) in self.title_widths().into_iter().enumerate() {
+            let (ref menu, ref title) = self.menus[index];
+            if self.menu_open(ui, menu) {
+                painter.rect((left, 0.0), (width, height), self.highlight_color);
+            }
+
+            if let Some(ref font) = self.font {
+                painter.text(
+                    (left + TAB_PADDING, (height - line_height) / 2.0),
+                    title,
+                    self.text_size,
+                    self.text_color,
+                    font,
+                );
+            }
+
+            if focused & (index == self.current) {
+                painter.stroke_rect((left + 0.5, 0.5), (width - 1.0, height - 1.0), 1.0, self.focus_color);
+            }
+
+            left += width;
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl MenuBar {
+    /// Retrieve the ids of the menus, in the order of their titles
+    pub fn menus(&self) -> Vec<Id> {
+        self.menus.iter().map(|menu| menu.0.clone()).collect()
+    }
+
+    /// Retrieve the title of a menu
+    pub fn title(&self, id: &str) -> Option<String> {
+        self.menus.iter().find(|menu| menu.0 == id).map(|menu| menu.1.clone())
+    }
+
+    /// Retrieve the id of the menu whose title the keyboard moves along the bar
+    pub fn current(&self) -> Option<Id> {
+        self.menus.get(self.current).map(|menu| menu.0.clone())
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Open the menu of a title below it, closing the menu instead if it is open
+    pub fn open_menu(&mut self, ui: &Ui, index: usize) -> Result<(), Error> {
+        let (left, top, _, height) = match self.area(ui)? {
+            None => {
+                return Ok(());
+            }
+            Some(val) => val,
+        };
+
+        let id = match self.menus.get(index) {
+            None => {
+                return Ok(());
+            }
+            Some(val) => val.0.clone(),
+        };
+        self.current = index;
+
+        let x = left + self.title_widths()[..index].iter().sum::<f32>();
+        if let Some(widget) = ui.widgets().get(&id) {
+            if let Some(menu) = widget.lock().downcast_mut::<Menu>() {
+                if menu.is_open() {
+                    menu.close(ui);
+                } else {
+                    menu.open(ui, x, top + height);
+                    menu.set_bar(Some(self.id()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The index of the menu before or after a menu of the bar, wrapping around, or None when it is
+    /// the menu itself
+    pub(crate) fn adjacent(&self, from: &str, delta: isize) -> Option<usize> {
+        let len = self.menus.len() as isize;
+        let index = self.menus.iter().position(|menu| menu.0 == from)? as isize;
+        let target = (((index + delta) % len) + len) % len;
+        if target == index {
+            None
+        } else {
+            Some(target as usize)
+        }
+    }
+
+    /// The index of the title at a position in pixels in the window
+    pub(crate) fn title_at(&self, ui: &Ui, x: f32, y: f32) -> Result<Option<usize>, Error> {
+        let (left, top, _, height) = match self.area(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        if (x < left) | (y < top) | (y >= top + height) {
+            return Ok(None);
+        }
+
+        let mut right = left;
+        for (index, width) in self.title_widths().into_iter().enumerate() {
+            right += width;
+            if x < right {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Whether a menu of the bar is open, the menu handling an event being locked is reported
+    /// closed
+    fn menu_open(&self, ui: &Ui, id: &str) -> bool {
+        match ui.widgets().get(id) {
+            None => false,
+            Some(widget) => match widget.try_lock() {
+                None => false,
+                Some(widget) => match widget.downcast_ref::<Menu>() {
+                    None => false,
+                    Some(menu) => menu.is_open(),
+                },
+            },
+        }
+    }
+
+    /// The width in pixels of each title
+    fn title_widths(&self) -> Vec<f32> {
+        self.menus
+            .iter()
+            .map(|menu| {
+                let width = match self.font {
+                    None => 0.0,
+                    Some(ref font) => font.measure(&menu.1, self.text_size).0,
+                };
+                (width + (2.0 * TAB_PADDING)).max(MIN_TAB_WIDTH)
+            })
+            .collect()
+    }
+
+    /// The position in pixels of the top left corner of the bar in the window, and its size
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+}
+
+/// The builder for the MenuBar widget
+#[derive(Clone, Default)]
+pub struct MenuBarBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    text_color:      String,
+    highlight_color: String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    menus:           Vec<(Id, String)>,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+    elevation:       Option<f32>,
+}
+
+impl MenuBarBuilder {
+    /// Initialize the builder for the MenuBar widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the MenuBar widget from another MenuBar widget
+    pub fn new_from_menu_bar(menu_bar: &MenuBar) -> Self {
+        Self {
+            id:              menu_bar.id(),
+            size:            menu_bar.size(),
+            position:        menu_bar.position(),
+            color:           menu_bar.fill().into_string(),
+            text_color:      menu_bar.text_color.into_hex(),
+            highlight_color: menu_bar.highlight_color.into_hex(),
+            font:            menu_bar.font(),
+            text_size:       Some(menu_bar.text_size()),
+            menus:           menu_bar.menus.clone(),
+            parent_id:       menu_bar.parent_id().unwrap(),
+            visible:         menu_bar.visible(),
+            opacity:         menu_bar.opacity(),
+            z_index:         menu_bar.z_index(),
+            layer:           menu_bar.layer(),
+            border_color:    menu_bar.border().color().into_hex(),
+            border_width:    Some(menu_bar.border().width()),
+            corner_radius:   Some(menu_bar.corner_radius().as_array()),
+            elevation:       Some(menu_bar.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the titles
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color behind the title of the menu open
+    pub fn with_highlight_color<V: Into<String>>(&mut self, highlight_color: V) -> &mut Self {
+        self.highlight_color = highlight_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Add a title after the others opening a Menu widget, built with its own builder
+    pub fn with_menu<V: Into<String>>(&mut self, id: V, title: V) -> &mut Self {
+        self.menus.push((id.into(), title.into()));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the MenuBar widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<MenuBar>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.bar_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = color(&self.text_color, theme.label_text_color())?;
+        let highlight_color = color(&self.highlight_color, theme.selection_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        for (index, (id, _)) in self.menus.iter().enumerate() {
+            if self.menus[..index].iter().any(|menu| menu.0 == *id) {
+                return Err(err_msg(format!("The menu {} was added twice", id)));
+            }
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, theme.shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(MenuBar {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color,
+            highlight_color,
+            focus_color: theme.checked_color()?,
+            font,
+            text_size,
+            menus: self.menus.clone(),
+            current: 0,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
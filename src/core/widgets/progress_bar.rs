@@ -0,0 +1,456 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+};
+use std::{
+    sync::Arc,
+    time::Instant,
+};
+
+/// The time in seconds the bar of an indeterminate progress bar takes to cross it
+const PERIOD: f32 = 1.5;
+/// The length of the bar of an indeterminate progress bar, as a fraction of the progress bar
+const SEGMENT: f32 = 0.3;
+
+/// The ProgressBar Widget, a bar filling up as a task progresses, or sweeping across while the
+/// progress is unknown. Horizontal progress bars fill from the left for the top and bottom
+/// orientations, vertical ones fill up from the bottom for the others.
+#[derive(Clone)]
+pub struct ProgressBar {
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    orientation:   Orientation,
+    bar_color:     Color,
+    progress:      f32,
+    indeterminate: bool,
+    started:       Instant,
+    text:          Arc<TextureCache>,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+}
+
+impl WidgetTrait for ProgressBar {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::ProgressBar
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        // The start and the end of the bar as fractions of the progress bar
+        let (start, end) = if self.indeterminate {
+            let elapsed = self.started.elapsed();
+            let seconds = elapsed.as_secs() as f32 + (elapsed.subsec_millis() as f32 / 1000.0);
+            let start = (((seconds % PERIOD) / PERIOD) * (1.0 + SEGMENT)) - SEGMENT;
+            (start.max(0.0), (start + SEGMENT).min(1.0))
+        } else {
+            (0.0, self.progress)
+        };
+
+        let (width, height) = painter.size();
+        if end <= start {
+            // Nothing to fill yet
+        } else if self.orientation.horizontal() {
+            painter.rect((start * width, 0.0), ((end - start) * width, height), self.bar_color);
+        } else {
+            // Vertical bars fill from the bottom up
+            painter.rect(
+                (0.0, (1.0 - end) * height),
+                (width, (end - start) * height),
+                self.bar_color,
+            );
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl ProgressBar {
+    /// Retrieve the progress, from 0.0 to 1.0
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Set the progress, from 0.0 to 1.0
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress = progress.max(0.0).min(1.0);
+    }
+
+    /// Whether the progress is unknown, a bar then sweeps across the progress bar
+    pub fn indeterminate(&self) -> bool {
+        self.indeterminate
+    }
+
+    /// Set whether the progress is unknown
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        if indeterminate & !self.indeterminate {
+            self.started = Instant::now();
+        }
+        self.indeterminate = indeterminate;
+    }
+
+    /// Retrieve the orientation
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+}
+
+/// The builder for the ProgressBar widget
+#[derive(Clone, Default)]
+pub struct ProgressBarBuilder {
+    id:            Id,
+    size:          Size,
+    position:      Position,
+    color:         String,
+    orientation:   Orientation,
+    bar_color:     String,
+    progress:      f32,
+    indeterminate: bool,
+    parent_id:     Id,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border_color:  String,
+    border_width:  Option<f32>,
+    corner_radius: Option<[f32; 4]>,
+    elevation:     Option<f32>,
+}
+
+impl ProgressBarBuilder {
+    /// Initialize the builder for the ProgressBar widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the ProgressBar widget from another ProgressBar widget
+    pub fn new_from_progress_bar(progress_bar: &ProgressBar) -> Self {
+        Self {
+            id:            progress_bar.id(),
+            size:          progress_bar.size(),
+            position:      progress_bar.position(),
+            color:         progress_bar.fill().into_string(),
+            orientation:   progress_bar.orientation(),
+            bar_color:     progress_bar.bar_color.into_hex(),
+            progress:      progress_bar.progress(),
+            indeterminate: progress_bar.indeterminate(),
+            parent_id:     progress_bar.parent_id().unwrap(),
+            visible:       progress_bar.visible(),
+            opacity:       progress_bar.opacity(),
+            z_index:       progress_bar.z_index(),
+            layer:         progress_bar.layer(),
+            border_color:  progress_bar.border().color().into_hex(),
+            border_width:  Some(progress_bar.border().width()),
+            corner_radius: Some(progress_bar.corner_radius().as_array()),
+            elevation:     Some(progress_bar.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill behind the bar, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the orientation, the progress bar is horizontal along the top or the bottom and
+    /// vertical along the left or the right
+    pub fn with_orientation(&mut self, orientation: Orientation) -> &mut Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the color of the bar
+    pub fn with_bar_color<V: Into<String>>(&mut self, bar_color: V) -> &mut Self {
+        self.bar_color = bar_color.into();
+        self
+    }
+
+    /// Set the progress, from 0.0 to 1.0
+    pub fn with_progress(&mut self, progress: f32) -> &mut Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Show that the progress is unknown, a bar then sweeps across the progress bar
+    pub fn with_indeterminate(&mut self, indeterminate: bool) -> &mut Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the ProgressBar widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<ProgressBar>, Error> {
+        let fill = if self.color.is_empty() {
+            Fill::parse(ui.theme().unchecked_color()?.into_hex())?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let bar_color = if self.bar_color.is_empty() {
+            ui.theme().checked_color()?
+        } else {
+            Color::from_hex(self.clone().bar_color)?
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, ui.theme().shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(ProgressBar {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            orientation: self.orientation,
+            bar_color,
+            progress: self.progress.max(0.0).min(1.0),
+            indeterminate: self.indeterminate,
+            started: Instant::now(),
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
@@ -0,0 +1,1042 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    checkbox::{
+        contains,
+        indicator_size,
+        CheckColors,
+        SPACING,
+    },
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The callback given the value of the chosen radio button
+pub type ChoiceCallback = Arc<Fn(&str) + Send + Sync>;
+
+/// The RadioGroup Widget, a container whose RadioButton children are exclusive choices
+#[derive(Clone)]
+pub struct RadioGroup {
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    selected:      Option<String>,
+    on_change:     Option<ChoiceCallback>,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+}
+
+impl WidgetTrait for RadioGroup {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::RadioGroup
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Visible
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+}
+
+impl RadioGroup {
+    /// Retrieve the value of the chosen radio button, the group in the ui is kept up to date
+    /// while the one returned by the builder is not
+    pub fn selected(&self) -> Option<String> {
+        self.selected.clone()
+    }
+
+    /// Choose the radio button of a value, or none of them, without calling the change callback
+    pub fn select(&mut self, ui: &Ui, value: Option<&str>) {
+        self.selected = None;
+
+        if let Some(children) = ui.heirarchy().get(&self.id) {
+            for child in children {
+                if let Some(widget) = ui.widgets().get(child) {
+                    if let Some(radio) = widget.lock().downcast_mut::<RadioButton>() {
+                        radio.checked = (value == Some(radio.value.as_str())) & self.selected.is_none();
+                        if radio.checked {
+                            self.selected = Some(radio.value());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Set the callback called with the value of the radio button chosen by the user
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+}
+
+/// The builder for the RadioGroup widget
+#[derive(Clone, Default)]
+pub struct RadioGroupBuilder {
+    id:            Id,
+    size:          Size,
+    position:      Position,
+    color:         String,
+    on_change:     Option<ChoiceCallback>,
+    parent_id:     Id,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border_color:  String,
+    border_width:  Option<f32>,
+    corner_radius: Option<[f32; 4]>,
+    elevation:     Option<f32>,
+}
+
+impl RadioGroupBuilder {
+    /// Initialize the builder for the RadioGroup widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the RadioGroup widget from another RadioGroup widget
+    pub fn new_from_radio_group(radio_group: &RadioGroup) -> Self {
+        Self {
+            id:            radio_group.id(),
+            size:          radio_group.size(),
+            position:      radio_group.position(),
+            color:         radio_group.fill().into_string(),
+            on_change:     radio_group.on_change.clone(),
+            parent_id:     radio_group.parent_id().unwrap(),
+            visible:       radio_group.visible(),
+            opacity:       radio_group.opacity(),
+            z_index:       radio_group.z_index(),
+            layer:         radio_group.layer(),
+            border_color:  radio_group.border().color().into_hex(),
+            border_width:  Some(radio_group.border().width()),
+            corner_radius: Some(radio_group.corner_radius().as_array()),
+            elevation:     Some(radio_group.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the callback called with the value of the radio button chosen by the user
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the RadioGroup widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<RadioGroup>, Error> {
+        let fill = if self.color.is_empty() {
+            ui.theme().check_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, ui.theme().shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(RadioGroup {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            selected: None,
+            on_change: self.clone().on_change,
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
+
+/// The RadioButton Widget, one of the exclusive choices of its parent RadioGroup
+#[derive(Clone)]
+pub struct RadioButton {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    text_color:      Color,
+    checked_color:   Color,
+    unchecked_color: Color,
+    disabled_color:  Color,
+    focus_color:     Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    label:           String,
+    value:           String,
+    checked:         bool,
+    enabled:         bool,
+    pressed:         bool,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for RadioButton {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::RadioButton
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        if !self.enabled {
+            return Ok(false);
+        }
+
+        match *event {
+            InputEvent::MousePressed {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.pressed = true;
+            }
+            InputEvent::MouseReleased {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                // The press is cancelled by releasing the button away from the radio button
+                if self.pressed & contains(ui, self.bounds(ui)?, x, y) {
+                    let id = self.id();
+                    self.choose(ui, &id);
+                }
+                self.pressed = false;
+            }
+            InputEvent::KeyPressed {
+                key: VirtualKeyCode::Space,
+                ..
+            } => {
+                let id = self.id();
+                self.choose(ui, &id);
+            }
+            // The arrows choose the previous or the next radio button of the group
+            InputEvent::KeyPressed { key, .. } => {
+                let step = match key {
+                    VirtualKeyCode::Up | VirtualKeyCode::Left => -1,
+                    VirtualKeyCode::Down | VirtualKeyCode::Right => 1,
+                    _ => {
+                        return Ok(false);
+                    }
+                };
+
+                let radios = self.radios(ui);
+                let index = radios.iter().position(|id| *id == self.id).unwrap_or(0) as isize;
+                let id = radios[(index + step).rem_euclid(radios.len() as isize) as usize].clone();
+                self.choose(ui, &id);
+                ui.set_focus(Some(id));
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let size = indicator_size(painter.size());
+        let radius = size / 2.0;
+        let center = (PADDING + radius, painter.size().1 / 2.0);
+
+        let color = match (self.enabled, self.checked) {
+            (false, _) => self.disabled_color,
+            (true, false) => self.unchecked_color,
+            (true, true) => self.checked_color,
+        };
+
+        painter.circle(center, radius, color);
+        if self.checked {
+            painter.circle(center, radius * 0.4, self.text_color);
+        }
+
+        if ui.focused() == Some(self.id()) {
+            painter.stroke_circle(center, radius + 2.0, 1.0, self.focus_color);
+        }
+
+        if let Some(ref font) = self.font {
+            let line_height = font.measure("", self.text_size).1;
+            painter.text(
+                (PADDING + size + SPACING, center.1 - (line_height / 2.0)),
+                &self.label,
+                self.text_size,
+                self.label_color(),
+                font,
+            );
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl RadioButton {
+    /// Retrieve the value given to the callback of the group when the radio button is chosen
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+
+    /// Whether the radio button is the chosen one of its group
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Retrieve the label
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    /// Set the label
+    pub fn set_label<V: Into<String>>(&mut self, label: V) {
+        self.label = label.into();
+    }
+
+    /// Whether the radio button can be focused and clicked
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable the radio button
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.pressed = false;
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Retrieve the ids of the enabled radio buttons sharing the parent of this one, this one
+    /// included, in the order they were added
+    fn radios(&self, ui: &Ui) -> Vec<Id> {
+        let siblings = match ui.heirarchy().get(&self.parent_id) {
+            None => {
+                return vec![self.id()];
+            }
+            Some(val) => val,
+        };
+
+        siblings
+            .iter()
+            .filter(|id| {
+                if **id == self.id {
+                    return true;
+                }
+                match ui.widgets().get(*id) {
+                    None => false,
+                    Some(widget) => match widget.lock().downcast_ref::<RadioButton>() {
+                        None => false,
+                        Some(radio) => radio.enabled & radio.visible,
+                    },
+                }
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Check the radio button of an id among the siblings of this one, unchecking the others, and
+    /// tell the group about the value chosen
+    fn choose(&mut self, ui: &Ui, id: &str) {
+        // The callback is called once the group is unlocked, it may look at the group again
+        if let Some((value, Some(on_change))) = self.check(ui, id) {
+            on_change(&value);
+        }
+    }
+
+    /// Check the radio button of an id like `choose` without calling the change callback,
+    /// retrieving the value chosen and the callback when the choice changed
+    fn check(&mut self, ui: &Ui, id: &str) -> Option<(String, Option<ChoiceCallback>)> {
+        if self.checked & (self.id == id) {
+            return None;
+        }
+
+        let mut value = None;
+        if self.id == id {
+            value = Some(self.value.clone());
+        }
+        self.checked = self.id == id;
+
+        if let Some(siblings) = ui.heirarchy().get(&self.parent_id) {
+            for sibling in siblings.iter().filter(|sibling| **sibling != self.id) {
+                if let Some(widget) = ui.widgets().get(sibling) {
+                    if let Some(radio) = widget.lock().downcast_mut::<RadioButton>() {
+                        radio.checked = radio.id == id;
+                        if radio.checked {
+                            value = Some(radio.value.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        let value = value?;
+        Some((value.clone(), set_selected(ui, &self.parent_id, Some(value))))
+    }
+
+    fn label_color(&self) -> Color {
+        if self.enabled {
+            self.text_color
+        } else {
+            self.disabled_color
+        }
+    }
+}
+
+/// Set the value chosen in the RadioGroup of an id, retrieving its change callback
+fn set_selected(ui: &Ui, group_id: &str, selected: Option<String>) -> Option<ChoiceCallback> {
+    let widget = ui.widgets().get(group_id)?;
+    let mut widget = widget.lock();
+    let group = widget.downcast_mut::<RadioGroup>()?;
+    group.selected = selected;
+    group.on_change.clone()
+}
+
+/// The builder for the RadioButton widget
+#[derive(Clone, Default)]
+pub struct RadioButtonBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    text_color:      String,
+    checked_color:   String,
+    unchecked_color: String,
+    disabled_color:  String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    label:           String,
+    value:           String,
+    checked:         bool,
+    disabled:        bool,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+    elevation:       Option<f32>,
+}
+
+impl RadioButtonBuilder {
+    /// Initialize the builder for the RadioButton widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the RadioButton widget from another RadioButton widget
+    pub fn new_from_radio_button(radio_button: &RadioButton) -> Self {
+        Self {
+            id:              radio_button.id(),
+            size:            radio_button.size(),
+            position:        radio_button.position(),
+            color:           radio_button.fill().into_string(),
+            text_color:      radio_button.text_color.into_hex(),
+            checked_color:   radio_button.checked_color.into_hex(),
+            unchecked_color: radio_button.unchecked_color.into_hex(),
+            disabled_color:  radio_button.disabled_color.into_hex(),
+            font:            radio_button.font(),
+            text_size:       Some(radio_button.text_size()),
+            label:           radio_button.label(),
+            value:           radio_button.value(),
+            checked:         radio_button.checked(),
+            disabled:        !radio_button.enabled(),
+            parent_id:       radio_button.parent_id().unwrap(),
+            visible:         radio_button.visible(),
+            opacity:         radio_button.opacity(),
+            z_index:         radio_button.z_index(),
+            layer:           radio_button.layer(),
+            border_color:    radio_button.border().color().into_hex(),
+            border_width:    Some(radio_button.border().width()),
+            corner_radius:   Some(radio_button.corner_radius().as_array()),
+            elevation:       Some(radio_button.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the label and the dot
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the circle while checked
+    pub fn with_checked_color<V: Into<String>>(&mut self, checked_color: V) -> &mut Self {
+        self.checked_color = checked_color.into();
+        self
+    }
+
+    /// Set the color of the circle while unchecked
+    pub fn with_unchecked_color<V: Into<String>>(&mut self, unchecked_color: V) -> &mut Self {
+        self.unchecked_color = unchecked_color.into();
+        self
+    }
+
+    /// Set the color of the circle and the label while disabled
+    pub fn with_disabled_color<V: Into<String>>(&mut self, disabled_color: V) -> &mut Self {
+        self.disabled_color = disabled_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the label drawn next to the circle
+    pub fn with_label<V: Into<String>>(&mut self, label: V) -> &mut Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set the value given to the callback of the group when the radio button is chosen, the
+    /// id of the radio button when not set
+    pub fn with_value<V: Into<String>>(&mut self, value: V) -> &mut Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Make the radio button the chosen one of its group
+    pub fn with_checked(&mut self, checked: bool) -> &mut Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Disable the radio button, it is then neither focused nor clicked
+    pub fn with_disabled(&mut self, disabled: bool) -> &mut Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the RadioButton widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<RadioButton>, Error> {
+        let colors = CheckColors::resolve(
+            ui,
+            &self.text_color,
+            &self.checked_color,
+            &self.unchecked_color,
+            &self.disabled_color,
+        )?;
+
+        let fill = if self.color.is_empty() {
+            ui.theme().check_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let font = match self.font {
+            None => ui.theme().font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let value = if self.value.is_empty() {
+            self.clone().id
+        } else {
+            self.clone().value
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, ui.theme().shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(RadioButton {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color: colors.text,
+            checked_color: colors.checked,
+            unchecked_color: colors.unchecked,
+            disabled_color: colors.disabled,
+            focus_color: colors.focus,
+            font,
+            text_size,
+            label: self.clone().label,
+            value,
+            checked: false,
+            enabled: !self.disabled,
+            pressed: false,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        if self.checked {
+            let id = widget.id();
+            if let Some(radio) = ui.widgets()[&id].lock().downcast_mut::<RadioButton>() {
+                radio.check(ui, &id);
+            }
+        }
+
+        Ok(widget)
+    }
+}
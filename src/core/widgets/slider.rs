@@ -0,0 +1,709 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    checkbox::{
+        CheckColors,
+        INDICATOR,
+    },
+    PADDING,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The thickness in pixels of the track
+const TRACK: f32 = 4.0;
+/// The number of steps moved by the page up and page down keys
+const PAGE: f32 = 10.0;
+/// The fraction of the range moved by the arrow keys when the slider has no step
+const KEY_FRACTION: f32 = 0.01;
+
+/// The callback given the value of a numeric widget
+pub type ValueCallback = Arc<Fn(f32) + Send + Sync>;
+
+/// The Slider Widget, a thumb dragged along a track to pick a value between a minimum and a
+/// maximum, horizontally for the top and bottom orientations and vertically for the others
+#[derive(Clone)]
+pub struct Slider {
+    id:             Id,
+    parent_id:      Id,
+    size:           Size,
+    position:       Position,
+    fill:           Fill,
+    orientation:    Orientation,
+    track_color:    Color,
+    value_color:    Color,
+    thumb_color:    Color,
+    disabled_color: Color,
+    focus_color:    Color,
+    min:            f32,
+    max:            f32,
+    step:           f32,
+    value:          f32,
+    enabled:        bool,
+    dragging:       bool,
+    on_change:      Option<ValueCallback>,
+    text:           Arc<TextureCache>,
+    visible:        bool,
+    opacity:        f32,
+    z_index:        i32,
+    layer:          Layer,
+    border:         Border,
+    corner_radius:  CornerRadius,
+    shadow:         Shadow,
+}
+
+impl WidgetTrait for Slider {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Slider
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        if !self.enabled {
+            return Ok(false);
+        }
+
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                // The thumb jumps under the cursor, then follows it
+                self.dragging = true;
+                let value = self.value_at(ui, x, y)?;
+                self.change(value);
+            }
+            InputEvent::CursorMoved { x, y } if self.dragging => {
+                let value = self.value_at(ui, x, y)?;
+                self.change(value);
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.dragging = false;
+            }
+            InputEvent::KeyPressed { key, .. } => {
+                let step = if self.step > 0.0 {
+                    self.step
+                } else {
+                    (self.max - self.min) * KEY_FRACTION
+                };
+
+                let value = match key {
+                    VirtualKeyCode::Right | VirtualKeyCode::Up => self.value + step,
+                    VirtualKeyCode::Left | VirtualKeyCode::Down => self.value - step,
+                    VirtualKeyCode::PageUp => self.value + (step * PAGE),
+                    VirtualKeyCode::PageDown => self.value - (step * PAGE),
+                    VirtualKeyCode::Home => self.min,
+                    VirtualKeyCode::End => self.max,
+                    _ => {
+                        return Ok(false);
+                    }
+                };
+                self.change(value);
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let (width, height) = painter.size();
+        let (start, length, radius) = self.track(painter.size());
+        let filled = length * self.fraction();
+
+        let (value_color, thumb_color) = if self.enabled {
+            (self.value_color, self.thumb_color)
+        } else {
+            (self.disabled_color, self.disabled_color)
+        };
+
+        // The vertical track fills up from its bottom
+        let thumb = if self.orientation.horizontal() {
+            let top = (height - TRACK) / 2.0;
+            painter.rect((start, top), (length, TRACK), self.track_color);
+            painter.rect((start, top), (filled, TRACK), value_color);
+            (start + filled, height / 2.0)
+        } else {
+            let (left, bottom) = ((width - TRACK) / 2.0, height - start);
+            painter.rect((left, start), (TRACK, length), self.track_color);
+            painter.rect((left, bottom - filled), (TRACK, filled), value_color);
+            (width / 2.0, bottom - filled)
+        };
+
+        painter.circle(thumb, radius, thumb_color);
+        if ui.focused() == Some(self.id()) {
+            painter.stroke_circle(thumb, radius + 2.0, 1.0, self.focus_color);
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Slider {
+    /// Retrieve the value
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Set the value, snapped to the step and kept between the minimum and the maximum, without
+    /// calling the change callback
+    pub fn set_value(&mut self, value: f32) {
+        self.value = self.snap(value);
+    }
+
+    /// Retrieve the minimum value
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Retrieve the maximum value
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Retrieve the step between the values, 0.0 when any value can be picked
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Retrieve the orientation
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Whether the slider can be focused and dragged
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable the slider
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.dragging = false;
+    }
+
+    /// Set the callback called with the value whenever the user changes it
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    fn change(&mut self, value: f32) {
+        let value = self.snap(value);
+        if value == self.value {
+            return;
+        }
+
+        self.value = value;
+        if let Some(ref on_change) = self.on_change {
+            on_change(value);
+        }
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        let value = value.max(self.min).min(self.max);
+        if self.step > 0.0 {
+            (self.min + (((value - self.min) / self.step).round() * self.step)).min(self.max)
+        } else {
+            value
+        }
+    }
+
+    /// The fraction of the track up to the value
+    fn fraction(&self) -> f32 {
+        (self.value - self.min) / (self.max - self.min)
+    }
+
+    /// The start and the length in pixels of the track along the slider, and the radius of the
+    /// thumb, for a slider of a size in pixels
+    fn track(&self, size: (f32, f32)) -> (f32, f32, f32) {
+        let (along, across) = if self.orientation.horizontal() {
+            size
+        } else {
+            (size.1, size.0)
+        };
+
+        let radius = INDICATOR.min(across - (2.0 * PADDING)).max(0.0) / 2.0;
+        let start = PADDING + radius;
+        (start, (along - (2.0 * start)).max(0.0), radius)
+    }
+
+    /// The value under a position in pixels in the window
+    fn value_at(&self, ui: &Ui, x: f32, y: f32) -> Result<f32, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(self.value);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        let (left, top) = (
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+        );
+        let size = (
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let (start, length, _) = self.track(size);
+        if length <= 0.0 {
+            return Ok(self.value);
+        }
+
+        let fraction = if self.orientation.horizontal() {
+            (x - left - start) / length
+        } else {
+            1.0 - ((y - top - start) / length)
+        };
+
+        Ok(self.min + (fraction.max(0.0).min(1.0) * (self.max - self.min)))
+    }
+}
+
+/// The builder for the Slider widget
+#[derive(Clone, Default)]
+pub struct SliderBuilder {
+    id:             Id,
+    size:           Size,
+    position:       Position,
+    color:          String,
+    orientation:    Orientation,
+    track_color:    String,
+    value_color:    String,
+    thumb_color:    String,
+    disabled_color: String,
+    min:            f32,
+    max:            f32,
+    step:           f32,
+    value:          f32,
+    disabled:       bool,
+    on_change:      Option<ValueCallback>,
+    parent_id:      Id,
+    visible:        bool,
+    opacity:        f32,
+    z_index:        i32,
+    layer:          Layer,
+    border_color:   String,
+    border_width:   Option<f32>,
+    corner_radius:  Option<[f32; 4]>,
+    elevation:      Option<f32>,
+}
+
+impl SliderBuilder {
+    /// Initialize the builder for the Slider widget, picking whole values from 0.0 to 100.0
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            max: 100.0,
+            step: 1.0,
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Slider widget from another Slider widget
+    pub fn new_from_slider(slider: &Slider) -> Self {
+        Self {
+            id:             slider.id(),
+            size:           slider.size(),
+            position:       slider.position(),
+            color:          slider.fill().into_string(),
+            orientation:    slider.orientation(),
+            track_color:    slider.track_color.into_hex(),
+            value_color:    slider.value_color.into_hex(),
+            thumb_color:    slider.thumb_color.into_hex(),
+            disabled_color: slider.disabled_color.into_hex(),
+            min:            slider.min(),
+            max:            slider.max(),
+            step:           slider.step(),
+            value:          slider.value(),
+            disabled:       !slider.enabled(),
+            on_change:      slider.on_change.clone(),
+            parent_id:      slider.parent_id().unwrap(),
+            visible:        slider.visible(),
+            opacity:        slider.opacity(),
+            z_index:        slider.z_index(),
+            layer:          slider.layer(),
+            border_color:   slider.border().color().into_hex(),
+            border_width:   Some(slider.border().width()),
+            corner_radius:  Some(slider.corner_radius().as_array()),
+            elevation:      Some(slider.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the orientation, the slider is horizontal along the top or the bottom and vertical
+    /// along the left or the right
+    pub fn with_orientation(&mut self, orientation: Orientation) -> &mut Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the color of the track
+    pub fn with_track_color<V: Into<String>>(&mut self, track_color: V) -> &mut Self {
+        self.track_color = track_color.into();
+        self
+    }
+
+    /// Set the color of the track up to the value
+    pub fn with_value_color<V: Into<String>>(&mut self, value_color: V) -> &mut Self {
+        self.value_color = value_color.into();
+        self
+    }
+
+    /// Set the color of the thumb
+    pub fn with_thumb_color<V: Into<String>>(&mut self, thumb_color: V) -> &mut Self {
+        self.thumb_color = thumb_color.into();
+        self
+    }
+
+    /// Set the color of the track and the thumb while disabled
+    pub fn with_disabled_color<V: Into<String>>(&mut self, disabled_color: V) -> &mut Self {
+        self.disabled_color = disabled_color.into();
+        self
+    }
+
+    /// Set the minimum and the maximum values
+    pub fn with_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Set the step between the values, 0.0 to pick any value
+    pub fn with_step(&mut self, step: f32) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the value
+    pub fn with_value(&mut self, value: f32) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Disable the slider, it is then neither focused nor dragged
+    pub fn with_disabled(&mut self, disabled: bool) -> &mut Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the callback called with the value whenever the user changes it
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the Slider widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Slider>, Error> {
+        let colors = CheckColors::resolve(
+            ui,
+            &self.thumb_color,
+            &self.value_color,
+            &self.track_color,
+            &self.disabled_color,
+        )?;
+
+        let fill = if self.color.is_empty() {
+            ui.theme().check_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        if self.max <= self.min {
+            return Err(err_msg("The maximum must be greater than the minimum"));
+        }
+
+        if self.step < 0.0 {
+            return Err(err_msg("The step must be 0.0 or greater"));
+        }
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, ui.theme().shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let mut widget = Box::new(Slider {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            orientation: self.orientation,
+            track_color: colors.unchecked,
+            value_color: colors.checked,
+            thumb_color: colors.text,
+            disabled_color: colors.disabled,
+            focus_color: colors.focus,
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            value: self.min,
+            enabled: !self.disabled,
+            dragging: false,
+            on_change: self.clone().on_change,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+        widget.set_value(self.value);
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
@@ -0,0 +1,815 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    text_input::char_index,
+    ValueCallback,
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The width in pixels of the increment and decrement buttons
+const BUTTON: f32 = 20.0;
+/// The number of steps moved by the page up and page down keys
+const PAGE: f32 = 10.0;
+
+/// The SpinBox Widget, a number typed in or stepped with its increment and decrement buttons or
+/// the arrow keys. Only the characters of a number are accepted while typing, the number is kept
+/// between the minimum and the maximum when enter is pressed.
+#[derive(Clone)]
+pub struct SpinBox {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    text_color:      Color,
+    selection_color: Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    edit:            TextEdit,
+    value:           f32,
+    min:             f32,
+    max:             f32,
+    step:            f32,
+    decimals:        usize,
+    on_change:       Option<ValueCallback>,
+    dragging:        bool,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for SpinBox {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::SpinBox
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                modifiers,
+            } => match self.button_at(ui, x, y)? {
+                Some(steps) => {
+                    self.step_by(steps);
+                }
+                None => {
+                    let position = self.position_at(ui, x)?;
+                    self.edit.set_caret(position, modifiers.shift);
+                    self.dragging = true;
+                }
+            },
+            InputEvent::CursorMoved { x, .. } if self.dragging => {
+                let position = self.position_at(ui, x)?;
+                self.edit.set_caret(position, true);
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.dragging = false;
+            }
+            InputEvent::Character(c) if !c.is_control() => {
+                let c = c.to_string();
+                if self.accepts(&self.replaced(&c)) {
+                    self.edit.insert(&c);
+                }
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                let command = modifiers.ctrl | modifiers.logo;
+                match key {
+                    VirtualKeyCode::Up => self.step_by(1.0),
+                    VirtualKeyCode::Down => self.step_by(-1.0),
+                    VirtualKeyCode::PageUp => self.step_by(PAGE),
+                    VirtualKeyCode::PageDown => self.step_by(-PAGE),
+                    VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => self.commit(),
+                    // Escape only goes to the spin box while the typed number isn't committed
+                    VirtualKeyCode::Escape if self.edit.text() != self.format(self.value) => {
+                        self.edit.set_text(self.format(self.value));
+                    }
+                    VirtualKeyCode::V if command => {
+                        let text = ui.clipboard().replace(|c: char| c.is_control(), "");
+                        if self.accepts(&self.replaced(&text)) {
+                            self.edit.paste(&text);
+                        }
+                    }
+                    _ => {
+                        if self.edit.handle_key(ui, key, modifiers, false).is_none() {
+                            return Ok(false);
+                        }
+                    }
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        // The buttons, split by a line from the text, each with an arrow
+        let (width, height) = painter.size();
+        let button = button_width(width);
+        let left = width - button;
+        let line_color = self.text_color.with_alpha(self.text_color.alpha() / 4);
+        painter.line((left, 0.0), (left, height), 1.0, line_color);
+        painter.line((left, height / 2.0), (width, height / 2.0), 1.0, line_color);
+
+        let arrow = (button.min(height / 2.0) / 4.0).max(1.0);
+        let center = left + (button / 2.0);
+        let (up, down) = (height / 4.0, (height * 3.0) / 4.0);
+        painter.polygon(
+            &[
+                (center - arrow, up + (arrow / 2.0)),
+                (center + arrow, up + (arrow / 2.0)),
+                (center, up - (arrow / 2.0)),
+            ],
+            self.text_color,
+        );
+        painter.polygon(
+            &[
+                (center - arrow, down - (arrow / 2.0)),
+                (center + arrow, down - (arrow / 2.0)),
+                (center, down + (arrow / 2.0)),
+            ],
+            self.text_color,
+        );
+
+        if let Some(ref font) = self.font {
+            let line_height = font.measure("", self.text_size).1;
+            let top = (height - line_height) / 2.0;
+            let text = self.edit.text();
+            let offsets = self.offsets(&text);
+            let focused = ui.focused() == Some(self.id());
+
+            if let (true, Some((start, end))) = (focused, self.edit.selection()) {
+                let start = offsets[char_index(&text, start)];
+                let end = offsets[char_index(&text, end)];
+                painter.rect((PADDING + start, top), (end - start, line_height), self.selection_color);
+            }
+
+            painter.text((PADDING, top), &text, self.text_size, self.text_color, font);
+
+            if focused {
+                let caret = offsets[char_index(&text, self.edit.caret())];
+                painter.rect((PADDING + caret, top), (1.0, line_height), self.text_color);
+            }
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl SpinBox {
+    /// Retrieve the value, the number typed in counts once enter is pressed
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Set the value, kept between the minimum and the maximum, without calling the change
+    /// callback
+    pub fn set_value(&mut self, value: f32) {
+        self.value = self.clamp(value);
+        self.edit.set_text(self.format(self.value));
+    }
+
+    /// Retrieve the minimum value
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Retrieve the maximum value
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Retrieve the step added or removed by the buttons and the arrow keys
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Retrieve the number of decimals shown and accepted
+    pub fn decimals(&self) -> usize {
+        self.decimals
+    }
+
+    /// Retrieve the text, the number being typed in
+    pub fn text(&self) -> String {
+        self.edit.text()
+    }
+
+    /// Retrieve the text being edited, along with the caret and the selection
+    pub fn edit(&self) -> &TextEdit {
+        &self.edit
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the value whenever the user changes it
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    /// Keep a value between the minimum and the maximum, rounded to the decimals
+    fn clamp(&self, value: f32) -> f32 {
+        let scale = 10f32.powi(self.decimals as i32);
+        // Adding 0.0 turns -0.0 into 0.0, which isn't shown with a sign
+        (((value.max(self.min).min(self.max)) * scale).round() / scale) + 0.0
+    }
+
+    fn format(&self, value: f32) -> String {
+        format!("{:.*}", self.decimals, value)
+    }
+
+    /// Set the value typed in, or restore the text of the value when it isn't a number
+    fn commit(&mut self) {
+        match self.edit.text().parse::<f32>() {
+            Err(_) => self.edit.set_text(self.format(self.value)),
+            Ok(val) => self.change(val),
+        }
+    }
+
+    /// Add a number of steps to the value typed in
+    fn step_by(&mut self, steps: f32) {
+        let value = self.edit.text().parse::<f32>().unwrap_or(self.value);
+        self.change(value + (steps * self.step));
+    }
+
+    fn change(&mut self, value: f32) {
+        let value = self.clamp(value);
+        let changed = value != self.value;
+
+        self.value = value;
+        self.edit.set_text(self.format(value));
+
+        if let (true, Some(on_change)) = (changed, self.on_change.as_ref()) {
+            on_change(value);
+        }
+    }
+
+    /// The text once the selection, or nothing at the caret, is replaced by some text
+    fn replaced(&self, text: &str) -> String {
+        let current = self.edit.text();
+        let (start, end) = self.edit.selection().unwrap_or((self.edit.caret(), self.edit.caret()));
+        format!("{}{}{}", &current[..start], text, &current[end..])
+    }
+
+    /// Whether a text is on the way to a number: an optional sign when the minimum is below 0.0,
+    /// digits, then a point followed by at most as many digits as the decimals
+    fn accepts(&self, text: &str) -> bool {
+        let text = if (self.min < 0.0) & text.starts_with('-') {
+            &text[1..]
+        } else {
+            text
+        };
+
+        let digits = |text: &str| text.chars().all(|c| c.is_ascii_digit());
+        let mut parts = text.splitn(2, '.');
+        let whole = parts.next().unwrap_or("");
+        match parts.next() {
+            None => digits(whole),
+            Some(fraction) => {
+                (self.decimals > 0) & digits(whole) & digits(fraction) & (fraction.len() <= self.decimals)
+            }
+        }
+    }
+
+    /// The distance in pixels from the start of a text to each character, followed by its width
+    fn offsets(&self, text: &str) -> Vec<f32> {
+        match self.font {
+            None => vec![0.0; text.chars().count() + 1],
+            Some(ref font) => font.offsets(text, self.text_size),
+        }
+    }
+
+    /// The left, top, width and height in pixels of the spin box in the window
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+
+    /// The steps added by the button under a position in pixels in the window, if any
+    fn button_at(&self, ui: &Ui, x: f32, y: f32) -> Result<Option<f32>, Error> {
+        let (left, top, width, height) = match self.area(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        if x - left < width - button_width(width) {
+            Ok(None)
+        } else if y - top < height / 2.0 {
+            Ok(Some(1.0))
+        } else {
+            Ok(Some(-1.0))
+        }
+    }
+
+    /// The position in the text closest to a horizontal position in pixels in the window
+    fn position_at(&self, ui: &Ui, x: f32) -> Result<usize, Error> {
+        let left = match self.area(ui)? {
+            None => {
+                return Ok(self.edit.caret());
+            }
+            Some((val, ..)) => val,
+        };
+
+        let x = x - left - PADDING;
+        let text = self.edit.text();
+        let index = self
+            .offsets(&text)
+            .iter()
+            .enumerate()
+            .min_by(|a, b| (a.1 - x).abs().partial_cmp(&(b.1 - x).abs()).unwrap())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        Ok(text
+            .char_indices()
+            .nth(index)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| text.len()))
+    }
+}
+
+/// The width in pixels of the buttons of a spin box of a width in pixels
+fn button_width(width: f32) -> f32 {
+    BUTTON.min(width / 2.0)
+}
+
+/// The builder for the SpinBox widget
+#[derive(Clone, Default)]
+pub struct SpinBoxBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    text_color:      String,
+    selection_color: String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    value:           f32,
+    min:             f32,
+    max:             f32,
+    step:            f32,
+    decimals:        usize,
+    on_change:       Option<ValueCallback>,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+    elevation:       Option<f32>,
+}
+
+impl SpinBoxBuilder {
+    /// Initialize the builder for the SpinBox widget, stepping whole values from 0.0 to 100.0
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            max: 100.0,
+            step: 1.0,
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the SpinBox widget from another SpinBox widget
+    pub fn new_from_spin_box(spin_box: &SpinBox) -> Self {
+        Self {
+            id:              spin_box.id(),
+            size:            spin_box.size(),
+            position:        spin_box.position(),
+            color:           spin_box.fill().into_string(),
+            text_color:      spin_box.text_color.into_hex(),
+            selection_color: spin_box.selection_color.into_hex(),
+            font:            spin_box.font(),
+            text_size:       Some(spin_box.text_size()),
+            value:           spin_box.value(),
+            min:             spin_box.min(),
+            max:             spin_box.max(),
+            step:            spin_box.step(),
+            decimals:        spin_box.decimals(),
+            on_change:       spin_box.on_change.clone(),
+            parent_id:       spin_box.parent_id().unwrap(),
+            visible:         spin_box.visible(),
+            opacity:         spin_box.opacity(),
+            z_index:         spin_box.z_index(),
+            layer:           spin_box.layer(),
+            border_color:    spin_box.border().color().into_hex(),
+            border_width:    Some(spin_box.border().width()),
+            corner_radius:   Some(spin_box.corner_radius().as_array()),
+            elevation:       Some(spin_box.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the text and the arrows of the buttons
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color behind the selected text
+    pub fn with_selection_color<V: Into<String>>(&mut self, selection_color: V) -> &mut Self {
+        self.selection_color = selection_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the value
+    pub fn with_value(&mut self, value: f32) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the minimum and the maximum values
+    pub fn with_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Set the step added or removed by the buttons and the arrow keys
+    pub fn with_step(&mut self, step: f32) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the number of decimals shown and accepted
+    pub fn with_decimals(&mut self, decimals: usize) -> &mut Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Set the callback called with the value whenever the user changes it
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the SpinBox widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<SpinBox>, Error> {
+        let fill = if self.color.is_empty() {
+            ui.theme().text_input_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = if self.text_color.is_empty() {
+            ui.theme().text_input_text_color()?
+        } else {
+            Color::from_hex(self.clone().text_color)?
+        };
+
+        let selection_color = if self.selection_color.is_empty() {
+            ui.theme().selection_color()?
+        } else {
+            Color::from_hex(self.clone().selection_color)?
+        };
+
+        let font = match self.font {
+            None => ui.theme().font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        if self.max <= self.min {
+            return Err(err_msg("The maximum must be greater than the minimum"));
+        }
+
+        if self.step <= 0.0 {
+            return Err(err_msg("The step must be greater than 0.0"));
+        }
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, ui.theme().shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let mut widget = Box::new(SpinBox {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color,
+            selection_color,
+            font,
+            text_size,
+            edit: TextEdit::default(),
+            value: self.min,
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            decimals: self.decimals,
+            on_change: self.clone().on_change,
+            dragging: false,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+        widget.set_value(self.value);
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
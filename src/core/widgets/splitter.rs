@@ -0,0 +1,1043 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::list_view::DOUBLE_CLICK;
+use crate::{
+    prelude::*,
+    window::MouseButton,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The thickness in pixels of the dividers
+const DIVIDER: f32 = 6.0;
+
+/// The callback given the ratios of the panes of a splitter
+pub type RatiosCallback = Arc<Fn(&[f32]) + Send + Sync>;
+
+/// A pane of the splitter, the id of a child widget, its share of the space and the limits of its
+/// size in pixels
+#[derive(Clone)]
+struct Pane {
+    id:          Id,
+    ratio:       f32,
+    min:         f32,
+    max:         Option<f32>,
+    collapsible: bool,
+    collapsed:   bool,
+}
+
+/// A divider dragged along the splitter
+#[derive(Clone, Copy)]
+struct Drag {
+    divider: usize,
+    grab:    f32,
+}
+
+/// The Splitter Widget, dividing its space between two or more panes with dividers dragged to
+/// resize them. The panes are laid out along the orientation, from left to right for the top and
+/// bottom orientations and from top to bottom for the others. Double clicking a divider collapses
+/// the pane before it, or the pane after it when the one before cannot be collapsed, and restores
+/// it when double clicked again. The panes are children of the splitter added as panes, every
+/// other child is hidden.
+#[derive(Clone)]
+pub struct Splitter {
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    orientation:   Orientation,
+    divider_color: Color,
+    drag_color:    Color,
+    divider_width: f32,
+    panes:         Vec<Pane>,
+    drag:          Option<Drag>,
+    last_click:    Option<(usize, Instant)>,
+    on_change:     Option<RatiosCallback>,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+}
+
+impl WidgetTrait for Splitter {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Splitter
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                let (start, length) = match self.span(ui)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                let offset = self.along(x, y) - start;
+                let lengths = self.lengths(length);
+                let divider = match self.divider_at(&lengths, offset) {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+
+                let double = match self.last_click {
+                    Some((last, time)) => (last == divider) & (time.elapsed() < Duration::from_millis(DOUBLE_CLICK)),
+                    None => false,
+                };
+                if double {
+                    self.last_click = None;
+                    self.drag = None;
+                    self.toggle(divider);
+                    self.changed();
+                } else {
+                    self.last_click = Some((divider, Instant::now()));
+                    self.drag = Some(Drag {
+                        divider,
+                        grab: offset - self.divider_start(&lengths, divider),
+                    });
+                }
+            }
+            InputEvent::CursorMoved { x, y } => {
+                let drag = match self.drag {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                let (start, length) = match self.span(ui)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+
+                let offset = self.along(x, y) - start - drag.grab;
+                if self.move_divider(length, drag.divider, offset) {
+                    self.last_click = None;
+                    self.changed();
+                }
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                if self.drag.take().is_none() {
+                    return Ok(false);
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn child_area(&self, ui: &Ui, child: &Id, bl_vertex: Vertex, tr_vertex: Vertex) -> Option<(Vertex, Vertex)> {
+        let index = self.index_of(child)?;
+        if self.panes[index].collapsed {
+            return None;
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            return None;
+        }
+
+        // The pane is laid out after the panes and dividers before it
+        let horizontal = self.orientation.horizontal();
+        let scale = if horizontal {
+            window_size[0] as f32 / 2.0
+        } else {
+            window_size[1] as f32 / 2.0
+        };
+        let (from, to) = if horizontal {
+            (bl_vertex.x(), tr_vertex.x())
+        } else {
+            (bl_vertex.y(), tr_vertex.y())
+        };
+
+        let lengths = self.lengths((to - from) * scale);
+        let start = from + (self.pane_start(&lengths, index) / scale);
+        let end = (start + (lengths[index] / scale)).min(to);
+
+        if horizontal {
+            Some((
+                Vertex {
+                    x: start,
+                    y: bl_vertex.y(),
+                },
+                Vertex {
+                    x: end,
+                    y: tr_vertex.y(),
+                },
+            ))
+        } else {
+            Some((
+                Vertex {
+                    x: bl_vertex.x(),
+                    y: start,
+                },
+                Vertex {
+                    x: tr_vertex.x(),
+                    y: end,
+                },
+            ))
+        }
+    }
+
+    fn places_child(&self, child: &Id) -> bool {
+        self.index_of(child).is_some()
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let (width, height) = painter.size();
+        let horizontal = self.orientation.horizontal();
+        let lengths = self.lengths(if horizontal { width } else { height });
+        let dragged = self.drag.map(|drag| drag.divider);
+        for divider in 0..lengths.len().saturating_sub(1) {
+            let start = self.divider_start(&lengths, divider);
+            let color = if dragged == Some(divider) {
+                self.drag_color
+            } else {
+                self.divider_color
+            };
+            if horizontal {
+                painter.rect((start, 0.0), (self.divider_width, height), color);
+            } else {
+                painter.rect((0.0, start), (width, self.divider_width), color);
+            }
+        }
+
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, None, shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Splitter {
+    /// Retrieve the ids of the panes, in the order they are laid out
+    pub fn panes(&self) -> Vec<Id> {
+        self.panes.iter().map(|pane| pane.id.clone()).collect()
+    }
+
+    /// Add a pane after the others for a child widget, sharing the space with the others as much
+    /// as the average pane. Adding a pane again does nothing.
+    pub fn add_pane(&mut self, id: &str) {
+        if self.index_of(id).is_some() {
+            return;
+        }
+
+        let ratio = if self.panes.is_empty() {
+            1.0
+        } else {
+            self.panes.iter().map(|pane| pane.ratio).sum::<f32>() / self.panes.len() as f32
+        };
+        self.panes.push(Pane {
+            id: String::from(id),
+            ratio,
+            min: 0.0,
+            max: None,
+            collapsible: true,
+            collapsed: false,
+        });
+        self.normalize();
+    }
+
+    /// Remove a pane, hiding its child widget and giving its space to the other panes
+    pub fn remove_pane(&mut self, id: &str) {
+        if let Some(index) = self.index_of(id) {
+            self.panes.remove(index);
+            self.drag = None;
+            self.last_click = None;
+            self.normalize();
+        }
+    }
+
+    /// Retrieve the share of the space of each pane, from 0.0 to 1.0 and adding up to 1.0, with
+    /// 0.0 for the collapsed panes. The sizes in pixels of the panes also follow their limits.
+    pub fn ratios(&self) -> Vec<f32> {
+        let total: f32 = self
+            .panes
+            .iter()
+            .filter(|pane| !pane.collapsed)
+            .map(|pane| pane.ratio)
+            .sum();
+        self.panes
+            .iter()
+            .map(|pane| {
+                if pane.collapsed | (total <= 0.0) {
+                    0.0
+                } else {
+                    pane.ratio / total
+                }
+            })
+            .collect()
+    }
+
+    /// Set the share of the space of each pane, scaled to add up to 1.0, restoring the collapsed
+    /// panes, without calling the change callback. The ratios are ignored unless there is one for
+    /// every pane, none of them negative and some of them positive.
+    pub fn set_ratios(&mut self, ratios: &[f32]) {
+        if (ratios.len() != self.panes.len())
+            | ratios.iter().any(|ratio| *ratio < 0.0)
+            | (ratios.iter().sum::<f32>() <= 0.0)
+        {
+            return;
+        }
+
+        for (pane, ratio) in self.panes.iter_mut().zip(ratios) {
+            pane.ratio = *ratio;
+            pane.collapsed = false;
+        }
+        self.normalize();
+    }
+
+    /// Retrieve the size in pixels of each pane, as laid out in the splitter
+    pub fn pane_sizes(&self, ui: &Ui) -> Result<Vec<f32>, Error> {
+        Ok(match self.span(ui)? {
+            None => vec![0.0; self.panes.len()],
+            Some((_, length)) => self.lengths(length),
+        })
+    }
+
+    /// Retrieve the smallest size in pixels of a pane
+    pub fn min_size(&self, id: &str) -> Option<f32> {
+        self.index_of(id).map(|index| self.panes[index].min)
+    }
+
+    /// Set the smallest size in pixels of a pane
+    pub fn set_min_size(&mut self, id: &str, min: f32) {
+        if let Some(index) = self.index_of(id) {
+            self.panes[index].min = min.max(0.0);
+        }
+    }
+
+    /// Retrieve the largest size in pixels of a pane, if it is limited
+    pub fn max_size(&self, id: &str) -> Option<f32> {
+        self.index_of(id).and_then(|index| self.panes[index].max)
+    }
+
+    /// Set the largest size in pixels of a pane, or lift the limit
+    pub fn set_max_size(&mut self, id: &str, max: Option<f32>) {
+        if let Some(index) = self.index_of(id) {
+            self.panes[index].max = max.map(|max| max.max(0.0));
+        }
+    }
+
+    /// Retrieve whether a pane collapses when a divider next to it is double clicked
+    pub fn collapsible(&self, id: &str) -> bool {
+        self.index_of(id)
+            .map(|index| self.panes[index].collapsible)
+            .unwrap_or(false)
+    }
+
+    /// Set whether a pane collapses when a divider next to it is double clicked
+    pub fn set_collapsible(&mut self, id: &str, collapsible: bool) {
+        if let Some(index) = self.index_of(id) {
+            self.panes[index].collapsible = collapsible;
+        }
+    }
+
+    /// Retrieve whether a pane is collapsed
+    pub fn collapsed(&self, id: &str) -> bool {
+        self.index_of(id)
+            .map(|index| self.panes[index].collapsed)
+            .unwrap_or(false)
+    }
+
+    /// Collapse a pane, hiding it and giving its space to the other panes until it is expanded,
+    /// without calling the change callback
+    pub fn collapse(&mut self, id: &str) {
+        if let Some(index) = self.index_of(id) {
+            self.panes[index].collapsed = true;
+        }
+    }
+
+    /// Expand a collapsed pane back to the share of the space it had, without calling the change
+    /// callback
+    pub fn expand(&mut self, id: &str) {
+        if let Some(index) = self.index_of(id) {
+            self.panes[index].collapsed = false;
+        }
+    }
+
+    /// Retrieve the orientation
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Retrieve the thickness in pixels of the dividers
+    pub fn divider_width(&self) -> f32 {
+        self.divider_width
+    }
+
+    /// Set the callback called with the ratios of the panes whenever the user drags a divider or
+    /// collapses or expands a pane
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(&[f32]) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    fn index_of(&self, id: &str) -> Option<usize> {
+        self.panes.iter().position(|pane| pane.id == id)
+    }
+
+    fn changed(&self) {
+        if let Some(ref on_change) = self.on_change {
+            on_change(&self.ratios());
+        }
+    }
+
+    /// Scale the ratios of the panes shown to add up to 1.0, the collapsed panes keep theirs
+    fn normalize(&mut self) {
+        let total: f32 = self
+            .panes
+            .iter()
+            .filter(|pane| !pane.collapsed)
+            .map(|pane| pane.ratio)
+            .sum();
+        if total > 0.0 {
+            for pane in self.panes.iter_mut().filter(|pane| !pane.collapsed) {
+                pane.ratio /= total;
+            }
+        }
+    }
+
+    /// The sizes in pixels of the panes sharing a length, following their ratios as far as their
+    /// limits allow
+    fn lengths(&self, length: f32) -> Vec<f32> {
+        let count = self.panes.len();
+        let total = (length - (self.divider_width * count.saturating_sub(1) as f32)).max(0.0);
+        let mut lengths = vec![0.0; count];
+        let mut fixed: Vec<bool> = self.panes.iter().map(|pane| pane.collapsed).collect();
+
+        // The panes outside of their limits are fixed at the limit, and the rest of the length is
+        // shared again between the others until they all fit
+        for _ in 0..=count {
+            let free: Vec<usize> = (0..count).filter(|index| !fixed[*index]).collect();
+            if free.is_empty() {
+                break;
+            }
+
+            let left = (total
+                - (0..count)
+                    .filter(|index| fixed[*index])
+                    .map(|index| lengths[index])
+                    .sum::<f32>())
+            .max(0.0);
+            let weight: f32 = free.iter().map(|index| self.panes[*index].ratio).sum();
+            let mut clamped = false;
+            for &index in &free {
+                let pane = &self.panes[index];
+                let share = if weight > 0.0 {
+                    (left * pane.ratio) / weight
+                } else {
+                    left / free.len() as f32
+                };
+                let limited = share.max(pane.min).min(pane.max.unwrap_or(share).max(pane.min));
+                lengths[index] = limited;
+                if (limited - share).abs() > 1e-3 {
+                    fixed[index] = true;
+                    clamped = true;
+                }
+            }
+            if !clamped {
+                break;
+            }
+        }
+
+        lengths
+    }
+
+    /// The offset in pixels of a pane from the start of the splitter
+    fn pane_start(&self, lengths: &[f32], index: usize) -> f32 {
+        lengths[..index].iter().sum::<f32>() + (self.divider_width * index as f32)
+    }
+
+    /// The offset in pixels of a divider from the start of the splitter
+    fn divider_start(&self, lengths: &[f32], divider: usize) -> f32 {
+        self.pane_start(lengths, divider) + lengths[divider]
+    }
+
+    /// The divider at an offset in pixels from the start of the splitter
+    fn divider_at(&self, lengths: &[f32], offset: f32) -> Option<usize> {
+        (0..lengths.len().saturating_sub(1)).find(|divider| {
+            let start = self.divider_start(lengths, *divider);
+            (offset >= start) & (offset < start + self.divider_width)
+        })
+    }
+
+    /// Move a divider to an offset in pixels from the start of the splitter, resizing the panes on
+    /// either side of it within their limits. Returns whether the ratios changed.
+    fn move_divider(&mut self, length: f32, divider: usize, offset: f32) -> bool {
+        let mut lengths = self.lengths(length);
+        let (before, after) = (divider, divider + 1);
+        let pair = lengths[before] + lengths[after];
+        let limit = |pane: &Pane| pane.max.unwrap_or(pair);
+
+        let low = self.panes[before].min.max(pair - limit(&self.panes[after]));
+        let high = limit(&self.panes[before]).min(pair - self.panes[after].min);
+        let size = (offset - self.pane_start(&lengths, before))
+            .max(low)
+            .min(high)
+            .max(0.0)
+            .min(pair);
+
+        if ((size - lengths[before]).abs() < 1e-3) & !self.panes[before].collapsed & !self.panes[after].collapsed {
+            return false;
+        }
+
+        // Dragging a divider restores the collapsed panes next to it
+        lengths[before] = size;
+        lengths[after] = pair - size;
+        self.panes[before].collapsed = false;
+        self.panes[after].collapsed = false;
+
+        let total: f32 = self
+            .panes
+            .iter()
+            .zip(&lengths)
+            .filter(|(pane, _)| !pane.collapsed)
+            .map(|(_, length)| length)
+            .sum();
+        if total > 0.0 {
+            for (pane, length) in self.panes.iter_mut().zip(&lengths) {
+                if !pane.collapsed {
+                    pane.ratio = length / total;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Collapse or expand a pane next to a double clicked divider
+    fn toggle(&mut self, divider: usize) {
+        let (before, after) = (divider, divider + 1);
+        if self.panes[before].collapsed {
+            self.panes[before].collapsed = false;
+        } else if self.panes[after].collapsed {
+            self.panes[after].collapsed = false;
+        } else if self.panes[before].collapsible {
+            self.panes[before].collapsed = true;
+        } else if self.panes[after].collapsible {
+            self.panes[after].collapsed = true;
+        }
+    }
+
+    /// The offset of a position in pixels along the orientation
+    fn along(&self, x: f32, y: f32) -> f32 {
+        if self.orientation.horizontal() {
+            x
+        } else {
+            y
+        }
+    }
+
+    /// The start and length in pixels of the splitter along the orientation
+    fn span(&self, ui: &Ui) -> Result<Option<(f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some(if self.orientation.horizontal() {
+            let scale = window_size[0] as f32 / 2.0;
+            (bl_vertex.x() * scale, (tr_vertex.x() - bl_vertex.x()) * scale)
+        } else {
+            let scale = window_size[1] as f32 / 2.0;
+            (bl_vertex.y() * scale, (tr_vertex.y() - bl_vertex.y()) * scale)
+        }))
+    }
+}
+
+/// The limits of the size of a pane given to the builder
+#[derive(Clone, Copy)]
+struct Limits {
+    min:         Option<f32>,
+    max:         Option<f32>,
+    collapsible: Option<bool>,
+}
+
+/// The builder for the Splitter widget
+#[derive(Clone, Default)]
+pub struct SplitterBuilder {
+    id:            Id,
+    size:          Size,
+    position:      Position,
+    color:         String,
+    orientation:   Orientation,
+    divider_color: String,
+    drag_color:    String,
+    divider_width: Option<f32>,
+    panes:         Vec<Id>,
+    ratios:        Option<Vec<f32>>,
+    limits:        HashMap<Id, Limits>,
+    on_change:     Option<RatiosCallback>,
+    parent_id:     Id,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    layer:         Layer,
+    border_color:  String,
+    border_width:  Option<f32>,
+    corner_radius: Option<[f32; 4]>,
+}
+
+impl SplitterBuilder {
+    /// Initialize the builder for the Splitter widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Splitter widget from another Splitter widget
+    pub fn new_from_splitter(splitter: &Splitter) -> Self {
+        Self {
+            id:            splitter.id(),
+            size:          splitter.size(),
+            position:      splitter.position(),
+            color:         splitter.fill().into_string(),
+            orientation:   splitter.orientation(),
+            divider_color: splitter.divider_color.into_hex(),
+            drag_color:    splitter.drag_color.into_hex(),
+            divider_width: Some(splitter.divider_width()),
+            panes:         splitter.panes(),
+            ratios:        Some(splitter.panes.iter().map(|pane| pane.ratio).collect()),
+            limits:        splitter
+                .panes
+                .iter()
+                .map(|pane| {
+                    (
+                        pane.id.clone(),
+                        Limits {
+                            min:         Some(pane.min),
+                            max:         pane.max,
+                            collapsible: Some(pane.collapsible),
+                        },
+                    )
+                })
+                .collect(),
+            on_change:     splitter.on_change.clone(),
+            parent_id:     splitter.parent_id().unwrap(),
+            visible:       splitter.visible(),
+            opacity:       splitter.opacity(),
+            z_index:       splitter.z_index(),
+            layer:         splitter.layer(),
+            border_color:  splitter.border().color().into_hex(),
+            border_width:  Some(splitter.border().width()),
+            corner_radius: Some(splitter.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill behind the panes, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the orientation, the panes run from left to right for the top and bottom orientations
+    /// and from top to bottom for the others
+    pub fn with_orientation(&mut self, orientation: Orientation) -> &mut Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the color of the dividers
+    pub fn with_divider_color<V: Into<String>>(&mut self, divider_color: V) -> &mut Self {
+        self.divider_color = divider_color.into();
+        self
+    }
+
+    /// Set the color of the divider dragged
+    pub fn with_drag_color<V: Into<String>>(&mut self, drag_color: V) -> &mut Self {
+        self.drag_color = drag_color.into();
+        self
+    }
+
+    /// Set the thickness in pixels of the dividers
+    pub fn with_divider_width(&mut self, divider_width: f32) -> &mut Self {
+        self.divider_width = Some(divider_width);
+        self
+    }
+
+    /// Add a pane after the others for a child widget, the panes share the space equally unless
+    /// given ratios
+    pub fn with_pane<V: Into<Id>>(&mut self, id: V) -> &mut Self {
+        self.panes.push(id.into());
+        self
+    }
+
+    /// Set the share of the space of each pane, scaled to add up to 1.0
+    pub fn with_ratios<I>(&mut self, ratios: I) -> &mut Self
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        self.ratios = Some(ratios.into_iter().collect());
+        self
+    }
+
+    /// Set the smallest size in pixels of a pane
+    pub fn with_min_size<V: Into<Id>>(&mut self, id: V, min: f32) -> &mut Self {
+        self.limits_of(id.into()).min = Some(min);
+        self
+    }
+
+    /// Set the largest size in pixels of a pane
+    pub fn with_max_size<V: Into<Id>>(&mut self, id: V, max: f32) -> &mut Self {
+        self.limits_of(id.into()).max = Some(max);
+        self
+    }
+
+    /// Set whether a pane collapses when a divider next to it is double clicked, every pane does
+    /// by default
+    pub fn with_collapsible<V: Into<Id>>(&mut self, id: V, collapsible: bool) -> &mut Self {
+        self.limits_of(id.into()).collapsible = Some(collapsible);
+        self
+    }
+
+    /// Set the callback called with the ratios of the panes whenever the user drags a divider or
+    /// collapses or expands a pane
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(&[f32]) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    fn limits_of(&mut self, id: Id) -> &mut Limits {
+        self.limits.entry(id).or_insert(Limits {
+            min:         None,
+            max:         None,
+            collapsible: None,
+        })
+    }
+
+    /// Build the Splitter widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Splitter>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.container_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let divider_color = color(&self.divider_color, theme.bar_color())?;
+        let drag_color = color(&self.drag_color, theme.checked_color())?;
+
+        let divider_width = self.divider_width.unwrap_or(DIVIDER);
+        if divider_width <= 0.0 {
+            return Err(err_msg("The divider width must be greater than 0.0"));
+        }
+
+        let ratios = match self.ratios {
+            None => vec![1.0; self.panes.len()],
+            Some(ref val) if val.len() != self.panes.len() => {
+                return Err(err_msg("There must be a ratio for every pane"));
+            }
+            Some(ref val) if val.iter().any(|ratio| *ratio < 0.0) | (val.iter().sum::<f32>() <= 0.0) => {
+                return Err(err_msg("The ratios must not be negative and add up to more than 0.0"));
+            }
+            Some(ref val) => val.clone(),
+        };
+
+        for id in self.limits.keys() {
+            if !self.panes.contains(id) {
+                return Err(err_msg(format!("The limits of {} are given but it is not a pane", id)));
+            }
+        }
+
+        let mut panes: Vec<Pane> = Vec::new();
+        for (id, ratio) in self.panes.iter().zip(ratios) {
+            if panes.iter().any(|pane| pane.id == *id) {
+                return Err(err_msg(format!("The pane {} was added twice", id)));
+            }
+
+            let limits = self.limits.get(id);
+            let min = limits.and_then(|limits| limits.min).unwrap_or(0.0);
+            let max = limits.and_then(|limits| limits.max);
+            if (min < 0.0) | max.map(|max| max < min).unwrap_or(false) {
+                return Err(err_msg(format!(
+                    "The smallest size of the pane {} must not be negative or larger than its largest size",
+                    id
+                )));
+            }
+
+            panes.push(Pane {
+                id: id.clone(),
+                ratio,
+                min,
+                max,
+                collapsible: limits.and_then(|limits| limits.collapsible).unwrap_or(true),
+                collapsed: false,
+            });
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let mut splitter = Splitter {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            orientation: self.orientation,
+            divider_color,
+            drag_color,
+            divider_width,
+            panes,
+            drag: None,
+            last_click: None,
+            on_change: self.clone().on_change,
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        };
+        splitter.normalize();
+
+        let widget = Box::new(splitter);
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
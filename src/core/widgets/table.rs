@@ -0,0 +1,1347 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    list_view::DOUBLE_CLICK,
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The distance in pixels from the edge between two headers where the column on its left is
+/// resized
+const RESIZE: f32 = 4.0;
+/// The width in pixels of the columns when none is set
+const COLUMN_WIDTH: f32 = 100.0;
+/// The size in pixels of the arrow showing the order of the sorted column
+const ARROW: f32 = 8.0;
+
+/// The callback given the selected cells of a table, as rows and columns
+pub type CellSelectionCallback = Arc<Fn(&[(usize, usize)]) + Send + Sync>;
+
+/// The callback given the row and the column of a cell
+pub type CellCallback = Arc<Fn(usize, usize) + Send + Sync>;
+
+/// The callback comparing two rows of a table in a column, given the column and the two rows
+pub type SortComparator = Arc<Fn(usize, usize, usize) -> Ordering + Send + Sync>;
+
+/// A column of a table
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableColumn {
+    title:     String,
+    width:     f32,
+    min_width: f32,
+    sortable:  bool,
+}
+
+impl TableColumn {
+    /// Create a sortable column with a title
+    pub fn new<V: Into<String>>(title: V) -> Self {
+        Self {
+            title:     title.into(),
+            width:     COLUMN_WIDTH,
+            min_width: 2.0 * RESIZE,
+            sortable:  true,
+        }
+    }
+
+    /// Set the width in pixels
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the narrowest width in pixels the column is resized to
+    pub fn with_min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Set whether clicking the header sorts the rows by the column
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Retrieve the title shown in the header
+    pub fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    /// Retrieve the width in pixels
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Retrieve the narrowest width in pixels the column is resized to
+    pub fn min_width(&self) -> f32 {
+        self.min_width
+    }
+
+    /// Whether clicking the header sorts the rows by the column
+    pub fn sortable(&self) -> bool {
+        self.sortable
+    }
+}
+
+/// A row shown by the table, bound to another row once scrolled out of view
+struct Row {
+    index: usize,
+    row:   usize,
+    texts: Vec<String>,
+}
+
+/// The Table Widget, the rows of a model in columns under a header. The header stays in place
+/// while the rows scroll, and only the rows in view are asked from the model.
+///
+/// The rows are sorted by clicking the header of a column, and the columns are resized by
+/// dragging the edges between the headers. The rows given to the callbacks and retrieved from the
+/// table are always the rows of the model, whichever way they are sorted.
+#[derive(Clone)]
+pub struct Table {
+    id:                Id,
+    parent_id:         Id,
+    size:              Size,
+    position:          Position,
+    fill:              Fill,
+    text_color:        Color,
+    header_color:      Color,
+    header_text_color: Color,
+    grid_color:        Color,
+    selection_color:   Color,
+    focus_color:       Color,
+    font:              Option<Font>,
+    text_size:         f32,
+    row_height:        f32,
+    model:             Arc<TableModel>,
+    columns:           Vec<TableColumn>,
+    sort:              Option<(usize, SortOrder)>,
+    order:             Option<Arc<Vec<usize>>>,
+    comparator:        Option<SortComparator>,
+    rows:              Arc<Mutex<Vec<Row>>>,
+    selection_mode:    SelectionMode,
+    selected:          BTreeSet<(usize, usize)>,
+    current:           Option<(usize, usize)>,
+    anchor:            Option<(usize, usize)>,
+    scroll:            (f32, f32),
+    resizing:          Option<usize>,
+    last_click:        Option<((usize, usize), Instant)>,
+    on_select:         Option<CellSelectionCallback>,
+    on_activate:       Option<CellCallback>,
+    text:              Arc<TextureCache>,
+    visible:           bool,
+    opacity:           f32,
+    z_index:           i32,
+    layer:             Layer,
+    border:            Border,
+    corner_radius:     CornerRadius,
+    shadow:            Shadow,
+}
+
+impl WidgetTrait for Table {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Table
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                modifiers,
+            } => {
+                let (left, top) = match self.area(ui)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some((left, top, ..)) => (left, top),
+                };
+                let (x, y) = (x - left, y - top);
+
+                if y < self.row_height {
+                    if let Some(column) = self.edge_at(x) {
+                        self.resizing = Some(column);
+                    } else if let Some(column) = self.column_at(x) {
+                        if self.columns[column].sortable {
+                            let order = match self.sort {
+                                Some((sorted, SortOrder::Ascending)) if sorted == column => SortOrder::Descending,
+                                _ => SortOrder::Ascending,
+                            };
+                            self.set_sort(Some((column, order)));
+                        }
+                    }
+                } else if let Some(cell) = self.cell_at(x, y) {
+                    let double = match self.last_click {
+                        Some((last, time)) => (last == cell) & (time.elapsed() < Duration::from_millis(DOUBLE_CLICK)),
+                        None => false,
+                    };
+                    self.click(cell, modifiers);
+                    if double {
+                        self.last_click = None;
+                        self.activate(cell);
+                    } else {
+                        self.last_click = Some((cell, Instant::now()));
+                    }
+                }
+            }
+            InputEvent::CursorMoved { x, .. } => {
+                let column = match self.resizing {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                let left = match self.area(ui)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some((val, ..)) => val,
+                };
+
+                let start = self.columns[..column].iter().map(|column| column.width).sum::<f32>() - self.scroll.0;
+                self.set_column_width(column, x - left - start);
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.resizing = None;
+            }
+            InputEvent::MouseWheel { delta_x, delta_y, .. } => {
+                return self.scroll_by(ui, -delta_x, -delta_y);
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                return self.handle_key(ui, key, modifiers);
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let (width, height) = painter.size();
+        let row_height = self.row_height;
+        let body = (height - row_height).max(0.0);
+        let last = (((self.scroll.1 + body) / row_height).ceil() as usize).min(self.model.len());
+        let first = ((self.scroll.1 / row_height).floor() as usize).min(last);
+        self.recycle(first, last);
+
+        let focused = ui.focused() == Some(self.id());
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+        let text_top = (row_height - line_height) / 2.0;
+
+        // The left of every column, followed by the right of the last one
+        let mut edges = vec![-self.scroll.0];
+        for column in &self.columns {
+            let edge = edges[edges.len() - 1] + column.width;
+            edges.push(edge);
+        }
+        let right = edges[edges.len() - 1];
+
+        for row in self.rows.lock().iter() {
+            let y = (row_height + (row.index as f32 * row_height)) - self.scroll.1;
+            for (column, text) in row.texts.iter().enumerate() {
+                let (x, cell_width) = (edges[column], self.columns[column].width);
+                if self.selected.contains(&(row.row, column)) {
+                    painter.rect((x, y), (cell_width, row_height), self.selection_color);
+                }
+                if let Some(ref font) = self.font {
+                    painter.text(
+                        (x + PADDING, y + text_top),
+                        font.fit(text, self.text_size, cell_width - (2.0 * PADDING)),
+                        self.text_size,
+                        self.text_color,
+                        font,
+                    );
+                }
+                if focused & (self.current == Some((row.index, column))) {
+                    painter.stroke_rect(
+                        (x + 0.5, y + 0.5),
+                        (cell_width - 1.0, row_height - 1.0),
+                        1.0,
+                        self.focus_color,
+                    );
+                }
+            }
+            painter.line((0.0, y + row_height), (right, y + row_height), 1.0, self.grid_color);
+        }
+
+        for &x in &edges[1..] {
+            painter.line((x, 0.0), (x, height), 1.0, self.grid_color);
+        }
+
+        // The header is drawn over the rows scrolled under it
+        painter.rect((0.0, 0.0), (width, row_height), self.header_color);
+        for (column, info) in self.columns.iter().enumerate() {
+            let (x, cell_width) = (edges[column], info.width);
+            let sorted = match self.sort {
+                Some((sorted, order)) if sorted == column => Some(order),
+                _ => None,
+            };
+
+            let room = match sorted {
+                None => cell_width - (2.0 * PADDING),
+                Some(_) => cell_width - (3.0 * PADDING) - ARROW,
+            };
+            if let Some(ref font) = self.font {
+                painter.text(
+                    (x + PADDING, text_top),
+                    font.fit(&info.title, self.text_size, room),
+                    self.text_size,
+                    self.header_text_color,
+                    font,
+                );
+            }
+
+            if let Some(order) = sorted {
+                let (center_x, center_y) = (x + cell_width - PADDING - (ARROW / 2.0), row_height / 2.0);
+                let (tip, base) = match order {
+                    SortOrder::Ascending => (center_y - (ARROW / 4.0), center_y + (ARROW / 4.0)),
+                    SortOrder::Descending => (center_y + (ARROW / 4.0), center_y - (ARROW / 4.0)),
+                };
+                painter.polygon(
+                    &[
+                        (center_x - (ARROW / 2.0), base),
+                        (center_x + (ARROW / 2.0), base),
+                        (center_x, tip),
+                    ],
+                    self.header_text_color,
+                );
+            }
+
+            painter.line(
+                (x + cell_width, 0.0),
+                (x + cell_width, row_height),
+                1.0,
+                self.grid_color,
+            );
+        }
+        painter.line((0.0, row_height), (width, row_height), 1.0, self.grid_color);
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Table {
+    /// Retrieve the model of the rows
+    pub fn model(&self) -> Arc<TableModel> {
+        Arc::clone(&self.model)
+    }
+
+    /// Show the rows of another model, sorted like the rows before, clearing the selection and
+    /// scrolling back to the top
+    pub fn set_model(&mut self, model: Arc<TableModel>) {
+        self.model = model;
+        self.selected.clear();
+        self.current = None;
+        self.anchor = None;
+        self.scroll = (0.0, 0.0);
+        self.sort_rows();
+    }
+
+    /// Ask the model again for the rows shown and sort the rows again, after its rows changed. The
+    /// selected rows past the end of the model are unselected.
+    pub fn refresh(&mut self) {
+        let len = self.model.len();
+        self.selected = self.selected.iter().cloned().filter(|&(row, _)| row < len).collect();
+        self.sort_rows();
+    }
+
+    /// Retrieve the rows of the model shown the last time the table was drawn, from the top
+    pub fn rows(&self) -> Vec<usize> {
+        self.rows.lock().iter().map(|row| row.row).collect()
+    }
+
+    /// Retrieve the columns
+    pub fn columns(&self) -> Vec<TableColumn> {
+        self.columns.clone()
+    }
+
+    /// Set the width in pixels of a column, no narrower than its minimum width
+    pub fn set_column_width(&mut self, column: usize, width: f32) {
+        if let Some(column) = self.columns.get_mut(column) {
+            column.width = width.max(column.min_width);
+        }
+    }
+
+    /// Retrieve the column the rows are sorted by and the order, or None when the rows are in the
+    /// order of the model
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sort the rows by a column in an order, or put them back in the order of the model
+    pub fn set_sort(&mut self, sort: Option<(usize, SortOrder)>) {
+        self.sort = sort.filter(|&(column, _)| column < self.columns.len());
+        self.sort_rows();
+    }
+
+    /// Set the comparator sorting the rows, given the column and the two rows of the model to
+    /// compare. The texts of the cells are compared by default.
+    pub fn set_comparator<F>(&mut self, comparator: F)
+    where
+        F: Fn(usize, usize, usize) -> Ordering + Send + Sync + 'static,
+    {
+        self.comparator = Some(Arc::new(comparator));
+        self.sort_rows();
+    }
+
+    /// Retrieve how many cells can be selected at once
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection_mode
+    }
+
+    /// Retrieve the selected cells as rows of the model and columns, in order
+    pub fn selected(&self) -> Vec<(usize, usize)> {
+        self.selected.iter().cloned().collect()
+    }
+
+    /// Select cells as rows of the model and columns, only the first one unless several cells can
+    /// be selected, without calling the selection callback. The cells past the end of the model or
+    /// of the columns are ignored.
+    pub fn set_selected(&mut self, cells: &[(usize, usize)]) {
+        let (len, columns) = (self.model.len(), self.columns.len());
+        let mut cells = cells
+            .iter()
+            .cloned()
+            .filter(|&(row, column)| (row < len) & (column < columns));
+        let first = cells.next();
+
+        self.selected = match self.selection_mode {
+            SelectionMode::Single => first.into_iter().collect(),
+            SelectionMode::Multiple => first.into_iter().chain(cells).collect(),
+        };
+        self.current = first.map(|(row, column)| (self.view_row(row), column));
+        self.anchor = self.current;
+    }
+
+    /// Retrieve the cell moved to with the keyboard or clicked last, as a row of the model and a
+    /// column
+    pub fn current(&self) -> Option<(usize, usize)> {
+        self.current.map(|(row, column)| (self.model_row(row), column))
+    }
+
+    /// Retrieve the distance in pixels the rows are scrolled to the right and down
+    pub fn scroll(&self) -> (f32, f32) {
+        self.scroll
+    }
+
+    /// Scroll just enough for the cell of a row of the model in a column to be shown
+    pub fn scroll_to(&mut self, ui: &Ui, row: usize, column: usize) -> Result<(), Error> {
+        let row = self.view_row(row);
+        self.show(ui, (row, column))
+    }
+
+    /// Retrieve the height in pixels of the header and the rows
+    pub fn row_height(&self) -> f32 {
+        self.row_height
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the selected cells whenever the user changes the selection
+    pub fn set_on_select<F>(&mut self, on_select: F)
+    where
+        F: Fn(&[(usize, usize)]) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+    }
+
+    /// Set the callback called with the row of the model and the column of a cell when it is
+    /// double clicked, or when enter is pressed on the current cell
+    pub fn set_on_activate<F>(&mut self, on_activate: F)
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(on_activate));
+    }
+
+    fn handle_key(&mut self, ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> Result<bool, Error> {
+        let (len, columns) = (self.model.len(), self.columns.len());
+        if (len == 0) | (columns == 0) {
+            return Ok(false);
+        }
+
+        let command = modifiers.ctrl | modifiers.logo;
+        let multiple = self.selection_mode == SelectionMode::Multiple;
+        let page = self.page_rows(ui)?;
+        let current = self.current.filter(|&(row, column)| (row < len) & (column < columns));
+        let (row, column) = current.unwrap_or((0, 0));
+
+        let target = match key {
+            VirtualKeyCode::Up => (row.saturating_sub(1), column),
+            VirtualKeyCode::Down => ((row + 1).min(len - 1), column),
+            VirtualKeyCode::Left => (row, column.saturating_sub(1)),
+            VirtualKeyCode::Right => (row, (column + 1).min(columns - 1)),
+            VirtualKeyCode::PageUp => (row.saturating_sub(page), column),
+            VirtualKeyCode::PageDown => ((row + page).min(len - 1), column),
+            VirtualKeyCode::Home if command => (0, column),
+            VirtualKeyCode::End if command => (len - 1, column),
+            VirtualKeyCode::Home => (row, 0),
+            VirtualKeyCode::End => (row, columns - 1),
+            VirtualKeyCode::A if command & multiple => {
+                self.change(self.block((0, 0), (len - 1, columns - 1)));
+                return Ok(true);
+            }
+            VirtualKeyCode::Space => {
+                let cell = match current {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                self.click(cell, modifiers);
+                return Ok(true);
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                return Ok(match current {
+                    None => false,
+                    Some(cell) => {
+                        self.activate(cell);
+                        true
+                    }
+                });
+            }
+            _ => {
+                return Ok(false);
+            }
+        };
+        // The first key moves to the first cell
+        let target = if current.is_none() { (0, 0) } else { target };
+
+        self.current = Some(target);
+        if multiple & modifiers.shift {
+            let anchor = self.anchor.unwrap_or(target);
+            self.change(self.block(anchor, target));
+        } else if !(multiple & command) {
+            // The control key moves through the cells of a multiple selection without selecting
+            self.anchor = Some(target);
+            self.change(self.block(target, target));
+        }
+        self.show(ui, target)?;
+
+        Ok(true)
+    }
+
+    /// Select a cell, as a row shown and a column, clicked or pressed space on with the modifier
+    /// keys held down
+    fn click(&mut self, cell: (usize, usize), modifiers: ModifiersState) {
+        let command = modifiers.ctrl | modifiers.logo;
+        let selected = match self.selection_mode {
+            SelectionMode::Multiple if modifiers.shift => self.block(self.anchor.unwrap_or(cell), cell),
+            SelectionMode::Multiple if command => {
+                let mut selected = self.selected.clone();
+                let model_cell = (self.model_row(cell.0), cell.1);
+                if !selected.remove(&model_cell) {
+                    selected.insert(model_cell);
+                }
+                self.anchor = Some(cell);
+                selected
+            }
+            _ => {
+                self.anchor = Some(cell);
+                self.block(cell, cell)
+            }
+        };
+
+        self.current = Some(cell);
+        self.change(selected);
+    }
+
+    fn change(&mut self, selected: BTreeSet<(usize, usize)>) {
+        if selected == self.selected {
+            return;
+        }
+
+        self.selected = selected;
+        if let Some(ref on_select) = self.on_select {
+            on_select(&self.selected());
+        }
+    }
+
+    fn activate(&self, (row, column): (usize, usize)) {
+        if let Some(ref on_activate) = self.on_activate {
+            on_activate(self.model_row(row), column);
+        }
+    }
+
+    /// The cells of the model from one cell shown to another, both included
+    fn block(&self, from: (usize, usize), to: (usize, usize)) -> BTreeSet<(usize, usize)> {
+        let mut cells = BTreeSet::new();
+        for row in from.0.min(to.0)..=from.0.max(to.0) {
+            let row = self.model_row(row);
+            for column in from.1.min(to.1)..=from.1.max(to.1) {
+                cells.insert((row, column));
+            }
+        }
+        cells
+    }
+
+    /// The row of the model shown at a row
+    fn model_row(&self, row: usize) -> usize {
+        match self.order {
+            None => row,
+            Some(ref order) => order.get(row).cloned().unwrap_or(row),
+        }
+    }
+
+    /// The row a row of the model is shown at
+    fn view_row(&self, row: usize) -> usize {
+        match self.order {
+            None => row,
+            Some(ref order) => order.iter().position(|&val| val == row).unwrap_or(row),
+        }
+    }
+
+    /// Sort the rows again, keeping the current cell on the same row of the model
+    fn sort_rows(&mut self) {
+        let len = self.model.len();
+        let current = self.current.map(|(row, column)| (self.model_row(row), column));
+        let anchor = self.anchor.map(|(row, column)| (self.model_row(row), column));
+
+        self.order = match self.sort {
+            None => None,
+            Some((column, order)) => {
+                let direct = |ordering: Ordering| match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                };
+
+                let mut rows: Vec<usize> = (0..len).collect();
+                match self.comparator {
+                    Some(ref comparator) => rows.sort_by(|&a, &b| direct(comparator(column, a, b))),
+                    None => {
+                        let texts: Vec<String> = (0..len).map(|row| self.model.text(row, column)).collect();
+                        rows.sort_by(|&a, &b| direct(texts[a].cmp(&texts[b])));
+                    }
+                }
+                Some(Arc::new(rows))
+            }
+        };
+
+        let positions = self.order.as_ref().map(|order| {
+            let mut positions = vec![0; order.len()];
+            for (position, &row) in order.iter().enumerate() {
+                positions[row] = position;
+            }
+            positions
+        });
+        let view = |(row, column): (usize, usize)| {
+            if row >= len {
+                return None;
+            }
+            match positions {
+                None => Some((row, column)),
+                Some(ref positions) => Some((positions[row], column)),
+            }
+        };
+
+        self.current = current.and_then(view);
+        self.anchor = anchor.and_then(view);
+        self.rows.lock().clear();
+    }
+
+    /// Bind the rows shown to the rows from first to last, reusing the rows scrolled out of view so
+    /// that only the rows coming into view are asked from the model
+    fn recycle(&self, first: usize, last: usize) {
+        let mut rows = self.rows.lock();
+        let shown = |index: usize| (index >= first) & (index < last);
+
+        let mut bound = vec![false; last - first];
+        let mut free = Vec::new();
+        for (i, row) in rows.iter().enumerate() {
+            if shown(row.index) {
+                bound[row.index - first] = true;
+            } else {
+                free.push(i);
+            }
+        }
+
+        for index in (first..last).filter(|index| !bound[index - first]) {
+            let row = self.model_row(index);
+            let texts = (0..self.columns.len())
+                .map(|column| self.model.text(row, column))
+                .collect();
+            match free.pop() {
+                None => rows.push(Row { index, row, texts }),
+                Some(i) => rows[i] = Row { index, row, texts },
+            }
+        }
+
+        // The rows left over once fewer rows are shown
+        rows.retain(|row| shown(row.index));
+        rows.sort_by_key(|row| row.index);
+    }
+
+    /// The position in pixels of the top left corner of the table in the window, and its size
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+
+    /// The column whose right edge is near a distance in pixels from the left of the table
+    fn edge_at(&self, x: f32) -> Option<usize> {
+        let mut edge = -self.scroll.0;
+        for (column, info) in self.columns.iter().enumerate() {
+            edge += info.width;
+            if (x - edge).abs() <= RESIZE {
+                return Some(column);
+            }
+        }
+        None
+    }
+
+    /// The column at a distance in pixels from the left of the table
+    fn column_at(&self, x: f32) -> Option<usize> {
+        let mut edge = -self.scroll.0;
+        for (column, info) in self.columns.iter().enumerate() {
+            if (x >= edge) & (x < edge + info.width) {
+                return Some(column);
+            }
+            edge += info.width;
+        }
+        None
+    }
+
+    /// The cell, as a row shown and a column, at a position in pixels from the top left corner of
+    /// the table, below the header
+    fn cell_at(&self, x: f32, y: f32) -> Option<(usize, usize)> {
+        let row = ((y - self.row_height + self.scroll.1) / self.row_height).floor();
+        if (row < 0.0) | (row as usize >= self.model.len()) {
+            return None;
+        }
+
+        self.column_at(x).map(|column| (row as usize, column))
+    }
+
+    /// The number of rows shown at once under the header
+    fn page_rows(&self, ui: &Ui) -> Result<usize, Error> {
+        Ok(match self.area(ui)? {
+            None => 1,
+            Some((_, _, _, height)) => ((((height - self.row_height) / self.row_height).floor()).max(1.0)) as usize,
+        })
+    }
+
+    /// The largest distance in pixels the rows can be scrolled to the right and down in a table of
+    /// a size
+    fn max_scroll(&self, width: f32, height: f32) -> (f32, f32) {
+        let columns: f32 = self.columns.iter().map(|column| column.width).sum();
+        let rows = self.model.len() as f32 * self.row_height;
+        ((columns - width).max(0.0), (rows - (height - self.row_height)).max(0.0))
+    }
+
+    /// Scroll the rows by a distance in pixels, returning whether they moved
+    fn scroll_by(&mut self, ui: &Ui, x: f32, y: f32) -> Result<bool, Error> {
+        let (width, height) = match self.area(ui)? {
+            None => {
+                return Ok(false);
+            }
+            Some((_, _, width, height)) => (width, height),
+        };
+
+        let max_scroll = self.max_scroll(width, height);
+        let scroll = (
+            (self.scroll.0 + x).min(max_scroll.0).max(0.0),
+            (self.scroll.1 + y).min(max_scroll.1).max(0.0),
+        );
+
+        let moved = scroll != self.scroll;
+        self.scroll = scroll;
+        Ok(moved)
+    }
+
+    /// Scroll just enough for a cell, as a row shown and a column, to be shown
+    fn show(&mut self, ui: &Ui, (row, column): (usize, usize)) -> Result<(), Error> {
+        let (width, height) = match self.area(ui)? {
+            None => {
+                return Ok(());
+            }
+            Some((_, _, width, height)) => (width, height),
+        };
+
+        let body = height - self.row_height;
+        let top = row as f32 * self.row_height;
+        if top < self.scroll.1 {
+            self.scroll.1 = top;
+        } else if (top + self.row_height) - self.scroll.1 > body {
+            self.scroll.1 = (top + self.row_height) - body;
+        }
+
+        let left: f32 = self.columns[..column].iter().map(|column| column.width).sum();
+        let right = left + self.columns.get(column).map(|column| column.width).unwrap_or(0.0);
+        if left < self.scroll.0 {
+            self.scroll.0 = left;
+        } else if right - self.scroll.0 > width {
+            self.scroll.0 = right - width;
+        }
+
+        let max_scroll = self.max_scroll(width, height);
+        self.scroll = (
+            self.scroll.0.min(max_scroll.0).max(0.0),
+            self.scroll.1.min(max_scroll.1).max(0.0),
+        );
+
+        Ok(())
+    }
+}
+
+/// The builder for the Table widget
+#[derive(Clone, Default)]
+pub struct TableBuilder {
+    id:                Id,
+    size:              Size,
+    position:          Position,
+    color:             String,
+    text_color:        String,
+    header_color:      String,
+    header_text_color: String,
+    grid_color:        String,
+    selection_color:   String,
+    font:              Option<Font>,
+    text_size:         Option<f32>,
+    row_height:        Option<f32>,
+    model:             Option<Arc<TableModel>>,
+    columns:           Vec<TableColumn>,
+    sort:              Option<(usize, SortOrder)>,
+    comparator:        Option<SortComparator>,
+    selection_mode:    SelectionMode,
+    selected:          Vec<(usize, usize)>,
+    on_select:         Option<CellSelectionCallback>,
+    on_activate:       Option<CellCallback>,
+    parent_id:         Id,
+    visible:           bool,
+    opacity:           f32,
+    z_index:           i32,
+    layer:             Layer,
+    border_color:      String,
+    border_width:      Option<f32>,
+    corner_radius:     Option<[f32; 4]>,
+}
+
+impl TableBuilder {
+    /// Initialize the builder for the Table widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Table widget from another Table widget
+    pub fn new_from_table(table: &Table) -> Self {
+        Self {
+            id:                table.id(),
+            size:              table.size(),
+            position:          table.position(),
+            color:             table.fill().into_string(),
+            text_color:        table.text_color.into_hex(),
+            header_color:      table.header_color.into_hex(),
+            header_text_color: table.header_text_color.into_hex(),
+            grid_color:        table.grid_color.into_hex(),
+            selection_color:   table.selection_color.into_hex(),
+            font:              table.font(),
+            text_size:         Some(table.text_size()),
+            row_height:        Some(table.row_height()),
+            model:             Some(table.model()),
+            columns:           table.columns(),
+            sort:              table.sort(),
+            comparator:        table.comparator.clone(),
+            selection_mode:    table.selection_mode(),
+            selected:          table.selected(),
+            on_select:         table.on_select.clone(),
+            on_activate:       table.on_activate.clone(),
+            parent_id:         table.parent_id().unwrap(),
+            visible:           table.visible(),
+            opacity:           table.opacity(),
+            z_index:           table.z_index(),
+            layer:             table.layer(),
+            border_color:      table.border().color().into_hex(),
+            border_width:      Some(table.border().width()),
+            corner_radius:     Some(table.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the text color of the cells
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the header
+    pub fn with_header_color<V: Into<String>>(&mut self, header_color: V) -> &mut Self {
+        self.header_color = header_color.into();
+        self
+    }
+
+    /// Set the text color of the header
+    pub fn with_header_text_color<V: Into<String>>(&mut self, header_text_color: V) -> &mut Self {
+        self.header_text_color = header_text_color.into();
+        self
+    }
+
+    /// Set the color of the lines between the cells
+    pub fn with_grid_color<V: Into<String>>(&mut self, grid_color: V) -> &mut Self {
+        self.grid_color = grid_color.into();
+        self
+    }
+
+    /// Set the color behind the selected cells
+    pub fn with_selection_color<V: Into<String>>(&mut self, selection_color: V) -> &mut Self {
+        self.selection_color = selection_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the height in pixels of the header and the rows, the size of the text and some padding
+    /// by default
+    pub fn with_row_height(&mut self, row_height: f32) -> &mut Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Set the model of the rows
+    pub fn with_model(&mut self, model: Arc<TableModel>) -> &mut Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// Show rows of texts, one text for each column
+    pub fn with_rows(&mut self, rows: Vec<Vec<String>>) -> &mut Self {
+        self.model = Some(Arc::new(rows));
+        self
+    }
+
+    /// Add a column
+    pub fn with_column(&mut self, column: TableColumn) -> &mut Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Sort the rows by a column in an order
+    pub fn with_sort(&mut self, column: usize, order: SortOrder) -> &mut Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Set the comparator sorting the rows, given the column and the two rows of the model to
+    /// compare. The texts of the cells are compared by default.
+    pub fn with_comparator<F>(&mut self, comparator: F) -> &mut Self
+    where
+        F: Fn(usize, usize, usize) -> Ordering + Send + Sync + 'static,
+    {
+        self.comparator = Some(Arc::new(comparator));
+        self
+    }
+
+    /// Set how many cells can be selected at once
+    pub fn with_selection_mode(&mut self, selection_mode: SelectionMode) -> &mut Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Set the selected cells as rows of the model and columns
+    pub fn with_selected(&mut self, selected: &[(usize, usize)]) -> &mut Self {
+        self.selected = selected.to_vec();
+        self
+    }
+
+    /// Set the callback called with the selected cells whenever the user changes the selection
+    pub fn with_on_select<F>(&mut self, on_select: F) -> &mut Self
+    where
+        F: Fn(&[(usize, usize)]) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+
+    /// Set the callback called with the row of the model and the column of a cell when it is
+    /// double clicked, or when enter is pressed on the current cell
+    pub fn with_on_activate<F>(&mut self, on_activate: F) -> &mut Self
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(on_activate));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the Table widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Table>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.text_input_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = color(&self.text_color, theme.text_input_text_color())?;
+        let header_color = color(&self.header_color, theme.bar_color())?;
+        let header_text_color = color(&self.header_text_color, theme.label_text_color())?;
+        let grid_color = color(&self.grid_color, theme.border_color())?;
+        let selection_color = color(&self.selection_color, theme.selection_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let row_height = self.row_height.unwrap_or(text_size + (2.0 * PADDING));
+        if row_height <= 0.0 {
+            return Err(err_msg("The row height must be greater than 0.0"));
+        }
+
+        for column in &self.columns {
+            if column.min_width < 0.0 {
+                return Err(err_msg("The minimum width of a column must be 0.0 or greater"));
+            }
+            if column.width < column.min_width {
+                return Err(err_msg("The width of a column must be at least its minimum width"));
+            }
+        }
+
+        if let Some((column, _)) = self.sort {
+            if column >= self.columns.len() {
+                return Err(err_msg("The rows must be sorted by one of the columns"));
+            }
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let model = match self.model {
+            None => Arc::new(Vec::<Vec<String>>::new()),
+            Some(ref val) => Arc::clone(val),
+        };
+
+        let mut widget = Box::new(Table {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color,
+            header_color,
+            header_text_color,
+            grid_color,
+            selection_color,
+            focus_color: theme.checked_color()?,
+            font,
+            text_size,
+            row_height,
+            model,
+            columns: self.clone().columns,
+            sort: self.sort,
+            order: None,
+            comparator: self.clone().comparator,
+            rows: Arc::new(Mutex::new(Vec::new())),
+            selection_mode: self.selection_mode,
+            selected: BTreeSet::new(),
+            current: None,
+            anchor: None,
+            scroll: (0.0, 0.0),
+            resizing: None,
+            last_click: None,
+            on_select: self.clone().on_select,
+            on_activate: self.clone().on_activate,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        });
+        widget.sort_rows();
+        widget.set_selected(&self.selected);
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
@@ -0,0 +1,1004 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    MIN_TAB_WIDTH,
+    PADDING,
+    TAB_PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The size in pixels of the cross closing a tab
+const CLOSE: f32 = 8.0;
+
+/// The callback given the id of a page
+pub type PageCallback = Arc<Fn(&str) + Send + Sync>;
+
+/// The callback given the ids of the pages, in the order of their tabs
+pub type PagesCallback = Arc<Fn(&[Id]) + Send + Sync>;
+
+/// A page of the tabs, the id of a child widget and the title of its tab
+#[derive(Clone)]
+struct Page {
+    id:    Id,
+    title: String,
+}
+
+/// A tab dragged along the tabs
+#[derive(Clone, Copy)]
+struct Drag {
+    index: usize,
+    grab:  f32,
+    x:     f32,
+    moved: bool,
+}
+
+/// The Tabs Widget, a row of tabs over pages of which only the page of the active tab is shown.
+/// The pages are children of the tabs added with their titles, every other child is hidden.
+#[derive(Clone)]
+pub struct Tabs {
+    id:               Id,
+    parent_id:        Id,
+    size:             Size,
+    position:         Position,
+    fill:             Fill,
+    tab_color:        Color,
+    active_tab_color: Color,
+    text_color:       Color,
+    focus_color:      Color,
+    font:             Option<Font>,
+    text_size:        f32,
+    tab_height:       f32,
+    closable:         bool,
+    pages:            Vec<Page>,
+    active:           Option<Id>,
+    drag:             Option<Drag>,
+    on_change:        Option<PageCallback>,
+    on_close:         Option<PageCallback>,
+    on_reorder:       Option<PagesCallback>,
+    text:             Arc<TextureCache>,
+    visible:          bool,
+    opacity:          f32,
+    z_index:          i32,
+    layer:            Layer,
+    border:           Border,
+    corner_radius:    CornerRadius,
+    shadow:           Shadow,
+}
+
+impl WidgetTrait for Tabs {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Tabs
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed { x, y, button, .. } => {
+                let (left, top) = match self.area(ui)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some((left, top, ..)) => (left, top),
+                };
+
+                let (x, y) = (x - left, y - top);
+                let index = match self.tab_at(x) {
+                    Some(val) if y < self.tab_height => val,
+                    _ => {
+                        return Ok(false);
+                    }
+                };
+
+                match button {
+                    MouseButton::Left if self.closable & self.on_close_button(index, x) => {
+                        self.close_tab(ui, index);
+                    }
+                    MouseButton::Left => {
+                        self.select(ui, index);
+                        self.drag = Some(Drag {
+                            index,
+                            grab: x - self.tab_left(index),
+                            x,
+                            moved: false,
+                        });
+                    }
+                    MouseButton::Middle if self.closable => {
+                        self.close_tab(ui, index);
+                    }
+                    _ => {
+                        return Ok(false);
+                    }
+                }
+            }
+            InputEvent::CursorMoved { x, .. } if self.drag.is_some() => {
+                let left = match self.area(ui)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some((val, ..)) => val,
+                };
+                self.drag_to(x - left);
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                let drag = match self.drag.take() {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                if drag.moved {
+                    if let Some(ref on_reorder) = self.on_reorder {
+                        on_reorder(&self.pages());
+                    }
+                }
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                let len = self.pages.len();
+                let index = match self.active_index() {
+                    Some(val) if len > 0 => val,
+                    _ => {
+                        return Ok(false);
+                    }
+                };
+
+                let command = modifiers.ctrl | modifiers.logo;
+                let focused = ui.focused() == Some(self.id());
+                let previous = (index + len - 1) % len;
+                let next = (index + 1) % len;
+
+                // The control key switches pages from anywhere inside the tabs, the arrow keys
+                // only when the tabs are focused
+                let target = match key {
+                    VirtualKeyCode::Tab if command & modifiers.shift => previous,
+                    VirtualKeyCode::Tab | VirtualKeyCode::PageDown if command => next,
+                    VirtualKeyCode::PageUp if command => previous,
+                    VirtualKeyCode::W if command & self.closable => {
+                        self.close_tab(ui, index);
+                        return Ok(true);
+                    }
+                    VirtualKeyCode::Left if focused => index.saturating_sub(1),
+                    VirtualKeyCode::Right if focused => (index + 1).min(len - 1),
+                    VirtualKeyCode::Home if focused => 0,
+                    VirtualKeyCode::End if focused => len - 1,
+                    _ => {
+                        return Ok(false);
+                    }
+                };
+                self.select(ui, target);
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn child_area(&self, ui: &Ui, child: &Id, bl_vertex: Vertex, tr_vertex: Vertex) -> Option<(Vertex, Vertex)> {
+        if self.active.as_ref() != Some(child) {
+            return None;
+        }
+
+        // The page is laid out below the row of tabs
+        let window_size = ui.window_size();
+        let strip = if window_size[1] == 0 {
+            0.0
+        } else {
+            (self.tab_height * 2.0) / window_size[1] as f32
+        };
+
+        Some((
+            Vertex {
+                x: bl_vertex.x(),
+                y: (bl_vertex.y() + strip).min(tr_vertex.y()),
+            },
+            tr_vertex,
+        ))
+    }
+
+    fn places_child(&self, child: &Id) -> bool {
+        self.index_of(child).is_some()
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let focused = ui.focused() == Some(self.id());
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+
+        let widths = self.tab_widths();
+        let mut left = 0.0;
+        let mut tabs = Vec::new();
+        for (index, width) in widths.iter().enumerate() {
+            tabs.push((index, left, *width));
+            left += width;
+        }
+
+        // The tab dragged is drawn last, over the others, following the cursor
+        if let Some(drag) = self.drag {
+            let (index, _, width) = tabs.remove(drag.index);
+            let x = (drag.x - drag.grab).min(left - width).max(0.0);
+            tabs.push((index, x, width));
+        }
+
+        for (index, x, width) in tabs {
+            let page = &self.pages[index];
+            let active = self.active.as_ref() == Some(&page.id);
+            let color = if active { self.active_tab_color } else { self.tab_color };
+            painter.rect((x, 0.0), (width, self.tab_height), color);
+
+            if let Some(ref font) = self.font {
+                painter.text(
+                    (x + TAB_PADDING, (self.tab_height - line_height) / 2.0),
+                    &page.title,
+                    self.text_size,
+                    self.text_color,
+                    font,
+                );
+            }
+
+            if self.closable {
+                let (cx, cy) = (x + width - TAB_PADDING - (CLOSE / 2.0), self.tab_height / 2.0);
+                let half = CLOSE / 2.0;
+                painter.line((cx - half, cy - half), (cx + half, cy + half), 1.5, self.text_color);
+                painter.line((cx - half, cy + half), (cx + half, cy - half), 1.5, self.text_color);
+            }
+
+            if focused & active {
+                painter.stroke_rect(
+                    (x + 0.5, 0.5),
+                    (width - 1.0, self.tab_height - 1.0),
+                    1.0,
+                    self.focus_color,
+                );
+            }
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Tabs {
+    /// Retrieve the ids of the pages, in the order of their tabs
+    pub fn pages(&self) -> Vec<Id> {
+        self.pages.iter().map(|page| page.id.clone()).collect()
+    }
+
+    /// Add a tab after the others for a child widget, the first page added being active. Adding a
+    /// page again only changes its title.
+    pub fn add_page<V: Into<String>>(&mut self, id: &str, title: V) {
+        let title = title.into();
+        match self.pages.iter_mut().find(|page| page.id == id) {
+            None => self.pages.push(Page {
+                id: String::from(id),
+                title,
+            }),
+            Some(page) => page.title = title,
+        }
+
+        if self.active.is_none() {
+            self.active = Some(String::from(id));
+        }
+    }
+
+    /// Remove the tab of a page, hiding the page, without calling the close callback. The page
+    /// after it becomes active if it was, or the page before it if it was the last.
+    pub fn close(&mut self, id: &str) {
+        if let Some(index) = self.index_of(id) {
+            self.remove(index);
+        }
+    }
+
+    /// Retrieve the title of the tab of a page
+    pub fn title(&self, id: &str) -> Option<String> {
+        self.index_of(id).map(|index| self.pages[index].title.clone())
+    }
+
+    /// Set the title of the tab of a page
+    pub fn set_title<V: Into<String>>(&mut self, id: &str, title: V) {
+        if let Some(index) = self.index_of(id) {
+            self.pages[index].title = title.into();
+        }
+    }
+
+    /// Move the tab of a page to another place among the tabs, without calling the reorder
+    /// callback
+    pub fn move_page(&mut self, id: &str, index: usize) {
+        if let Some(from) = self.index_of(id) {
+            let page = self.pages.remove(from);
+            let index = index.min(self.pages.len());
+            self.pages.insert(index, page);
+        }
+    }
+
+    /// Retrieve the id of the page shown
+    pub fn active(&self) -> Option<Id> {
+        self.active.clone()
+    }
+
+    /// Show another page, without calling the change callback. The ids of widgets that are not
+    /// pages are ignored.
+    pub fn set_active(&mut self, id: &str) {
+        if self.index_of(id).is_some() {
+            self.active = Some(String::from(id));
+        }
+    }
+
+    /// Retrieve the height in pixels of the tabs
+    pub fn tab_height(&self) -> f32 {
+        self.tab_height
+    }
+
+    /// Retrieve whether the tabs can be closed
+    pub fn closable(&self) -> bool {
+        self.closable
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the id of the page shown whenever the user picks another tab
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    /// Set the callback called with the id of a page whenever the user closes its tab
+    pub fn set_on_close<F>(&mut self, on_close: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_close = Some(Arc::new(on_close));
+    }
+
+    /// Set the callback called with the ids of the pages in their new order whenever the user drops
+    /// a tab dragged to another place
+    pub fn set_on_reorder<F>(&mut self, on_reorder: F)
+    where
+        F: Fn(&[Id]) + Send + Sync + 'static,
+    {
+        self.on_reorder = Some(Arc::new(on_reorder));
+    }
+
+    /// Show the page of a tab picked by the user
+    fn select(&mut self, ui: &Ui, index: usize) {
+        let id = self.pages[index].id.clone();
+        if self.active.as_ref() == Some(&id) {
+            return;
+        }
+
+        self.active = Some(id.clone());
+        self.unfocus(ui);
+        if let Some(ref on_change) = self.on_change {
+            on_change(&id);
+        }
+    }
+
+    /// Close the tab of a page as asked by the user
+    fn close_tab(&mut self, ui: &Ui, index: usize) {
+        let active = self.active.clone();
+        let page = self.remove(index);
+        self.unfocus(ui);
+
+        if let Some(ref on_close) = self.on_close {
+            on_close(&page.id);
+        }
+        if self.active != active {
+            if let (Some(ref on_change), Some(ref id)) = (&self.on_change, &self.active) {
+                on_change(id);
+            }
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Page {
+        let page = self.pages.remove(index);
+        if self.active.as_ref() == Some(&page.id) {
+            let index = index.min(self.pages.len().saturating_sub(1));
+            self.active = self.pages.get(index).map(|page| page.id.clone());
+        }
+        self.drag = None;
+        page
+    }
+
+    /// Move the keyboard focus to the tabs when it is on a widget of a page no longer shown
+    fn unfocus(&self, ui: &Ui) {
+        let mut id = ui.focused();
+        while let Some(val) = id {
+            if val == self.id {
+                return;
+            }
+
+            // The widgets are walked up to the tabs, which are locked while handling the event
+            let parent_id = match ui.widgets().get(&val) {
+                None => {
+                    return;
+                }
+                Some(widget) => widget.lock().parent_id(),
+            };
+            if (parent_id.as_ref() == Some(&self.id)) & (self.active.as_ref() != Some(&val)) {
+                ui.set_focus(Some(self.id()));
+                return;
+            }
+            id = parent_id;
+        }
+    }
+
+    /// Move the tab dragged under the cursor, at a distance in pixels from the left of the tabs
+    fn drag_to(&mut self, x: f32) {
+        let mut drag = match self.drag {
+            None => {
+                return;
+            }
+            Some(val) => val,
+        };
+
+        drag.x = x;
+        let target = match self.tab_at(x) {
+            Some(val) => val,
+            None if x < 0.0 => 0,
+            None => self.pages.len() - 1,
+        };
+        if target != drag.index {
+            let page = self.pages.remove(drag.index);
+            self.pages.insert(target, page);
+            drag.index = target;
+            drag.moved = true;
+        }
+        self.drag = Some(drag);
+    }
+
+    fn active_index(&self) -> Option<usize> {
+        self.active.as_ref().and_then(|id| self.index_of(id))
+    }
+
+    fn index_of(&self, id: &str) -> Option<usize> {
+        self.pages.iter().position(|page| page.id == id)
+    }
+
+    /// The width in pixels of each tab, fitting its title
+    fn tab_widths(&self) -> Vec<f32> {
+        let close = if self.closable { CLOSE + TAB_PADDING } else { 0.0 };
+        self.pages
+            .iter()
+            .map(|page| {
+                let width = match self.font {
+                    None => 0.0,
+                    Some(ref font) => font.measure(&page.title, self.text_size).0,
+                };
+                (width + (2.0 * TAB_PADDING) + close).max(MIN_TAB_WIDTH)
+            })
+            .collect()
+    }
+
+    /// The distance in pixels from the left of the tabs to the left of a tab
+    fn tab_left(&self, index: usize) -> f32 {
+        self.tab_widths()[..index].iter().sum()
+    }
+
+    /// The tab at a distance in pixels from the left of the tabs
+    fn tab_at(&self, x: f32) -> Option<usize> {
+        if x < 0.0 {
+            return None;
+        }
+
+        let mut right = 0.0;
+        for (index, width) in self.tab_widths().into_iter().enumerate() {
+            right += width;
+            if x < right {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    /// Whether a distance in pixels from the left of the tabs is on the cross closing a tab
+    fn on_close_button(&self, index: usize, x: f32) -> bool {
+        let right = self.tab_left(index) + self.tab_widths()[index];
+        (x >= right - TAB_PADDING - CLOSE - PADDING) & (x < right - TAB_PADDING + PADDING)
+    }
+
+    /// The position in pixels of the top left corner of the tabs in the window, and their size
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+}
+
+/// The builder for the Tabs widget
+#[derive(Clone, Default)]
+pub struct TabsBuilder {
+    id:               Id,
+    size:             Size,
+    position:         Position,
+    color:            String,
+    tab_color:        String,
+    active_tab_color: String,
+    text_color:       String,
+    font:             Option<Font>,
+    text_size:        Option<f32>,
+    tab_height:       Option<f32>,
+    closable:         bool,
+    pages:            Vec<(Id, String)>,
+    active:           Option<Id>,
+    on_change:        Option<PageCallback>,
+    on_close:         Option<PageCallback>,
+    on_reorder:       Option<PagesCallback>,
+    parent_id:        Id,
+    visible:          bool,
+    opacity:          f32,
+    z_index:          i32,
+    layer:            Layer,
+    border_color:     String,
+    border_width:     Option<f32>,
+    corner_radius:    Option<[f32; 4]>,
+}
+
+impl TabsBuilder {
+    /// Initialize the builder for the Tabs widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Tabs widget from another Tabs widget
+    pub fn new_from_tabs(tabs: &Tabs) -> Self {
+        Self {
+            id:               tabs.id(),
+            size:             tabs.size(),
+            position:         tabs.position(),
+            color:            tabs.fill().into_string(),
+            tab_color:        tabs.tab_color.into_hex(),
+            active_tab_color: tabs.active_tab_color.into_hex(),
+            text_color:       tabs.text_color.into_hex(),
+            font:             tabs.font(),
+            text_size:        Some(tabs.text_size()),
+            tab_height:       Some(tabs.tab_height()),
+            closable:         tabs.closable(),
+            pages:            tabs
+                .pages
+                .iter()
+                .map(|page| (page.id.clone(), page.title.clone()))
+                .collect(),
+            active:           tabs.active(),
+            on_change:        tabs.on_change.clone(),
+            on_close:         tabs.on_close.clone(),
+            on_reorder:       tabs.on_reorder.clone(),
+            parent_id:        tabs.parent_id().unwrap(),
+            visible:          tabs.visible(),
+            opacity:          tabs.opacity(),
+            z_index:          tabs.z_index(),
+            layer:            tabs.layer(),
+            border_color:     tabs.border().color().into_hex(),
+            border_width:     Some(tabs.border().width()),
+            corner_radius:    Some(tabs.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill behind the pages, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the tabs of the pages not shown
+    pub fn with_tab_color<V: Into<String>>(&mut self, tab_color: V) -> &mut Self {
+        self.tab_color = tab_color.into();
+        self
+    }
+
+    /// Set the color of the tab of the page shown
+    pub fn with_active_tab_color<V: Into<String>>(&mut self, active_tab_color: V) -> &mut Self {
+        self.active_tab_color = active_tab_color.into();
+        self
+    }
+
+    /// Set the color of the titles
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the height in pixels of the tabs, twice the size of the text by default
+    pub fn with_tab_height(&mut self, tab_height: f32) -> &mut Self {
+        self.tab_height = Some(tab_height);
+        self
+    }
+
+    /// Set whether the tabs show a cross closing them
+    pub fn with_closable(&mut self, closable: bool) -> &mut Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Add a tab after the others for a child widget, built afterwards with the tabs as its parent
+    pub fn with_page<V: Into<String>>(&mut self, id: V, title: V) -> &mut Self {
+        self.pages.push((id.into(), title.into()));
+        self
+    }
+
+    /// Set the page shown, the first one by default
+    pub fn with_active<V: Into<Id>>(&mut self, active: V) -> &mut Self {
+        self.active = Some(active.into());
+        self
+    }
+
+    /// Set the callback called with the id of the page shown whenever the user picks another tab
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the callback called with the id of a page whenever the user closes its tab
+    pub fn with_on_close<F>(&mut self, on_close: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_close = Some(Arc::new(on_close));
+        self
+    }
+
+    /// Set the callback called with the ids of the pages in their new order whenever the user drops
+    /// a tab dragged to another place
+    pub fn with_on_reorder<F>(&mut self, on_reorder: F) -> &mut Self
+    where
+        F: Fn(&[Id]) + Send + Sync + 'static,
+    {
+        self.on_reorder = Some(Arc::new(on_reorder));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the Tabs widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Tabs>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.container_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let tab_color = color(&self.tab_color, theme.bar_color())?;
+        let active_tab_color = color(&self.active_tab_color, theme.container_color())?;
+        let text_color = color(&self.text_color, theme.label_text_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let tab_height = self.tab_height.unwrap_or(2.0 * text_size);
+        if tab_height <= 0.0 {
+            return Err(err_msg("The tab height must be greater than 0.0"));
+        }
+
+        let mut pages: Vec<Page> = Vec::new();
+        for (id, title) in &self.pages {
+            if pages.iter().any(|page| page.id == *id) {
+                return Err(err_msg(format!("The page {} was added twice", id)));
+            }
+            pages.push(Page {
+                id:    id.clone(),
+                title: title.clone(),
+            });
+        }
+
+        let active = match self.active {
+            None => pages.first().map(|page| page.id.clone()),
+            Some(ref val) if pages.iter().any(|page| page.id == *val) => Some(val.clone()),
+            Some(ref val) => {
+                return Err(err_msg(format!("The active page {} is not one of the pages", val)));
+            }
+        };
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Tabs {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            tab_color,
+            active_tab_color,
+            text_color,
+            focus_color: theme.checked_color()?,
+            font,
+            text_size,
+            tab_height,
+            closable: self.closable,
+            pages,
+            active,
+            drag: None,
+            on_change: self.clone().on_change,
+            on_close: self.clone().on_close,
+            on_reorder: self.clone().on_reorder,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
@@ -0,0 +1,990 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    TextCallback,
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::{
+        painter::TextureCache,
+        text_edit::previous_grapheme,
+    },
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// A line of the text as shown in the text area
+struct Line {
+    start:   usize,
+    end:     usize,
+    offsets: Vec<f32>,
+}
+
+/// The TextArea Widget, several lines of editable text
+#[derive(Clone)]
+pub struct TextArea {
+    id:                Id,
+    parent_id:         Id,
+    size:              Size,
+    position:          Position,
+    fill:              Fill,
+    text_color:        Color,
+    placeholder_color: Color,
+    selection_color:   Color,
+    font:              Option<Font>,
+    text_size:         f32,
+    edit:              TextEdit,
+    placeholder:       String,
+    wrap:              bool,
+    on_change:         Option<TextCallback>,
+    scroll:            (f32, f32),
+    goal:              Option<f32>,
+    dragging:          bool,
+    text:              Arc<TextureCache>,
+    visible:           bool,
+    opacity:           f32,
+    z_index:           i32,
+    layer:             Layer,
+    border:            Border,
+    corner_radius:     CornerRadius,
+    shadow:            Shadow,
+}
+
+impl WidgetTrait for TextArea {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::TextArea
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        let mut goal = None;
+
+        match *event {
+            InputEvent::Composition { ref text, caret } => {
+                self.edit.set_composition(text.as_str(), caret);
+            }
+            InputEvent::Commit(ref text) => {
+                if self.edit.commit(text) {
+                    self.changed();
+                }
+            }
+            // The keys belong to the input method while it composes
+            InputEvent::Character(_) | InputEvent::KeyPressed { .. } if self.edit.composing() => {}
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                modifiers,
+            } => {
+                let position = self.position_at(ui, x, y)?;
+                self.edit.set_caret(position, modifiers.shift);
+                self.dragging = true;
+            }
+            InputEvent::CursorMoved { x, y } if self.dragging => {
+                let position = self.position_at(ui, x, y)?;
+                self.edit.set_caret(position, true);
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.dragging = false;
+            }
+            InputEvent::MouseWheel { delta_x, delta_y, .. } => {
+                return self.scroll_by(ui, -delta_x, -delta_y);
+            }
+            InputEvent::Character(c) if !c.is_control() => {
+                if self.edit.insert(&c.to_string()) {
+                    self.changed();
+                }
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                let select = modifiers.shift;
+                let command = modifiers.ctrl | modifiers.logo;
+                match key {
+                    VirtualKeyCode::Up => goal = self.move_lines(ui, -1, select)?,
+                    VirtualKeyCode::Down => goal = self.move_lines(ui, 1, select)?,
+                    VirtualKeyCode::PageUp => {
+                        let page = -(self.page_lines(ui)? as isize);
+                        goal = self.move_lines(ui, page, select)?;
+                    }
+                    VirtualKeyCode::PageDown => {
+                        let page = self.page_lines(ui)? as isize;
+                        goal = self.move_lines(ui, page, select)?;
+                    }
+                    VirtualKeyCode::Home if !command => self.move_line_start(ui, select)?,
+                    VirtualKeyCode::End if !command => self.move_line_end(ui, select)?,
+                    VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                        if self.edit.insert("\n") {
+                            self.changed();
+                        }
+                    }
+                    _ => match self.edit.handle_key(ui, key, modifiers, true) {
+                        None => {
+                            return Ok(false);
+                        }
+                        Some(changed) => {
+                            if changed {
+                                self.changed();
+                            }
+                        }
+                    },
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        self.goal = goal;
+        self.scroll_to_caret(ui)?;
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        let font = match self.font {
+            Some(ref val) if (window_size[0] != 0) & (window_size[1] != 0) => val,
+            _ => {
+                self.text.clear();
+                return Ok(vertices);
+            }
+        };
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let (width, height) = painter.size();
+        let line_height = self.line_height();
+        let left = PADDING - self.scroll.0;
+        let top = PADDING - self.scroll.1;
+        let (text, caret, composition) = self.edit.composed();
+        let lines = self.lines(&text, width);
+        let focused = ui.focused() == Some(self.id());
+
+        let first = (self.scroll.1 / line_height).floor().max(0.0) as usize;
+        let last = (((self.scroll.1 + height) / line_height).ceil() as usize).min(lines.len());
+
+        for (i, line) in lines.iter().enumerate().take(last).skip(first) {
+            let y = top + (i as f32 * line_height);
+
+            if let (true, None, Some((start, end))) = (focused, composition, self.edit.selection()) {
+                if (start <= line.end) & (end >= line.start) {
+                    let from = self.offset(&text, line, start);
+                    let mut to = self.offset(&text, line, end);
+                    // The selected new line at the end of a line
+                    if end > line.end {
+                        to += line_height / 4.0;
+                    }
+                    if to > from {
+                        painter.rect((left + from, y), (to - from, line_height), self.selection_color);
+                    }
+                }
+            }
+
+            // The composed text is underlined
+            if let Some((start, end)) = composition {
+                if (start <= line.end) & (end >= line.start) {
+                    let from = self.offset(&text, line, start);
+                    let to = self.offset(&text, line, end);
+                    painter.rect((left + from, y + line_height - 1.0), (to - from, 1.0), self.text_color);
+                }
+            }
+
+            painter.text(
+                (left, y),
+                &text[line.start..line.end],
+                self.text_size,
+                self.text_color,
+                font,
+            );
+        }
+
+        if text.is_empty() {
+            painter.text(
+                (left, top),
+                &self.placeholder,
+                self.text_size,
+                self.placeholder_color,
+                font,
+            );
+        }
+
+        if focused {
+            let row = line_of(&lines, caret);
+            let caret = self.offset(&text, &lines[row], caret);
+            painter.rect(
+                (left + caret, top + (row as f32 * line_height)),
+                (1.0, line_height),
+                self.text_color,
+            );
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl TextArea {
+    /// Retrieve the text
+    pub fn text(&self) -> String {
+        self.edit.text()
+    }
+
+    /// Replace the text, moving the caret to its end
+    pub fn set_text<V: Into<String>>(&mut self, text: V) {
+        self.edit.set_text(text);
+        self.scroll = (0.0, 0.0);
+        self.goal = None;
+    }
+
+    /// Retrieve the text being edited, along with the caret, the selection and the edits to undo
+    pub fn edit(&self) -> &TextEdit {
+        &self.edit
+    }
+
+    /// Retrieve the line and the column of the caret, counted in graphemes from 0. Lines only
+    /// end at new lines, not where the text is wrapped.
+    pub fn line_column(&self) -> (usize, usize) {
+        self.edit.line_column(self.edit.caret())
+    }
+
+    /// Undo the last edit, or the last run of edits of the same kind, returning whether the text
+    /// changed
+    pub fn undo(&mut self) -> bool {
+        let changed = self.edit.undo();
+        if changed {
+            self.changed();
+        }
+        changed
+    }
+
+    /// Redo the last undone edit, returning whether the text changed
+    pub fn redo(&mut self) -> bool {
+        let changed = self.edit.redo();
+        if changed {
+            self.changed();
+        }
+        changed
+    }
+
+    /// Retrieve the distance in pixels the text is scrolled to the right and down
+    pub fn scroll(&self) -> (f32, f32) {
+        self.scroll
+    }
+
+    /// Retrieve the text shown while the text area is empty
+    pub fn placeholder(&self) -> String {
+        self.placeholder.clone()
+    }
+
+    /// Set the text shown while the text area is empty
+    pub fn set_placeholder<V: Into<String>>(&mut self, placeholder: V) {
+        self.placeholder = placeholder.into();
+    }
+
+    /// Whether the lines longer than the text area are wrapped, instead of scrolling sideways
+    pub fn wrap(&self) -> bool {
+        self.wrap
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the text whenever it is edited
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    fn changed(&self) {
+        if let Some(ref on_change) = self.on_change {
+            on_change(&self.edit.text());
+        }
+    }
+
+    fn line_height(&self) -> f32 {
+        match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        }
+    }
+
+    /// The position in pixels of the top left corner of the text area in the window, and its size
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+
+    /// Lay out the text in a text area of a width in pixels
+    fn lines(&self, text: &str, width: f32) -> Vec<Line> {
+        let ranges = match self.font {
+            Some(ref font) if self.wrap => font.wrap(text, self.text_size, width - (2.0 * PADDING)),
+            _ => {
+                let mut start = 0;
+                text.split('\n')
+                    .map(|line| {
+                        let range = (start, start + line.len());
+                        start += line.len() + 1;
+                        range
+                    })
+                    .collect()
+            }
+        };
+
+        ranges
+            .into_iter()
+            .map(|(start, end)| Line {
+                start,
+                end,
+                offsets: match self.font {
+                    None => vec![0.0; text[start..end].chars().count() + 1],
+                    Some(ref font) => font.offsets(&text[start..end], self.text_size),
+                },
+            })
+            .collect()
+    }
+
+    /// The distance in pixels from the start of a line of a text to a position in it
+    fn offset(&self, text: &str, line: &Line, position: usize) -> f32 {
+        let index = text[line.start..position.max(line.start).min(line.end)].chars().count();
+        line.offsets[index]
+    }
+
+    /// The position in a line closest to a distance in pixels from its start, the end of a wrapped
+    /// line being the start of the next one
+    fn position_in(&self, lines: &[Line], row: usize, x: f32) -> usize {
+        let line = &lines[row];
+        let wrapped = lines.get(row + 1).map(|next| next.start == line.end).unwrap_or(false);
+        let count = if wrapped {
+            line.offsets.len() - 1
+        } else {
+            line.offsets.len()
+        };
+
+        let index = line.offsets[..count.max(1)]
+            .iter()
+            .enumerate()
+            .min_by(|a, b| (a.1 - x).abs().partial_cmp(&(b.1 - x).abs()).unwrap())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        let text = self.edit.text();
+        text[line.start..line.end]
+            .char_indices()
+            .nth(index)
+            .map(|(i, _)| line.start + i)
+            .unwrap_or(line.end)
+    }
+
+    /// The position in the text closest to a position in pixels in the window
+    fn position_at(&self, ui: &Ui, x: f32, y: f32) -> Result<usize, Error> {
+        let (left, top, width, _) = match self.area(ui)? {
+            None => {
+                return Ok(self.edit.caret());
+            }
+            Some(val) => val,
+        };
+
+        let lines = self.lines(&self.edit.text(), width);
+        let row = ((y - top - PADDING + self.scroll.1) / self.line_height())
+            .floor()
+            .max(0.0) as usize;
+
+        Ok(self.position_in(&lines, row.min(lines.len() - 1), x - left - PADDING + self.scroll.0))
+    }
+
+    /// The number of lines shown at once
+    fn page_lines(&self, ui: &Ui) -> Result<usize, Error> {
+        Ok(match self.area(ui)? {
+            None => 1,
+            Some((_, _, _, height)) => (((height - (2.0 * PADDING)) / self.line_height()).floor() as usize).max(1),
+        })
+    }
+
+    /// Move the caret a number of lines up or down, keeping it as close as possible to where it
+    /// was before the first move, and retrieve that distance in pixels from the start of the line
+    fn move_lines(&mut self, ui: &Ui, rows: isize, select: bool) -> Result<Option<f32>, Error> {
+        let width = match self.area(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some((_, _, val, _)) => val,
+        };
+
+        let lines = self.lines(&self.edit.text(), width);
+        let row = line_of(&lines, self.edit.caret());
+        let goal = match self.goal {
+            None => self.offset(&self.edit.text(), &lines[row], self.edit.caret()),
+            Some(val) => val,
+        };
+
+        let target = row as isize + rows;
+        let position = if target < 0 {
+            0
+        } else if target as usize >= lines.len() {
+            self.edit.text().len()
+        } else {
+            self.position_in(&lines, target as usize, goal)
+        };
+
+        self.edit.set_caret(position, select);
+
+        Ok(Some(goal))
+    }
+
+    /// Move the caret to the start of the line as shown
+    fn move_line_start(&mut self, ui: &Ui, select: bool) -> Result<(), Error> {
+        if let Some((_, _, width, _)) = self.area(ui)? {
+            let lines = self.lines(&self.edit.text(), width);
+            let row = line_of(&lines, self.edit.caret());
+            self.edit.set_caret(lines[row].start, select);
+        }
+
+        Ok(())
+    }
+
+    /// Move the caret to the end of the line as shown
+    fn move_line_end(&mut self, ui: &Ui, select: bool) -> Result<(), Error> {
+        if let Some((_, _, width, _)) = self.area(ui)? {
+            let lines = self.lines(&self.edit.text(), width);
+            let row = line_of(&lines, self.edit.caret());
+            let line = &lines[row];
+            // The end of a wrapped line is shown at the start of the next one
+            let position = match lines.get(row + 1) {
+                Some(next) if next.start == line.end => previous_grapheme(&self.edit.text(), line.end).max(line.start),
+                _ => line.end,
+            };
+            self.edit.set_caret(position, select);
+        }
+
+        Ok(())
+    }
+
+    /// The largest distance in pixels the text can be scrolled to the right and down
+    fn max_scroll(&self, text: &str, width: f32, height: f32) -> (f32, f32) {
+        let lines = self.lines(text, width);
+        let text_width = lines
+            .iter()
+            .map(|line| *line.offsets.last().unwrap())
+            .fold(0.0, f32::max);
+        let text_height = lines.len() as f32 * self.line_height();
+
+        (
+            (text_width - (width - (2.0 * PADDING))).max(0.0),
+            (text_height - (height - (2.0 * PADDING))).max(0.0),
+        )
+    }
+
+    /// Scroll the text by a distance in pixels, returning whether it moved
+    fn scroll_by(&mut self, ui: &Ui, x: f32, y: f32) -> Result<bool, Error> {
+        let (width, height) = match self.area(ui)? {
+            None => {
+                return Ok(false);
+            }
+            Some((_, _, width, height)) => (width, height),
+        };
+
+        let max_scroll = self.max_scroll(&self.edit.text(), width, height);
+        let scroll = (
+            if self.wrap {
+                0.0
+            } else {
+                (self.scroll.0 + x).min(max_scroll.0).max(0.0)
+            },
+            (self.scroll.1 + y).min(max_scroll.1).max(0.0),
+        );
+
+        let moved = scroll != self.scroll;
+        self.scroll = scroll;
+        Ok(moved)
+    }
+
+    /// Scroll the text just enough for the caret to be shown
+    fn scroll_to_caret(&mut self, ui: &Ui) -> Result<(), Error> {
+        let (width, height) = match self.area(ui)? {
+            None => {
+                return Ok(());
+            }
+            Some((_, _, width, height)) => (width, height),
+        };
+
+        let (text, caret, _) = self.edit.composed();
+        let lines = self.lines(&text, width);
+        let line_height = self.line_height();
+        let row = line_of(&lines, caret);
+        let caret = (self.offset(&text, &lines[row], caret), row as f32 * line_height);
+        let inner = (width - (2.0 * PADDING), height - (2.0 * PADDING));
+
+        if caret.0 - self.scroll.0 > inner.0 {
+            self.scroll.0 = caret.0 - inner.0;
+        } else if caret.0 < self.scroll.0 {
+            self.scroll.0 = caret.0;
+        }
+        if (caret.1 + line_height) - self.scroll.1 > inner.1 {
+            self.scroll.1 = (caret.1 + line_height) - inner.1;
+        } else if caret.1 < self.scroll.1 {
+            self.scroll.1 = caret.1;
+        }
+
+        let max_scroll = self.max_scroll(&text, width, height);
+        self.scroll = (
+            self.scroll.0.min(max_scroll.0).max(0.0),
+            self.scroll.1.min(max_scroll.1).max(0.0),
+        );
+
+        Ok(())
+    }
+}
+
+/// The line a position is shown on, the end of a wrapped line being the start of the next one
+fn line_of(lines: &[Line], position: usize) -> usize {
+    lines.iter().rposition(|line| line.start <= position).unwrap_or(0)
+}
+
+/// The builder for the TextArea widget
+#[derive(Clone, Default)]
+pub struct TextAreaBuilder {
+    id:                Id,
+    size:              Size,
+    position:          Position,
+    color:             String,
+    text_color:        String,
+    placeholder_color: String,
+    selection_color:   String,
+    font:              Option<Font>,
+    text_size:         Option<f32>,
+    text:              String,
+    placeholder:       String,
+    wrap:              bool,
+    max_length:        Option<usize>,
+    on_change:         Option<TextCallback>,
+    parent_id:         Id,
+    visible:           bool,
+    opacity:           f32,
+    z_index:           i32,
+    layer:             Layer,
+    border_color:      String,
+    border_width:      Option<f32>,
+    corner_radius:     Option<[f32; 4]>,
+}
+
+impl TextAreaBuilder {
+    /// Initialize the builder for the TextArea widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            wrap: true,
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the TextArea widget from another TextArea widget
+    pub fn new_from_text_area(text_area: &TextArea) -> Self {
+        Self {
+            id:                text_area.id(),
+            size:              text_area.size(),
+            position:          text_area.position(),
+            color:             text_area.fill().into_string(),
+            text_color:        text_area.text_color.into_hex(),
+            placeholder_color: text_area.placeholder_color.into_hex(),
+            selection_color:   text_area.selection_color.into_hex(),
+            font:              text_area.font(),
+            text_size:         Some(text_area.text_size()),
+            text:              text_area.text(),
+            placeholder:       text_area.placeholder(),
+            wrap:              text_area.wrap(),
+            max_length:        text_area.edit().max_length(),
+            on_change:         text_area.on_change.clone(),
+            parent_id:         text_area.parent_id().unwrap(),
+            visible:           text_area.visible(),
+            opacity:           text_area.opacity(),
+            z_index:           text_area.z_index(),
+            layer:             text_area.layer(),
+            border_color:      text_area.border().color().into_hex(),
+            border_width:      Some(text_area.border().width()),
+            corner_radius:     Some(text_area.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the text color
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the placeholder text
+    pub fn with_placeholder_color<V: Into<String>>(&mut self, placeholder_color: V) -> &mut Self {
+        self.placeholder_color = placeholder_color.into();
+        self
+    }
+
+    /// Set the color behind the selected text
+    pub fn with_selection_color<V: Into<String>>(&mut self, selection_color: V) -> &mut Self {
+        self.selection_color = selection_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the text
+    pub fn with_text<V: Into<String>>(&mut self, text: V) -> &mut Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Set the text shown while the text area is empty
+    pub fn with_placeholder<V: Into<String>>(&mut self, placeholder: V) -> &mut Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Wrap the lines longer than the text area, the default, or scroll sideways to show them
+    pub fn with_wrap(&mut self, wrap: bool) -> &mut Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set the maximum number of graphemes
+    pub fn with_max_length(&mut self, max_length: usize) -> &mut Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the callback called with the text whenever it is edited
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the TextArea widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<TextArea>, Error> {
+        let fill = if self.color.is_empty() {
+            ui.theme().text_input_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = if self.text_color.is_empty() {
+            ui.theme().text_input_text_color()?
+        } else {
+            Color::from_hex(self.clone().text_color)?
+        };
+
+        let placeholder_color = if self.placeholder_color.is_empty() {
+            ui.theme().placeholder_color()?
+        } else {
+            Color::from_hex(self.clone().placeholder_color)?
+        };
+
+        let selection_color = if self.selection_color.is_empty() {
+            ui.theme().selection_color()?
+        } else {
+            Color::from_hex(self.clone().selection_color)?
+        };
+
+        let font = match self.font {
+            None => ui.theme().font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let mut edit = TextEdit::default();
+        edit.set_max_length(self.max_length);
+        edit.set_text(
+            self.text
+                .replace("\r\n", "\n")
+                .replace(|c: char| c.is_control() & (c != '\n') & (c != '\t'), ""),
+        );
+
+        let widget = Box::new(TextArea {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color,
+            placeholder_color,
+            selection_color,
+            font,
+            text_size,
+            edit,
+            placeholder: self.clone().placeholder,
+            wrap: self.wrap,
+            on_change: self.clone().on_change,
+            scroll: (0.0, 0.0),
+            goal: None,
+            dragging: false,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
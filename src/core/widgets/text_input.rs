@@ -0,0 +1,749 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The character shown in place of each character of a password
+const MASK: char = '•';
+
+/// The callback given the text of a text widget
+pub type TextCallback = Arc<Fn(&str) + Send + Sync>;
+
+/// The TextInput Widget, a single line of editable text
+#[derive(Clone)]
+pub struct TextInput {
+    id:                Id,
+    parent_id:         Id,
+    size:              Size,
+    position:          Position,
+    fill:              Fill,
+    text_color:        Color,
+    placeholder_color: Color,
+    selection_color:   Color,
+    font:              Option<Font>,
+    text_size:         f32,
+    edit:              TextEdit,
+    placeholder:       String,
+    password:          bool,
+    on_change:         Option<TextCallback>,
+    on_submit:         Option<TextCallback>,
+    scroll:            f32,
+    dragging:          bool,
+    text:              Arc<TextureCache>,
+    visible:           bool,
+    opacity:           f32,
+    z_index:           i32,
+    layer:             Layer,
+    border:            Border,
+    corner_radius:     CornerRadius,
+    shadow:            Shadow,
+}
+
+impl WidgetTrait for TextInput {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::TextInput
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::Composition { ref text, caret } => {
+                self.edit.set_composition(text.as_str(), caret);
+            }
+            InputEvent::Commit(ref text) => {
+                if self.edit.commit(text) {
+                    self.changed();
+                }
+            }
+            // The keys belong to the input method while it composes
+            InputEvent::Character(_) | InputEvent::KeyPressed { .. } if self.edit.composing() => {}
+            InputEvent::MousePressed {
+                x,
+                button: MouseButton::Left,
+                modifiers,
+                ..
+            } => {
+                let position = self.position_at(ui, x)?;
+                self.edit.set_caret(position, modifiers.shift);
+                self.dragging = true;
+            }
+            InputEvent::CursorMoved { x, .. } if self.dragging => {
+                let position = self.position_at(ui, x)?;
+                self.edit.set_caret(position, true);
+            }
+            InputEvent::MouseReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.dragging = false;
+            }
+            InputEvent::Character(c) if !c.is_control() => {
+                if self.edit.insert(&c.to_string()) {
+                    self.changed();
+                }
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                let command = modifiers.ctrl | modifiers.logo;
+                match key {
+                    // Passwords are never copied out of the widget
+                    VirtualKeyCode::C | VirtualKeyCode::X if command && self.password => {}
+                    VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                        if let Some(ref on_submit) = self.on_submit {
+                            on_submit(&self.edit.text());
+                        }
+                    }
+                    _ => match self.edit.handle_key(ui, key, modifiers, false) {
+                        None => {
+                            return Ok(false);
+                        }
+                        Some(changed) => {
+                            if changed {
+                                self.changed();
+                            }
+                        }
+                    },
+                }
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        self.scroll_to_caret(ui)?;
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        let font = match self.font {
+            Some(ref val) if (window_size[0] != 0) & (window_size[1] != 0) => val,
+            _ => {
+                self.text.clear();
+                return Ok(vertices);
+            }
+        };
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let line_height = font.measure("", self.text_size).1;
+        let top = (painter.size().1 - line_height) / 2.0;
+        let left = PADDING - self.scroll;
+        let (text, caret, composition) = self.edit.composed();
+        let offsets = self.offsets(&text);
+        let focused = ui.focused() == Some(self.id());
+
+        if let (true, None, Some((start, end))) = (focused, composition, self.edit.selection()) {
+            let start = offsets[char_index(&text, start)];
+            let end = offsets[char_index(&text, end)];
+            painter.rect((left + start, top), (end - start, line_height), self.selection_color);
+        }
+
+        // The composed text is underlined
+        if let Some((start, end)) = composition {
+            let start = offsets[char_index(&text, start)];
+            let end = offsets[char_index(&text, end)];
+            painter.rect(
+                (left + start, top + line_height - 1.0),
+                (end - start, 1.0),
+                self.text_color,
+            );
+        }
+
+        let shown = self.display_text(&text);
+        if shown.is_empty() {
+            painter.text(
+                (left, top),
+                &self.placeholder,
+                self.text_size,
+                self.placeholder_color,
+                font,
+            );
+        } else {
+            painter.text((left, top), &shown, self.text_size, self.text_color, font);
+        }
+
+        if focused {
+            let caret = offsets[char_index(&text, caret)];
+            painter.rect((left + caret, top), (1.0, line_height), self.text_color);
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl TextInput {
+    /// Retrieve the text
+    pub fn text(&self) -> String {
+        self.edit.text()
+    }
+
+    /// Replace the text, moving the caret to its end
+    pub fn set_text<V: Into<String>>(&mut self, text: V) {
+        self.edit.set_text(text);
+        self.scroll = 0.0;
+    }
+
+    /// Retrieve the text being edited, along with the caret and the selection
+    pub fn edit(&self) -> &TextEdit {
+        &self.edit
+    }
+
+    /// Retrieve the text shown while the text input is empty
+    pub fn placeholder(&self) -> String {
+        self.placeholder.clone()
+    }
+
+    /// Set the text shown while the text input is empty
+    pub fn set_placeholder<V: Into<String>>(&mut self, placeholder: V) {
+        self.placeholder = placeholder.into();
+    }
+
+    /// Whether every character is masked
+    pub fn password(&self) -> bool {
+        self.password
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the text whenever it is edited
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    /// Set the callback called with the text when enter is pressed
+    pub fn set_on_submit<F>(&mut self, on_submit: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_submit = Some(Arc::new(on_submit));
+    }
+
+    fn changed(&self) {
+        if let Some(ref on_change) = self.on_change {
+            on_change(&self.edit.text());
+        }
+    }
+
+    fn display_text(&self, text: &str) -> String {
+        if self.password {
+            text.chars().map(|_| MASK).collect()
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// The distance in pixels from the start of a text to each character, followed by its width
+    fn offsets(&self, text: &str) -> Vec<f32> {
+        match self.font {
+            None => vec![0.0; text.chars().count() + 1],
+            Some(ref font) => font.offsets(&self.display_text(text), self.text_size),
+        }
+    }
+
+    /// The position in the text closest to a horizontal position in pixels in the window
+    fn position_at(&self, ui: &Ui, x: f32) -> Result<usize, Error> {
+        let bl_vertex = match self.bounds(ui)? {
+            None => {
+                return Ok(self.edit.caret());
+            }
+            Some((val, _)) => val,
+        };
+
+        let x = (x - ((bl_vertex.x() * ui.window_size()[0] as f32) / 2.0)) - PADDING + self.scroll;
+        let index = self
+            .offsets(&self.edit.text())
+            .iter()
+            .enumerate()
+            .min_by(|a, b| (a.1 - x).abs().partial_cmp(&(b.1 - x).abs()).unwrap())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        let text = self.edit.text();
+        Ok(text
+            .char_indices()
+            .nth(index)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| text.len()))
+    }
+
+    /// Scroll the text just enough for the caret to be shown
+    fn scroll_to_caret(&mut self, ui: &Ui) -> Result<(), Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(());
+            }
+            Some(val) => val,
+        };
+
+        let width = (((tr_vertex.x() - bl_vertex.x()) * ui.window_size()[0] as f32) / 2.0) - (2.0 * PADDING);
+        let (text, caret, _) = self.edit.composed();
+        let offsets = self.offsets(&text);
+        let caret = offsets[char_index(&text, caret)];
+        let text_width = *offsets.last().unwrap();
+
+        if caret - self.scroll > width {
+            self.scroll = caret - width;
+        } else if caret < self.scroll {
+            self.scroll = caret;
+        }
+        self.scroll = self.scroll.min(text_width - width).max(0.0);
+
+        Ok(())
+    }
+}
+
+/// The number of characters before a position in a text
+pub(crate) fn char_index(text: &str, position: usize) -> usize {
+    text[..position].chars().count()
+}
+
+/// The builder for the TextInput widget
+#[derive(Clone, Default)]
+pub struct TextInputBuilder {
+    id:                Id,
+    size:              Size,
+    position:          Position,
+    color:             String,
+    text_color:        String,
+    placeholder_color: String,
+    selection_color:   String,
+    font:              Option<Font>,
+    text_size:         Option<f32>,
+    text:              String,
+    placeholder:       String,
+    password:          bool,
+    max_length:        Option<usize>,
+    on_change:         Option<TextCallback>,
+    on_submit:         Option<TextCallback>,
+    parent_id:         Id,
+    visible:           bool,
+    opacity:           f32,
+    z_index:           i32,
+    layer:             Layer,
+    border_color:      String,
+    border_width:      Option<f32>,
+    corner_radius:     Option<[f32; 4]>,
+}
+
+impl TextInputBuilder {
+    /// Initialize the builder for the TextInput widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the TextInput widget from another TextInput widget
+    pub fn new_from_text_input(text_input: &TextInput) -> Self {
+        Self {
+            id:                text_input.id(),
+            size:              text_input.size(),
+            position:          text_input.position(),
+            color:             text_input.fill().into_string(),
+            text_color:        text_input.text_color.into_hex(),
+            placeholder_color: text_input.placeholder_color.into_hex(),
+            selection_color:   text_input.selection_color.into_hex(),
+            font:              text_input.font(),
+            text_size:         Some(text_input.text_size()),
+            text:              text_input.text(),
+            placeholder:       text_input.placeholder(),
+            password:          text_input.password(),
+            max_length:        text_input.edit().max_length(),
+            on_change:         text_input.on_change.clone(),
+            on_submit:         text_input.on_submit.clone(),
+            parent_id:         text_input.parent_id().unwrap(),
+            visible:           text_input.visible(),
+            opacity:           text_input.opacity(),
+            z_index:           text_input.z_index(),
+            layer:             text_input.layer(),
+            border_color:      text_input.border().color().into_hex(),
+            border_width:      Some(text_input.border().width()),
+            corner_radius:     Some(text_input.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the text color
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the placeholder text
+    pub fn with_placeholder_color<V: Into<String>>(&mut self, placeholder_color: V) -> &mut Self {
+        self.placeholder_color = placeholder_color.into();
+        self
+    }
+
+    /// Set the color behind the selected text
+    pub fn with_selection_color<V: Into<String>>(&mut self, selection_color: V) -> &mut Self {
+        self.selection_color = selection_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the text
+    pub fn with_text<V: Into<String>>(&mut self, text: V) -> &mut Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Set the text shown while the text input is empty
+    pub fn with_placeholder<V: Into<String>>(&mut self, placeholder: V) -> &mut Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Mask every character, the text can't be copied or cut either
+    pub fn with_password(&mut self, password: bool) -> &mut Self {
+        self.password = password;
+        self
+    }
+
+    /// Set the maximum number of graphemes
+    pub fn with_max_length(&mut self, max_length: usize) -> &mut Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the callback called with the text whenever it is edited
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the callback called with the text when enter is pressed
+    pub fn with_on_submit<F>(&mut self, on_submit: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_submit = Some(Arc::new(on_submit));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the TextInput widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<TextInput>, Error> {
+        let fill = if self.color.is_empty() {
+            ui.theme().text_input_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = if self.text_color.is_empty() {
+            ui.theme().text_input_text_color()?
+        } else {
+            Color::from_hex(self.clone().text_color)?
+        };
+
+        let placeholder_color = if self.placeholder_color.is_empty() {
+            ui.theme().placeholder_color()?
+        } else {
+            Color::from_hex(self.clone().placeholder_color)?
+        };
+
+        let selection_color = if self.selection_color.is_empty() {
+            ui.theme().selection_color()?
+        } else {
+            Color::from_hex(self.clone().selection_color)?
+        };
+
+        let font = match self.font {
+            None => ui.theme().font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let mut edit = TextEdit::default();
+        edit.set_max_length(self.max_length);
+        edit.set_text(self.text.replace(|c: char| c.is_control(), ""));
+
+        let widget = Box::new(TextInput {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color,
+            placeholder_color,
+            selection_color,
+            font,
+            text_size,
+            edit,
+            placeholder: self.clone().placeholder,
+            password: self.password,
+            on_change: self.clone().on_change,
+            on_submit: self.clone().on_submit,
+            scroll: 0.0,
+            dragging: false,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
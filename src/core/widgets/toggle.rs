@@ -0,0 +1,632 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    checkbox::{
+        contains,
+        indicator_size,
+        CheckColors,
+        SPACING,
+    },
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use std::sync::Arc;
+
+/// The callback given whether a toggle is on
+pub type ToggleCallback = Arc<Fn(bool) + Send + Sync>;
+
+/// The Toggle Widget, a switch turned on or off when clicked
+#[derive(Clone)]
+pub struct Toggle {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    text_color:      Color,
+    checked_color:   Color,
+    unchecked_color: Color,
+    disabled_color:  Color,
+    focus_color:     Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    label:           String,
+    on:              bool,
+    enabled:         bool,
+    pressed:         bool,
+    on_change:       Option<ToggleCallback>,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for Toggle {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Toggle
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        if !self.enabled {
+            return Ok(false);
+        }
+
+        match *event {
+            InputEvent::MousePressed {
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.pressed = true;
+            }
+            InputEvent::MouseReleased {
+                x,
+                y,
+                button: MouseButton::Left,
+                ..
+            } => {
+                // The press is cancelled by releasing the button away from the toggle
+                if self.pressed & contains(ui, self.bounds(ui)?, x, y) {
+                    self.toggle();
+                }
+                self.pressed = false;
+            }
+            InputEvent::KeyPressed {
+                key: VirtualKeyCode::Space,
+                ..
+            } => {
+                self.toggle();
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        // The track is twice as wide as the knob, with round ends
+        let size = indicator_size(painter.size());
+        let radius = size / 2.0;
+        let (left, middle) = (PADDING, painter.size().1 / 2.0);
+
+        let track_color = match (self.enabled, self.on) {
+            (false, _) => self.disabled_color,
+            (true, false) => self.unchecked_color,
+            (true, true) => self.checked_color,
+        };
+
+        painter.circle((left + radius, middle), radius, track_color);
+        painter.circle((left + size + radius, middle), radius, track_color);
+        painter.rect((left + radius, middle - radius), (size, size), track_color);
+
+        let knob = if self.on { left + size + radius } else { left + radius };
+        painter.circle((knob, middle), (radius - 2.0).max(0.0), self.text_color);
+
+        if ui.focused() == Some(self.id()) {
+            painter.stroke_rect(
+                (left - 2.0, middle - radius - 2.0),
+                ((2.0 * size) + 4.0, size + 4.0),
+                1.0,
+                self.focus_color,
+            );
+        }
+
+        if let Some(ref font) = self.font {
+            let line_height = font.measure("", self.text_size).1;
+            painter.text(
+                (left + (2.0 * size) + SPACING, middle - (line_height / 2.0)),
+                &self.label,
+                self.text_size,
+                self.label_color(),
+                font,
+            );
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Toggle {
+    /// Whether the toggle is on
+    pub fn on(&self) -> bool {
+        self.on
+    }
+
+    /// Turn the toggle on or off without calling the change callback
+    pub fn set_on(&mut self, on: bool) {
+        self.on = on;
+    }
+
+    /// Retrieve the label
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    /// Set the label
+    pub fn set_label<V: Into<String>>(&mut self, label: V) {
+        self.label = label.into();
+    }
+
+    /// Whether the toggle can be focused and clicked
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable the toggle
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.pressed = false;
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with whether the toggle is on whenever it is clicked
+    pub fn set_on_change<F>(&mut self, on_change: F)
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+    }
+
+    fn toggle(&mut self) {
+        self.on = !self.on;
+
+        if let Some(ref on_change) = self.on_change {
+            on_change(self.on);
+        }
+    }
+
+    fn label_color(&self) -> Color {
+        if self.enabled {
+            self.text_color
+        } else {
+            self.disabled_color
+        }
+    }
+}
+
+/// The builder for the Toggle widget
+#[derive(Clone, Default)]
+pub struct ToggleBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    text_color:      String,
+    checked_color:   String,
+    unchecked_color: String,
+    disabled_color:  String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    label:           String,
+    on:              bool,
+    disabled:        bool,
+    on_change:       Option<ToggleCallback>,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+    elevation:       Option<f32>,
+}
+
+impl ToggleBuilder {
+    /// Initialize the builder for the Toggle widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Toggle widget from another Toggle widget
+    pub fn new_from_toggle(toggle: &Toggle) -> Self {
+        Self {
+            id:              toggle.id(),
+            size:            toggle.size(),
+            position:        toggle.position(),
+            color:           toggle.fill().into_string(),
+            text_color:      toggle.text_color.into_hex(),
+            checked_color:   toggle.checked_color.into_hex(),
+            unchecked_color: toggle.unchecked_color.into_hex(),
+            disabled_color:  toggle.disabled_color.into_hex(),
+            font:            toggle.font(),
+            text_size:       Some(toggle.text_size()),
+            label:           toggle.label(),
+            on:              toggle.on(),
+            disabled:        !toggle.enabled(),
+            on_change:       toggle.on_change.clone(),
+            parent_id:       toggle.parent_id().unwrap(),
+            visible:         toggle.visible(),
+            opacity:         toggle.opacity(),
+            z_index:         toggle.z_index(),
+            layer:           toggle.layer(),
+            border_color:    toggle.border().color().into_hex(),
+            border_width:    Some(toggle.border().width()),
+            corner_radius:   Some(toggle.corner_radius().as_array()),
+            elevation:       Some(toggle.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the label and the knob
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the track while on
+    pub fn with_checked_color<V: Into<String>>(&mut self, checked_color: V) -> &mut Self {
+        self.checked_color = checked_color.into();
+        self
+    }
+
+    /// Set the color of the track while off
+    pub fn with_unchecked_color<V: Into<String>>(&mut self, unchecked_color: V) -> &mut Self {
+        self.unchecked_color = unchecked_color.into();
+        self
+    }
+
+    /// Set the color of the track and the label while disabled
+    pub fn with_disabled_color<V: Into<String>>(&mut self, disabled_color: V) -> &mut Self {
+        self.disabled_color = disabled_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the label drawn next to the track
+    pub fn with_label<V: Into<String>>(&mut self, label: V) -> &mut Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Turn the toggle on or off
+    pub fn with_on(&mut self, on: bool) -> &mut Self {
+        self.on = on;
+        self
+    }
+
+    /// Disable the toggle, it is then neither focused nor clicked
+    pub fn with_disabled(&mut self, disabled: bool) -> &mut Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the callback called with whether the toggle is on whenever it is clicked
+    pub fn with_on_change<F>(&mut self, on_change: F) -> &mut Self
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the Toggle widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Toggle>, Error> {
+        let colors = CheckColors::resolve(
+            ui,
+            &self.text_color,
+            &self.checked_color,
+            &self.unchecked_color,
+            &self.disabled_color,
+        )?;
+
+        let fill = if self.color.is_empty() {
+            ui.theme().check_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let font = match self.font {
+            None => ui.theme().font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => Shadow::default(),
+            Some(val) => Shadow::from_elevation(val, ui.theme().shadow_color()?)?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Toggle {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color: colors.text,
+            checked_color: colors.checked,
+            unchecked_color: colors.unchecked,
+            disabled_color: colors.disabled,
+            focus_color: colors.focus,
+            font,
+            text_size,
+            label: self.clone().label,
+            on: self.on,
+            enabled: !self.disabled,
+            pressed: false,
+            on_change: self.clone().on_change,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
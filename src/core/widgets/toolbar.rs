@@ -13,15 +13,22 @@ use crate::prelude::*;
 /// The Toolbar Widget
 #[derive(Clone)]
 pub struct Toolbar {
-    id:          Id,
-    parent_id:   Id,
-    size:        Size,
-    position:    Position,
-    color:       Color,
-    visible:     bool,
-    thickness:   DockSize,
-    length:      f32,
-    orientation: Orientation,
+    id:            Id,
+    parent_id:     Id,
+    size:          Size,
+    position:      Position,
+    fill:          Fill,
+    visible:       bool,
+    opacity:       f32,
+    overflow:      Overflow,
+    z_index:       i32,
+    layer:         Layer,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+    thickness:     DockSize,
+    length:        f32,
+    orientation:   Orientation,
 }
 
 impl WidgetTrait for Toolbar {
@@ -50,7 +57,11 @@ impl WidgetTrait for Toolbar {
     }
 
     fn color(&self) -> Color {
-        self.color
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
     }
 
     fn visible(&self) -> bool {
@@ -64,6 +75,42 @@ impl WidgetTrait for Toolbar {
     fn hide(&mut self) {
         self.visible = false;
     }
+
+    fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
 }
 
 impl DockTrait for Toolbar {
@@ -83,12 +130,23 @@ impl DockTrait for Toolbar {
 /// The builder for the Toolbar widget
 #[derive(Clone, Default)]
 pub struct ToolbarBuilder {
-    id:          Id,
-    thickness:   DockSize,
-    orientation: Orientation,
-    color:       String,
-    parent_id:   Id,
-    visible:     bool,
+    id:                 Id,
+    thickness:          DockSize,
+    orientation:        Orientation,
+    color:              String,
+    parent_id:          Id,
+    visible:            bool,
+    opacity:            f32,
+    overflow:           Overflow,
+    z_index:            i32,
+    layer:              Layer,
+    border_color:       String,
+    border_width:       Option<f32>,
+    corner_radius:      Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<(f32, f32)>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       String,
 }
 
 impl ToolbarBuilder {
@@ -101,6 +159,7 @@ impl ToolbarBuilder {
             id: id.into(),
             parent_id: parent_id.into(),
             visible: true,
+            opacity: 1.0,
             ..Self::default()
         }
     }
@@ -108,12 +167,23 @@ impl ToolbarBuilder {
     /// Initialize the builder for the Bar widget from another Bar widget
     pub fn new_from_toolbar(toolbar: &Toolbar) -> Self {
         Self {
-            id:          toolbar.id(),
-            thickness:   toolbar.thickness(),
-            orientation: toolbar.orientation(),
-            color:       toolbar.color().into_hex(),
-            parent_id:   toolbar.parent_id().unwrap(),
-            visible:     toolbar.visible(),
+            id:                 toolbar.id(),
+            thickness:          toolbar.thickness(),
+            orientation:        toolbar.orientation(),
+            color:              toolbar.fill().into_string(),
+            parent_id:          toolbar.parent_id().unwrap(),
+            visible:            toolbar.visible(),
+            opacity:            toolbar.opacity(),
+            overflow:           toolbar.overflow(),
+            z_index:            toolbar.z_index(),
+            layer:              toolbar.layer(),
+            border_color:       toolbar.border().color().into_hex(),
+            border_width:       Some(toolbar.border().width()),
+            corner_radius:      Some(toolbar.corner_radius().as_array()),
+            elevation:          None,
+            shadow_offset:      Some(toolbar.shadow().offset()),
+            shadow_blur_radius: Some(toolbar.shadow().blur_radius()),
+            shadow_color:       toolbar.shadow().color().into_hex(),
         }
     }
 
@@ -123,7 +193,7 @@ impl ToolbarBuilder {
         self
     }
 
-    /// Set the color
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
     pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
         self.color = color.into();
         self
@@ -135,6 +205,90 @@ impl ToolbarBuilder {
         self
     }
 
+    /// Set how the children are clipped
+    pub fn with_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Set the offset in pixels of the shadow
+    pub fn with_shadow_offset(&mut self, x: f32, y: f32) -> &mut Self {
+        self.shadow_offset = Some((x, y));
+        self
+    }
+
+    /// Set the blur radius in pixels of the shadow
+    pub fn with_shadow_blur_radius(&mut self, blur_radius: f32) -> &mut Self {
+        self.shadow_blur_radius = Some(blur_radius);
+        self
+    }
+
+    /// Set the shadow color
+    pub fn with_shadow_color<V: Into<String>>(&mut self, shadow_color: V) -> &mut Self {
+        self.shadow_color = shadow_color.into();
+        self
+    }
+
     /// Build the Bar widget
     pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Toolbar>, Error> {
         let thickness = self.thickness;
@@ -147,19 +301,65 @@ impl ToolbarBuilder {
             Orientation::Right => (Size::Size(thickness.into_f32(), length), Position::Right),
         };
 
-        let color = if self.color.is_empty() {
-            ui.theme().bar_color()?
+        let fill = if self.color.is_empty() {
+            ui.theme().bar_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let border_color = if self.border_color.is_empty() {
+            ui.theme().border_color()?
+        } else {
+            Color::from_hex(self.clone().border_color)?
+        };
+
+        let border_width = match self.border_width {
+            None => ui.theme().border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => ui.theme().corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => ui.theme().bar_shadow()?,
+            Some(val) => Shadow::from_elevation(val, ui.theme().bar_shadow()?.color())?,
+        };
+
+        let shadow_color = if self.shadow_color.is_empty() {
+            shadow.color()
         } else {
-            Color::from_hex(self.clone().color)?
+            Color::from_hex(self.clone().shadow_color)?
         };
 
+        let shadow = Shadow::new(
+            self.shadow_offset.unwrap_or_else(|| shadow.offset()),
+            self.shadow_blur_radius.unwrap_or_else(|| shadow.blur_radius()),
+            shadow_color,
+        )?;
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
         let widget = Box::new(Toolbar {
             id: self.clone().id,
             parent_id: self.clone().parent_id,
             position,
             size,
-            color,
+            fill,
             visible: self.visible,
+            opacity: self.opacity,
+            overflow: self.overflow,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow,
             thickness,
             length,
             orientation,
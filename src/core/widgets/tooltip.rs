@@ -0,0 +1,555 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+/// The time in milliseconds the cursor stays over a widget before its tooltip is shown
+const DELAY: u64 = 500;
+/// The distance in pixels from the cursor to the top left corner of the tooltip
+const OFFSET: (f32, f32) = (12.0, 20.0);
+/// The widest a text tooltip gets in pixels before its text is wrapped
+const MAX_WIDTH: f32 = 320.0;
+
+/// The Tooltip Widget, a text or the children of the tooltip shown near the cursor once it stayed
+/// over one of the widgets the tooltip is attached to for a delay. It is drawn in the overlay
+/// layer, moved away from the edges of the window, and hidden when the cursor leaves the widget or
+/// a mouse button is pressed. The children are laid out over the whole tooltip.
+#[derive(Clone)]
+pub struct Tooltip {
+    id:            Id,
+    parent_id:     Id,
+    fill:          Fill,
+    text_color:    Color,
+    font:          Option<Font>,
+    text_size:     f32,
+    message:       String,
+    content_size:  Option<(f32, f32)>,
+    delay:         Duration,
+    text:          Arc<TextureCache>,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    border:        Border,
+    corner_radius: CornerRadius,
+    shadow:        Shadow,
+}
+
+impl WidgetTrait for Tooltip {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::Tooltip
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        Size::Full
+    }
+
+    fn set_size(&mut self, _size: Size) {}
+
+    fn position(&self) -> Position {
+        Position::default()
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        Layer::Overlay
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn bounds(&self, ui: &Ui) -> Result<Option<(Vertex, Vertex)>, Error> {
+        let window_size = ui.window_size();
+        if !self.visible | (window_size[0] == 0) | (window_size[1] == 0) {
+            return Ok(None);
+        }
+
+        let position = match ui.hovered() {
+            Some(ref hover) if (hover.tooltip == self.id) & hover.shown() => hover.position,
+            _ => {
+                return Ok(None);
+            }
+        };
+
+        // The tooltip goes below and to the right of the cursor, or above it at the bottom of the
+        // window
+        let (width, height) = self.dimensions();
+        let (window_width, window_height) = (window_size[0] as f32, window_size[1] as f32);
+        let x = (position.0 + OFFSET.0).min(window_width - width).max(0.0);
+        let y = if position.1 + OFFSET.1 + height > window_height {
+            position.1 - height - PADDING
+        } else {
+            position.1 + OFFSET.1
+        }
+        .max(0.0);
+
+        Ok(Some((
+            Vertex {
+                x: (x * 2.0) / window_width,
+                y: (y * 2.0) / window_height,
+            },
+            Vertex {
+                x: ((x + width) * 2.0) / window_width,
+                y: ((y + height) * 2.0) / window_height,
+            },
+        )))
+    }
+
+    fn child_area(&self, ui: &Ui, _child: &Id, _bl_vertex: Vertex, _tr_vertex: Vertex) -> Option<(Vertex, Vertex)> {
+        // The children are laid out over the tooltip where it is shown, not where its parent is
+        self.bounds(ui).unwrap_or_default()
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                self.text.clear();
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let font = match self.font {
+            Some(ref val) if self.content_size.is_none() => val,
+            _ => {
+                self.text.clear();
+                return Ok(vertices);
+            }
+        };
+
+        let (width, height) = self.dimensions();
+        let mut painter = Painter::new(width, height);
+        let line_height = font.measure("", self.text_size).1;
+        let mut y = PADDING;
+        for (line, start, end) in self.lines() {
+            painter.text((PADDING, y), &line[start..end], self.text_size, self.text_color, font);
+            y += line_height;
+        }
+
+        let window_size = ui.window_size();
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl Tooltip {
+    /// Retrieve the text
+    pub fn text(&self) -> String {
+        self.message.clone()
+    }
+
+    /// Set the text, wrapped when it gets too wide
+    pub fn set_text<V: Into<String>>(&mut self, text: V) {
+        self.message = text.into();
+    }
+
+    /// Retrieve the size in pixels of a tooltip showing its children instead of a text
+    pub fn content_size(&self) -> Option<(f32, f32)> {
+        self.content_size
+    }
+
+    /// Retrieve the time the cursor stays over a widget before the tooltip is shown
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Set the time the cursor stays over a widget before the tooltip is shown
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// The lines of the text wrapped at the widest a tooltip gets, with the start and end of the
+    /// part of each line shown on a row
+    fn lines(&self) -> Vec<(&str, usize, usize)> {
+        let mut lines = Vec::new();
+        for line in self.message.lines() {
+            match self.font {
+                None => lines.push((line, 0, line.len())),
+                Some(ref font) => {
+                    for (start, end) in font.wrap(line, self.text_size, MAX_WIDTH - (2.0 * PADDING)) {
+                        lines.push((line, start, end));
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    /// The size in pixels of the tooltip, fitting its text unless it shows its children
+    fn dimensions(&self) -> (f32, f32) {
+        if let Some(val) = self.content_size {
+            return val;
+        }
+
+        let (width, line_height) = match self.font {
+            None => (0.0, self.text_size),
+            Some(ref font) => (
+                self.lines()
+                    .iter()
+                    .map(|(line, start, end)| font.measure(&line[*start..*end], self.text_size).0)
+                    .fold(0.0, f32::max),
+                font.measure("", self.text_size).1,
+            ),
+        };
+        let rows = self.lines().len().max(1) as f32;
+
+        (width + (2.0 * PADDING), (rows * line_height) + (2.0 * PADDING))
+    }
+}
+
+/// The builder for the Tooltip widget
+#[derive(Clone, Default)]
+pub struct TooltipBuilder {
+    id:            Id,
+    color:         String,
+    text_color:    String,
+    font:          Option<Font>,
+    text_size:     Option<f32>,
+    text:          String,
+    content_size:  Option<(f32, f32)>,
+    delay:         Option<Duration>,
+    targets:       Vec<Id>,
+    parent_id:     Id,
+    visible:       bool,
+    opacity:       f32,
+    z_index:       i32,
+    border_color:  String,
+    border_width:  Option<f32>,
+    corner_radius: Option<[f32; 4]>,
+    elevation:     Option<f32>,
+}
+
+impl TooltipBuilder {
+    /// Initialize the builder for the Tooltip widget, the parent only matters for the opacity
+    pub fn new<V>(id: V, parent_id: V) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the Tooltip widget from another Tooltip widget
+    pub fn new_from_tooltip(tooltip: &Tooltip) -> Self {
+        Self {
+            id:            tooltip.id(),
+            color:         tooltip.fill().into_string(),
+            text_color:    tooltip.text_color.into_hex(),
+            font:          tooltip.font(),
+            text_size:     Some(tooltip.text_size()),
+            text:          tooltip.text(),
+            content_size:  tooltip.content_size(),
+            delay:         Some(tooltip.delay()),
+            targets:       Vec::new(),
+            parent_id:     tooltip.parent_id().unwrap(),
+            visible:       tooltip.visible(),
+            opacity:       tooltip.opacity(),
+            z_index:       tooltip.z_index(),
+            border_color:  tooltip.border().color().into_hex(),
+            border_width:  Some(tooltip.border().width()),
+            corner_radius: Some(tooltip.corner_radius().as_array()),
+            elevation:     Some(tooltip.shadow().offset().1),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the text
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the text
+    pub fn with_text<V: Into<String>>(&mut self, text: V) -> &mut Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Set the size in pixels of the tooltip to show its children, built afterwards with the
+    /// tooltip as their parent, instead of a text
+    pub fn with_content_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.content_size = Some((width, height));
+        self
+    }
+
+    /// Set the time the cursor stays over a widget before the tooltip is shown, half a second by
+    /// default
+    pub fn with_delay(&mut self, delay: Duration) -> &mut Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Attach the tooltip to a widget
+    pub fn with_target<V: Into<Id>>(&mut self, target: V) -> &mut Self {
+        self.targets.push(target.into());
+        self
+    }
+
+    /// Set the visibility, a hidden tooltip is never shown
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a tooltip is drawn over the other widgets of the overlay layer with a lower
+    /// z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Set the elevation in pixels, casting a shadow that drops and softens as the widget rises
+    pub fn with_elevation(&mut self, elevation: f32) -> &mut Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    /// Build the Tooltip widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<Tooltip>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.label_background_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = color(&self.text_color, theme.label_text_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        if let Some((width, height)) = self.content_size {
+            if (width <= 0.0) | (height <= 0.0) {
+                return Err(err_msg("The content size must be greater than 0.0"));
+            }
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        let shadow = match self.elevation {
+            None => theme.tooltip_shadow()?,
+            Some(val) => Shadow::from_elevation(val, theme.tooltip_shadow()?.color())?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let widget = Box::new(Tooltip {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            fill,
+            text_color,
+            font,
+            text_size,
+            message: self.clone().text,
+            content_size: self.content_size,
+            delay: self.delay.unwrap_or_else(|| Duration::from_millis(DELAY)),
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            border,
+            corner_radius,
+            shadow,
+        });
+
+        ui.add_widget(widget.clone());
+        for target in &self.targets {
+            ui.set_tooltip(target.clone(), Some(self.id.clone()));
+        }
+
+        Ok(widget)
+    }
+}
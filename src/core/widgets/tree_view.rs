@@ -0,0 +1,1239 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use self::super::{
+    list_view::DOUBLE_CLICK,
+    PADDING,
+    TEXT_SIZE,
+};
+use crate::{
+    core::painter::TextureCache,
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The distance in pixels each level of the tree is indented by when none is set
+const INDENT: f32 = 16.0;
+/// The size in pixels of the arrow expanding a node
+const ARROW: f32 = 8.0;
+
+/// The callback given the path of a node
+pub type PathCallback = Arc<Fn(&[usize]) + Send + Sync>;
+
+/// The callback given the paths of the selected nodes of a tree
+pub type PathSelectionCallback = Arc<Fn(&[Vec<usize>]) + Send + Sync>;
+
+/// The callback given the path of a node expanded or collapsed, and whether it is now expanded
+pub type ExpandCallback = Arc<Fn(&[usize], bool) + Send + Sync>;
+
+/// A node of a tree known in advance, a list of nodes being the model of a tree view
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeNode {
+    text:     String,
+    children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    /// Create a node without children
+    pub fn new<V: Into<String>>(text: V) -> Self {
+        Self {
+            text:     text.into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Add a child
+    pub fn with_child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Retrieve the text
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Retrieve the children
+    pub fn children(&self) -> &[TreeNode] {
+        &self.children
+    }
+}
+
+impl TreeModel for Vec<TreeNode> {
+    fn children(&self, path: &[usize]) -> usize {
+        match path.split_last() {
+            None => self.len(),
+            Some(_) => node(self, path).map_or(0, |node| node.children.len()),
+        }
+    }
+
+    fn text(&self, path: &[usize]) -> String {
+        node(self, path).map(TreeNode::text).unwrap_or_default()
+    }
+}
+
+/// The node of a list of nodes at a path
+fn node<'a>(nodes: &'a [TreeNode], path: &[usize]) -> Option<&'a TreeNode> {
+    let (first, rest) = path.split_first()?;
+    let mut node = nodes.get(*first)?;
+    for &index in rest {
+        node = node.children.get(index)?;
+    }
+    Some(node)
+}
+
+/// A node shown by the tree view, bound to another node once scrolled out of view
+struct Row {
+    index:        usize,
+    path:         Vec<usize>,
+    text:         String,
+    has_children: bool,
+}
+
+/// The TreeView Widget, the nodes of a model in a tree of expandable nodes. The children of a
+/// node are only asked from the model once it is expanded, and only the nodes in view are drawn.
+#[derive(Clone)]
+pub struct TreeView {
+    id:              Id,
+    parent_id:       Id,
+    size:            Size,
+    position:        Position,
+    fill:            Fill,
+    text_color:      Color,
+    guide_color:     Color,
+    selection_color: Color,
+    focus_color:     Color,
+    font:            Option<Font>,
+    text_size:       f32,
+    row_height:      f32,
+    indent:          f32,
+    model:           Arc<TreeModel>,
+    children:        Arc<Mutex<HashMap<Vec<usize>, usize>>>,
+    expanded:        BTreeSet<Vec<usize>>,
+    nodes:           Arc<Vec<Vec<usize>>>,
+    rows:            Arc<Mutex<Vec<Row>>>,
+    selection_mode:  SelectionMode,
+    selected:        BTreeSet<Vec<usize>>,
+    current:         Option<Vec<usize>>,
+    anchor:          Option<Vec<usize>>,
+    scroll:          f32,
+    last_click:      Option<(Vec<usize>, Instant)>,
+    on_select:       Option<PathSelectionCallback>,
+    on_expand:       Option<ExpandCallback>,
+    on_activate:     Option<PathCallback>,
+    text:            Arc<TextureCache>,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border:          Border,
+    corner_radius:   CornerRadius,
+    shadow:          Shadow,
+}
+
+impl WidgetTrait for TreeView {
+    fn widget_type(&self) -> WidgetType {
+        WidgetType::TreeView
+    }
+
+    fn id(&self) -> Id {
+        self.clone().id
+    }
+
+    fn parent_id(&self) -> Option<Id> {
+        Some(self.clone().parent_id)
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn color(&self) -> Color {
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
+    }
+
+    fn visible(&self) -> bool {
+        self.visible
+    }
+
+    fn show(&mut self) {
+        self.visible = true;
+    }
+
+    fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn border(&self) -> Border {
+        self.border
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        self.corner_radius
+    }
+
+    fn shadow(&self) -> Shadow {
+        self.shadow
+    }
+
+    fn texture(&self) -> Option<Texture> {
+        self.text.get()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, ui: &Ui, event: &InputEvent) -> Result<bool, Error> {
+        match *event {
+            InputEvent::MousePressed {
+                x,
+                y,
+                button: MouseButton::Left,
+                modifiers,
+            } => {
+                let (left, top) = match self.area(ui)? {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some((left, top, ..)) => (left, top),
+                };
+
+                let index = ((y - top + self.scroll) / self.row_height).floor();
+                if (index < 0.0) | (index as usize >= self.nodes.len()) {
+                    return Ok(true);
+                }
+                let path = self.nodes[index as usize].clone();
+
+                // The arrow in front of a node expands or collapses it
+                let arrow = (path.len() - 1) as f32 * self.indent;
+                if (x - left >= arrow) & (x - left < arrow + self.indent) & self.model.has_children(&path) {
+                    let expanded = !self.expanded.contains(&path);
+                    self.expand(&path, expanded);
+                    return Ok(true);
+                }
+
+                let double = match self.last_click {
+                    Some((ref last, time)) => (*last == path) & (time.elapsed() < Duration::from_millis(DOUBLE_CLICK)),
+                    None => false,
+                };
+                self.click(&path, modifiers);
+                if double {
+                    self.last_click = None;
+                    self.activate(&path);
+                } else {
+                    self.last_click = Some((path, Instant::now()));
+                }
+            }
+            InputEvent::MouseWheel { delta_y, .. } => {
+                return self.scroll_by(ui, -delta_y);
+            }
+            InputEvent::KeyPressed { key, modifiers } => {
+                return self.handle_key(ui, key, modifiers);
+            }
+            _ => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, ui: &Ui) -> Result<Vec<DrawVertex>, Error> {
+        let mut vertices = Vec::new();
+
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(vertices);
+            }
+            Some(val) => val,
+        };
+
+        let opacity = self.inherited_opacity(ui)?;
+
+        let mut border_color = self.border.color().into_scaled_rgba_float();
+        border_color[3] *= opacity;
+
+        let bounds = (bl_vertex, tr_vertex);
+        let shape = |position: Vertex, mut color: [f32; 4]| {
+            color[3] *= opacity;
+            DrawVertex::new(position, color).with_shape(bounds, self.corner_radius, self.border.width(), border_color)
+        };
+
+        for (position, color) in self.fill.triangles(bl_vertex, tr_vertex) {
+            vertices.push(shape(position, color));
+        }
+
+        let window_size = ui.window_size();
+        if (window_size[0] == 0) | (window_size[1] == 0) {
+            self.text.clear();
+            return Ok(vertices);
+        }
+
+        let mut painter = Painter::new(
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        );
+
+        let (width, height) = painter.size();
+        let row_height = self.row_height;
+        let last = (((self.scroll + height) / row_height).ceil() as usize).min(self.nodes.len());
+        let first = ((self.scroll / row_height).floor() as usize).min(last);
+        self.recycle(first, last);
+
+        let focused = ui.focused() == Some(self.id());
+        let line_height = match self.font {
+            None => self.text_size,
+            Some(ref font) => font.measure("", self.text_size).1,
+        };
+
+        for row in self.rows.lock().iter() {
+            let y = (row.index as f32 * row_height) - self.scroll;
+            let depth = row.path.len() - 1;
+
+            if self.selected.contains(&row.path) {
+                painter.rect((0.0, y), (width, row_height), self.selection_color);
+            }
+
+            // The guides run down from the arrow of each parent
+            for level in 0..depth {
+                let x = (level as f32 + 0.5) * self.indent;
+                painter.line((x, y), (x, y + row_height), 1.0, self.guide_color);
+            }
+
+            if row.has_children {
+                let (x, y) = ((depth as f32 + 0.5) * self.indent, y + (row_height / 2.0));
+                let (half, quarter) = (ARROW / 2.0, ARROW / 4.0);
+                let arrow = if self.expanded.contains(&row.path) {
+                    [(x - half, y - quarter), (x + half, y - quarter), (x, y + quarter)]
+                } else {
+                    [(x - quarter, y - half), (x - quarter, y + half), (x + quarter, y)]
+                };
+                painter.polygon(&arrow, self.text_color);
+            }
+
+            if let Some(ref font) = self.font {
+                painter.text(
+                    ((depth + 1) as f32 * self.indent, y + ((row_height - line_height) / 2.0)),
+                    &row.text,
+                    self.text_size,
+                    self.text_color,
+                    font,
+                );
+            }
+
+            if focused & (self.current.as_ref() == Some(&row.path)) {
+                painter.stroke_rect((0.5, y + 0.5), (width - 1.0, row_height - 1.0), 1.0, self.focus_color);
+            }
+        }
+
+        let texture = painter.texture(&self.text);
+        vertices.append(&mut painter.draw_vertices(bl_vertex, window_size, texture.as_ref(), shape));
+
+        Ok(vertices)
+    }
+}
+
+impl TreeView {
+    /// Retrieve the model of the nodes
+    pub fn model(&self) -> Arc<TreeModel> {
+        Arc::clone(&self.model)
+    }
+
+    /// Show the nodes of another model, collapsing every node, clearing the selection and
+    /// scrolling back to the top
+    pub fn set_model(&mut self, model: Arc<TreeModel>) {
+        self.model = model;
+        self.expanded.clear();
+        self.selected.clear();
+        self.current = None;
+        self.anchor = None;
+        self.scroll = 0.0;
+        self.refresh();
+    }
+
+    /// Ask the model again for the children of the expanded nodes and for the nodes shown, after
+    /// its nodes changed. The selected nodes no longer shown are unselected.
+    pub fn refresh(&mut self) {
+        self.children.lock().clear();
+        self.flatten();
+
+        let nodes: BTreeSet<&Vec<usize>> = self.nodes.iter().collect();
+        self.selected = self
+            .selected
+            .iter()
+            .filter(|path| nodes.contains(path))
+            .cloned()
+            .collect();
+        self.current = self.current.take().filter(|path| nodes.contains(path));
+        self.anchor = self.anchor.take().filter(|path| nodes.contains(path));
+    }
+
+    /// Retrieve the paths of the nodes shown, from the top, whether in view or scrolled out of
+    /// view
+    pub fn nodes(&self) -> Vec<Vec<usize>> {
+        self.nodes.to_vec()
+    }
+
+    /// Retrieve the paths of the nodes shown the last time the tree view was drawn
+    pub fn rows(&self) -> Vec<Vec<usize>> {
+        self.rows.lock().iter().map(|row| row.path.clone()).collect()
+    }
+
+    /// Whether a node is expanded
+    pub fn expanded(&self, path: &[usize]) -> bool {
+        self.expanded.contains(path)
+    }
+
+    /// Expand a node along with its parents, or collapse a node, without calling the expand
+    /// callback
+    pub fn set_expanded(&mut self, path: &[usize], expanded: bool) {
+        if expanded {
+            for end in 1..=path.len() {
+                self.expanded.insert(path[..end].to_vec());
+            }
+        } else {
+            self.expanded.remove(path);
+        }
+        self.collapsed(path);
+        self.flatten();
+    }
+
+    /// Retrieve how many nodes can be selected at once
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection_mode
+    }
+
+    /// Retrieve the paths of the selected nodes, in order
+    pub fn selected(&self) -> Vec<Vec<usize>> {
+        self.selected.iter().cloned().collect()
+    }
+
+    /// Select nodes, only the first one unless several nodes can be selected, without calling the
+    /// selection callback. The nodes not shown are ignored.
+    pub fn set_selected(&mut self, paths: &[Vec<usize>]) {
+        let nodes: BTreeSet<&Vec<usize>> = self.nodes.iter().collect();
+        let mut paths = paths.iter().filter(|path| nodes.contains(path)).cloned();
+        let first = paths.next();
+
+        self.selected = match self.selection_mode {
+            SelectionMode::Single => first.clone().into_iter().collect(),
+            SelectionMode::Multiple => first.clone().into_iter().chain(paths).collect(),
+        };
+        self.current = first;
+        self.anchor = self.current.clone();
+    }
+
+    /// Retrieve the path of the node moved to with the keyboard or clicked last
+    pub fn current(&self) -> Option<Vec<usize>> {
+        self.current.clone()
+    }
+
+    /// Retrieve the distance in pixels the nodes are scrolled down
+    pub fn scroll(&self) -> f32 {
+        self.scroll
+    }
+
+    /// Scroll just enough for a node shown to be in view
+    pub fn scroll_to(&mut self, ui: &Ui, path: &[usize]) -> Result<(), Error> {
+        let index = match self.index_of(path) {
+            None => {
+                return Ok(());
+            }
+            Some(val) => val,
+        };
+
+        let height = match self.area(ui)? {
+            None => {
+                return Ok(());
+            }
+            Some((_, _, _, val)) => val,
+        };
+
+        let top = index as f32 * self.row_height;
+        if top < self.scroll {
+            self.scroll = top;
+        } else if (top + self.row_height) - self.scroll > height {
+            self.scroll = (top + self.row_height) - height;
+        }
+        self.scroll = self.scroll.min(self.max_scroll(height)).max(0.0);
+
+        Ok(())
+    }
+
+    /// Retrieve the height in pixels of the rows
+    pub fn row_height(&self) -> f32 {
+        self.row_height
+    }
+
+    /// Retrieve the distance in pixels each level of the tree is indented by
+    pub fn indent(&self) -> f32 {
+        self.indent
+    }
+
+    /// Retrieve the font
+    pub fn font(&self) -> Option<Font> {
+        self.font.clone()
+    }
+
+    /// Retrieve the size of the text in pixels
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    /// Set the callback called with the paths of the selected nodes whenever the user changes the
+    /// selection
+    pub fn set_on_select<F>(&mut self, on_select: F)
+    where
+        F: Fn(&[Vec<usize>]) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+    }
+
+    /// Set the callback called with the path of a node whenever the user expands or collapses it,
+    /// along with whether it is now expanded
+    pub fn set_on_expand<F>(&mut self, on_expand: F)
+    where
+        F: Fn(&[usize], bool) + Send + Sync + 'static,
+    {
+        self.on_expand = Some(Arc::new(on_expand));
+    }
+
+    /// Set the callback called with the path of a node when it is double clicked, or when enter is
+    /// pressed on the current node
+    pub fn set_on_activate<F>(&mut self, on_activate: F)
+    where
+        F: Fn(&[usize]) + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(on_activate));
+    }
+
+    fn handle_key(&mut self, ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> Result<bool, Error> {
+        let len = self.nodes.len();
+        if len == 0 {
+            return Ok(false);
+        }
+
+        let command = modifiers.ctrl | modifiers.logo;
+        let multiple = self.selection_mode == SelectionMode::Multiple;
+        let page = self.page_rows(ui)?;
+        let current = self.current.as_ref().and_then(|path| self.index_of(path));
+
+        let target = match key {
+            VirtualKeyCode::Up => current.map_or(len - 1, |index| index.saturating_sub(1)),
+            VirtualKeyCode::Down => current.map_or(0, |index| (index + 1).min(len - 1)),
+            VirtualKeyCode::PageUp => current.map_or(0, |index| index.saturating_sub(page)),
+            VirtualKeyCode::PageDown => current.map_or(0, |index| (index + page).min(len - 1)),
+            VirtualKeyCode::Home => 0,
+            VirtualKeyCode::End => len - 1,
+            // Right expands the current node, then moves to its first child
+            VirtualKeyCode::Right => {
+                let index = match current {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                };
+                let path = self.nodes[index].clone();
+                if !self.model.has_children(&path) {
+                    return Ok(false);
+                }
+                if !self.expanded.contains(&path) {
+                    self.expand(&path, true);
+                    return Ok(true);
+                }
+                match self.nodes.get(index + 1) {
+                    Some(next) if next.len() > path.len() => index + 1,
+                    _ => {
+                        return Ok(false);
+                    }
+                }
+            }
+            // Left collapses the current node, then moves to its parent
+            VirtualKeyCode::Left => {
+                let path = match self.current {
+                    Some(ref val) if current.is_some() => val.clone(),
+                    _ => {
+                        return Ok(false);
+                    }
+                };
+                if self.expanded.contains(&path) {
+                    self.expand(&path, false);
+                    return Ok(true);
+                }
+                match self.index_of(&path[..path.len() - 1]) {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => val,
+                }
+            }
+            VirtualKeyCode::A if command & multiple => {
+                let selected = self.nodes.iter().cloned().collect();
+                self.change(selected);
+                return Ok(true);
+            }
+            VirtualKeyCode::Space => {
+                let path = match current {
+                    None => {
+                        return Ok(false);
+                    }
+                    Some(val) => self.nodes[val].clone(),
+                };
+                self.click(&path, modifiers);
+                return Ok(true);
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                return Ok(match current {
+                    None => false,
+                    Some(index) => {
+                        let path = self.nodes[index].clone();
+                        self.activate(&path);
+                        true
+                    }
+                });
+            }
+            _ => {
+                return Ok(false);
+            }
+        };
+
+        let path = self.nodes[target].clone();
+        self.current = Some(path.clone());
+        if multiple & modifiers.shift {
+            let anchor = self
+                .anchor
+                .as_ref()
+                .and_then(|path| self.index_of(path))
+                .unwrap_or(target);
+            self.change(self.range(anchor, target));
+        } else if !(multiple & command) {
+            // The control key moves through the nodes of a multiple selection without selecting
+            self.anchor = Some(path.clone());
+            self.change(Some(path.clone()).into_iter().collect());
+        }
+        self.scroll_to(ui, &path)?;
+
+        Ok(true)
+    }
+
+    /// Select a node clicked, or pressed space on, with the modifier keys held down
+    fn click(&mut self, path: &[usize], modifiers: ModifiersState) {
+        let command = modifiers.ctrl | modifiers.logo;
+        let selected = match self.selection_mode {
+            SelectionMode::Multiple if modifiers.shift => {
+                let index = self.index_of(path).unwrap_or(0);
+                let anchor = self
+                    .anchor
+                    .as_ref()
+                    .and_then(|path| self.index_of(path))
+                    .unwrap_or(index);
+                self.range(anchor, index)
+            }
+            SelectionMode::Multiple if command => {
+                let mut selected = self.selected.clone();
+                if !selected.remove(path) {
+                    selected.insert(path.to_vec());
+                }
+                self.anchor = Some(path.to_vec());
+                selected
+            }
+            _ => {
+                self.anchor = Some(path.to_vec());
+                Some(path.to_vec()).into_iter().collect()
+            }
+        };
+
+        self.current = Some(path.to_vec());
+        self.change(selected);
+    }
+
+    fn change(&mut self, selected: BTreeSet<Vec<usize>>) {
+        if selected == self.selected {
+            return;
+        }
+
+        self.selected = selected;
+        if let Some(ref on_select) = self.on_select {
+            on_select(&self.selected());
+        }
+    }
+
+    fn activate(&self, path: &[usize]) {
+        if let Some(ref on_activate) = self.on_activate {
+            on_activate(path);
+        }
+    }
+
+    /// Expand or collapse a node as asked by the user
+    fn expand(&mut self, path: &[usize], expanded: bool) {
+        if expanded {
+            self.expanded.insert(path.to_vec());
+        } else {
+            self.expanded.remove(path);
+        }
+        self.collapsed(path);
+        self.flatten();
+
+        if let Some(ref on_expand) = self.on_expand {
+            on_expand(path, expanded);
+        }
+    }
+
+    /// Move the current node out of a node collapsed
+    fn collapsed(&mut self, path: &[usize]) {
+        if self.expanded.contains(path) {
+            return;
+        }
+
+        for node in [&mut self.current, &mut self.anchor].iter_mut() {
+            if let Some(ref mut node) = **node {
+                if (node.len() > path.len()) && node.starts_with(path) {
+                    *node = path.to_vec();
+                }
+            }
+        }
+    }
+
+    /// The paths of the nodes shown from one to another, both included
+    fn range(&self, from: usize, to: usize) -> BTreeSet<Vec<usize>> {
+        self.nodes[from.min(to)..=from.max(to)].iter().cloned().collect()
+    }
+
+    /// The position of a node among the nodes shown
+    fn index_of(&self, path: &[usize]) -> Option<usize> {
+        self.nodes.iter().position(|node| node.as_slice() == path)
+    }
+
+    /// List the nodes shown, the nodes at the top level followed by the children of the expanded
+    /// nodes
+    fn flatten(&mut self) {
+        let mut nodes = Vec::new();
+        self.visit(&mut Vec::new(), &mut nodes);
+        self.nodes = Arc::new(nodes);
+        self.rows.lock().clear();
+    }
+
+    fn visit(&self, path: &mut Vec<usize>, nodes: &mut Vec<Vec<usize>>) {
+        // The children of a node are only asked once, until the tree view is refreshed
+        let children = {
+            let model = &self.model;
+            *self
+                .children
+                .lock()
+                .entry(path.clone())
+                .or_insert_with(|| model.children(path))
+        };
+
+        for child in 0..children {
+            path.push(child);
+            nodes.push(path.clone());
+            if self.expanded.contains(path) {
+                self.visit(path, nodes);
+            }
+            path.pop();
+        }
+    }
+
+    /// Bind the rows shown to the nodes shown from first to last, reusing the rows scrolled out of
+    /// view so that only the nodes coming into view are asked from the model
+    fn recycle(&self, first: usize, last: usize) {
+        let mut rows = self.rows.lock();
+        let shown = |index: usize| (index >= first) & (index < last);
+
+        let mut bound = vec![false; last - first];
+        let mut free = Vec::new();
+        for (i, row) in rows.iter().enumerate() {
+            if shown(row.index) {
+                bound[row.index - first] = true;
+            } else {
+                free.push(i);
+            }
+        }
+
+        for index in (first..last).filter(|index| !bound[index - first]) {
+            let path = self.nodes[index].clone();
+            let row = Row {
+                index,
+                text: self.model.text(&path),
+                has_children: self.model.has_children(&path),
+                path,
+            };
+            match free.pop() {
+                None => rows.push(row),
+                Some(i) => rows[i] = row,
+            }
+        }
+
+        // The rows left over once fewer rows are shown
+        rows.retain(|row| shown(row.index));
+        rows.sort_by_key(|row| row.index);
+    }
+
+    /// The position in pixels of the top left corner of the tree view in the window, and its size
+    fn area(&self, ui: &Ui) -> Result<Option<(f32, f32, f32, f32)>, Error> {
+        let (bl_vertex, tr_vertex) = match self.bounds(ui)? {
+            None => {
+                return Ok(None);
+            }
+            Some(val) => val,
+        };
+
+        let window_size = ui.window_size();
+        Ok(Some((
+            (bl_vertex.x() * window_size[0] as f32) / 2.0,
+            (bl_vertex.y() * window_size[1] as f32) / 2.0,
+            ((tr_vertex.x() - bl_vertex.x()) * window_size[0] as f32) / 2.0,
+            ((tr_vertex.y() - bl_vertex.y()) * window_size[1] as f32) / 2.0,
+        )))
+    }
+
+    /// The number of rows shown at once
+    fn page_rows(&self, ui: &Ui) -> Result<usize, Error> {
+        Ok(match self.area(ui)? {
+            None => 1,
+            Some((_, _, _, height)) => ((height / self.row_height).floor() as usize).max(1),
+        })
+    }
+
+    /// The largest distance in pixels the nodes can be scrolled down in a tree view of a height
+    fn max_scroll(&self, height: f32) -> f32 {
+        ((self.nodes.len() as f32 * self.row_height) - height).max(0.0)
+    }
+
+    /// Scroll the nodes down by a distance in pixels, returning whether they moved
+    fn scroll_by(&mut self, ui: &Ui, y: f32) -> Result<bool, Error> {
+        let height = match self.area(ui)? {
+            None => {
+                return Ok(false);
+            }
+            Some((_, _, _, val)) => val,
+        };
+
+        let scroll = (self.scroll + y).min(self.max_scroll(height)).max(0.0);
+        let moved = scroll != self.scroll;
+        self.scroll = scroll;
+        Ok(moved)
+    }
+}
+
+/// The builder for the TreeView widget
+#[derive(Clone, Default)]
+pub struct TreeViewBuilder {
+    id:              Id,
+    size:            Size,
+    position:        Position,
+    color:           String,
+    text_color:      String,
+    guide_color:     String,
+    selection_color: String,
+    font:            Option<Font>,
+    text_size:       Option<f32>,
+    row_height:      Option<f32>,
+    indent:          Option<f32>,
+    model:           Option<Arc<TreeModel>>,
+    expanded:        Vec<Vec<usize>>,
+    selection_mode:  SelectionMode,
+    selected:        Vec<Vec<usize>>,
+    on_select:       Option<PathSelectionCallback>,
+    on_expand:       Option<ExpandCallback>,
+    on_activate:     Option<PathCallback>,
+    parent_id:       Id,
+    visible:         bool,
+    opacity:         f32,
+    z_index:         i32,
+    layer:           Layer,
+    border_color:    String,
+    border_width:    Option<f32>,
+    corner_radius:   Option<[f32; 4]>,
+}
+
+impl TreeViewBuilder {
+    /// Initialize the builder for the TreeView widget
+    pub fn new<V>(id: V, parent_id: V, position: Position) -> Self
+    where
+        V: Into<Id>,
+    {
+        Self {
+            id: id.into(),
+            position,
+            parent_id: parent_id.into(),
+            visible: true,
+            opacity: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Initialize the builder for the TreeView widget from another TreeView widget
+    pub fn new_from_tree_view(tree_view: &TreeView) -> Self {
+        Self {
+            id:              tree_view.id(),
+            size:            tree_view.size(),
+            position:        tree_view.position(),
+            color:           tree_view.fill().into_string(),
+            text_color:      tree_view.text_color.into_hex(),
+            guide_color:     tree_view.guide_color.into_hex(),
+            selection_color: tree_view.selection_color.into_hex(),
+            font:            tree_view.font(),
+            text_size:       Some(tree_view.text_size()),
+            row_height:      Some(tree_view.row_height()),
+            indent:          Some(tree_view.indent()),
+            model:           Some(tree_view.model()),
+            expanded:        tree_view.expanded.iter().cloned().collect(),
+            selection_mode:  tree_view.selection_mode(),
+            selected:        tree_view.selected(),
+            on_select:       tree_view.on_select.clone(),
+            on_expand:       tree_view.on_expand.clone(),
+            on_activate:     tree_view.on_activate.clone(),
+            parent_id:       tree_view.parent_id().unwrap(),
+            visible:         tree_view.visible(),
+            opacity:         tree_view.opacity(),
+            z_index:         tree_view.z_index(),
+            layer:           tree_view.layer(),
+            border_color:    tree_view.border().color().into_hex(),
+            border_width:    Some(tree_view.border().width()),
+            corner_radius:   Some(tree_view.corner_radius().as_array()),
+        }
+    }
+
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
+    pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of the text and of the arrows
+    pub fn with_text_color<V: Into<String>>(&mut self, text_color: V) -> &mut Self {
+        self.text_color = text_color.into();
+        self
+    }
+
+    /// Set the color of the indentation guides
+    pub fn with_guide_color<V: Into<String>>(&mut self, guide_color: V) -> &mut Self {
+        self.guide_color = guide_color.into();
+        self
+    }
+
+    /// Set the color behind the selected nodes
+    pub fn with_selection_color<V: Into<String>>(&mut self, selection_color: V) -> &mut Self {
+        self.selection_color = selection_color.into();
+        self
+    }
+
+    /// Set the font
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the size of the text in pixels
+    pub fn with_text_size(&mut self, text_size: f32) -> &mut Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Set the height in pixels of the rows, the size of the text and some padding by default
+    pub fn with_row_height(&mut self, row_height: f32) -> &mut Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Set the distance in pixels each level of the tree is indented by
+    pub fn with_indent(&mut self, indent: f32) -> &mut Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Set the model of the nodes
+    pub fn with_model(&mut self, model: Arc<TreeModel>) -> &mut Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// Show nodes known in advance
+    pub fn with_nodes(&mut self, nodes: Vec<TreeNode>) -> &mut Self {
+        self.model = Some(Arc::new(nodes));
+        self
+    }
+
+    /// Expand a node along with its parents
+    pub fn with_expanded(&mut self, path: &[usize]) -> &mut Self {
+        self.expanded.push(path.to_vec());
+        self
+    }
+
+    /// Set how many nodes can be selected at once
+    pub fn with_selection_mode(&mut self, selection_mode: SelectionMode) -> &mut Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Set the paths of the selected nodes
+    pub fn with_selected(&mut self, selected: &[Vec<usize>]) -> &mut Self {
+        self.selected = selected.to_vec();
+        self
+    }
+
+    /// Set the callback called with the paths of the selected nodes whenever the user changes the
+    /// selection
+    pub fn with_on_select<F>(&mut self, on_select: F) -> &mut Self
+    where
+        F: Fn(&[Vec<usize>]) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+
+    /// Set the callback called with the path of a node whenever the user expands or collapses it,
+    /// along with whether it is now expanded
+    pub fn with_on_expand<F>(&mut self, on_expand: F) -> &mut Self
+    where
+        F: Fn(&[usize], bool) + Send + Sync + 'static,
+    {
+        self.on_expand = Some(Arc::new(on_expand));
+        self
+    }
+
+    /// Set the callback called with the path of a node when it is double clicked, or when enter is
+    /// pressed on the current node
+    pub fn with_on_activate<F>(&mut self, on_activate: F) -> &mut Self
+    where
+        F: Fn(&[usize]) + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(on_activate));
+        self
+    }
+
+    /// Set the size
+    pub fn with_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the visibility, a hidden widget isn't drawn
+    pub fn with_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the z-index, a widget is drawn over its siblings with a lower z-index
+    pub fn with_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the layer
+    pub fn with_layer(&mut self, layer: Layer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the border color
+    pub fn with_border_color<V: Into<String>>(&mut self, border_color: V) -> &mut Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Set the border width in pixels
+    pub fn with_border_width(&mut self, border_width: f32) -> &mut Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set the same radius in pixels for every corner
+    pub fn with_corner_radius(&mut self, corner_radius: f32) -> &mut Self {
+        self.corner_radius = Some([corner_radius; 4]);
+        self
+    }
+
+    /// Set the radius in pixels of each corner
+    pub fn with_corner_radii(
+        &mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> &mut Self {
+        self.corner_radius = Some([top_left, top_right, bottom_right, bottom_left]);
+        self
+    }
+
+    /// Build the TreeView widget
+    pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<TreeView>, Error> {
+        let theme = ui.theme();
+        let color = |hex: &str, default: Result<Color, Error>| {
+            if hex.is_empty() {
+                default
+            } else {
+                Color::from_hex(hex)
+            }
+        };
+
+        let fill = if self.color.is_empty() {
+            theme.text_input_fill()?
+        } else {
+            Fill::parse(self.clone().color)?
+        };
+
+        let text_color = color(&self.text_color, theme.text_input_text_color())?;
+        let guide_color = color(&self.guide_color, theme.border_color())?;
+        let selection_color = color(&self.selection_color, theme.selection_color())?;
+
+        let font = match self.font {
+            None => theme.font()?,
+            Some(ref val) => Some(val.clone()),
+        };
+
+        let text_size = self.text_size.unwrap_or(TEXT_SIZE);
+        if text_size <= 0.0 {
+            return Err(err_msg("The text size must be greater than 0.0"));
+        }
+
+        let row_height = self.row_height.unwrap_or(text_size + (2.0 * PADDING));
+        if row_height <= 0.0 {
+            return Err(err_msg("The row height must be greater than 0.0"));
+        }
+
+        let indent = self.indent.unwrap_or(INDENT);
+        if indent <= 0.0 {
+            return Err(err_msg("The indent must be greater than 0.0"));
+        }
+
+        let border_color = color(&self.border_color, theme.border_color())?;
+
+        let border_width = match self.border_width {
+            None => theme.border_width(),
+            Some(val) => val,
+        };
+
+        let border = Border::new(border_width, border_color)?;
+
+        let corner_radius = match self.corner_radius {
+            None => theme.corner_radius()?,
+            Some(val) => CornerRadius::new(val[0], val[1], val[2], val[3])?,
+        };
+
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
+        let model = match self.model {
+            None => Arc::new(Vec::<TreeNode>::new()),
+            Some(ref val) => Arc::clone(val),
+        };
+
+        let mut widget = Box::new(TreeView {
+            id: self.clone().id,
+            parent_id: self.clone().parent_id,
+            position: self.position,
+            size: self.size,
+            fill,
+            text_color,
+            guide_color,
+            selection_color,
+            focus_color: theme.checked_color()?,
+            font,
+            text_size,
+            row_height,
+            indent,
+            model,
+            children: Arc::new(Mutex::new(HashMap::new())),
+            expanded: BTreeSet::new(),
+            nodes: Arc::new(Vec::new()),
+            rows: Arc::new(Mutex::new(Vec::new())),
+            selection_mode: self.selection_mode,
+            selected: BTreeSet::new(),
+            current: None,
+            anchor: None,
+            scroll: 0.0,
+            last_click: None,
+            on_select: self.clone().on_select,
+            on_expand: self.clone().on_expand,
+            on_activate: self.clone().on_activate,
+            text: Arc::new(TextureCache::default()),
+            visible: self.visible,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            layer: self.layer,
+            border,
+            corner_radius,
+            shadow: Shadow::default(),
+        });
+        for path in &self.expanded {
+            for end in 1..=path.len() {
+                widget.expanded.insert(path[..end].to_vec());
+            }
+        }
+        widget.flatten();
+        widget.set_selected(&self.selected);
+
+        ui.add_widget(widget.clone());
+
+        Ok(widget)
+    }
+}
@@ -20,9 +20,11 @@ use crate::{
 /// The most top level widget
 #[derive(Clone)]
 pub struct WindowContainer {
-    window: Box<WindowBuilder>,
-    id:     Id,
-    color:  Color,
+    window:      Box<WindowBuilder>,
+    id:          Id,
+    fill:        Fill,
+    opacity:     f32,
+    transparent: bool,
 }
 
 impl WidgetTrait for WindowContainer {
@@ -51,7 +53,11 @@ impl WidgetTrait for WindowContainer {
     }
 
     fn color(&self) -> Color {
-        self.color
+        self.fill.color()
+    }
+
+    fn fill(&self) -> Fill {
+        self.fill.clone()
     }
 
     fn visible(&self) -> bool {
@@ -65,6 +71,42 @@ impl WidgetTrait for WindowContainer {
     fn hide(&mut self) {
         panic!("Cannot call hide for a window container")
     }
+
+    fn overflow(&self) -> Overflow {
+        Overflow::Hidden
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
+    fn z_index(&self) -> i32 {
+        0
+    }
+
+    fn set_z_index(&mut self, _z_index: i32) {
+        panic!("Cannot call set_z_index for a window container")
+    }
+
+    fn layer(&self) -> Layer {
+        Layer::Content
+    }
+
+    fn border(&self) -> Border {
+        Border::default()
+    }
+
+    fn corner_radius(&self) -> CornerRadius {
+        CornerRadius::default()
+    }
+
+    fn shadow(&self) -> Shadow {
+        Shadow::default()
+    }
 }
 
 impl WindowContainer {
@@ -72,6 +114,11 @@ impl WindowContainer {
     pub fn window(&self) -> Box<WindowBuilder> {
         self.clone().window
     }
+
+    /// Whether the background of the window is transparent
+    pub fn transparent(&self) -> bool {
+        self.transparent
+    }
 }
 
 /// The builder for the Window Container widget
@@ -80,6 +127,7 @@ pub struct WindowContainerBuilder {
     id:             Id,
     position:       Position,
     color:          String,
+    opacity:        f32,
     dimensions:     Option<LogicalSize>,
     min_dimensions: Option<LogicalSize>,
     max_dimensions: Option<LogicalSize>,
@@ -100,6 +148,7 @@ impl Default for WindowContainerBuilder {
             id:             String::new(),
             position:       Position::Center,
             color:          String::new(),
+            opacity:        1.0,
             dimensions:     None,
             min_dimensions: None,
             max_dimensions: None,
@@ -128,12 +177,18 @@ impl WindowContainerBuilder {
         }
     }
 
-    /// Set the color
+    /// Set the fill, either a hex color or a gradient as parsed by `Fill::parse`
     pub fn with_color<V: Into<String>>(&mut self, color: V) -> &mut Self {
         self.color = color.into();
         self
     }
 
+    /// Set the opacity, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn with_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
     /// Requests the window to be of specific dimensions.
     pub fn with_dimensions(&mut self, size: LogicalSize) -> &mut Self {
         self.dimensions = Some(size);
@@ -193,6 +248,9 @@ impl WindowContainerBuilder {
     }
 
     /// Sets whether the background of the window should be transparent.
+    ///
+    /// When no color is set, the theme color is used with its alpha cleared so that whatever is
+    /// behind the window shows through.
     pub fn with_transparency(&mut self, transparent: bool) -> &mut Self {
         self.transparent = transparent;
         self
@@ -220,12 +278,18 @@ impl WindowContainerBuilder {
     pub fn build<'a>(&self, ui: &'a mut Ui) -> Result<Box<WindowContainer>, Error> {
         let id = self.clone().id;
 
-        let color = if self.color.is_empty() {
-            ui.theme().window_container_color()?
+        let fill = if self.color.is_empty() && self.transparent {
+            Fill::Solid(ui.theme().window_container_color()?.with_alpha(0))
+        } else if self.color.is_empty() {
+            ui.theme().window_container_fill()?
         } else {
-            Color::from_hex(self.clone().color)?
+            Fill::parse(self.clone().color)?
         };
 
+        if (self.opacity < 0.0) | (self.opacity > 1.0) {
+            return Err(err_msg("The opacity must be between 0.0 and 1.0"));
+        }
+
         let w = WindowBuilder::new()
             .with_resizable(self.resizable)
             .with_title(self.clone().title)
@@ -258,7 +322,18 @@ impl WindowContainerBuilder {
 
         let window = Box::new(win);
 
-        let widget = Box::new(WindowContainer { window, id, color });
+        let widget = Box::new(WindowContainer {
+            window,
+            id,
+            fill,
+            opacity: self.opacity,
+            transparent: self.transparent,
+        });
+
+        // The requested size stands in for the size of the window until it is drawn
+        if let Some(val) = self.dimensions {
+            ui.set_window_size([val.width as u32, val.height as u32]);
+        }
 
         ui.add_widget(widget.clone());
 
@@ -24,14 +24,66 @@ use self::core::widgets::window::{
 pub use self::{
     core::widgets::{
         window,
+        Canvas,
+        CanvasBuilder,
+        Checkbox,
+        CheckboxBuilder,
+        ComboBox,
+        ComboBoxBuilder,
         Container,
         ContainerBuilder,
+        Dialog,
+        DialogBuilder,
         Dock,
+        DockArea,
+        DockAreaBuilder,
         DockBuilder,
+        DockLayout,
+        DockPanel,
+        DockPanelBuilder,
+        DockedGroup,
+        FloatingGroup,
+        Image,
+        ImageBuilder,
         Label,
         LabelBuilder,
+        ListView,
+        ListViewBuilder,
+        Menu,
+        MenuBar,
+        MenuBarBuilder,
+        MenuBuilder,
+        MenuItem,
+        ProgressBar,
+        ProgressBarBuilder,
+        RadioButton,
+        RadioButtonBuilder,
+        RadioGroup,
+        RadioGroupBuilder,
+        Slider,
+        SliderBuilder,
+        SpinBox,
+        SpinBoxBuilder,
+        Splitter,
+        SplitterBuilder,
+        Table,
+        TableBuilder,
+        TableColumn,
+        Tabs,
+        TabsBuilder,
+        TextArea,
+        TextAreaBuilder,
+        TextInput,
+        TextInputBuilder,
+        Toggle,
+        ToggleBuilder,
         Toolbar,
         ToolbarBuilder,
+        Tooltip,
+        TooltipBuilder,
+        TreeNode,
+        TreeView,
+        TreeViewBuilder,
         WindowContainer,
         WindowContainerBuilder,
     },
@@ -53,30 +105,54 @@ use vulkano::{
         AutoCommandBufferBuilder,
         DynamicState,
     },
+    descriptor::{
+        descriptor_set::PersistentDescriptorSet,
+        DescriptorSet,
+    },
     device::{
         Device,
         DeviceExtensions,
     },
+    format::Format,
     framebuffer::{
         Framebuffer,
         FramebufferAbstract,
         RenderPassAbstract,
         Subpass,
     },
-    image::SwapchainImage,
+    image::{
+        Dimensions,
+        ImmutableImage,
+        SwapchainImage,
+    },
     impl_vertex,
     instance::{
         Instance,
         PhysicalDevice,
     },
     pipeline::{
-        viewport::Viewport,
+        blend::{
+            AttachmentBlend,
+            BlendFactor,
+            BlendOp,
+        },
+        viewport::{
+            Scissor,
+            Viewport,
+        },
         GraphicsPipeline,
     },
+    sampler::{
+        Filter,
+        MipmapMode,
+        Sampler,
+        SamplerAddressMode,
+    },
     single_pass_renderpass,
     swapchain::{
         self,
         AcquireError,
+        CompositeAlpha,
         PresentMode,
         SurfaceTransform,
         Swapchain,
@@ -254,9 +330,18 @@ impl UiBuild for Ui {
                 Ok(val) => val,
             };
             let usage = caps.supported_usage_flags;
-            let alpha = match caps.supported_composite_alpha.iter().next() {
-                None => return Err(err_msg("Next Composite alpha does not exist")),
-                Some(val) => val,
+            let supported_alpha = caps.supported_composite_alpha;
+            let alpha = if wcontainer.transparent() && supported_alpha.pre_multiplied {
+                CompositeAlpha::PreMultiplied
+            } else if wcontainer.transparent() && supported_alpha.post_multiplied {
+                CompositeAlpha::PostMultiplied
+            } else if wcontainer.transparent() && supported_alpha.inherit {
+                CompositeAlpha::Inherit
+            } else {
+                match supported_alpha.iter().next() {
+                    None => return Err(err_msg("Next Composite alpha does not exist")),
+                    Some(val) => val,
+                }
             };
             let format = caps.supported_formats[0].0;
 
@@ -330,8 +415,9 @@ impl UiBuild for Ui {
             .vertex_input_single_buffer()
             .vertex_shader(vs.main_entry_point(), ())
             .triangle_list()
-            .viewports_dynamic_scissors_irrelevant(1)
+            .viewports_scissors_dynamic(1)
             .fragment_shader(fs.main_entry_point(), ())
+            .blend_collective(premultiplied_alpha_blend())
             .render_pass(subpass)
             .build(device.clone())
         {
@@ -343,6 +429,28 @@ impl UiBuild for Ui {
 
         let pipeline = Arc::new(gpipeline);
 
+        let sampler = match Sampler::new(
+            device.clone(),
+            Filter::Linear,
+            Filter::Linear,
+            MipmapMode::Nearest,
+            SamplerAddressMode::ClampToEdge,
+            SamplerAddressMode::ClampToEdge,
+            SamplerAddressMode::ClampToEdge,
+            0.0,
+            1.0,
+            0.0,
+            0.0,
+        ) {
+            Err(err) => {
+                return Err(err_msg(format!("failed to create sampler: {}", err)));
+            }
+            Ok(val) => val,
+        };
+
+        // Every texture is uploaded once and bound by its id, untextured batches bind a white pixel
+        let mut textures: HashMap<usize, Arc<DescriptorSet + Send + Sync>> = HashMap::new();
+
         let mut recreate_swapchain = false;
         let mut previous_frame_end = Box::new(sync::now(device.clone())) as Box<GpuFuture>;
         let mut dynamic_state = DynamicState {
@@ -352,8 +460,22 @@ impl UiBuild for Ui {
         };
         let mut framebuffers = window_size_dependent_setup(&images, render_pass.clone(), &mut dynamic_state);
 
-        impl_vertex!(Vertex, position, color);
-        let mut vertices = build_vertices(&self, dimensions)?;
+        impl_vertex!(
+            Vertex,
+            position,
+            color,
+            local,
+            half_size,
+            radii,
+            border_width,
+            border_color,
+            blur_radius,
+            uv,
+            textured
+        );
+        // The widgets are laid out and drawn again every frame, for the size of the window
+        let mut ui = self.clone();
+        ui.set_window_size(dimensions);
 
         loop {
             previous_frame_end.cleanup_finished();
@@ -378,11 +500,58 @@ impl UiBuild for Ui {
 
                 swapchain = new_swapchain;
                 framebuffers = window_size_dependent_setup(&new_images, render_pass.clone(), &mut dynamic_state);
-                let a = build_vertices(&self, dimensions)?;
-                vertices = a;
+                ui.set_window_size(dimensions);
                 recreate_swapchain = false;
             }
 
+            let batches = build_vertices(&ui, ui.window_size())?;
+
+            let used: Vec<usize> = batches
+                .iter()
+                .map(|batch| batch.texture.clone().unwrap_or_default().id())
+                .collect();
+            textures.retain(|id, _| used.contains(id));
+            for batch in batches.iter() {
+                let texture = batch.texture.clone().unwrap_or_default();
+                if textures.contains_key(&texture.id()) {
+                    continue;
+                }
+
+                let (image, future) = match ImmutableImage::from_iter(
+                    texture.pixels().to_vec().into_iter(),
+                    Dimensions::Dim2d {
+                        width:  texture.width(),
+                        height: texture.height(),
+                    },
+                    Format::R8G8B8A8Unorm,
+                    queue.clone(),
+                ) {
+                    Err(err) => {
+                        return Err(err_msg(format!("failed to upload texture: {}", err)));
+                    }
+                    Ok(val) => val,
+                };
+
+                let set = match PersistentDescriptorSet::start(pipeline.clone(), 0)
+                    .add_sampled_image(image, sampler.clone())
+                {
+                    Err(err) => {
+                        return Err(err_msg(format!("failed to bind texture: {}", err)));
+                    }
+                    Ok(val) => val.build(),
+                };
+
+                let set = match set {
+                    Err(err) => {
+                        return Err(err_msg(format!("failed to bind texture: {}", err)));
+                    }
+                    Ok(val) => val,
+                };
+
+                textures.insert(texture.id(), Arc::new(set));
+                previous_frame_end = Box::new(previous_frame_end.join(future)) as Box<GpuFuture>;
+            }
+
             let (image_num, acquire_future) = match swapchain::acquire_next_image(swapchain.clone(), None) {
                 Ok(r) => r,
                 Err(AcquireError::OutOfDate) => {
@@ -394,21 +563,42 @@ impl UiBuild for Ui {
                 }
             };
 
-            let clear_values = vec![[0.0, 0.0, 0.0, 1.0].into()];
+            let clear_values = if wcontainer.transparent() {
+                vec![[0.0, 0.0, 0.0, 0.0].into()]
+            } else {
+                vec![[0.0, 0.0, 0.0, 1.0].into()]
+            };
 
-            let vertex_buffer =
-                CpuAccessibleBuffer::from_iter(device.clone(), BufferUsage::all(), vertices.clone().into_iter())
+            let mut command_buffer_builder =
+                AutoCommandBufferBuilder::primary_one_time_submit(device.clone(), queue.family())
+                    .unwrap()
+                    .begin_render_pass(framebuffers[image_num].clone(), false, clear_values)
                     .unwrap();
-            let command_buffer = AutoCommandBufferBuilder::primary_one_time_submit(device.clone(), queue.family())
-                .unwrap()
-                .begin_render_pass(framebuffers[image_num].clone(), false, clear_values)
-                .unwrap()
-                .draw(pipeline.clone(), &dynamic_state, vertex_buffer.clone(), (), ())
-                .unwrap()
-                .end_render_pass()
-                .unwrap()
-                .build()
+
+            for batch in batches.iter() {
+                let vertex_buffer = CpuAccessibleBuffer::from_iter(
+                    device.clone(),
+                    BufferUsage::all(),
+                    batch.vertices.clone().into_iter(),
+                )
                 .unwrap();
+                let batch_state = DynamicState {
+                    scissors: Some(vec![batch.scissor]),
+                    ..dynamic_state.clone()
+                };
+                let texture = batch.texture.clone().unwrap_or_default();
+                command_buffer_builder = command_buffer_builder
+                    .draw(
+                        pipeline.clone(),
+                        &batch_state,
+                        vertex_buffer.clone(),
+                        textures[&texture.id()].clone(),
+                        (),
+                    )
+                    .unwrap();
+            }
+
+            let command_buffer = command_buffer_builder.end_render_pass().unwrap().build().unwrap();
 
             let future = previous_frame_end
                 .join(acquire_future)
@@ -432,10 +622,21 @@ impl UiBuild for Ui {
             }
 
             let mut done = false;
-            events_loop.poll_events(|event| match callback(event, surface.window()) {
-                Run::Continue => (),
-                Run::Done => done = true,
-                Run::Redraw => recreate_swapchain = true,
+            events_loop.poll_events(|event| {
+                if let Event::WindowEvent { ref event, .. } = event {
+                    let hidpi_factor = surface.window().get_hidpi_factor();
+                    if let Some(input) = InputEvent::from_window_event(event, ui.cursor_position(), hidpi_factor) {
+                        if let Err(err) = ui.dispatch(input) {
+                            println!("{:?}", err);
+                        }
+                    }
+                }
+
+                match callback(event, surface.window()) {
+                    Run::Continue => (),
+                    Run::Done => done = true,
+                    Run::Redraw => recreate_swapchain = true,
+                }
             });
 
             if done {
@@ -445,6 +646,24 @@ impl UiBuild for Ui {
     }
 }
 
+/// The vertex colors are premultiplied by their alpha, so translucent widgets are blended over what
+/// was drawn before them and the result can be handed to a compositor expecting premultiplied alpha
+fn premultiplied_alpha_blend() -> AttachmentBlend {
+    AttachmentBlend {
+        enabled:           true,
+        color_op:          BlendOp::Add,
+        color_source:      BlendFactor::One,
+        color_destination: BlendFactor::OneMinusSrcAlpha,
+        alpha_op:          BlendOp::Add,
+        alpha_source:      BlendFactor::One,
+        alpha_destination: BlendFactor::OneMinusSrcAlpha,
+        mask_red:          true,
+        mask_green:        true,
+        mask_blue:         true,
+        mask_alpha:        true,
+    }
+}
+
 /// This method is called once during initialization, then again whenever the window is resized
 fn window_size_dependent_setup(
     images: &[Arc<SwapchainImage<Window>>],
@@ -490,31 +709,136 @@ mod fs {
 
 #[derive(Debug, Clone)]
 struct Vertex {
-    position: [f32; 2],
-    color:    [f32; 4],
+    position:     [f32; 2],
+    color:        [f32; 4],
+    local:        [f32; 2],
+    half_size:    [f32; 2],
+    radii:        [f32; 4],
+    border_width: f32,
+    border_color: [f32; 4],
+    blur_radius:  f32,
+    uv:           [f32; 2],
+    textured:     f32,
+}
+
+/// The vertices sharing the same clipping rectangle and texture, drawn with a single scissored draw
+/// call
+#[derive(Debug, Clone)]
+struct DrawBatch {
+    scissor:  Scissor,
+    texture:  Option<Texture>,
+    vertices: Vec<Vertex>,
 }
 
-fn build_vertices(ui: &Ui, window_size: [u32; 2]) -> Result<Vec<Vertex>, Error> {
-    let ui = normalize_sizes(ui)?;
+fn build_vertices(ui: &Ui, window_size: [u32; 2]) -> Result<Vec<DrawBatch>, Error> {
+    ui.normalize_sizes()?;
+    let mut ui = ui.clone();
+    ui.set_window_size(window_size);
     let h = window_size[1] as f32;
     let w = window_size[0] as f32;
 
-    let mut vertices = Vec::new();
-    for widget in ui.widgets().values() {
-        let widget = widget.lock();
-        let v = widget.draw(&ui)?;
+    let mut batches: Vec<DrawBatch> = Vec::new();
+    for id in ui.draw_order() {
+        let widget = match ui.widgets().get(&id) {
+            None => {
+                return Err(err_msg("BVError: Attempted to use an id that does not exist"));
+            }
+            Some(val) => val.lock(),
+        };
+        let scissor = match widget.clip(&ui)? {
+            None => Scissor::irrelevant(),
+            Some((bl, tr)) => {
+                let x = ((bl.x() * w) / 2.0).floor();
+                let y = ((bl.y() * h) / 2.0).floor();
+                let width = ((tr.x() * w) / 2.0).ceil() - x;
+                let height = ((tr.y() * h) / 2.0).ceil() - y;
+                if (width <= 0.0) | (height <= 0.0) {
+                    continue;
+                }
+                Scissor {
+                    origin:     [x as i32, y as i32],
+                    dimensions: [width as u32, height as u32],
+                }
+            }
+        };
+        // The shadow is drawn first so the widget covers it
+        let mut v = widget.draw_shadow(&ui)?;
+        let shadow_len = v.len();
+        v.append(&mut widget.draw(&ui)?);
+        if v.is_empty() {
+            continue;
+        }
+        let texture = widget.texture();
         let mut vx1 = None;
         let mut vx2 = None;
         for (i, vertex) in v.iter().enumerate() {
             let pos = vertex.position();
+            let color = vertex.color();
+            let border_color = vertex.border_color();
+            let offset = vertex.offset();
+            let blur_radius = vertex.blur_radius();
+            // The shape is measured in pixels from its center so its edges can be anti-aliased,
+            // vertices without a shape are given one large enough to never be clipped. Blurred
+            // vertices are pushed away from the center to make room for the faded edge
+            let (position, local, half_size) = match vertex.bounds() {
+                None => (pos.as_array(), [0.0, 0.0], [w, h]),
+                Some((bl, tr)) => {
+                    let center_x = (bl.x() + tr.x()) / 2.0;
+                    let center_y = (bl.y() + tr.y()) / 2.0;
+                    let local = [
+                        (((pos.x() - center_x) * w) / 2.0) + ((pos.x() - center_x).signum() * blur_radius),
+                        (((pos.y() - center_y) * h) / 2.0) + ((pos.y() - center_y).signum() * blur_radius),
+                    ];
+                    let position = if (blur_radius > 0.0) | (offset != (0.0, 0.0)) {
+                        [
+                            (center_x - 1.0) + (((local[0] + offset.0) * 2.0) / w),
+                            (center_y - 1.0) + (((local[1] + offset.1) * 2.0) / h),
+                        ]
+                    } else {
+                        pos.as_array()
+                    };
+                    (
+                        position,
+                        local,
+                        [((tr.x() - bl.x()) * w) / 4.0, ((tr.y() - bl.y()) * h) / 4.0],
+                    )
+                }
+            };
             let vert = Vertex {
-                position: pos.as_array(),
-                color:    vertex.color(),
+                position,
+                color: [color[0] * color[3], color[1] * color[3], color[2] * color[3], color[3]],
+                local,
+                half_size,
+                radii: vertex.corner_radius().as_array(),
+                border_width: vertex.border_width(),
+                border_color: [
+                    border_color[0] * border_color[3],
+                    border_color[1] * border_color[3],
+                    border_color[2] * border_color[3],
+                    border_color[3],
+                ],
+                blur_radius,
+                uv: vertex.uv(),
+                textured: if vertex.texture().is_some() { 1.0 } else { 0.0 },
+            };
+            // Only the texture of the widget itself can be drawn, other vertices are left untextured
+            let vertex_texture = match (vertex.texture(), &texture) {
+                (Some(id), Some(val)) if id == val.id() => Some(val.clone()),
+                _ => None,
             };
-            vertices.push(vert.clone());
-            if i == 1 {
+            match batches.last_mut() {
+                Some(ref mut batch) if (batch.scissor == scissor) & (batch.texture == vertex_texture) => {
+                    batch.vertices.push(vert)
+                }
+                _ => batches.push(DrawBatch {
+                    scissor,
+                    texture: vertex_texture,
+                    vertices: vec![vert],
+                }),
+            }
+            if i == shadow_len + 1 {
                 vx1 = Some(pos);
-            } else if i == 4 {
+            } else if i == shadow_len + 4 {
                 vx2 = Some(pos);
             }
         }
@@ -537,182 +861,5 @@ fn build_vertices(ui: &Ui, window_size: [u32; 2]) -> Result<Vec<Vertex>, Error>
         }
     }
 
-    Ok(vertices)
-}
-
-fn normalize_sizes(ui: &Ui) -> Result<Ui, Error> {
-    let ui = ui.clone();
-    for children in ui.heirarchy().values() {
-        let mut top_count: u16 = 0;
-        let mut hcenter_count: u16 = 0;
-        let mut bottom_count: u16 = 0;
-        let mut left_count: u16 = 0;
-        let mut vcenter_count: u16 = 0;
-        let mut right_count: u16 = 0;
-
-        let mut top_width_percent = 0.0;
-        let mut center_width_percent = 0.0;
-        let mut bottom_width_percent = 0.0;
-        let mut left_height_percent = 0.0;
-        let mut center_height_percent = 0.0;
-        let mut right_height_percent = 0.0;
-
-        for child in children {
-            let widgets = &ui.widgets();
-            let widget = widgets[child].lock();
-            match widget.size() {
-                Size::Full => match widget.position() {
-                    Position::TopLeft => {
-                        top_count += 1;
-                        left_count += 1;
-                    }
-                    Position::Top => {
-                        top_count += 1;
-                        vcenter_count += 1;
-                    }
-                    Position::TopRight => {
-                        top_count += 1;
-                        right_count += 1;
-                    }
-                    Position::Left => {
-                        hcenter_count += 1;
-                        left_count += 1;
-                    }
-                    Position::Center => {
-                        hcenter_count += 1;
-                        vcenter_count += 1;
-                    }
-                    Position::Right => {
-                        hcenter_count += 1;
-                        right_count += 1;
-                    }
-                    Position::BottomLeft => {
-                        bottom_count += 1;
-                        left_count += 1;
-                    }
-                    Position::Bottom => {
-                        bottom_count += 1;
-                        vcenter_count += 1;
-                    }
-                    Position::BottomRight => {
-                        bottom_count += 1;
-                        right_count += 1;
-                    }
-                },
-                Size::Size(width, height) => match widget.position() {
-                    Position::TopLeft => {
-                        top_width_percent += width;
-                        left_height_percent += height;
-                    }
-                    Position::Top => {
-                        top_width_percent += width;
-                        center_height_percent += height;
-                    }
-                    Position::TopRight => {
-                        top_width_percent += width;
-                        right_height_percent += height;
-                    }
-                    Position::Left => {
-                        center_width_percent += width;
-                        left_height_percent += height;
-                    }
-                    Position::Center => {
-                        center_width_percent += width;
-                        center_height_percent += height;
-                    }
-                    Position::Right => {
-                        center_width_percent += width;
-                        right_height_percent += height;
-                    }
-                    Position::BottomLeft => {
-                        bottom_width_percent += width;
-                        left_height_percent += height;
-                    }
-                    Position::Bottom => {
-                        bottom_width_percent += width;
-                        center_height_percent += height;
-                    }
-                    Position::BottomRight => {
-                        bottom_width_percent += width;
-                        right_height_percent += height;
-                    }
-                },
-            }
-        }
-
-        if top_width_percent > 100.0 {
-            return Err(err_msg("The top width exceeds the maximum 100%"));
-        } else if center_width_percent > 100.0 {
-            return Err(err_msg("The center width exceeds the maximum 100%"));
-        } else if bottom_width_percent > 100.0 {
-            return Err(err_msg("The bottome width exceeds the maximum 100%"));
-        } else if left_height_percent > 100.0 {
-            return Err(err_msg("The left height exceeds the maximum 100%"));
-        } else if center_height_percent > 100.0 {
-            return Err(err_msg("The center height exceeds the maximum 100%"));
-        } else if right_height_percent > 100.0 {
-            return Err(err_msg("The right height exceeds the maximum 100%"));
-        }
-        for child in children {
-            match ui.widgets().get(child) {
-                None => {
-                    return Err(err_msg("NCError: Attempted to use an id that does not exist"));
-                }
-                Some(widget) => {
-                    let mut widget = widget.lock();
-                    if let Size::Full = widget.size() {
-                        let size = match widget.position() {
-                            Position::TopLeft => {
-                                let full_width = (100.0 - top_width_percent) / f32::from(top_count);
-                                let full_height = (100.0 - left_height_percent) / f32::from(left_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::Top => {
-                                let full_width = (100.0 - top_width_percent) / f32::from(top_count);
-                                let full_height = (100.0 - center_height_percent) / f32::from(vcenter_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::TopRight => {
-                                let full_width = (100.0 - top_width_percent) / f32::from(top_count);
-                                let full_height = (100.0 - right_height_percent) / f32::from(right_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::Left => {
-                                let full_width = (100.0 - center_width_percent) / f32::from(hcenter_count);
-                                let full_height = (100.0 - left_height_percent) / f32::from(left_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::Center => {
-                                let full_width = (100.0 - center_width_percent) / f32::from(hcenter_count);
-                                let full_height = (100.0 - center_height_percent) / f32::from(vcenter_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::Right => {
-                                let full_width = (100.0 - center_width_percent) / f32::from(hcenter_count);
-                                let full_height = (100.0 - right_height_percent) / f32::from(right_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::BottomLeft => {
-                                let full_width = (100.0 - bottom_width_percent) / f32::from(bottom_count);
-                                let full_height = (100.0 - left_height_percent) / f32::from(left_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::Bottom => {
-                                let full_width = (100.0 - bottom_width_percent) / f32::from(bottom_count);
-                                let full_height = (100.0 - center_height_percent) / f32::from(vcenter_count);
-                                Size::Size(full_width, full_height)
-                            }
-                            Position::BottomRight => {
-                                let full_width = (100.0 - bottom_width_percent) / f32::from(bottom_count);
-                                let full_height = (100.0 - right_height_percent) / f32::from(right_count);
-                                Size::Size(full_width, full_height)
-                            }
-                        };
-                        widget.set_size(size);
-                    }
-                }
-            }
-        }
-    }
-    Ok(ui)
+    Ok(batches)
 }
@@ -11,10 +11,36 @@
 use self::super::{
     prelude::*,
     window::dpi::LogicalSize,
+    CheckboxBuilder,
+    ComboBoxBuilder,
     ContainerBuilder,
+    Dialog,
+    DialogBuilder,
+    DockAreaBuilder,
     DockBuilder,
+    DockPanelBuilder,
+    ImageBuilder,
     LabelBuilder,
+    ListViewBuilder,
+    MenuBarBuilder,
+    MenuBuilder,
+    MenuItem,
+    ProgressBarBuilder,
+    RadioButtonBuilder,
+    RadioGroupBuilder,
+    SliderBuilder,
+    SpinBoxBuilder,
+    SplitterBuilder,
+    TableBuilder,
+    TableColumn,
+    TabsBuilder,
+    TextAreaBuilder,
+    TextInputBuilder,
+    ToggleBuilder,
     ToolbarBuilder,
+    TooltipBuilder,
+    TreeNode,
+    TreeViewBuilder,
     WindowContainerBuilder,
 };
 use serde::de::DeserializeOwned;
@@ -23,6 +49,7 @@ use std::{
     fs::File,
     io::Read,
     path::Path,
+    time::Duration,
 };
 use toml::from_slice;
 
@@ -33,6 +60,27 @@ struct Widgets {
     dock:             Option<Vec<DockMarkup>>,
     container:        Option<Vec<ContainerMarkup>>,
     label:            Option<Vec<LabelMarkup>>,
+    image:            Option<Vec<ImageMarkup>>,
+    text_area:        Option<Vec<TextAreaMarkup>>,
+    text_input:       Option<Vec<TextInputMarkup>>,
+    checkbox:         Option<Vec<CheckboxMarkup>>,
+    radio_button:     Option<Vec<RadioButtonMarkup>>,
+    radio_group:      Option<Vec<RadioGroupMarkup>>,
+    toggle:           Option<Vec<ToggleMarkup>>,
+    slider:           Option<Vec<SliderMarkup>>,
+    progress_bar:     Option<Vec<ProgressBarMarkup>>,
+    spin_box:         Option<Vec<SpinBoxMarkup>>,
+    list_view:        Option<Vec<ListViewMarkup>>,
+    table:            Option<Vec<TableMarkup>>,
+    tree_view:        Option<Vec<TreeViewMarkup>>,
+    tabs:             Option<Vec<TabsMarkup>>,
+    menu_bar:         Option<Vec<MenuBarMarkup>>,
+    menu:             Option<Vec<MenuMarkup>>,
+    combo_box:        Option<Vec<ComboBoxMarkup>>,
+    dialog:           Option<Vec<DialogMarkup>>,
+    tooltip:          Option<Vec<TooltipMarkup>>,
+    splitter:         Option<Vec<SplitterMarkup>>,
+    dock_area:        Option<Vec<DockAreaMarkup>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -47,6 +95,7 @@ struct WindowContainerMarkup {
     resizable:      Option<bool>,
     maximized:      Option<bool>,
     visible:        Option<bool>,
+    opacity:        Option<f32>,
     transparent:    Option<bool>,
     decorations:    Option<bool>,
     always_on_top:  Option<bool>,
@@ -55,66 +104,917 @@ struct WindowContainerMarkup {
     container:      Option<Vec<ContainerMarkup>>,
     dock:           Option<Vec<DockMarkup>>,
     label:          Option<Vec<LabelMarkup>>,
+    image:          Option<Vec<ImageMarkup>>,
+    text_area:      Option<Vec<TextAreaMarkup>>,
+    text_input:     Option<Vec<TextInputMarkup>>,
+    checkbox:       Option<Vec<CheckboxMarkup>>,
+    radio_group:    Option<Vec<RadioGroupMarkup>>,
+    toggle:         Option<Vec<ToggleMarkup>>,
+    slider:         Option<Vec<SliderMarkup>>,
+    progress_bar:   Option<Vec<ProgressBarMarkup>>,
+    spin_box:       Option<Vec<SpinBoxMarkup>>,
+    list_view:      Option<Vec<ListViewMarkup>>,
+    table:          Option<Vec<TableMarkup>>,
+    tree_view:      Option<Vec<TreeViewMarkup>>,
+    tabs:           Option<Vec<TabsMarkup>>,
+    menu_bar:       Option<Vec<MenuBarMarkup>>,
+    menu:           Option<Vec<MenuMarkup>>,
+    combo_box:      Option<Vec<ComboBoxMarkup>>,
+    dialog:         Option<Vec<DialogMarkup>>,
+    tooltip:        Option<Vec<TooltipMarkup>>,
+    splitter:       Option<Vec<SplitterMarkup>>,
+    dock_area:      Option<Vec<DockAreaMarkup>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 struct ToolbarMarkup {
-    id:          Id,
-    thickness:   Option<DockSize>,
-    orientation: Option<Orientation>,
-    color:       Option<String>,
-    parent_id:   Option<Id>,
-    visible:     Option<bool>,
-    toolbar:     Option<Vec<ToolbarMarkup>>,
-    container:   Option<Vec<ContainerMarkup>>,
-    dock:        Option<Vec<DockMarkup>>,
-    label:       Option<Vec<LabelMarkup>>,
+    id:                 Id,
+    thickness:          Option<DockSize>,
+    orientation:        Option<Orientation>,
+    color:              Option<String>,
+    parent_id:          Option<Id>,
+    visible:            Option<bool>,
+    opacity:            Option<f32>,
+    overflow:           Option<Overflow>,
+    z_index:            Option<i32>,
+    layer:              Option<Layer>,
+    border_color:       Option<String>,
+    border_width:       Option<f32>,
+    corner_radius:      Option<f32>,
+    corner_radii:       Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<[f32; 2]>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       Option<String>,
+    toolbar:            Option<Vec<ToolbarMarkup>>,
+    container:          Option<Vec<ContainerMarkup>>,
+    dock:               Option<Vec<DockMarkup>>,
+    label:              Option<Vec<LabelMarkup>>,
+    image:              Option<Vec<ImageMarkup>>,
+    text_area:          Option<Vec<TextAreaMarkup>>,
+    text_input:         Option<Vec<TextInputMarkup>>,
+    checkbox:           Option<Vec<CheckboxMarkup>>,
+    radio_group:        Option<Vec<RadioGroupMarkup>>,
+    toggle:             Option<Vec<ToggleMarkup>>,
+    slider:             Option<Vec<SliderMarkup>>,
+    progress_bar:       Option<Vec<ProgressBarMarkup>>,
+    spin_box:           Option<Vec<SpinBoxMarkup>>,
+    list_view:          Option<Vec<ListViewMarkup>>,
+    table:              Option<Vec<TableMarkup>>,
+    tree_view:          Option<Vec<TreeViewMarkup>>,
+    tabs:               Option<Vec<TabsMarkup>>,
+    menu_bar:           Option<Vec<MenuBarMarkup>>,
+    menu:               Option<Vec<MenuMarkup>>,
+    combo_box:          Option<Vec<ComboBoxMarkup>>,
+    dialog:             Option<Vec<DialogMarkup>>,
+    tooltip:            Option<Vec<TooltipMarkup>>,
+    splitter:           Option<Vec<SplitterMarkup>>,
+    dock_area:          Option<Vec<DockAreaMarkup>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 struct ContainerMarkup {
-    id:        Id,
-    position:  Position,
-    size:      Option<Size>,
-    color:     Option<String>,
-    parent_id: Option<Id>,
-    visible:   Option<bool>,
-    toolbar:   Option<Vec<ToolbarMarkup>>,
-    container: Option<Vec<ContainerMarkup>>,
-    dock:      Option<Vec<DockMarkup>>,
-    label:     Option<Vec<LabelMarkup>>,
+    id:                 Id,
+    position:           Position,
+    size:               Option<Size>,
+    color:              Option<String>,
+    parent_id:          Option<Id>,
+    visible:            Option<bool>,
+    opacity:            Option<f32>,
+    overflow:           Option<Overflow>,
+    z_index:            Option<i32>,
+    layer:              Option<Layer>,
+    border_color:       Option<String>,
+    border_width:       Option<f32>,
+    corner_radius:      Option<f32>,
+    corner_radii:       Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<[f32; 2]>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       Option<String>,
+    background_image:   Option<String>,
+    background_insets:  Option<[u32; 4]>,
+    toolbar:            Option<Vec<ToolbarMarkup>>,
+    container:          Option<Vec<ContainerMarkup>>,
+    dock:               Option<Vec<DockMarkup>>,
+    label:              Option<Vec<LabelMarkup>>,
+    image:              Option<Vec<ImageMarkup>>,
+    text_area:          Option<Vec<TextAreaMarkup>>,
+    text_input:         Option<Vec<TextInputMarkup>>,
+    checkbox:           Option<Vec<CheckboxMarkup>>,
+    radio_group:        Option<Vec<RadioGroupMarkup>>,
+    toggle:             Option<Vec<ToggleMarkup>>,
+    slider:             Option<Vec<SliderMarkup>>,
+    progress_bar:       Option<Vec<ProgressBarMarkup>>,
+    spin_box:           Option<Vec<SpinBoxMarkup>>,
+    list_view:          Option<Vec<ListViewMarkup>>,
+    table:              Option<Vec<TableMarkup>>,
+    tree_view:          Option<Vec<TreeViewMarkup>>,
+    tabs:               Option<Vec<TabsMarkup>>,
+    menu_bar:           Option<Vec<MenuBarMarkup>>,
+    menu:               Option<Vec<MenuMarkup>>,
+    combo_box:          Option<Vec<ComboBoxMarkup>>,
+    dialog:             Option<Vec<DialogMarkup>>,
+    tooltip:            Option<Vec<TooltipMarkup>>,
+    splitter:           Option<Vec<SplitterMarkup>>,
+    dock_area:          Option<Vec<DockAreaMarkup>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 struct DockMarkup {
-    id:          Id,
-    length:      Option<f32>,
-    thickness:   Option<DockSize>,
-    orientation: Option<Orientation>,
-    color:       Option<String>,
-    parent_id:   Option<Id>,
-    visible:     Option<bool>,
-    toolbar:     Option<Vec<ToolbarMarkup>>,
-    container:   Option<Vec<ContainerMarkup>>,
-    dock:        Option<Vec<DockMarkup>>,
-    label:       Option<Vec<LabelMarkup>>,
+    id:                 Id,
+    length:             Option<f32>,
+    thickness:          Option<DockSize>,
+    orientation:        Option<Orientation>,
+    color:              Option<String>,
+    parent_id:          Option<Id>,
+    visible:            Option<bool>,
+    opacity:            Option<f32>,
+    overflow:           Option<Overflow>,
+    z_index:            Option<i32>,
+    layer:              Option<Layer>,
+    border_color:       Option<String>,
+    border_width:       Option<f32>,
+    corner_radius:      Option<f32>,
+    corner_radii:       Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<[f32; 2]>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       Option<String>,
+    background_image:   Option<String>,
+    background_insets:  Option<[u32; 4]>,
+    toolbar:            Option<Vec<ToolbarMarkup>>,
+    container:          Option<Vec<ContainerMarkup>>,
+    dock:               Option<Vec<DockMarkup>>,
+    label:              Option<Vec<LabelMarkup>>,
+    image:              Option<Vec<ImageMarkup>>,
+    text_area:          Option<Vec<TextAreaMarkup>>,
+    text_input:         Option<Vec<TextInputMarkup>>,
+    checkbox:           Option<Vec<CheckboxMarkup>>,
+    radio_group:        Option<Vec<RadioGroupMarkup>>,
+    toggle:             Option<Vec<ToggleMarkup>>,
+    slider:             Option<Vec<SliderMarkup>>,
+    progress_bar:       Option<Vec<ProgressBarMarkup>>,
+    spin_box:           Option<Vec<SpinBoxMarkup>>,
+    list_view:          Option<Vec<ListViewMarkup>>,
+    table:              Option<Vec<TableMarkup>>,
+    tree_view:          Option<Vec<TreeViewMarkup>>,
+    tabs:               Option<Vec<TabsMarkup>>,
+    menu_bar:           Option<Vec<MenuBarMarkup>>,
+    menu:               Option<Vec<MenuMarkup>>,
+    combo_box:          Option<Vec<ComboBoxMarkup>>,
+    dialog:             Option<Vec<DialogMarkup>>,
+    tooltip:            Option<Vec<TooltipMarkup>>,
+    splitter:           Option<Vec<SplitterMarkup>>,
+    dock_area:          Option<Vec<DockAreaMarkup>>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 struct LabelMarkup {
+    id:                 Id,
+    text:               String,
+    position:           Position,
+    size:               Option<Size>,
+    background_color:   Option<String>,
+    text_color:         Option<String>,
+    text_size:          Option<f32>,
+    parent_id:          Option<Id>,
+    visible:            Option<bool>,
+    opacity:            Option<f32>,
+    overflow:           Option<Overflow>,
+    z_index:            Option<i32>,
+    layer:              Option<Layer>,
+    border_color:       Option<String>,
+    border_width:       Option<f32>,
+    corner_radius:      Option<f32>,
+    corner_radii:       Option<[f32; 4]>,
+    elevation:          Option<f32>,
+    shadow_offset:      Option<[f32; 2]>,
+    shadow_blur_radius: Option<f32>,
+    shadow_color:       Option<String>,
+    toolbar:            Option<Vec<ToolbarMarkup>>,
+    container:          Option<Vec<ContainerMarkup>>,
+    dock:               Option<Vec<DockMarkup>>,
+    label:              Option<Vec<LabelMarkup>>,
+    image:              Option<Vec<ImageMarkup>>,
+    text_area:          Option<Vec<TextAreaMarkup>>,
+    text_input:         Option<Vec<TextInputMarkup>>,
+    checkbox:           Option<Vec<CheckboxMarkup>>,
+    radio_group:        Option<Vec<RadioGroupMarkup>>,
+    toggle:             Option<Vec<ToggleMarkup>>,
+    slider:             Option<Vec<SliderMarkup>>,
+    progress_bar:       Option<Vec<ProgressBarMarkup>>,
+    spin_box:           Option<Vec<SpinBoxMarkup>>,
+    list_view:          Option<Vec<ListViewMarkup>>,
+    table:              Option<Vec<TableMarkup>>,
+    tree_view:          Option<Vec<TreeViewMarkup>>,
+    tabs:               Option<Vec<TabsMarkup>>,
+    menu_bar:           Option<Vec<MenuBarMarkup>>,
+    menu:               Option<Vec<MenuMarkup>>,
+    combo_box:          Option<Vec<ComboBoxMarkup>>,
+    dialog:             Option<Vec<DialogMarkup>>,
+    tooltip:            Option<Vec<TooltipMarkup>>,
+    splitter:           Option<Vec<SplitterMarkup>>,
+    dock_area:          Option<Vec<DockAreaMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ImageMarkup {
+    id:            Id,
+    position:      Position,
+    size:          Option<Size>,
+    path:          String,
+    fit:           Option<Fit>,
+    tint:          Option<String>,
+    parent_id:     Option<Id>,
+    visible:       Option<bool>,
+    opacity:       Option<f32>,
+    z_index:       Option<i32>,
+    layer:         Option<Layer>,
+    border_color:  Option<String>,
+    border_width:  Option<f32>,
+    corner_radius: Option<f32>,
+    corner_radii:  Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct TextAreaMarkup {
+    id:                Id,
+    position:          Position,
+    size:              Option<Size>,
+    text:              Option<String>,
+    placeholder:       Option<String>,
+    wrap:              Option<bool>,
+    max_length:        Option<usize>,
+    color:             Option<String>,
+    text_color:        Option<String>,
+    placeholder_color: Option<String>,
+    selection_color:   Option<String>,
+    text_size:         Option<f32>,
+    font:              Option<String>,
+    parent_id:         Option<Id>,
+    visible:           Option<bool>,
+    opacity:           Option<f32>,
+    z_index:           Option<i32>,
+    layer:             Option<Layer>,
+    border_color:      Option<String>,
+    border_width:      Option<f32>,
+    corner_radius:     Option<f32>,
+    corner_radii:      Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct TextInputMarkup {
+    id:                Id,
+    position:          Position,
+    size:              Option<Size>,
+    text:              Option<String>,
+    placeholder:       Option<String>,
+    password:          Option<bool>,
+    max_length:        Option<usize>,
+    color:             Option<String>,
+    text_color:        Option<String>,
+    placeholder_color: Option<String>,
+    selection_color:   Option<String>,
+    text_size:         Option<f32>,
+    font:              Option<String>,
+    parent_id:         Option<Id>,
+    visible:           Option<bool>,
+    opacity:           Option<f32>,
+    z_index:           Option<i32>,
+    layer:             Option<Layer>,
+    border_color:      Option<String>,
+    border_width:      Option<f32>,
+    corner_radius:     Option<f32>,
+    corner_radii:      Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct CheckboxMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    state:           Option<CheckState>,
+    checked:         Option<bool>,
+    tri_state:       Option<bool>,
+    label:           Option<String>,
+    disabled:        Option<bool>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    checked_color:   Option<String>,
+    unchecked_color: Option<String>,
+    disabled_color:  Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ToggleMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    on:              Option<bool>,
+    label:           Option<String>,
+    disabled:        Option<bool>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    checked_color:   Option<String>,
+    unchecked_color: Option<String>,
+    disabled_color:  Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct RadioGroupMarkup {
+    id:            Id,
+    position:      Position,
+    size:          Option<Size>,
+    color:         Option<String>,
+    parent_id:     Option<Id>,
+    visible:       Option<bool>,
+    opacity:       Option<f32>,
+    z_index:       Option<i32>,
+    layer:         Option<Layer>,
+    border_color:  Option<String>,
+    border_width:  Option<f32>,
+    corner_radius: Option<f32>,
+    corner_radii:  Option<[f32; 4]>,
+    radio_button:  Option<Vec<RadioButtonMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct RadioButtonMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    value:           Option<String>,
+    checked:         Option<bool>,
+    label:           Option<String>,
+    disabled:        Option<bool>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    checked_color:   Option<String>,
+    unchecked_color: Option<String>,
+    disabled_color:  Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct SliderMarkup {
+    id:             Id,
+    position:       Position,
+    size:           Option<Size>,
+    orientation:    Option<Orientation>,
+    min:            Option<f32>,
+    max:            Option<f32>,
+    step:           Option<f32>,
+    value:          Option<f32>,
+    disabled:       Option<bool>,
+    color:          Option<String>,
+    track_color:    Option<String>,
+    value_color:    Option<String>,
+    thumb_color:    Option<String>,
+    disabled_color: Option<String>,
+    parent_id:      Option<Id>,
+    visible:        Option<bool>,
+    opacity:        Option<f32>,
+    z_index:        Option<i32>,
+    layer:          Option<Layer>,
+    border_color:   Option<String>,
+    border_width:   Option<f32>,
+    corner_radius:  Option<f32>,
+    corner_radii:   Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ProgressBarMarkup {
+    id:            Id,
+    position:      Position,
+    size:          Option<Size>,
+    orientation:   Option<Orientation>,
+    progress:      Option<f32>,
+    indeterminate: Option<bool>,
+    color:         Option<String>,
+    bar_color:     Option<String>,
+    parent_id:     Option<Id>,
+    visible:       Option<bool>,
+    opacity:       Option<f32>,
+    z_index:       Option<i32>,
+    layer:         Option<Layer>,
+    border_color:  Option<String>,
+    border_width:  Option<f32>,
+    corner_radius: Option<f32>,
+    corner_radii:  Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct SpinBoxMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    value:           Option<f32>,
+    min:             Option<f32>,
+    max:             Option<f32>,
+    step:            Option<f32>,
+    decimals:        Option<usize>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    selection_color: Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ComboBoxMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    choices:         Option<Vec<String>>,
+    selected:        Option<usize>,
+    editable:        Option<bool>,
+    text:            Option<String>,
+    visible_choices: Option<usize>,
+    row_height:      Option<f32>,
+    color:           Option<String>,
+    list_color:      Option<String>,
+    text_color:      Option<String>,
+    highlight_color: Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct DialogMarkup {
+    id:                   Id,
+    position:             Position,
+    size:                 Option<Size>,
+    title:                Option<String>,
+    text:                 Option<String>,
+    buttons:              Option<Vec<String>>,
+    default_button:       Option<usize>,
+    open:                 Option<bool>,
+    color:                Option<String>,
+    title_color:          Option<String>,
+    text_color:           Option<String>,
+    button_color:         Option<String>,
+    default_button_color: Option<String>,
+    dim_color:            Option<String>,
+    text_size:            Option<f32>,
+    font:                 Option<String>,
+    parent_id:            Option<Id>,
+    opacity:              Option<f32>,
+    z_index:              Option<i32>,
+    border_color:         Option<String>,
+    border_width:         Option<f32>,
+    corner_radius:        Option<f32>,
+    corner_radii:         Option<[f32; 4]>,
+    toolbar:              Option<Vec<ToolbarMarkup>>,
+    container:            Option<Vec<ContainerMarkup>>,
+    dock:                 Option<Vec<DockMarkup>>,
+    label:                Option<Vec<LabelMarkup>>,
+    image:                Option<Vec<ImageMarkup>>,
+    text_area:            Option<Vec<TextAreaMarkup>>,
+    text_input:           Option<Vec<TextInputMarkup>>,
+    checkbox:             Option<Vec<CheckboxMarkup>>,
+    radio_group:          Option<Vec<RadioGroupMarkup>>,
+    toggle:               Option<Vec<ToggleMarkup>>,
+    slider:               Option<Vec<SliderMarkup>>,
+    progress_bar:         Option<Vec<ProgressBarMarkup>>,
+    spin_box:             Option<Vec<SpinBoxMarkup>>,
+    list_view:            Option<Vec<ListViewMarkup>>,
+    table:                Option<Vec<TableMarkup>>,
+    tree_view:            Option<Vec<TreeViewMarkup>>,
+    tabs:                 Option<Vec<TabsMarkup>>,
+    menu_bar:             Option<Vec<MenuBarMarkup>>,
+    menu:                 Option<Vec<MenuMarkup>>,
+    combo_box:            Option<Vec<ComboBoxMarkup>>,
+    dialog:               Option<Vec<DialogMarkup>>,
+    tooltip:              Option<Vec<TooltipMarkup>>,
+    splitter:             Option<Vec<SplitterMarkup>>,
+    dock_area:            Option<Vec<DockAreaMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct TooltipMarkup {
+    id:            Id,
+    text:          Option<String>,
+    targets:       Option<Vec<Id>>,
+    delay:         Option<u64>,
+    color:         Option<String>,
+    text_color:    Option<String>,
+    text_size:     Option<f32>,
+    font:          Option<String>,
+    parent_id:     Option<Id>,
+    visible:       Option<bool>,
+    opacity:       Option<f32>,
+    z_index:       Option<i32>,
+    border_color:  Option<String>,
+    border_width:  Option<f32>,
+    corner_radius: Option<f32>,
+    corner_radii:  Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct SplitterMarkup {
+    id:            Id,
+    position:      Position,
+    size:          Option<Size>,
+    orientation:   Option<Orientation>,
+    divider_width: Option<f32>,
+    color:         Option<String>,
+    divider_color: Option<String>,
+    drag_color:    Option<String>,
+    parent_id:     Option<Id>,
+    visible:       Option<bool>,
+    opacity:       Option<f32>,
+    z_index:       Option<i32>,
+    layer:         Option<Layer>,
+    border_color:  Option<String>,
+    border_width:  Option<f32>,
+    corner_radius: Option<f32>,
+    corner_radii:  Option<[f32; 4]>,
+    pane:          Option<Vec<PaneMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct PaneMarkup {
+    id:           Id,
+    ratio:        Option<f32>,
+    min_size:     Option<f32>,
+    max_size:     Option<f32>,
+    collapsible:  Option<bool>,
+    color:        Option<String>,
+    toolbar:      Option<Vec<ToolbarMarkup>>,
+    container:    Option<Vec<ContainerMarkup>>,
+    dock:         Option<Vec<DockMarkup>>,
+    label:        Option<Vec<LabelMarkup>>,
+    image:        Option<Vec<ImageMarkup>>,
+    text_area:    Option<Vec<TextAreaMarkup>>,
+    text_input:   Option<Vec<TextInputMarkup>>,
+    checkbox:     Option<Vec<CheckboxMarkup>>,
+    radio_group:  Option<Vec<RadioGroupMarkup>>,
+    toggle:       Option<Vec<ToggleMarkup>>,
+    slider:       Option<Vec<SliderMarkup>>,
+    progress_bar: Option<Vec<ProgressBarMarkup>>,
+    spin_box:     Option<Vec<SpinBoxMarkup>>,
+    list_view:    Option<Vec<ListViewMarkup>>,
+    table:        Option<Vec<TableMarkup>>,
+    tree_view:    Option<Vec<TreeViewMarkup>>,
+    tabs:         Option<Vec<TabsMarkup>>,
+    menu_bar:     Option<Vec<MenuBarMarkup>>,
+    menu:         Option<Vec<MenuMarkup>>,
+    combo_box:    Option<Vec<ComboBoxMarkup>>,
+    dialog:       Option<Vec<DialogMarkup>>,
+    tooltip:      Option<Vec<TooltipMarkup>>,
+    splitter:     Option<Vec<SplitterMarkup>>,
+    dock_area:    Option<Vec<DockAreaMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct DockAreaMarkup {
+    id:            Id,
+    position:      Position,
+    size:          Option<Size>,
+    color:         Option<String>,
+    preview_color: Option<String>,
+    tab_height:    Option<f32>,
+    parent_id:     Option<Id>,
+    opacity:       Option<f32>,
+    z_index:       Option<i32>,
+    layer:         Option<Layer>,
+    border_color:  Option<String>,
+    border_width:  Option<f32>,
+    corner_radius: Option<f32>,
+    corner_radii:  Option<[f32; 4]>,
+    panel:         Option<Vec<DockPanelMarkup>>,
+    toolbar:       Option<Vec<ToolbarMarkup>>,
+    container:     Option<Vec<ContainerMarkup>>,
+    dock:          Option<Vec<DockMarkup>>,
+    label:         Option<Vec<LabelMarkup>>,
+    image:         Option<Vec<ImageMarkup>>,
+    text_area:     Option<Vec<TextAreaMarkup>>,
+    text_input:    Option<Vec<TextInputMarkup>>,
+    checkbox:      Option<Vec<CheckboxMarkup>>,
+    radio_group:   Option<Vec<RadioGroupMarkup>>,
+    toggle:        Option<Vec<ToggleMarkup>>,
+    slider:        Option<Vec<SliderMarkup>>,
+    progress_bar:  Option<Vec<ProgressBarMarkup>>,
+    spin_box:      Option<Vec<SpinBoxMarkup>>,
+    list_view:     Option<Vec<ListViewMarkup>>,
+    table:         Option<Vec<TableMarkup>>,
+    tree_view:     Option<Vec<TreeViewMarkup>>,
+    tabs:          Option<Vec<TabsMarkup>>,
+    menu_bar:      Option<Vec<MenuBarMarkup>>,
+    menu:          Option<Vec<MenuMarkup>>,
+    combo_box:     Option<Vec<ComboBoxMarkup>>,
+    dialog:        Option<Vec<DialogMarkup>>,
+    tooltip:       Option<Vec<TooltipMarkup>>,
+    splitter:      Option<Vec<SplitterMarkup>>,
+    dock_area:     Option<Vec<DockAreaMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct DockPanelMarkup {
     id:               Id,
-    text:             String,
-    position:         Position,
-    size:             Option<Size>,
-    background_color: Option<String>,
+    title:            Option<String>,
+    edge:             Option<Orientation>,
+    dock_size:        Option<f32>,
+    floating:         Option<[f32; 4]>,
+    color:            Option<String>,
+    tab_color:        Option<String>,
+    active_tab_color: Option<String>,
     text_color:       Option<String>,
     text_size:        Option<f32>,
-    parent_id:        Option<Id>,
-    visible:          Option<bool>,
+    font:             Option<String>,
+    opacity:          Option<f32>,
+    z_index:          Option<i32>,
+    border_color:     Option<String>,
+    border_width:     Option<f32>,
+    corner_radius:    Option<f32>,
+    corner_radii:     Option<[f32; 4]>,
     toolbar:          Option<Vec<ToolbarMarkup>>,
     container:        Option<Vec<ContainerMarkup>>,
     dock:             Option<Vec<DockMarkup>>,
     label:            Option<Vec<LabelMarkup>>,
+    image:            Option<Vec<ImageMarkup>>,
+    text_area:        Option<Vec<TextAreaMarkup>>,
+    text_input:       Option<Vec<TextInputMarkup>>,
+    checkbox:         Option<Vec<CheckboxMarkup>>,
+    radio_group:      Option<Vec<RadioGroupMarkup>>,
+    toggle:           Option<Vec<ToggleMarkup>>,
+    slider:           Option<Vec<SliderMarkup>>,
+    progress_bar:     Option<Vec<ProgressBarMarkup>>,
+    spin_box:         Option<Vec<SpinBoxMarkup>>,
+    list_view:        Option<Vec<ListViewMarkup>>,
+    table:            Option<Vec<TableMarkup>>,
+    tree_view:        Option<Vec<TreeViewMarkup>>,
+    tabs:             Option<Vec<TabsMarkup>>,
+    menu_bar:         Option<Vec<MenuBarMarkup>>,
+    menu:             Option<Vec<MenuMarkup>>,
+    combo_box:        Option<Vec<ComboBoxMarkup>>,
+    dialog:           Option<Vec<DialogMarkup>>,
+    tooltip:          Option<Vec<TooltipMarkup>>,
+    splitter:         Option<Vec<SplitterMarkup>>,
+    dock_area:        Option<Vec<DockAreaMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ListViewMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    items:           Option<Vec<String>>,
+    selection_mode:  Option<SelectionMode>,
+    selected:        Option<Vec<usize>>,
+    row_height:      Option<f32>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    selection_color: Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct TableMarkup {
+    id:                Id,
+    position:          Position,
+    size:              Option<Size>,
+    rows:              Option<Vec<Vec<String>>>,
+    sort_column:       Option<usize>,
+    sort_order:        Option<SortOrder>,
+    selection_mode:    Option<SelectionMode>,
+    row_height:        Option<f32>,
+    color:             Option<String>,
+    text_color:        Option<String>,
+    header_color:      Option<String>,
+    header_text_color: Option<String>,
+    grid_color:        Option<String>,
+    selection_color:   Option<String>,
+    text_size:         Option<f32>,
+    font:              Option<String>,
+    parent_id:         Option<Id>,
+    visible:           Option<bool>,
+    opacity:           Option<f32>,
+    z_index:           Option<i32>,
+    layer:             Option<Layer>,
+    border_color:      Option<String>,
+    border_width:      Option<f32>,
+    corner_radius:     Option<f32>,
+    corner_radii:      Option<[f32; 4]>,
+    column:            Option<Vec<ColumnMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ColumnMarkup {
+    title:     String,
+    width:     Option<f32>,
+    min_width: Option<f32>,
+    sortable:  Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct TreeViewMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    selection_mode:  Option<SelectionMode>,
+    row_height:      Option<f32>,
+    indent:          Option<f32>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    guide_color:     Option<String>,
+    selection_color: Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+    node:            Option<Vec<NodeMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct NodeMarkup {
+    text:     String,
+    expanded: Option<bool>,
+    node:     Option<Vec<NodeMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct TabsMarkup {
+    id:               Id,
+    position:         Position,
+    size:             Option<Size>,
+    active:           Option<Id>,
+    closable:         Option<bool>,
+    tab_height:       Option<f32>,
+    color:            Option<String>,
+    tab_color:        Option<String>,
+    active_tab_color: Option<String>,
+    text_color:       Option<String>,
+    text_size:        Option<f32>,
+    font:             Option<String>,
+    parent_id:        Option<Id>,
+    visible:          Option<bool>,
+    opacity:          Option<f32>,
+    z_index:          Option<i32>,
+    layer:            Option<Layer>,
+    border_color:     Option<String>,
+    border_width:     Option<f32>,
+    corner_radius:    Option<f32>,
+    corner_radii:     Option<[f32; 4]>,
+    page:             Option<Vec<PageMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct PageMarkup {
+    id:           Id,
+    title:        String,
+    color:        Option<String>,
+    toolbar:      Option<Vec<ToolbarMarkup>>,
+    container:    Option<Vec<ContainerMarkup>>,
+    dock:         Option<Vec<DockMarkup>>,
+    label:        Option<Vec<LabelMarkup>>,
+    image:        Option<Vec<ImageMarkup>>,
+    text_area:    Option<Vec<TextAreaMarkup>>,
+    text_input:   Option<Vec<TextInputMarkup>>,
+    checkbox:     Option<Vec<CheckboxMarkup>>,
+    radio_group:  Option<Vec<RadioGroupMarkup>>,
+    toggle:       Option<Vec<ToggleMarkup>>,
+    slider:       Option<Vec<SliderMarkup>>,
+    progress_bar: Option<Vec<ProgressBarMarkup>>,
+    spin_box:     Option<Vec<SpinBoxMarkup>>,
+    list_view:    Option<Vec<ListViewMarkup>>,
+    table:        Option<Vec<TableMarkup>>,
+    tree_view:    Option<Vec<TreeViewMarkup>>,
+    tabs:         Option<Vec<TabsMarkup>>,
+    menu_bar:     Option<Vec<MenuBarMarkup>>,
+    menu:         Option<Vec<MenuMarkup>>,
+    combo_box:    Option<Vec<ComboBoxMarkup>>,
+    dialog:       Option<Vec<DialogMarkup>>,
+    tooltip:      Option<Vec<TooltipMarkup>>,
+    splitter:     Option<Vec<SplitterMarkup>>,
+    dock_area:    Option<Vec<DockAreaMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct MenuBarMarkup {
+    id:              Id,
+    position:        Position,
+    size:            Option<Size>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    highlight_color: Option<String>,
+    text_size:       Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    visible:         Option<bool>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    layer:           Option<Layer>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    corner_radius:   Option<f32>,
+    corner_radii:    Option<[f32; 4]>,
+    menu:            Option<Vec<BarMenuMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct BarMenuMarkup {
+    id:    Id,
+    title: String,
+    item:  Option<Vec<ItemMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct MenuMarkup {
+    id:              Id,
+    target:          Option<Vec<Id>>,
+    color:           Option<String>,
+    text_color:      Option<String>,
+    highlight_color: Option<String>,
+    disabled_color:  Option<String>,
+    text_size:       Option<f32>,
+    item_height:     Option<f32>,
+    font:            Option<String>,
+    parent_id:       Option<Id>,
+    opacity:         Option<f32>,
+    z_index:         Option<i32>,
+    border_color:    Option<String>,
+    border_width:    Option<f32>,
+    item:            Option<Vec<ItemMarkup>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ItemMarkup {
+    id:        Option<Id>,
+    text:      Option<String>,
+    shortcut:  Option<String>,
+    checked:   Option<bool>,
+    enabled:   Option<bool>,
+    separator: Option<bool>,
+    item:      Option<Vec<ItemMarkup>>,
 }
 
 fn from_reader<R, T>(r: &mut R) -> Result<T, Error>
@@ -199,6 +1099,131 @@ impl MlBuild for Ui {
             }
         }
 
+        if let Some(widget) = widgets.image {
+            for image in widget {
+                add_image(self, image, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.text_area {
+            for text_area in widget {
+                add_text_area(self, text_area, None)?;
+            }
+        }
+        if let Some(widget) = widgets.text_input {
+            for text_input in widget {
+                add_text_input(self, text_input, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.checkbox {
+            for checkbox in widget {
+                add_checkbox(self, checkbox, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.radio_group {
+            for radio_group in widget {
+                add_radio_group(self, radio_group, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.radio_button {
+            for radio_button in widget {
+                add_radio_button(self, radio_button, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.toggle {
+            for toggle in widget {
+                add_toggle(self, toggle, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.slider {
+            for slider in widget {
+                add_slider(self, slider, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.progress_bar {
+            for progress_bar in widget {
+                add_progress_bar(self, progress_bar, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.spin_box {
+            for spin_box in widget {
+                add_spin_box(self, spin_box, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.list_view {
+            for list_view in widget {
+                add_list_view(self, list_view, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.table {
+            for table in widget {
+                add_table(self, table, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.tree_view {
+            for tree_view in widget {
+                add_tree_view(self, tree_view, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.tabs {
+            for tabs in widget {
+                add_tabs(self, tabs, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.menu_bar {
+            for menu_bar in widget {
+                add_menu_bar(self, menu_bar, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.menu {
+            for menu in widget {
+                add_menu(self, menu, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.combo_box {
+            for combo_box in widget {
+                add_combo_box(self, combo_box, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.dialog {
+            for dialog in widget {
+                add_dialog(self, dialog, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.tooltip {
+            for tooltip in widget {
+                add_tooltip(self, tooltip, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.splitter {
+            for splitter in widget {
+                add_splitter(self, splitter, None)?;
+            }
+        }
+
+        if let Some(widget) = widgets.dock_area {
+            for dock_area in widget {
+                add_dock_area(self, dock_area, None)?;
+            }
+        }
+
         Ok(self)
     }
 }
@@ -246,6 +1271,9 @@ fn add_window_container(ui: &mut Ui, widget: WindowContainerMarkup) -> Result<&m
             builder.with_multitouch();
         }
     }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
 
     builder.build(ui)?;
 
@@ -273,117 +1301,129 @@ fn add_window_container(ui: &mut Ui, widget: WindowContainerMarkup) -> Result<&m
         }
     }
 
-    Ok(ui)
-}
-
-fn add_container(ui: &mut Ui, widget: ContainerMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
-    let id = widget.id;
-    let parent_id = match parent_id {
-        Some(val) => val,
-        None => match widget.parent_id {
-            None => {
-                return Err(err_msg(
-                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
-                ));
-            }
-            Some(val) => val,
-        },
-    };
-
-    let mut builder = ContainerBuilder::new(id.clone(), parent_id, widget.position);
+    if let Some(widget) = widget.image {
+        for image in widget {
+            add_image(ui, image, Some(id.clone()))?;
+        }
+    }
 
-    if let Some(val) = widget.color {
-        builder.with_color(val);
+    if let Some(widget) = widget.text_area {
+        for text_area in widget {
+            add_text_area(ui, text_area, Some(id.clone()))?;
+        }
     }
-    if let Some(val) = widget.size {
-        builder.with_size(val);
+    if let Some(widget) = widget.text_input {
+        for text_input in widget {
+            add_text_input(ui, text_input, Some(id.clone()))?;
+        }
     }
 
-    builder.build(ui)?;
+    if let Some(widget) = widget.checkbox {
+        for checkbox in widget {
+            add_checkbox(ui, checkbox, Some(id.clone()))?;
+        }
+    }
 
-    if let Some(widget) = widget.container {
-        for container in widget {
-            add_container(ui, container, Some(id.clone()))?;
+    if let Some(widget) = widget.radio_group {
+        for radio_group in widget {
+            add_radio_group(ui, radio_group, Some(id.clone()))?;
         }
     }
 
-    if let Some(widget) = widget.toolbar {
-        for toolbar in widget {
-            add_toolbar(ui, toolbar, Some(id.clone()))?;
+    if let Some(widget) = widget.toggle {
+        for toggle in widget {
+            add_toggle(ui, toggle, Some(id.clone()))?;
         }
     }
 
-    if let Some(widget) = widget.dock {
-        for dock in widget {
-            add_dock(ui, dock, Some(id.clone()))?;
+    if let Some(widget) = widget.slider {
+        for slider in widget {
+            add_slider(ui, slider, Some(id.clone()))?;
         }
     }
 
-    if let Some(widget) = widget.label {
-        for label in widget {
-            add_label(ui, label, Some(id.clone()))?;
+    if let Some(widget) = widget.progress_bar {
+        for progress_bar in widget {
+            add_progress_bar(ui, progress_bar, Some(id.clone()))?;
         }
     }
 
-    Ok(ui)
-}
+    if let Some(widget) = widget.spin_box {
+        for spin_box in widget {
+            add_spin_box(ui, spin_box, Some(id.clone()))?;
+        }
+    }
 
-fn add_toolbar(ui: &mut Ui, widget: ToolbarMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
-    let id = widget.id;
-    let parent_id = match parent_id {
-        Some(val) => val,
-        None => match widget.parent_id {
-            None => {
-                return Err(err_msg(
-                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
-                ));
-            }
-            Some(val) => val,
-        },
-    };
+    if let Some(widget) = widget.list_view {
+        for list_view in widget {
+            add_list_view(ui, list_view, Some(id.clone()))?;
+        }
+    }
 
-    let mut builder = ToolbarBuilder::new(id.clone(), parent_id);
+    if let Some(widget) = widget.table {
+        for table in widget {
+            add_table(ui, table, Some(id.clone()))?;
+        }
+    }
 
-    if let Some(val) = widget.color {
-        builder.with_color(val);
+    if let Some(widget) = widget.tree_view {
+        for tree_view in widget {
+            add_tree_view(ui, tree_view, Some(id.clone()))?;
+        }
     }
-    if let Some(val) = widget.orientation {
-        builder.with_orientation(val);
+
+    if let Some(widget) = widget.tabs {
+        for tabs in widget {
+            add_tabs(ui, tabs, Some(id.clone()))?;
+        }
     }
-    if let Some(val) = widget.thickness {
-        builder.with_thickness(val);
+
+    if let Some(widget) = widget.menu_bar {
+        for menu_bar in widget {
+            add_menu_bar(ui, menu_bar, Some(id.clone()))?;
+        }
     }
 
-    builder.build(ui)?;
+    if let Some(widget) = widget.menu {
+        for menu in widget {
+            add_menu(ui, menu, Some(id.clone()))?;
+        }
+    }
 
-    if let Some(widget) = widget.container {
-        for container in widget {
-            add_container(ui, container, Some(id.clone()))?;
+    if let Some(widget) = widget.combo_box {
+        for combo_box in widget {
+            add_combo_box(ui, combo_box, Some(id.clone()))?;
         }
     }
 
-    if let Some(widget) = widget.toolbar {
-        for toolbar in widget {
-            add_toolbar(ui, toolbar, Some(id.clone()))?;
+    if let Some(widget) = widget.dialog {
+        for dialog in widget {
+            add_dialog(ui, dialog, Some(id.clone()))?;
         }
     }
 
-    if let Some(widget) = widget.dock {
-        for dock in widget {
-            add_dock(ui, dock, Some(id.clone()))?;
+    if let Some(widget) = widget.tooltip {
+        for tooltip in widget {
+            add_tooltip(ui, tooltip, Some(id.clone()))?;
         }
     }
 
-    if let Some(widget) = widget.label {
-        for label in widget {
-            add_label(ui, label, Some(id.clone()))?;
+    if let Some(widget) = widget.splitter {
+        for splitter in widget {
+            add_splitter(ui, splitter, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dock_area {
+        for dock_area in widget {
+            add_dock_area(ui, dock_area, Some(id.clone()))?;
         }
     }
 
     Ok(ui)
 }
 
-fn add_dock(ui: &mut Ui, widget: DockMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+fn add_container(ui: &mut Ui, widget: ContainerMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
     let id = widget.id;
     let parent_id = match parent_id {
         Some(val) => val,
@@ -397,7 +1437,223 @@ fn add_dock(ui: &mut Ui, widget: DockMarkup, parent_id: Option<Id>) -> Result<&m
         },
     };
 
-    let mut builder = DockBuilder::new(id.clone(), parent_id);
+    let mut builder = ContainerBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.overflow {
+        builder.with_overflow(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+    if let Some(val) = widget.elevation {
+        builder.with_elevation(val);
+    }
+    if let Some(val) = widget.shadow_offset {
+        builder.with_shadow_offset(val[0], val[1]);
+    }
+    if let Some(val) = widget.shadow_blur_radius {
+        builder.with_shadow_blur_radius(val);
+    }
+    if let Some(val) = widget.shadow_color {
+        builder.with_shadow_color(val);
+    }
+    if let Some(val) = widget.background_image {
+        builder.with_background_image(val);
+    }
+    if let Some(val) = widget.background_insets {
+        builder.with_background_insets(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    if let Some(widget) = widget.container {
+        for container in widget {
+            add_container(ui, container, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.toolbar {
+        for toolbar in widget {
+            add_toolbar(ui, toolbar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dock {
+        for dock in widget {
+            add_dock(ui, dock, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.label {
+        for label in widget {
+            add_label(ui, label, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.image {
+        for image in widget {
+            add_image(ui, image, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.text_area {
+        for text_area in widget {
+            add_text_area(ui, text_area, Some(id.clone()))?;
+        }
+    }
+    if let Some(widget) = widget.text_input {
+        for text_input in widget {
+            add_text_input(ui, text_input, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.checkbox {
+        for checkbox in widget {
+            add_checkbox(ui, checkbox, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.radio_group {
+        for radio_group in widget {
+            add_radio_group(ui, radio_group, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.toggle {
+        for toggle in widget {
+            add_toggle(ui, toggle, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.slider {
+        for slider in widget {
+            add_slider(ui, slider, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.progress_bar {
+        for progress_bar in widget {
+            add_progress_bar(ui, progress_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.spin_box {
+        for spin_box in widget {
+            add_spin_box(ui, spin_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.list_view {
+        for list_view in widget {
+            add_list_view(ui, list_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.table {
+        for table in widget {
+            add_table(ui, table, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tree_view {
+        for tree_view in widget {
+            add_tree_view(ui, tree_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tabs {
+        for tabs in widget {
+            add_tabs(ui, tabs, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu_bar {
+        for menu_bar in widget {
+            add_menu_bar(ui, menu_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu {
+        for menu in widget {
+            add_menu(ui, menu, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.combo_box {
+        for combo_box in widget {
+            add_combo_box(ui, combo_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dialog {
+        for dialog in widget {
+            add_dialog(ui, dialog, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tooltip {
+        for tooltip in widget {
+            add_tooltip(ui, tooltip, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.splitter {
+        for splitter in widget {
+            add_splitter(ui, splitter, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dock_area {
+        for dock_area in widget {
+            add_dock_area(ui, dock_area, Some(id.clone()))?;
+        }
+    }
+
+    Ok(ui)
+}
+
+fn add_toolbar(ui: &mut Ui, widget: ToolbarMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = ToolbarBuilder::new(id.clone(), parent_id);
 
     if let Some(val) = widget.color {
         builder.with_color(val);
@@ -408,8 +1664,44 @@ fn add_dock(ui: &mut Ui, widget: DockMarkup, parent_id: Option<Id>) -> Result<&m
     if let Some(val) = widget.thickness {
         builder.with_thickness(val);
     }
-    if let Some(val) = widget.length {
-        builder.with_length(val);
+    if let Some(val) = widget.overflow {
+        builder.with_overflow(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+    if let Some(val) = widget.elevation {
+        builder.with_elevation(val);
+    }
+    if let Some(val) = widget.shadow_offset {
+        builder.with_shadow_offset(val[0], val[1]);
+    }
+    if let Some(val) = widget.shadow_blur_radius {
+        builder.with_shadow_blur_radius(val);
+    }
+    if let Some(val) = widget.shadow_color {
+        builder.with_shadow_color(val);
     }
 
     builder.build(ui)?;
@@ -438,10 +1730,129 @@ fn add_dock(ui: &mut Ui, widget: DockMarkup, parent_id: Option<Id>) -> Result<&m
         }
     }
 
+    if let Some(widget) = widget.image {
+        for image in widget {
+            add_image(ui, image, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.text_area {
+        for text_area in widget {
+            add_text_area(ui, text_area, Some(id.clone()))?;
+        }
+    }
+    if let Some(widget) = widget.text_input {
+        for text_input in widget {
+            add_text_input(ui, text_input, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.checkbox {
+        for checkbox in widget {
+            add_checkbox(ui, checkbox, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.radio_group {
+        for radio_group in widget {
+            add_radio_group(ui, radio_group, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.toggle {
+        for toggle in widget {
+            add_toggle(ui, toggle, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.slider {
+        for slider in widget {
+            add_slider(ui, slider, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.progress_bar {
+        for progress_bar in widget {
+            add_progress_bar(ui, progress_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.spin_box {
+        for spin_box in widget {
+            add_spin_box(ui, spin_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.list_view {
+        for list_view in widget {
+            add_list_view(ui, list_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.table {
+        for table in widget {
+            add_table(ui, table, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tree_view {
+        for tree_view in widget {
+            add_tree_view(ui, tree_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tabs {
+        for tabs in widget {
+            add_tabs(ui, tabs, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu_bar {
+        for menu_bar in widget {
+            add_menu_bar(ui, menu_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu {
+        for menu in widget {
+            add_menu(ui, menu, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.combo_box {
+        for combo_box in widget {
+            add_combo_box(ui, combo_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dialog {
+        for dialog in widget {
+            add_dialog(ui, dialog, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tooltip {
+        for tooltip in widget {
+            add_tooltip(ui, tooltip, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.splitter {
+        for splitter in widget {
+            add_splitter(ui, splitter, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dock_area {
+        for dock_area in widget {
+            add_dock_area(ui, dock_area, Some(id.clone()))?;
+        }
+    }
+
     Ok(ui)
 }
 
-fn add_label(ui: &mut Ui, widget: LabelMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+fn add_dock(ui: &mut Ui, widget: DockMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
     let id = widget.id;
     let parent_id = match parent_id {
         Some(val) => val,
@@ -455,19 +1866,64 @@ fn add_label(ui: &mut Ui, widget: LabelMarkup, parent_id: Option<Id>) -> Result<
         },
     };
 
-    let mut builder = LabelBuilder::new(id.clone(), parent_id, widget.position, widget.text);
+    let mut builder = DockBuilder::new(id.clone(), parent_id);
 
-    if let Some(val) = widget.background_color {
-        builder.with_background_color(val);
+    if let Some(val) = widget.color {
+        builder.with_color(val);
     }
-    if let Some(val) = widget.text_color {
-        builder.with_text_color(val);
+    if let Some(val) = widget.orientation {
+        builder.with_orientation(val);
     }
-    if let Some(val) = widget.size {
-        builder.with_size(val);
+    if let Some(val) = widget.thickness {
+        builder.with_thickness(val);
     }
-    if let Some(val) = widget.text_size {
-        builder.with_text_size(val);
+    if let Some(val) = widget.length {
+        builder.with_length(val);
+    }
+    if let Some(val) = widget.overflow {
+        builder.with_overflow(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+    if let Some(val) = widget.elevation {
+        builder.with_elevation(val);
+    }
+    if let Some(val) = widget.shadow_offset {
+        builder.with_shadow_offset(val[0], val[1]);
+    }
+    if let Some(val) = widget.shadow_blur_radius {
+        builder.with_shadow_blur_radius(val);
+    }
+    if let Some(val) = widget.shadow_color {
+        builder.with_shadow_color(val);
+    }
+    if let Some(val) = widget.background_image {
+        builder.with_background_image(val);
+    }
+    if let Some(val) = widget.background_insets {
+        builder.with_background_insets(val[0], val[1], val[2], val[3]);
     }
 
     builder.build(ui)?;
@@ -496,5 +1952,2278 @@ fn add_label(ui: &mut Ui, widget: LabelMarkup, parent_id: Option<Id>) -> Result<
         }
     }
 
-    Ok(ui)
+    if let Some(widget) = widget.image {
+        for image in widget {
+            add_image(ui, image, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.text_area {
+        for text_area in widget {
+            add_text_area(ui, text_area, Some(id.clone()))?;
+        }
+    }
+    if let Some(widget) = widget.text_input {
+        for text_input in widget {
+            add_text_input(ui, text_input, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.checkbox {
+        for checkbox in widget {
+            add_checkbox(ui, checkbox, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.radio_group {
+        for radio_group in widget {
+            add_radio_group(ui, radio_group, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.toggle {
+        for toggle in widget {
+            add_toggle(ui, toggle, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.slider {
+        for slider in widget {
+            add_slider(ui, slider, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.progress_bar {
+        for progress_bar in widget {
+            add_progress_bar(ui, progress_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.spin_box {
+        for spin_box in widget {
+            add_spin_box(ui, spin_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.list_view {
+        for list_view in widget {
+            add_list_view(ui, list_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.table {
+        for table in widget {
+            add_table(ui, table, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tree_view {
+        for tree_view in widget {
+            add_tree_view(ui, tree_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tabs {
+        for tabs in widget {
+            add_tabs(ui, tabs, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu_bar {
+        for menu_bar in widget {
+            add_menu_bar(ui, menu_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu {
+        for menu in widget {
+            add_menu(ui, menu, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.combo_box {
+        for combo_box in widget {
+            add_combo_box(ui, combo_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dialog {
+        for dialog in widget {
+            add_dialog(ui, dialog, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tooltip {
+        for tooltip in widget {
+            add_tooltip(ui, tooltip, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.splitter {
+        for splitter in widget {
+            add_splitter(ui, splitter, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dock_area {
+        for dock_area in widget {
+            add_dock_area(ui, dock_area, Some(id.clone()))?;
+        }
+    }
+
+    Ok(ui)
+}
+
+fn add_label(ui: &mut Ui, widget: LabelMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = LabelBuilder::new(id.clone(), parent_id, widget.position, widget.text);
+
+    if let Some(val) = widget.background_color {
+        builder.with_background_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.overflow {
+        builder.with_overflow(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+    if let Some(val) = widget.elevation {
+        builder.with_elevation(val);
+    }
+    if let Some(val) = widget.shadow_offset {
+        builder.with_shadow_offset(val[0], val[1]);
+    }
+    if let Some(val) = widget.shadow_blur_radius {
+        builder.with_shadow_blur_radius(val);
+    }
+    if let Some(val) = widget.shadow_color {
+        builder.with_shadow_color(val);
+    }
+
+    builder.build(ui)?;
+
+    if let Some(widget) = widget.container {
+        for container in widget {
+            add_container(ui, container, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.toolbar {
+        for toolbar in widget {
+            add_toolbar(ui, toolbar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dock {
+        for dock in widget {
+            add_dock(ui, dock, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.label {
+        for label in widget {
+            add_label(ui, label, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.image {
+        for image in widget {
+            add_image(ui, image, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.text_area {
+        for text_area in widget {
+            add_text_area(ui, text_area, Some(id.clone()))?;
+        }
+    }
+    if let Some(widget) = widget.text_input {
+        for text_input in widget {
+            add_text_input(ui, text_input, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.checkbox {
+        for checkbox in widget {
+            add_checkbox(ui, checkbox, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.radio_group {
+        for radio_group in widget {
+            add_radio_group(ui, radio_group, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.toggle {
+        for toggle in widget {
+            add_toggle(ui, toggle, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.slider {
+        for slider in widget {
+            add_slider(ui, slider, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.progress_bar {
+        for progress_bar in widget {
+            add_progress_bar(ui, progress_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.spin_box {
+        for spin_box in widget {
+            add_spin_box(ui, spin_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.list_view {
+        for list_view in widget {
+            add_list_view(ui, list_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.table {
+        for table in widget {
+            add_table(ui, table, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tree_view {
+        for tree_view in widget {
+            add_tree_view(ui, tree_view, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tabs {
+        for tabs in widget {
+            add_tabs(ui, tabs, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu_bar {
+        for menu_bar in widget {
+            add_menu_bar(ui, menu_bar, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.menu {
+        for menu in widget {
+            add_menu(ui, menu, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.combo_box {
+        for combo_box in widget {
+            add_combo_box(ui, combo_box, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dialog {
+        for dialog in widget {
+            add_dialog(ui, dialog, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.tooltip {
+        for tooltip in widget {
+            add_tooltip(ui, tooltip, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.splitter {
+        for splitter in widget {
+            add_splitter(ui, splitter, Some(id.clone()))?;
+        }
+    }
+
+    if let Some(widget) = widget.dock_area {
+        for dock_area in widget {
+            add_dock_area(ui, dock_area, Some(id.clone()))?;
+        }
+    }
+
+    Ok(ui)
+}
+
+fn add_image(ui: &mut Ui, widget: ImageMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = ImageBuilder::new(id.clone(), parent_id, widget.position);
+    builder.with_path(widget.path);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.fit {
+        builder.with_fit(val);
+    }
+    if let Some(val) = widget.tint {
+        builder.with_tint(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_text_area(ui: &mut Ui, widget: TextAreaMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = TextAreaBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.text {
+        builder.with_text(val);
+    }
+    if let Some(val) = widget.placeholder {
+        builder.with_placeholder(val);
+    }
+    if let Some(val) = widget.wrap {
+        builder.with_wrap(val);
+    }
+    if let Some(val) = widget.max_length {
+        builder.with_max_length(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.placeholder_color {
+        builder.with_placeholder_color(val);
+    }
+    if let Some(val) = widget.selection_color {
+        builder.with_selection_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_text_input(ui: &mut Ui, widget: TextInputMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = TextInputBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.text {
+        builder.with_text(val);
+    }
+    if let Some(val) = widget.placeholder {
+        builder.with_placeholder(val);
+    }
+    if let Some(val) = widget.password {
+        builder.with_password(val);
+    }
+    if let Some(val) = widget.max_length {
+        builder.with_max_length(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.placeholder_color {
+        builder.with_placeholder_color(val);
+    }
+    if let Some(val) = widget.selection_color {
+        builder.with_selection_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_checkbox(ui: &mut Ui, widget: CheckboxMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = CheckboxBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.tri_state {
+        builder.with_tri_state(val);
+    }
+    if let Some(val) = widget.checked {
+        builder.with_checked(val);
+    }
+    if let Some(val) = widget.state {
+        builder.with_state(val);
+    }
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.label {
+        builder.with_label(val);
+    }
+    if let Some(val) = widget.disabled {
+        builder.with_disabled(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.checked_color {
+        builder.with_checked_color(val);
+    }
+    if let Some(val) = widget.unchecked_color {
+        builder.with_unchecked_color(val);
+    }
+    if let Some(val) = widget.disabled_color {
+        builder.with_disabled_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_radio_group(ui: &mut Ui, widget: RadioGroupMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = RadioGroupBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    if let Some(widget) = widget.radio_button {
+        for radio_button in widget {
+            add_radio_button(ui, radio_button, Some(id.clone()))?;
+        }
+    }
+
+    Ok(ui)
+}
+
+fn add_radio_button(ui: &mut Ui, widget: RadioButtonMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = RadioButtonBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.value {
+        builder.with_value(val);
+    }
+    if let Some(val) = widget.checked {
+        builder.with_checked(val);
+    }
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.label {
+        builder.with_label(val);
+    }
+    if let Some(val) = widget.disabled {
+        builder.with_disabled(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.checked_color {
+        builder.with_checked_color(val);
+    }
+    if let Some(val) = widget.unchecked_color {
+        builder.with_unchecked_color(val);
+    }
+    if let Some(val) = widget.disabled_color {
+        builder.with_disabled_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_toggle(ui: &mut Ui, widget: ToggleMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = ToggleBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.on {
+        builder.with_on(val);
+    }
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.label {
+        builder.with_label(val);
+    }
+    if let Some(val) = widget.disabled {
+        builder.with_disabled(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.checked_color {
+        builder.with_checked_color(val);
+    }
+    if let Some(val) = widget.unchecked_color {
+        builder.with_unchecked_color(val);
+    }
+    if let Some(val) = widget.disabled_color {
+        builder.with_disabled_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_slider(ui: &mut Ui, widget: SliderMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = SliderBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.orientation {
+        builder.with_orientation(val);
+    }
+    if widget.min.is_some() | widget.max.is_some() {
+        builder.with_range(widget.min.unwrap_or(0.0), widget.max.unwrap_or(100.0));
+    }
+    if let Some(val) = widget.step {
+        builder.with_step(val);
+    }
+    if let Some(val) = widget.value {
+        builder.with_value(val);
+    }
+    if let Some(val) = widget.disabled {
+        builder.with_disabled(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.track_color {
+        builder.with_track_color(val);
+    }
+    if let Some(val) = widget.value_color {
+        builder.with_value_color(val);
+    }
+    if let Some(val) = widget.thumb_color {
+        builder.with_thumb_color(val);
+    }
+    if let Some(val) = widget.disabled_color {
+        builder.with_disabled_color(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_progress_bar(ui: &mut Ui, widget: ProgressBarMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = ProgressBarBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.orientation {
+        builder.with_orientation(val);
+    }
+    if let Some(val) = widget.progress {
+        builder.with_progress(val);
+    }
+    if let Some(val) = widget.indeterminate {
+        builder.with_indeterminate(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.bar_color {
+        builder.with_bar_color(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_spin_box(ui: &mut Ui, widget: SpinBoxMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = SpinBoxBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if widget.min.is_some() | widget.max.is_some() {
+        builder.with_range(widget.min.unwrap_or(0.0), widget.max.unwrap_or(100.0));
+    }
+    if let Some(val) = widget.step {
+        builder.with_step(val);
+    }
+    if let Some(val) = widget.decimals {
+        builder.with_decimals(val);
+    }
+    if let Some(val) = widget.value {
+        builder.with_value(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.selection_color {
+        builder.with_selection_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_combo_box(ui: &mut Ui, widget: ComboBoxMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = ComboBoxBuilder::new(id, parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.choices {
+        builder.with_choices(val);
+    }
+    if let Some(val) = widget.selected {
+        builder.with_selected(val);
+    }
+    if let Some(val) = widget.editable {
+        builder.with_editable(val);
+    }
+    if let Some(val) = widget.text {
+        builder.with_text(val);
+    }
+    if let Some(val) = widget.visible_choices {
+        builder.with_visible_choices(val);
+    }
+    if let Some(val) = widget.row_height {
+        builder.with_row_height(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.list_color {
+        builder.with_list_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.highlight_color {
+        builder.with_highlight_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_dialog(ui: &mut Ui, widget: DialogMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = DialogBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.title {
+        builder.with_title(val);
+    }
+    if let Some(val) = widget.text {
+        builder.with_text(val);
+    }
+    if let Some(val) = widget.buttons {
+        builder.with_buttons(val);
+    }
+    if let Some(val) = widget.default_button {
+        builder.with_default_button(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.title_color {
+        builder.with_title_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.button_color {
+        builder.with_button_color(val);
+    }
+    if let Some(val) = widget.default_button_color {
+        builder.with_default_button_color(val);
+    }
+    if let Some(val) = widget.dim_color {
+        builder.with_dim_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    // The children are laid out in a transparent container filling the dialog below its title
+    let container = ContainerMarkup {
+        id:                 format!("{}_content", id),
+        position:           Position::Center,
+        color:              Some(String::from("#00000000")),
+        size:               None,
+        parent_id:          None,
+        visible:            None,
+        opacity:            None,
+        overflow:           None,
+        z_index:            None,
+        layer:              None,
+        border_color:       None,
+        border_width:       None,
+        corner_radius:      None,
+        corner_radii:       None,
+        elevation:          None,
+        shadow_offset:      None,
+        shadow_blur_radius: None,
+        shadow_color:       None,
+        background_image:   None,
+        background_insets:  None,
+        toolbar:            widget.toolbar,
+        container:          widget.container,
+        dock:               widget.dock,
+        label:              widget.label,
+        image:              widget.image,
+        text_area:          widget.text_area,
+        text_input:         widget.text_input,
+        checkbox:           widget.checkbox,
+        radio_group:        widget.radio_group,
+        toggle:             widget.toggle,
+        slider:             widget.slider,
+        progress_bar:       widget.progress_bar,
+        spin_box:           widget.spin_box,
+        list_view:          widget.list_view,
+        table:              widget.table,
+        tree_view:          widget.tree_view,
+        tabs:               widget.tabs,
+        menu_bar:           widget.menu_bar,
+        menu:               widget.menu,
+        combo_box:          widget.combo_box,
+        dialog:             widget.dialog,
+        tooltip:            widget.tooltip,
+        splitter:           widget.splitter,
+        dock_area:          widget.dock_area,
+    };
+    add_container(ui, container, Some(id.clone()))?;
+
+    // The dialog is opened once its children exist, giving the focus to the first of them
+    if widget.open == Some(true) {
+        let widget = ui.widgets()[&id].clone();
+        let mut widget = widget.lock();
+        if let Some(dialog) = widget.downcast_mut::<Dialog>() {
+            dialog.open(ui);
+        }
+    }
+
+    Ok(ui)
+}
+
+fn add_tooltip(ui: &mut Ui, widget: TooltipMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = TooltipBuilder::new(id, parent_id);
+
+    if let Some(val) = widget.text {
+        builder.with_text(val);
+    }
+    if let Some(val) = widget.targets {
+        for target in val {
+            builder.with_target(target);
+        }
+    }
+    if let Some(val) = widget.delay {
+        builder.with_delay(Duration::from_millis(val));
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_splitter(ui: &mut Ui, widget: SplitterMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = SplitterBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.orientation {
+        builder.with_orientation(val);
+    }
+    if let Some(val) = widget.divider_width {
+        builder.with_divider_width(val);
+    }
+    let panes = widget.pane.unwrap_or_default();
+    for pane in &panes {
+        builder.with_pane(pane.id.clone());
+        if let Some(val) = pane.min_size {
+            builder.with_min_size(pane.id.clone(), val);
+        }
+        if let Some(val) = pane.max_size {
+            builder.with_max_size(pane.id.clone(), val);
+        }
+        if let Some(val) = pane.collapsible {
+            builder.with_collapsible(pane.id.clone(), val);
+        }
+    }
+    if panes.iter().any(|pane| pane.ratio.is_some()) {
+        builder.with_ratios(panes.iter().map(|pane| pane.ratio.unwrap_or(1.0)));
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.divider_color {
+        builder.with_divider_color(val);
+    }
+    if let Some(val) = widget.drag_color {
+        builder.with_drag_color(val);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    // Each pane is a container filling its part of the splitter
+    for pane in panes {
+        let container = ContainerMarkup {
+            id:                 pane.id,
+            position:           Position::Center,
+            size:               None,
+            color:              pane.color,
+            parent_id:          None,
+            visible:            None,
+            opacity:            None,
+            overflow:           None,
+            z_index:            None,
+            layer:              None,
+            border_color:       None,
+            border_width:       None,
+            corner_radius:      None,
+            corner_radii:       None,
+            elevation:          None,
+            shadow_offset:      None,
+            shadow_blur_radius: None,
+            shadow_color:       None,
+            background_image:   None,
+            background_insets:  None,
+            toolbar:            pane.toolbar,
+            container:          pane.container,
+            dock:               pane.dock,
+            label:              pane.label,
+            image:              pane.image,
+            text_area:          pane.text_area,
+            text_input:         pane.text_input,
+            checkbox:           pane.checkbox,
+            radio_group:        pane.radio_group,
+            toggle:             pane.toggle,
+            slider:             pane.slider,
+            progress_bar:       pane.progress_bar,
+            spin_box:           pane.spin_box,
+            list_view:          pane.list_view,
+            table:              pane.table,
+            tree_view:          pane.tree_view,
+            tabs:               pane.tabs,
+            menu_bar:           pane.menu_bar,
+            menu:               pane.menu,
+            combo_box:          pane.combo_box,
+            dialog:             pane.dialog,
+            tooltip:            pane.tooltip,
+            splitter:           pane.splitter,
+            dock_area:          pane.dock_area,
+        };
+        add_container(ui, container, Some(id.clone()))?;
+    }
+
+    Ok(ui)
+}
+
+fn add_dock_area(ui: &mut Ui, widget: DockAreaMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = DockAreaBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.preview_color {
+        builder.with_preview_color(val);
+    }
+    if let Some(val) = widget.tab_height {
+        builder.with_tab_height(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    // Each panel lays its children out in a transparent container filling it below its tabs
+    for panel in widget.panel.unwrap_or_default() {
+        let mut builder = DockPanelBuilder::new(panel.id.clone(), id.clone());
+
+        if let Some(val) = panel.title {
+            builder.with_title(val);
+        }
+        if let Some(val) = panel.edge {
+            builder.with_edge(val);
+        }
+        if let Some(val) = panel.dock_size {
+            builder.with_dock_size(val);
+        }
+        if let Some(val) = panel.floating {
+            builder.with_floating(val[0], val[1], val[2], val[3]);
+        }
+        if let Some(val) = panel.color {
+            builder.with_color(val);
+        }
+        if let Some(val) = panel.tab_color {
+            builder.with_tab_color(val);
+        }
+        if let Some(val) = panel.active_tab_color {
+            builder.with_active_tab_color(val);
+        }
+        if let Some(val) = panel.text_color {
+            builder.with_text_color(val);
+        }
+        if let Some(val) = panel.text_size {
+            builder.with_text_size(val);
+        }
+        if let Some(val) = panel.font {
+            builder.with_font(Font::from_path(val)?);
+        }
+        if let Some(val) = panel.opacity {
+            builder.with_opacity(val);
+        }
+        if let Some(val) = panel.z_index {
+            builder.with_z_index(val);
+        }
+        if let Some(val) = panel.border_color {
+            builder.with_border_color(val);
+        }
+        if let Some(val) = panel.border_width {
+            builder.with_border_width(val);
+        }
+        if let Some(val) = panel.corner_radius {
+            builder.with_corner_radius(val);
+        }
+        if let Some(val) = panel.corner_radii {
+            builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+        }
+
+        builder.build(ui)?;
+
+        let container = ContainerMarkup {
+            id:                 format!("{}_content", panel.id),
+            position:           Position::Center,
+            color:              Some(String::from("#00000000")),
+            size:               None,
+            parent_id:          None,
+            visible:            None,
+            opacity:            None,
+            overflow:           None,
+            z_index:            None,
+            layer:              None,
+            border_color:       None,
+            border_width:       None,
+            corner_radius:      None,
+            corner_radii:       None,
+            elevation:          None,
+            shadow_offset:      None,
+            shadow_blur_radius: None,
+            shadow_color:       None,
+            background_image:   None,
+            background_insets:  None,
+            toolbar:            panel.toolbar,
+            container:          panel.container,
+            dock:               panel.dock,
+            label:              panel.label,
+            image:              panel.image,
+            text_area:          panel.text_area,
+            text_input:         panel.text_input,
+            checkbox:           panel.checkbox,
+            radio_group:        panel.radio_group,
+            toggle:             panel.toggle,
+            slider:             panel.slider,
+            progress_bar:       panel.progress_bar,
+            spin_box:           panel.spin_box,
+            list_view:          panel.list_view,
+            table:              panel.table,
+            tree_view:          panel.tree_view,
+            tabs:               panel.tabs,
+            menu_bar:           panel.menu_bar,
+            menu:               panel.menu,
+            combo_box:          panel.combo_box,
+            dialog:             panel.dialog,
+            tooltip:            panel.tooltip,
+            splitter:           panel.splitter,
+            dock_area:          panel.dock_area,
+        };
+        add_container(ui, container, Some(panel.id.clone()))?;
+    }
+
+    // The other children are laid out in a transparent container filling the space left between
+    // the docked panels
+    let container = ContainerMarkup {
+        id:                 format!("{}_content", id),
+        position:           Position::Center,
+        color:              Some(String::from("#00000000")),
+        size:               None,
+        parent_id:          None,
+        visible:            None,
+        opacity:            None,
+        overflow:           None,
+        z_index:            None,
+        layer:              None,
+        border_color:       None,
+        border_width:       None,
+        corner_radius:      None,
+        corner_radii:       None,
+        elevation:          None,
+        shadow_offset:      None,
+        shadow_blur_radius: None,
+        shadow_color:       None,
+        background_image:   None,
+        background_insets:  None,
+        toolbar:            widget.toolbar,
+        container:          widget.container,
+        dock:               widget.dock,
+        label:              widget.label,
+        image:              widget.image,
+        text_area:          widget.text_area,
+        text_input:         widget.text_input,
+        checkbox:           widget.checkbox,
+        radio_group:        widget.radio_group,
+        toggle:             widget.toggle,
+        slider:             widget.slider,
+        progress_bar:       widget.progress_bar,
+        spin_box:           widget.spin_box,
+        list_view:          widget.list_view,
+        table:              widget.table,
+        tree_view:          widget.tree_view,
+        tabs:               widget.tabs,
+        menu_bar:           widget.menu_bar,
+        menu:               widget.menu,
+        combo_box:          widget.combo_box,
+        dialog:             widget.dialog,
+        tooltip:            widget.tooltip,
+        splitter:           widget.splitter,
+        dock_area:          widget.dock_area,
+    };
+    add_container(ui, container, Some(id.clone()))?;
+
+    Ok(ui)
+}
+
+fn add_list_view(ui: &mut Ui, widget: ListViewMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = ListViewBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(val) = widget.items {
+        builder.with_items(val);
+    }
+    if let Some(val) = widget.selection_mode {
+        builder.with_selection_mode(val);
+    }
+    if let Some(val) = widget.selected {
+        builder.with_selected(&val);
+    }
+    if let Some(val) = widget.row_height {
+        builder.with_row_height(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.selection_color {
+        builder.with_selection_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_table(ui: &mut Ui, widget: TableMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = TableBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(columns) = widget.column {
+        for column in columns {
+            let mut table_column = TableColumn::new(column.title);
+            if let Some(val) = column.width {
+                table_column = table_column.with_width(val);
+            }
+            if let Some(val) = column.min_width {
+                table_column = table_column.with_min_width(val);
+            }
+            if let Some(val) = column.sortable {
+                table_column = table_column.with_sortable(val);
+            }
+            builder.with_column(table_column);
+        }
+    }
+    if let Some(val) = widget.rows {
+        builder.with_rows(val);
+    }
+    if let Some(val) = widget.sort_column {
+        builder.with_sort(val, widget.sort_order.unwrap_or_default());
+    }
+    if let Some(val) = widget.selection_mode {
+        builder.with_selection_mode(val);
+    }
+    if let Some(val) = widget.row_height {
+        builder.with_row_height(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.header_color {
+        builder.with_header_color(val);
+    }
+    if let Some(val) = widget.header_text_color {
+        builder.with_header_text_color(val);
+    }
+    if let Some(val) = widget.grid_color {
+        builder.with_grid_color(val);
+    }
+    if let Some(val) = widget.selection_color {
+        builder.with_selection_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_tree_view(ui: &mut Ui, widget: TreeViewMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = TreeViewBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    if let Some(nodes) = widget.node {
+        let mut expanded = Vec::new();
+        builder.with_nodes(tree_nodes(nodes, &mut Vec::new(), &mut expanded));
+        for path in expanded {
+            builder.with_expanded(&path);
+        }
+    }
+    if let Some(val) = widget.selection_mode {
+        builder.with_selection_mode(val);
+    }
+    if let Some(val) = widget.row_height {
+        builder.with_row_height(val);
+    }
+    if let Some(val) = widget.indent {
+        builder.with_indent(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.guide_color {
+        builder.with_guide_color(val);
+    }
+    if let Some(val) = widget.selection_color {
+        builder.with_selection_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+fn add_tabs(ui: &mut Ui, widget: TabsMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = TabsBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    let pages = widget.page.unwrap_or_default();
+    for page in &pages {
+        builder.with_page(page.id.clone(), page.title.clone());
+    }
+    if let Some(val) = widget.active {
+        builder.with_active(val);
+    }
+    if let Some(val) = widget.closable {
+        builder.with_closable(val);
+    }
+    if let Some(val) = widget.tab_height {
+        builder.with_tab_height(val);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.tab_color {
+        builder.with_tab_color(val);
+    }
+    if let Some(val) = widget.active_tab_color {
+        builder.with_active_tab_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    // Each page is a container filling the tabs below the tabs themselves
+    for page in pages {
+        let container = ContainerMarkup {
+            id:                 page.id,
+            position:           Position::Center,
+            size:               None,
+            color:              page.color,
+            parent_id:          None,
+            visible:            None,
+            opacity:            None,
+            overflow:           None,
+            z_index:            None,
+            layer:              None,
+            border_color:       None,
+            border_width:       None,
+            corner_radius:      None,
+            corner_radii:       None,
+            elevation:          None,
+            shadow_offset:      None,
+            shadow_blur_radius: None,
+            shadow_color:       None,
+            background_image:   None,
+            background_insets:  None,
+            toolbar:            page.toolbar,
+            container:          page.container,
+            dock:               page.dock,
+            label:              page.label,
+            image:              page.image,
+            text_area:          page.text_area,
+            text_input:         page.text_input,
+            checkbox:           page.checkbox,
+            radio_group:        page.radio_group,
+            toggle:             page.toggle,
+            slider:             page.slider,
+            progress_bar:       page.progress_bar,
+            spin_box:           page.spin_box,
+            list_view:          page.list_view,
+            table:              page.table,
+            tree_view:          page.tree_view,
+            tabs:               page.tabs,
+            menu_bar:           page.menu_bar,
+            menu:               page.menu,
+            combo_box:          page.combo_box,
+            dialog:             page.dialog,
+            tooltip:            page.tooltip,
+            splitter:           page.splitter,
+            dock_area:          page.dock_area,
+        };
+        add_container(ui, container, Some(id.clone()))?;
+    }
+
+    Ok(ui)
+}
+
+fn add_menu_bar(ui: &mut Ui, widget: MenuBarMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = MenuBarBuilder::new(id.clone(), parent_id, widget.position);
+
+    if let Some(val) = widget.size {
+        builder.with_size(val);
+    }
+    let menus = widget.menu.unwrap_or_default();
+    for menu in &menus {
+        builder.with_menu(menu.id.clone(), menu.title.clone());
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.highlight_color {
+        builder.with_highlight_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.visible {
+        builder.with_visible(val);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.layer {
+        builder.with_layer(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+    if let Some(val) = widget.corner_radius {
+        builder.with_corner_radius(val);
+    }
+    if let Some(val) = widget.corner_radii {
+        builder.with_corner_radii(val[0], val[1], val[2], val[3]);
+    }
+
+    builder.build(ui)?;
+
+    // The menus opened by the titles are children of the bar
+    for menu in menus {
+        let mut builder = MenuBuilder::new(menu.id, id.clone());
+        for item in menu_items(menu.item.unwrap_or_default()) {
+            builder.with_item(item);
+        }
+        builder.build(ui)?;
+    }
+
+    Ok(ui)
+}
+
+fn add_menu(ui: &mut Ui, widget: MenuMarkup, parent_id: Option<Id>) -> Result<&mut Ui, Error> {
+    let id = widget.id;
+    let parent_id = match parent_id {
+        Some(val) => val,
+        None => match widget.parent_id {
+            None => {
+                return Err(err_msg(
+                    "The parent id is missing in either the text itself, or by means of nesting the widgets",
+                ));
+            }
+            Some(val) => val,
+        },
+    };
+
+    let mut builder = MenuBuilder::new(id, parent_id);
+
+    for item in menu_items(widget.item.unwrap_or_default()) {
+        builder.with_item(item);
+    }
+    for target in widget.target.unwrap_or_default() {
+        builder.with_target(target);
+    }
+    if let Some(val) = widget.color {
+        builder.with_color(val);
+    }
+    if let Some(val) = widget.text_color {
+        builder.with_text_color(val);
+    }
+    if let Some(val) = widget.highlight_color {
+        builder.with_highlight_color(val);
+    }
+    if let Some(val) = widget.disabled_color {
+        builder.with_disabled_color(val);
+    }
+    if let Some(val) = widget.text_size {
+        builder.with_text_size(val);
+    }
+    if let Some(val) = widget.item_height {
+        builder.with_item_height(val);
+    }
+    if let Some(val) = widget.font {
+        builder.with_font(Font::from_path(val)?);
+    }
+    if let Some(val) = widget.opacity {
+        builder.with_opacity(val);
+    }
+    if let Some(val) = widget.z_index {
+        builder.with_z_index(val);
+    }
+    if let Some(val) = widget.border_color {
+        builder.with_border_color(val);
+    }
+    if let Some(val) = widget.border_width {
+        builder.with_border_width(val);
+    }
+
+    builder.build(ui)?;
+
+    Ok(ui)
+}
+
+/// Turn the items of a menu markup into menu items
+fn menu_items(items: Vec<ItemMarkup>) -> Vec<MenuItem> {
+    items
+        .into_iter()
+        .map(|item| {
+            if item.separator.unwrap_or(false) {
+                return MenuItem::separator();
+            }
+
+            let mut menu_item = MenuItem::new(item.id.unwrap_or_default(), item.text.unwrap_or_default());
+            if let Some(val) = item.shortcut {
+                menu_item = menu_item.with_shortcut(val);
+            }
+            if let Some(val) = item.checked {
+                menu_item = menu_item.with_checked(val);
+            }
+            if let Some(val) = item.enabled {
+                menu_item = menu_item.with_enabled(val);
+            }
+            for child in menu_items(item.item.unwrap_or_default()) {
+                menu_item = menu_item.with_item(child);
+            }
+            menu_item
+        })
+        .collect()
+}
+
+/// Turn the nodes of a tree view markup into tree nodes, collecting the paths of the nodes expanded
+fn tree_nodes(nodes: Vec<NodeMarkup>, path: &mut Vec<usize>, expanded: &mut Vec<Vec<usize>>) -> Vec<TreeNode> {
+    let mut children = Vec::new();
+    for (index, node) in nodes.into_iter().enumerate() {
+        path.push(index);
+        if node.expanded.unwrap_or(false) {
+            expanded.push(path.clone());
+        }
+        let mut tree_node = TreeNode::new(node.text);
+        for child in tree_nodes(node.node.unwrap_or_default(), path, expanded) {
+            tree_node = tree_node.with_child(child);
+        }
+        children.push(tree_node);
+        path.pop();
+    }
+    children
 }
@@ -11,9 +11,43 @@
 #version 450
 
 layout(location = 0) in vec4 v_color;
+layout(location = 1) in vec2 v_local;
+layout(location = 2) in vec2 v_half_size;
+layout(location = 3) in vec4 v_radii;
+layout(location = 4) in float v_border_width;
+layout(location = 5) in vec4 v_border_color;
+layout(location = 6) in float v_blur_radius;
+layout(location = 7) in vec2 v_uv;
+layout(location = 8) in float v_textured;
+
+layout(set = 0, binding = 0) uniform sampler2D tex;
 
 layout(location = 0) out vec4 f_color;
 
+// Signed distance in pixels from the edge of a box centered at the origin with the radius of each
+// corner in the order top left, top right, bottom right, bottom left
+float rounded_box(vec2 p, vec2 half_size, vec4 radii) {
+    float radius = p.x < 0.0 ? (p.y < 0.0 ? radii.x : radii.w) : (p.y < 0.0 ? radii.y : radii.z);
+    radius = min(radius, min(half_size.x, half_size.y));
+    vec2 q = abs(p) - half_size + radius;
+    return min(max(q.x, q.y), 0.0) + length(max(q, 0.0)) - radius;
+}
+
 void main() {
-    f_color = v_color;
+    float dist = rounded_box(v_local, v_half_size, v_radii);
+
+    // Anti-alias the edges over a single pixel, or fade them out over the blur radius for shadows,
+    // the colors are premultiplied by their alpha
+    float softness = max(v_blur_radius, 0.5);
+    float coverage = 1.0 - smoothstep(-softness, softness, dist);
+    float fill = v_border_width > 0.0 ? 1.0 - smoothstep(-0.5, 0.5, dist + v_border_width) : 1.0;
+
+    // Textures hold straight alpha, premultiply them before tinting with the premultiplied color
+    vec4 color = v_color;
+    if (v_textured > 0.5) {
+        vec4 texel = texture(tex, v_uv);
+        color *= vec4(texel.rgb * texel.a, texel.a);
+    }
+
+    f_color = mix(v_border_color, color, fill) * coverage;
 }
\ No newline at end of file
@@ -12,11 +12,35 @@
 
 layout(location = 0) in vec2 position;
 layout(location = 1) in vec4 color;
+layout(location = 2) in vec2 local;
+layout(location = 3) in vec2 half_size;
+layout(location = 4) in vec4 radii;
+layout(location = 5) in float border_width;
+layout(location = 6) in vec4 border_color;
+layout(location = 7) in float blur_radius;
+layout(location = 8) in vec2 uv;
+layout(location = 9) in float textured;
 
 layout(location = 0) out vec4 v_color;
+layout(location = 1) out vec2 v_local;
+layout(location = 2) out vec2 v_half_size;
+layout(location = 3) out vec4 v_radii;
+layout(location = 4) out float v_border_width;
+layout(location = 5) out vec4 v_border_color;
+layout(location = 6) out float v_blur_radius;
+layout(location = 7) out vec2 v_uv;
+layout(location = 8) out float v_textured;
 
 void main() {
     v_color = color;
+    v_local = local;
+    v_half_size = half_size;
+    v_radii = radii;
+    v_border_width = border_width;
+    v_border_color = border_color;
+    v_blur_radius = blur_radius;
+    v_uv = uv;
+    v_textured = textured;
 
     gl_Position = vec4(position, 0.0, 1.0);
 }
\ No newline at end of file
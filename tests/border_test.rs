@@ -0,0 +1,36 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::border_vertices;
+
+#[test]
+fn test_border_shape() {
+    for vertex in border_vertices(2.0, [1.0, 2.0, 3.0, 4.0]) {
+        let (bl, tr) = vertex.bounds().unwrap();
+        assert_eq!([[-1.0, -1.0], [1.0, 1.0]], [bl.as_array(), tr.as_array()]);
+        assert_eq!([1.0, 2.0, 3.0, 4.0], vertex.corner_radius().as_array());
+        assert_eq!(2.0, vertex.border_width());
+        assert_eq!([1.0; 4], vertex.border_color());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_border_negative_width() {
+    border_vertices(-2.0, [0.0; 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_border_negative_radius() {
+    border_vertices(0.0, [0.0, -1.0, 0.0, 0.0]);
+}
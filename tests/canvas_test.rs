@@ -0,0 +1,204 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    canvas_vertices,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    Canvas,
+    CanvasBuilder,
+};
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+fn white() -> Color {
+    Color::from_hex("#FFFFFFFF").unwrap()
+}
+
+/// The shapes drawn by the callback, without the six vertices of the background, in pixels
+fn shapes<F>(on_draw: F) -> Vec<[f32; 2]>
+where
+    F: Fn(&mut Painter) + Send + Sync + 'static,
+{
+    canvas_vertices(on_draw)
+        .iter()
+        .skip(6)
+        .map(|vertex| {
+            let [x, y] = vertex.position().as_array();
+            [((x + 0.5) * 800.0) / 2.0, ((y + 0.5) * 600.0) / 2.0]
+        })
+        .collect()
+}
+
+/// The area covered by a triangle list
+fn area(points: &[[f32; 2]]) -> f32 {
+    points
+        .chunks(3)
+        .map(|t| {
+            (((t[1][0] - t[0][0]) * (t[2][1] - t[0][1])) - ((t[2][0] - t[0][0]) * (t[1][1] - t[0][1]))).abs() / 2.0
+        })
+        .sum()
+}
+
+fn bounds(points: &[[f32; 2]]) -> [f32; 4] {
+    points
+        .iter()
+        .fold([std::f32::MAX, std::f32::MAX, std::f32::MIN, std::f32::MIN], |b, p| {
+            [b[0].min(p[0]), b[1].min(p[1]), b[2].max(p[0]), b[3].max(p[1])]
+        })
+}
+
+fn assert_close(expected: f32, actual: f32) {
+    assert!(
+        (expected - actual).abs() < 0.01,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_canvas_size() {
+    canvas_vertices(|painter| assert_eq!((400.0, 300.0), painter.size()));
+}
+
+#[test]
+fn test_canvas_rect() {
+    let points = shapes(|painter| painter.rect((10.0, 20.0), (100.0, 50.0), white()));
+    assert_eq!(6, points.len());
+    assert_close(5000.0, area(&points));
+    let [min_x, min_y, max_x, max_y] = bounds(&points);
+    assert_close(10.0, min_x);
+    assert_close(20.0, min_y);
+    assert_close(110.0, max_x);
+    assert_close(70.0, max_y);
+}
+
+#[test]
+fn test_canvas_clipped_to_bounds() {
+    let points = shapes(|painter| painter.rect((-100.0, 250.0), (200.0, 100.0), white()));
+    assert_close(100.0 * 50.0, area(&points));
+    let [min_x, _, _, max_y] = bounds(&points);
+    assert_close(0.0, min_x);
+    assert_close(300.0, max_y);
+}
+
+#[test]
+fn test_canvas_line_width() {
+    let points = shapes(|painter| painter.line((50.0, 100.0), (150.0, 100.0), 10.0, white()));
+    assert_close(1000.0, area(&points));
+    let [min_x, min_y, max_x, max_y] = bounds(&points);
+    assert_close(50.0, min_x);
+    assert_close(95.0, min_y);
+    assert_close(150.0, max_x);
+    assert_close(105.0, max_y);
+}
+
+#[test]
+fn test_canvas_concave_polygon() {
+    let l_shape = [
+        (0.0, 0.0),
+        (100.0, 0.0),
+        (100.0, 50.0),
+        (50.0, 50.0),
+        (50.0, 100.0),
+        (0.0, 100.0),
+    ];
+    let points = shapes(move |painter| painter.polygon(&l_shape, white()));
+    assert_eq!(12, points.len());
+    assert_close(7500.0, area(&points));
+}
+
+#[test]
+fn test_canvas_circle() {
+    let points = shapes(|painter| painter.circle((200.0, 150.0), 100.0, white()));
+    let [min_x, min_y, max_x, max_y] = bounds(&points);
+    assert_close(100.0, min_x);
+    assert!(min_y >= 50.0);
+    assert_close(300.0, max_x);
+    assert!(max_y <= 250.0);
+    assert!(area(&points) > std::f32::consts::PI * 100.0 * 100.0 * 0.99);
+}
+
+#[test]
+fn test_canvas_bezier_path() {
+    let mut path = Path::new();
+    path.move_to(0.0, 0.0).quadratic_to((100.0, 200.0), 200.0, 0.0).close();
+    let points = shapes(move |painter| painter.fill_path(&path, white()));
+    // The area under a parabola is two thirds of its bounding box
+    assert!((area(&points) - ((2.0 / 3.0) * 200.0 * 100.0)).abs() < 200.0);
+}
+
+#[test]
+fn test_canvas_colors_and_opacity() {
+    let vertices =
+        canvas_vertices(|painter| painter.rect((0.0, 0.0), (10.0, 10.0), Color::from_hex("#FF000080").unwrap()));
+    for vertex in vertices.iter().skip(6) {
+        assert_eq!(1.0, vertex.color()[0]);
+        assert!((vertex.color()[3] - (128.0 / 255.0)).abs() < 0.001);
+        assert!(vertex.texture().is_none());
+    }
+}
+
+#[test]
+fn test_canvas_texture_kept_between_frames() {
+    let tint = Arc::new(Mutex::new(white()));
+    let drawn = tint.clone();
+    let texture = Texture::new(1, 1, vec![255; 4]).unwrap();
+
+    let mut ui = window_ui();
+    let canvas = CanvasBuilder::new("canvas", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_on_draw(move |painter| painter.image((10.0, 10.0), (20.0, 20.0), &texture, *drawn.lock().unwrap()))
+        .build(&mut ui)
+        .unwrap();
+
+    let id = |canvas: &Canvas| {
+        let vertices = canvas.draw(&ui).unwrap();
+        let id = canvas.texture().unwrap().id();
+        assert!(vertices.iter().skip(6).all(|vertex| vertex.texture() == Some(id)));
+        id
+    };
+
+    // The same image is not rasterized and uploaded again on the next frame
+    let first = id(&canvas);
+    assert_eq!(first, id(&canvas));
+
+    *tint.lock().unwrap() = Color::from_hex("#FF0000FF").unwrap();
+    let second = id(&canvas);
+    assert_ne!(first, second);
+    assert_eq!(second, id(&canvas));
+}
+
+#[test]
+fn test_path_cubic_ends_at_its_point() {
+    let mut path = Path::new();
+    path.move_to(10.0, 10.0)
+        .cubic_to((20.0, 80.0), (80.0, 80.0), 90.0, 10.0);
+    let subpaths = path.subpaths();
+    assert_eq!(1, subpaths.len());
+    let (points, closed) = &subpaths[0];
+    assert!(!closed);
+    assert_eq!((10.0, 10.0), points[0]);
+    let last = points[points.len() - 1];
+    assert!((last.0 - 90.0).abs() < 0.001 && (last.1 - 10.0).abs() < 0.001);
+    assert!(points.len() > 4);
+}
+
+#[test]
+fn test_font_invalid() {
+    assert!(Font::from_bytes(vec![0; 16]).is_err());
+}
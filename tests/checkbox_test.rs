@@ -0,0 +1,220 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    Checkbox,
+    CheckboxBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn checkbox_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut CheckboxBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = CheckboxBuilder::new("checkbox", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 10.0)).with_label("Checkbox");
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn click(ui: &Ui, x: f32, y: f32) -> bool {
+    let pressed = ui
+        .dispatch(InputEvent::MousePressed {
+            x,
+            y,
+            button: MouseButton::Left,
+            modifiers: ModifiersState::default(),
+        })
+        .unwrap();
+    let released = ui
+        .dispatch(InputEvent::MouseReleased {
+            x,
+            y,
+            button: MouseButton::Left,
+        })
+        .unwrap();
+    pressed & released
+}
+
+fn space(ui: &Ui) -> bool {
+    ui.dispatch(InputEvent::KeyPressed {
+        key:       VirtualKeyCode::Space,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_checkbox_click() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        checkbox_ui(move |builder| {
+            builder.with_on_change(move |state| changes.lock().push(state));
+        })
+    };
+    assert_eq!(CheckState::Unchecked, widget::<Checkbox>(&ui, "checkbox").state());
+
+    assert!(click(&ui, 400.0, 300.0));
+    assert_eq!(Some(String::from("checkbox")), ui.focused());
+    assert!(widget::<Checkbox>(&ui, "checkbox").checked());
+    assert!(click(&ui, 400.0, 300.0));
+    assert!(!widget::<Checkbox>(&ui, "checkbox").checked());
+    assert_eq!(vec![CheckState::Checked, CheckState::Unchecked], *changes.lock());
+}
+
+#[test]
+fn test_checkbox_release_outside() {
+    let ui = checkbox_ui(|_| ());
+
+    let pressed = InputEvent::MousePressed {
+        x:         400.0,
+        y:         300.0,
+        button:    MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    };
+    assert!(ui.dispatch(pressed).unwrap());
+
+    // The released button goes to the checkbox that handled the press, which ignores it
+    let released = InputEvent::MouseReleased {
+        x:      10.0,
+        y:      10.0,
+        button: MouseButton::Left,
+    };
+    assert!(ui.dispatch(released).unwrap());
+    assert!(!widget::<Checkbox>(&ui, "checkbox").checked());
+}
+
+#[test]
+fn test_checkbox_space() {
+    let ui = checkbox_ui(|builder| {
+        builder.with_checked(true);
+    });
+    assert!(!space(&ui));
+
+    ui.set_focus(Some(String::from("checkbox")));
+    assert!(space(&ui));
+    assert_eq!(CheckState::Unchecked, widget::<Checkbox>(&ui, "checkbox").state());
+}
+
+#[test]
+fn test_checkbox_tri_state() {
+    let ui = checkbox_ui(|builder| {
+        builder.with_tri_state(true);
+    });
+    ui.set_focus(Some(String::from("checkbox")));
+
+    let mut states = Vec::new();
+    for _ in 0..3 {
+        assert!(space(&ui));
+        states.push(widget::<Checkbox>(&ui, "checkbox").state());
+    }
+    assert_eq!(
+        vec![CheckState::Checked, CheckState::Indeterminate, CheckState::Unchecked],
+        states
+    );
+}
+
+#[test]
+fn test_checkbox_indeterminate_without_tri_state() {
+    // An indeterminate checkbox set by the application is checked when clicked
+    let ui = checkbox_ui(|_| ());
+    ui.widgets()["checkbox"]
+        .lock()
+        .downcast_mut::<Checkbox>()
+        .unwrap()
+        .set_state(CheckState::Indeterminate);
+
+    ui.set_focus(Some(String::from("checkbox")));
+    assert!(space(&ui));
+    assert_eq!(CheckState::Checked, widget::<Checkbox>(&ui, "checkbox").state());
+}
+
+#[test]
+fn test_checkbox_builder_indeterminate() {
+    let mut ui = window_ui();
+
+    let mut builder = CheckboxBuilder::new("checkbox", "wcontainer", Position::Center);
+    builder.with_state(CheckState::Indeterminate);
+    assert!(builder.build(&mut ui).is_err());
+    assert!(builder.with_tri_state(true).build(&mut ui).is_ok());
+}
+
+#[test]
+fn test_checkbox_disabled() {
+    let changes = Arc::new(Mutex::new(0));
+    let ui = {
+        let changes = changes.clone();
+        checkbox_ui(move |builder| {
+            builder
+                .with_disabled(true)
+                .with_on_change(move |_| *changes.lock() += 1);
+        })
+    };
+
+    assert!(!click(&ui, 400.0, 300.0));
+    assert_eq!(None, ui.focused());
+
+    ui.set_focus(Some(String::from("checkbox")));
+    assert!(!space(&ui));
+    assert!(!widget::<Checkbox>(&ui, "checkbox").checked());
+    assert_eq!(0, *changes.lock());
+
+    ui.widgets()["checkbox"]
+        .lock()
+        .downcast_mut::<Checkbox>()
+        .unwrap()
+        .set_enabled(true);
+    assert!(space(&ui));
+    assert_eq!(1, *changes.lock());
+}
+
+#[test]
+fn test_checkbox_theme() {
+    let mut ui = themed_ui();
+    let checkbox = CheckboxBuilder::new("checkbox", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(2.0, checkbox.border().width());
+    assert_eq!([3.0; 4], checkbox.corner_radius().as_array());
+    assert_eq!(Fill::parse("#11223344").unwrap(), checkbox.fill());
+    assert!(!checkbox.shadow().visible());
+
+    let checkbox = CheckboxBuilder::new("raised", "wcontainer", Position::Center)
+        .with_border_width(1.0)
+        .with_corner_radius(0.0)
+        .with_color("#FFFFFFFF")
+        .with_elevation(4.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(1.0, checkbox.border().width());
+    assert_eq!([0.0; 4], checkbox.corner_radius().as_array());
+    assert_eq!(Fill::parse("#FFFFFFFF").unwrap(), checkbox.fill());
+    assert_eq!((0.0, 4.0), checkbox.shadow().offset());
+    assert_eq!(8.0, checkbox.shadow().blur_radius());
+}
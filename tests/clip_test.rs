@@ -0,0 +1,27 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::clip_vertices;
+use o2tk::prelude::*;
+
+#[test]
+fn test_clip_hidden() {
+    let expected = Some([[-1.0, -1.0], [0.0, 0.0]]);
+    assert_eq!(expected, clip_vertices(Overflow::Hidden));
+}
+
+#[test]
+fn test_clip_visible() {
+    // The window container always hides its overflow
+    let expected = Some([[-1.0, -1.0], [1.0, 1.0]]);
+    assert_eq!(expected, clip_vertices(Overflow::Visible));
+}
@@ -0,0 +1,339 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    ComboBox,
+    ComboBoxBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn combo_box_ui<F>(position: Position, configure: F) -> Ui
+where
+    F: FnOnce(&mut ComboBoxBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = ComboBoxBuilder::new("combo_box", "wcontainer", position);
+    builder.with_size(Size::Size(50.0, 10.0)).with_choices(vec![
+        "Apple",
+        "Banana",
+        "Blueberry",
+        "Cherry",
+        "Date",
+        "Elderberry",
+        "Fig",
+        "Grape",
+        "Kiwi",
+        "Lemon",
+    ]);
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn alt() -> ModifiersState {
+    ModifiersState {
+        alt: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap()
+}
+
+fn type_text(ui: &Ui, text: &str) {
+    for c in text.chars() {
+        ui.dispatch(InputEvent::Character(c)).unwrap();
+    }
+}
+
+/// Press and release the left button at a position in pixels in the window
+fn click(ui: &Ui, x: f32, y: f32) -> bool {
+    let handled = ui
+        .dispatch(InputEvent::MousePressed {
+            x,
+            y,
+            button: MouseButton::Left,
+            modifiers: ModifiersState::default(),
+        })
+        .unwrap();
+    ui.dispatch(InputEvent::MouseReleased {
+        x,
+        y,
+        button: MouseButton::Left,
+    })
+    .unwrap();
+    handled
+}
+
+fn hover(ui: &Ui, x: f32, y: f32) {
+    ui.dispatch(InputEvent::CursorMoved { x, y }).unwrap();
+}
+
+/// The vertical position in pixels of the middle of a row of the list opened below the combo
+/// box, which spans from 270 to 330 pixels
+fn row(index: usize) -> f32 {
+    334.0 + (index as f32 * 24.0) + 12.0
+}
+
+#[test]
+fn test_combo_box_click() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        combo_box_ui(Position::Center, move |builder| {
+            builder.with_on_change(move |index, text| changes.lock().push((index, String::from(text))));
+        })
+    };
+
+    assert_eq!(None, widget::<ComboBox>(&ui, "combo_box").selected());
+    assert!(click(&ui, 300.0, 300.0));
+    assert!(widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert_eq!(Some(String::from("combo_box")), ui.focused());
+    assert_eq!(Some(String::from("combo_box_popup")), ui.hit_test(1.0, 1.0).unwrap());
+
+    hover(&ui, 300.0, row(2));
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    assert!(click(&ui, 300.0, row(2)));
+    assert!(!widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").selected());
+    assert_eq!("Blueberry", widget::<ComboBox>(&ui, "combo_box").text());
+    assert_eq!(
+        None,
+        ui.hit_test(1.0, 1.0 / 3.0)
+            .unwrap()
+            .filter(|id| id == "combo_box_popup")
+    );
+
+    // Pressing outside of the list, or on the combo box itself, closes it without a change
+    assert!(click(&ui, 300.0, 300.0));
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    assert!(click(&ui, 100.0, 100.0));
+    assert!(!widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert!(click(&ui, 300.0, 300.0));
+    assert!(click(&ui, 300.0, 300.0));
+    assert!(!widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").selected());
+    assert_eq!(Some(String::from("combo_box")), ui.focused());
+
+    assert_eq!(vec![(Some(2), String::from("Blueberry"))], *changes.lock());
+}
+
+#[test]
+fn test_combo_box_keys() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        combo_box_ui(Position::Center, move |builder| {
+            builder.with_on_change(move |index, _| changes.lock().push(index));
+        })
+    };
+    ui.set_focus(Some(String::from("combo_box")));
+
+    // The arrow keys pick the choices while the list is closed
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Up, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::End, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::End, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Home, ModifiersState::default()));
+    assert_eq!(vec![Some(0), Some(1), Some(0), Some(9), Some(0)], *changes.lock());
+
+    // And move the choice highlighted while it is open, scrolling the list
+    assert!(press(&ui, VirtualKeyCode::Down, alt()));
+    assert!(widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert_eq!(Some(0), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    assert!(press(&ui, VirtualKeyCode::PageDown, ModifiersState::default()));
+    assert_eq!(Some(8), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    hover(&ui, 300.0, row(0));
+    assert_eq!(Some(1), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+    assert!(!widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert_eq!(Some(0), widget::<ComboBox>(&ui, "combo_box").selected());
+
+    assert!(press(&ui, VirtualKeyCode::Space, ModifiersState::default()));
+    assert!(widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert!(press(&ui, VirtualKeyCode::End, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Up, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert!(!widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert_eq!(Some(8), widget::<ComboBox>(&ui, "combo_box").selected());
+    assert_eq!(Some(&Some(8)), changes.lock().last());
+}
+
+#[test]
+fn test_combo_box_type_ahead() {
+    let ui = combo_box_ui(Position::Center, |_| {});
+    ui.set_focus(Some(String::from("combo_box")));
+
+    // Typing the same character cycles through the choices starting with it
+    type_text(&ui, "b");
+    assert_eq!(Some(1), widget::<ComboBox>(&ui, "combo_box").selected());
+    type_text(&ui, "b");
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").selected());
+    type_text(&ui, "b");
+    assert_eq!(Some(1), widget::<ComboBox>(&ui, "combo_box").selected());
+
+    // Otherwise the characters typed shortly one after the other are searched together
+    let ui = combo_box_ui(Position::Center, |_| {});
+    ui.set_focus(Some(String::from("combo_box")));
+    type_text(&ui, "bl");
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").selected());
+    type_text(&ui, "x");
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").selected());
+
+    // While the list is open only the choice highlighted moves
+    let ui = combo_box_ui(Position::Center, |_| {});
+    ui.set_focus(Some(String::from("combo_box")));
+    assert!(press(&ui, VirtualKeyCode::F4, ModifiersState::default()));
+    type_text(&ui, "K");
+    assert_eq!(Some(8), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    assert_eq!(None, widget::<ComboBox>(&ui, "combo_box").selected());
+}
+
+#[test]
+fn test_combo_box_editable() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        combo_box_ui(Position::Center, move |builder| {
+            builder
+                .with_editable(true)
+                .with_text("Plum")
+                .with_on_change(move |index, text| changes.lock().push((index, String::from(text))));
+        })
+    };
+    assert_eq!("Plum", widget::<ComboBox>(&ui, "combo_box").text());
+    assert_eq!(None, widget::<ComboBox>(&ui, "combo_box").selected());
+
+    // Pressing the text places the caret, only the button opens the list
+    assert!(click(&ui, 300.0, 300.0));
+    assert!(!widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert!(press(
+        &ui,
+        VirtualKeyCode::A,
+        ModifiersState {
+            ctrl: true,
+            ..ModifiersState::default()
+        }
+    ));
+    type_text(&ui, "Fi");
+    assert_eq!("Fi", widget::<ComboBox>(&ui, "combo_box").text());
+    assert_eq!(None, widget::<ComboBox>(&ui, "combo_box").selected());
+    type_text(&ui, "g");
+    assert_eq!(Some(6), widget::<ComboBox>(&ui, "combo_box").selected());
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+
+    // Escape restores the text committed
+    assert!(press(&ui, VirtualKeyCode::Back, ModifiersState::default()));
+    assert_eq!("Fi", widget::<ComboBox>(&ui, "combo_box").text());
+    assert!(press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+    assert_eq!("Fig", widget::<ComboBox>(&ui, "combo_box").text());
+    assert!(!press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+
+    // The choice starting with the text typed is highlighted in the list
+    assert!(click(&ui, 590.0, 300.0));
+    assert!(widget::<ComboBox>(&ui, "combo_box").is_open());
+    assert!(press(
+        &ui,
+        VirtualKeyCode::A,
+        ModifiersState {
+            ctrl: true,
+            ..ModifiersState::default()
+        }
+    ));
+    type_text(&ui, "gr");
+    assert_eq!(Some(7), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert_eq!("Grape", widget::<ComboBox>(&ui, "combo_box").text());
+
+    assert_eq!(
+        vec![(Some(6), String::from("Fig")), (Some(7), String::from("Grape"))],
+        *changes.lock()
+    );
+}
+
+#[test]
+fn test_combo_box_list_above() {
+    let ui = combo_box_ui(Position::Bottom, |_| {});
+
+    // There is no room left below the combo box, from 540 to 600 pixels, so the list opens above
+    assert!(click(&ui, 300.0, 570.0));
+    hover(&ui, 300.0, 350.0);
+    assert_eq!(Some(0), widget::<ComboBox>(&ui, "combo_box").highlighted());
+
+    ui.dispatch(InputEvent::MouseWheel {
+        x:       300.0,
+        y:       350.0,
+        delta_x: 0.0,
+        delta_y: -48.0,
+    })
+    .unwrap();
+    hover(&ui, 300.0, 350.0);
+    assert_eq!(Some(2), widget::<ComboBox>(&ui, "combo_box").highlighted());
+    assert!(!ui.widgets()["combo_box_popup"].lock().draw(&ui).unwrap().is_empty());
+}
+
+#[test]
+fn test_combo_box_theme() {
+    let mut ui = themed_ui();
+    let combo_box = ComboBoxBuilder::new("combo_box", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(2.0, combo_box.border().width());
+    assert_eq!([3.0; 4], combo_box.corner_radius().as_array());
+    assert!(!combo_box.shadow().visible());
+
+    let combo_box = ComboBoxBuilder::new("raised", "wcontainer", Position::Center)
+        .with_elevation(4.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 4.0), combo_box.shadow().offset());
+}
+
+#[test]
+fn test_combo_box_list_shadow() {
+    let ui = combo_box_ui(Position::Center, |builder| {
+        builder.with_elevation(4.0);
+    });
+    assert!(ui.widgets()["combo_box_popup"]
+        .lock()
+        .draw_shadow(&ui)
+        .unwrap()
+        .is_empty());
+
+    // Only the list casts a shadow, not the whole window the popup covers
+    assert!(click(&ui, 300.0, 300.0));
+    let vertices = ui.widgets()["combo_box_popup"].lock().draw_shadow(&ui).unwrap();
+    assert!(!vertices.is_empty());
+    for vertex in vertices {
+        let (bl_vertex, tr_vertex) = vertex.bounds().unwrap();
+        assert_eq!(330.0 * 2.0 / 600.0, bl_vertex.y());
+        assert!((bl_vertex.x() > 0.0) & (tr_vertex.x() < 2.0) & (tr_vertex.y() < 2.0));
+        assert_eq!((0.0, 4.0), vertex.offset());
+    }
+}
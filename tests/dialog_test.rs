@@ -0,0 +1,339 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    Container,
+    ContainerBuilder,
+    Dialog,
+    DialogBuilder,
+    TextInput,
+    TextInputBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn dialog_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut DialogBuilder),
+{
+    let mut ui = window_ui();
+
+    TextInputBuilder::new("behind", "wcontainer", Position::TopLeft)
+        .with_size(Size::Size(25.0, 10.0))
+        .build(&mut ui)
+        .unwrap();
+
+    let mut builder = DialogBuilder::new("dialog", "wcontainer", Position::Center);
+    builder.with_title("Rename").with_buttons(vec!["OK", "Cancel"]);
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    TextInputBuilder::new("name", "dialog", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    TextInputBuilder::new("extension", "dialog", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+
+    ui
+}
+
+fn open_dialog(ui: &Ui, id: &str) {
+    ui.widgets()[id].lock().downcast_mut::<Dialog>().unwrap().open(ui);
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap()
+}
+
+fn shift() -> ModifiersState {
+    ModifiersState {
+        shift: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn mouse(ui: &Ui, x: f32, y: f32, pressed: bool) -> bool {
+    let event = if pressed {
+        InputEvent::MousePressed {
+            x,
+            y,
+            button: MouseButton::Left,
+            modifiers: ModifiersState::default(),
+        }
+    } else {
+        InputEvent::MouseReleased {
+            x,
+            y,
+            button: MouseButton::Left,
+        }
+    };
+    ui.dispatch(event).unwrap()
+}
+
+/// Press and release the left button at a position in pixels in the window
+fn click(ui: &Ui, x: f32, y: f32) -> bool {
+    let handled = mouse(ui, x, y, true);
+    mouse(ui, x, y, false);
+    handled
+}
+
+fn text(ui: &Ui, id: &str) -> String {
+    widget::<TextInput>(ui, id).text()
+}
+
+/// The dialog spans from 200 to 600 pixels horizontally and from 210 to 390 pixels vertically,
+/// with its buttons of 80 by 32 pixels at the bottom right
+const OK: (f32, f32) = (464.0, 370.0);
+const CANCEL: (f32, f32) = (548.0, 370.0);
+
+#[test]
+fn test_dialog_modal() {
+    let closed = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let closed = closed.clone();
+        dialog_ui(move |builder| {
+            builder.with_on_close(move |button| closed.lock().push(button.map(String::from)));
+        })
+    };
+
+    assert!(click(&ui, 100.0, 30.0));
+    assert_eq!(Some(String::from("behind")), ui.focused());
+    assert_eq!(None, ui.modal());
+
+    // Opening the dialog focuses the first widget inside it
+    open_dialog(&ui, "dialog");
+    assert_eq!(Some(String::from("dialog")), ui.modal());
+    assert_eq!(Some(String::from("name")), ui.focused());
+
+    // The content below the dialog is out of reach of the mouse and the keyboard
+    assert!(click(&ui, 100.0, 30.0));
+    assert_eq!(Some(String::from("name")), ui.focused());
+    ui.set_focus(Some(String::from("behind")));
+    ui.dispatch(InputEvent::Character('x')).unwrap();
+    assert_eq!("", text(&ui, "behind"));
+
+    // Tab goes around the widgets inside the dialog and the dialog itself, which has buttons
+    assert!(press(&ui, VirtualKeyCode::Tab, ModifiersState::default()));
+    assert_eq!(Some(String::from("name")), ui.focused());
+    assert!(press(&ui, VirtualKeyCode::Tab, ModifiersState::default()));
+    assert_eq!(Some(String::from("extension")), ui.focused());
+    assert!(press(&ui, VirtualKeyCode::Tab, ModifiersState::default()));
+    assert_eq!(Some(String::from("dialog")), ui.focused());
+    assert!(press(&ui, VirtualKeyCode::Tab, ModifiersState::default()));
+    assert_eq!(Some(String::from("name")), ui.focused());
+    assert!(press(&ui, VirtualKeyCode::Tab, shift()));
+    assert_eq!(Some(String::from("dialog")), ui.focused());
+    assert!(press(&ui, VirtualKeyCode::Tab, shift()));
+    ui.dispatch(InputEvent::Character('a')).unwrap();
+    assert_eq!("a", text(&ui, "extension"));
+
+    // Escape dismisses the dialog and gives the focus back
+    assert!(press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+    assert!(!widget::<Dialog>(&ui, "dialog").is_open());
+    assert_eq!(None, ui.modal());
+    assert_eq!(Some(String::from("behind")), ui.focused());
+    assert!(!press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+    assert_eq!(vec![None], *closed.lock());
+}
+
+#[test]
+fn test_dialog_buttons() {
+    let closed = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let closed = closed.clone();
+        dialog_ui(move |builder| {
+            builder.with_on_close(move |button| closed.lock().push(button.map(String::from)));
+        })
+    };
+
+    open_dialog(&ui, "dialog");
+    assert!(click(&ui, CANCEL.0, CANCEL.1));
+    assert!(!widget::<Dialog>(&ui, "dialog").is_open());
+
+    // Releasing the button away from the button pressed chooses nothing
+    open_dialog(&ui, "dialog");
+    assert!(mouse(&ui, OK.0, OK.1, true));
+    assert!(mouse(&ui, 300.0, 300.0, false));
+    assert!(widget::<Dialog>(&ui, "dialog").is_open());
+
+    // Return chooses the default button from anywhere inside the dialog
+    assert_eq!(Some(0), widget::<Dialog>(&ui, "dialog").default_button());
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert!(!widget::<Dialog>(&ui, "dialog").is_open());
+
+    // The arrow keys move the default button while the dialog is focused
+    open_dialog(&ui, "dialog");
+    ui.set_focus(Some(String::from("dialog")));
+    assert!(press(&ui, VirtualKeyCode::Right, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Right, ModifiersState::default()));
+    assert_eq!(Some(1), widget::<Dialog>(&ui, "dialog").default_button());
+    assert!(press(&ui, VirtualKeyCode::Left, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Space, ModifiersState::default()));
+
+    assert_eq!(
+        vec![
+            Some(String::from("Cancel")),
+            Some(String::from("OK")),
+            Some(String::from("OK"))
+        ],
+        *closed.lock()
+    );
+}
+
+#[test]
+fn test_dialog_layout() {
+    let ui = dialog_ui(|_| {});
+
+    assert_eq!(None, ui.widgets()["name"].lock().bounds(&ui).unwrap().map(|_| ()));
+    assert!(ui.widgets()["dialog"].lock().draw(&ui).unwrap().is_empty());
+
+    // The children are laid out between the title, 32 pixels high, and the row of buttons, 40
+    // pixels high
+    open_dialog(&ui, "dialog");
+    let (bl, tr) = ui.widgets()["name"].lock().bounds(&ui).unwrap().unwrap();
+    assert!((bl.x() - 0.5).abs() < 1e-4);
+    assert!((tr.x() - 1.5).abs() < 1e-4);
+    assert!((bl.y() - (242.0 / 300.0)).abs() < 1e-4);
+    assert!((tr.y() - (350.0 / 300.0)).abs() < 1e-4);
+
+    // The whole window is dimmed first, then the panel is drawn
+    assert!(ui.widgets()["dialog"].lock().draw(&ui).unwrap().len() >= 6);
+    let vertices = ui.widgets()["dialog"].lock().draw_shadow(&ui).unwrap();
+    assert!(vertices.len() >= 6);
+    let dim: Vec<[f32; 2]> = vertices[..6]
+        .iter()
+        .map(|vertex| vertex.position().as_array())
+        .collect();
+    assert!(dim.contains(&[-1.0, -1.0]) & dim.contains(&[1.0, 1.0]));
+    assert!((vertices[0].color()[3] - (128.0 / 255.0)).abs() < 1e-4);
+}
+
+#[test]
+fn test_dialog_shadow() {
+    let ui = dialog_ui(|builder| {
+        builder.with_elevation(8.0);
+    });
+    assert_eq!((0.0, 8.0), widget::<Dialog>(&ui, "dialog").shadow().offset());
+    assert!(ui.widgets()["dialog"].lock().draw_shadow(&ui).unwrap().is_empty());
+
+    // The shadow falls on the dimmed window around the panel, from 200 to 600 pixels across and
+    // 210 to 390 pixels down
+    open_dialog(&ui, "dialog");
+    let vertices = ui.widgets()["dialog"].lock().draw_shadow(&ui).unwrap();
+    assert_eq!(12, vertices.len());
+    assert_eq!(None, vertices[0].bounds().map(|_| ()));
+    for vertex in &vertices[6..] {
+        let (bl, tr) = vertex.bounds().unwrap();
+        let pixels = [bl.x() * 400.0, bl.y() * 300.0, tr.x() * 400.0, tr.y() * 300.0];
+        for (expected, actual) in [200.0, 210.0, 600.0, 390.0].iter().zip(pixels.iter()) {
+            assert!((expected - actual).abs() < 1e-2, "{:?} != {:?}", expected, actual);
+        }
+        assert_eq!((0.0, 8.0), vertex.offset());
+    }
+}
+
+#[test]
+fn test_dialog_theme() {
+    let mut ui = themed_ui();
+    let dialog = DialogBuilder::new("dialog", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 12.0), dialog.shadow().offset());
+
+    // Without a theme the dialogs are raised the most of all the widgets
+    let mut ui = window_ui();
+    let dialog = DialogBuilder::new("dialog", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 24.0), dialog.shadow().offset());
+}
+
+#[test]
+fn test_message_box() {
+    let closed = Arc::new(Mutex::new(Vec::new()));
+    let mut ui = dialog_ui(|_| {});
+
+    let on_close = {
+        let closed = closed.clone();
+        move |button: Option<&str>| closed.lock().push(button.map(String::from))
+    };
+    let id = ui
+        .message_box("Delete", "Delete the file?", &["Yes", "No"], on_close.clone())
+        .unwrap();
+    assert_eq!("message_box_0", id);
+    assert_eq!(Some(id.clone()), ui.modal());
+    assert_eq!(Some(id.clone()), ui.focused());
+    assert_eq!("Delete the file?", widget::<Dialog>(&ui, &id).text());
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert_eq!(None, ui.modal());
+
+    // The message boxes closed are reused, the ones open stack up
+    let id = ui
+        .message_box(
+            "Save",
+            "Save the changes?",
+            &["Save", "Discard", "Cancel"],
+            on_close.clone(),
+        )
+        .unwrap();
+    assert_eq!("message_box_0", id);
+    assert_eq!("Save", widget::<Dialog>(&ui, &id).title());
+    let id = ui.message_box("Error", "The disk is full", &["OK"], on_close).unwrap();
+    assert_eq!("message_box_1", id);
+    assert_eq!(Some(id), ui.modal());
+    assert!(press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+    assert_eq!(Some(String::from("message_box_0")), ui.modal());
+    assert_eq!(Some(String::from("message_box_0")), ui.focused());
+    // The second of three buttons is where the first of two buttons is
+    assert!(click(&ui, OK.0, OK.1));
+
+    assert_eq!(
+        vec![Some(String::from("Yes")), None, Some(String::from("Discard"))],
+        *closed.lock()
+    );
+}
+
+#[test]
+fn test_dialog_normalize_sizes() {
+    let mut ui = window_ui();
+    ContainerBuilder::new("content", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    DialogBuilder::new("dialog", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+
+    // The dialogs are overlays, the container keeps the whole window whether they are open or not
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Size::Size(100.0, 100.0), widget::<Container>(&ui, "content").size());
+    assert_eq!(Size::Size(50.0, 30.0), widget::<Dialog>(&ui, "dialog").size());
+
+    // Message boxes are added after the first frame, and more than two would overflow the center
+    for _ in 0..3 {
+        ui.message_box("Error", "The disk is full", &["OK"], |_| {}).unwrap();
+    }
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Size::Size(100.0, 100.0), widget::<Container>(&ui, "content").size());
+    assert_eq!(Size::Size(50.0, 30.0), widget::<Dialog>(&ui, "message_box_2").size());
+}
@@ -0,0 +1,452 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+    },
+    ContainerBuilder,
+    DockArea,
+    DockAreaBuilder,
+    DockLayout,
+    DockPanel,
+    DockPanelBuilder,
+    DockedGroup,
+    FloatingGroup,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// A dock area filling the window with 30 pixels high tabs, a container as its content and the
+/// panels added by a closure given the builder of each of them
+fn dock_area_ui<F>(panels: &[&str], configure: F) -> Ui
+where
+    F: Fn(&str, &mut DockPanelBuilder),
+{
+    let mut ui = window_ui();
+
+    DockAreaBuilder::new("dock_area", "wcontainer", Position::Center)
+        .with_size(Size::Full)
+        .with_tab_height(30.0)
+        .build(&mut ui)
+        .unwrap();
+
+    for panel in panels {
+        let mut builder = DockPanelBuilder::new(*panel, "dock_area");
+        builder.with_title(*panel);
+        configure(panel, &mut builder);
+        builder.build(&mut ui).unwrap();
+    }
+
+    ContainerBuilder::new("content", "dock_area", Position::Center)
+        .with_size(Size::Full)
+        .build(&mut ui)
+        .unwrap();
+
+    ui
+}
+
+fn press(ui: &Ui, x: f32, y: f32) -> bool {
+    ui.dispatch(InputEvent::MousePressed {
+        x,
+        y,
+        button: MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+fn release(ui: &Ui, x: f32, y: f32) -> bool {
+    ui.dispatch(InputEvent::MouseReleased {
+        x,
+        y,
+        button: MouseButton::Left,
+    })
+    .unwrap()
+}
+
+fn hover(ui: &Ui, x: f32, y: f32) -> bool {
+    ui.dispatch(InputEvent::CursorMoved { x, y }).unwrap()
+}
+
+/// Press the left button at a position in pixels in the window, move the cursor through the other
+/// positions and release it at the last one
+fn drag(ui: &Ui, from: (f32, f32), through: &[(f32, f32)]) {
+    press(ui, from.0, from.1);
+    for &(x, y) in through {
+        hover(ui, x, y);
+    }
+    let to = through.last().cloned().unwrap_or(from);
+    release(ui, to.0, to.1);
+}
+
+/// The bounds of a widget in pixels, left, top, right and bottom, in an 800x600 window
+fn pixels(ui: &Ui, id: &str) -> Option<[f32; 4]> {
+    ui.widgets()[id]
+        .lock()
+        .bounds(ui)
+        .unwrap()
+        .map(|(bl, tr)| [bl.x() * 400.0, bl.y() * 300.0, tr.x() * 400.0, tr.y() * 300.0])
+}
+
+/// Call a closure with the dock area
+fn with_area<F>(ui: &Ui, f: F)
+where
+    F: FnOnce(&mut DockArea),
+{
+    let area = ui.widgets()["dock_area"].clone();
+    let mut area = area.lock();
+    f(area.downcast_mut::<DockArea>().unwrap());
+}
+
+fn assert_close(expected: &[f32], actual: &[f32]) {
+    assert_eq!(expected.len(), actual.len());
+    for (expected, actual) in expected.iter().zip(actual.iter()) {
+        assert!((expected - actual).abs() < 1e-2, "{:?} != {:?}", expected, actual);
+    }
+}
+
+/// Files docked at the left, output at the bottom and properties at the right
+fn edges_ui() -> Ui {
+    dock_area_ui(&["files", "output", "props"], |panel, builder| {
+        match panel {
+            "files" => builder.with_edge(Orientation::Left).with_dock_size(200.0),
+            "output" => builder.with_edge(Orientation::Bottom).with_dock_size(150.0),
+            _ => builder.with_edge(Orientation::Right).with_dock_size(100.0),
+        };
+    })
+}
+
+fn docked(edge: Orientation, size: f32, panels: &[&str], active: &str) -> DockedGroup {
+    DockedGroup {
+        edge,
+        size,
+        panels: panels.iter().map(|panel| String::from(*panel)).collect(),
+        active: Some(String::from(active)),
+    }
+}
+
+#[test]
+fn test_dock_area_layout() {
+    let ui = edges_ui();
+
+    assert_close(&[0.0, 0.0, 200.0, 600.0], &pixels(&ui, "files").unwrap());
+    assert_close(&[200.0, 450.0, 800.0, 600.0], &pixels(&ui, "output").unwrap());
+    assert_close(&[700.0, 0.0, 800.0, 450.0], &pixels(&ui, "props").unwrap());
+    assert_close(&[200.0, 0.0, 700.0, 450.0], &pixels(&ui, "content").unwrap());
+
+    assert_eq!(
+        DockLayout {
+            docked:   vec![
+                docked(Orientation::Left, 200.0, &["files"], "files"),
+                docked(Orientation::Bottom, 150.0, &["output"], "output"),
+                docked(Orientation::Right, 100.0, &["props"], "props"),
+            ],
+            floating: Vec::new(),
+        },
+        widget::<DockArea>(&ui, "dock_area").layout()
+    );
+    assert_eq!(
+        vec!["files", "output", "props"],
+        widget::<DockArea>(&ui, "dock_area").panels()
+    );
+    assert!(!widget::<DockPanel>(&ui, "files").floating());
+    assert_eq!(Layer::Content, ui.widgets()["files"].lock().layer());
+}
+
+#[test]
+fn test_dock_area_normalize_sizes() {
+    let ui = edges_ui();
+
+    // The panels are placed by the dock area, the content keeps the room between them
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Size::Size(100.0, 100.0), ui.widgets()["content"].lock().size());
+    assert_close(&[200.0, 0.0, 700.0, 450.0], &pixels(&ui, "content").unwrap());
+    assert_close(&[0.0, 0.0, 200.0, 600.0], &pixels(&ui, "files").unwrap());
+}
+
+#[test]
+fn test_dock_panel_shadow() {
+    let ui = dock_area_ui(&["files", "tools"], |panel, builder| {
+        if panel == "tools" {
+            builder.with_floating(100.0, 100.0, 200.0, 150.0).with_elevation(8.0);
+        }
+    });
+
+    // The panels cast the shadow of the docks unless they are given an elevation
+    assert_eq!((0.0, 4.0), widget::<DockPanel>(&ui, "files").shadow().offset());
+    assert_eq!((0.0, 8.0), widget::<DockPanel>(&ui, "tools").shadow().offset());
+    assert!(!ui.widgets()["tools"].lock().draw_shadow(&ui).unwrap().is_empty());
+}
+
+#[test]
+fn test_dock_area_drag_to_edge() {
+    let ui = edges_ui();
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let log = changes.clone();
+    with_area(&ui, |area| {
+        area.set_on_change(move |layout| log.lock().push(layout.clone()))
+    });
+
+    // A few pixels do not take the panel out of its group
+    press(&ui, 20.0, 10.0);
+    hover(&ui, 22.0, 11.0);
+    assert!(!widget::<DockPanel>(&ui, "files").floating());
+
+    // Dragged further it floats under the cursor, over the preview of the right edge
+    hover(&ui, 400.0, 300.0);
+    assert!(widget::<DockPanel>(&ui, "files").floating());
+    assert_eq!(Layer::Overlay, ui.widgets()["files"].lock().layer());
+    let vertices = ui.widgets()["files"].lock().draw(&ui).unwrap().len();
+    hover(&ui, 790.0, 200.0);
+    assert_eq!(vertices + 6, ui.widgets()["files"].lock().draw(&ui).unwrap().len());
+    release(&ui, 790.0, 200.0);
+
+    let layout = widget::<DockArea>(&ui, "dock_area").layout();
+    assert_eq!(
+        vec![
+            docked(Orientation::Bottom, 150.0, &["output"], "output"),
+            docked(Orientation::Right, 100.0, &["props", "files"], "files"),
+        ],
+        layout.docked
+    );
+    assert!(layout.floating.is_empty());
+    assert_eq!(vec![layout], *changes.lock());
+
+    assert!(!widget::<DockPanel>(&ui, "files").floating());
+    assert_close(&[700.0, 0.0, 800.0, 450.0], &pixels(&ui, "files").unwrap());
+    assert!(pixels(&ui, "props").is_none());
+    assert_close(&[0.0, 450.0, 800.0, 600.0], &pixels(&ui, "output").unwrap());
+    assert_close(&[0.0, 0.0, 700.0, 450.0], &pixels(&ui, "content").unwrap());
+
+    // Dropped near an edge without a group, the panel docks there on its own
+    drag(&ui, (750.0, 10.0), &[(400.0, 300.0), (400.0, 5.0)]);
+    let layout = widget::<DockArea>(&ui, "dock_area").layout();
+    assert_eq!(
+        vec![
+            docked(Orientation::Bottom, 150.0, &["output"], "output"),
+            docked(Orientation::Right, 100.0, &["props"], "props"),
+            docked(Orientation::Top, 200.0, &["files"], "files"),
+        ],
+        layout.docked
+    );
+    assert_close(&[0.0, 0.0, 700.0, 200.0], &pixels(&ui, "files").unwrap());
+    assert_eq!(2, changes.lock().len());
+}
+
+#[test]
+fn test_dock_area_tabs() {
+    let ui = edges_ui();
+
+    // The properties dropped on the tabs of the files join them, shown
+    drag(&ui, (710.0, 10.0), &[(400.0, 300.0), (100.0, 10.0)]);
+    let layout = widget::<DockArea>(&ui, "dock_area").layout();
+    assert_eq!(
+        vec![
+            docked(Orientation::Left, 200.0, &["files", "props"], "props"),
+            docked(Orientation::Bottom, 150.0, &["output"], "output"),
+        ],
+        layout.docked
+    );
+    assert_close(&[0.0, 0.0, 200.0, 600.0], &pixels(&ui, "props").unwrap());
+    assert!(pixels(&ui, "files").is_none());
+
+    // A click on a tab shows its panel
+    press(&ui, 50.0, 10.0);
+    release(&ui, 50.0, 10.0);
+    assert_eq!(
+        Some(String::from("files")),
+        widget::<DockArea>(&ui, "dock_area").layout().docked[0].active
+    );
+    assert_close(&[0.0, 0.0, 200.0, 600.0], &pixels(&ui, "files").unwrap());
+    assert!(pixels(&ui, "props").is_none());
+}
+
+#[test]
+fn test_dock_area_children() {
+    let mut ui = edges_ui();
+    ContainerBuilder::new("tree", "files", Position::Center)
+        .with_size(Size::Full)
+        .build(&mut ui)
+        .unwrap();
+    assert_close(&[0.0, 30.0, 200.0, 600.0], &pixels(&ui, "tree").unwrap());
+
+    // A press below the tabs of a panel is left to its children
+    assert!(!press(&ui, 100.0, 300.0));
+    release(&ui, 100.0, 300.0);
+    assert_eq!(
+        Orientation::Left,
+        widget::<DockArea>(&ui, "dock_area").layout().docked[0].edge
+    );
+
+    // The children of a panel not shown are hidden
+    with_area(&ui, |area| area.group(&ui, "output", "files"));
+    assert!(pixels(&ui, "tree").is_none());
+    with_area(&ui, |area| area.set_active(&ui, "files"));
+    assert_close(&[0.0, 30.0, 200.0, 600.0], &pixels(&ui, "tree").unwrap());
+}
+
+#[test]
+fn test_dock_area_floating() {
+    let ui = edges_ui();
+
+    // Dropped away from the edges and the tabs, the panel floats where it is
+    drag(&ui, (20.0, 10.0), &[(300.0, 200.0), (400.0, 300.0)]);
+    let layout = widget::<DockArea>(&ui, "dock_area").layout();
+    assert_eq!(
+        vec![FloatingGroup {
+            x:      380.0,
+            y:      290.0,
+            width:  200.0,
+            height: 240.0,
+            panels: vec![String::from("files")],
+            active: Some(String::from("files")),
+        }],
+        layout.floating
+    );
+    assert!(widget::<DockPanel>(&ui, "files").floating());
+    assert_close(&[380.0, 290.0, 580.0, 530.0], &pixels(&ui, "files").unwrap());
+    assert_close(&[0.0, 0.0, 700.0, 450.0], &pixels(&ui, "content").unwrap());
+
+    // The group is moved by its tabs, kept inside the dock area
+    drag(&ui, (570.0, 300.0), &[(610.0, 300.0)]);
+    assert_close(&[420.0, 290.0, 620.0, 530.0], &pixels(&ui, "files").unwrap());
+    drag(&ui, (610.0, 300.0), &[(900.0, 700.0)]);
+    assert_close(&[600.0, 360.0, 800.0, 600.0], &pixels(&ui, "files").unwrap());
+
+    // Another panel dropped on its tabs joins it
+    drag(&ui, (710.0, 10.0), &[(400.0, 100.0), (610.0, 370.0)]);
+    let layout = widget::<DockArea>(&ui, "dock_area").layout();
+    assert_eq!(1, layout.docked.len());
+    assert_eq!(vec!["files", "props"], layout.floating[0].panels);
+    assert_eq!(Some(String::from("props")), layout.floating[0].active);
+    assert_close(&[600.0, 360.0, 800.0, 600.0], &pixels(&ui, "props").unwrap());
+    assert!(pixels(&ui, "files").is_none());
+
+    // Dropped near an edge, a floating panel docks again
+    drag(&ui, (710.0, 370.0), &[(400.0, 300.0), (5.0, 300.0)]);
+    let layout = widget::<DockArea>(&ui, "dock_area").layout();
+    assert_eq!(docked(Orientation::Left, 200.0, &["props"], "props"), layout.docked[1]);
+    assert_eq!(vec!["files"], layout.floating[0].panels);
+}
+
+#[test]
+fn test_dock_area_resize() {
+    let ui = edges_ui();
+
+    // A docked group is resized from its inner side, down to a minimum
+    drag(&ui, (197.0, 300.0), &[(300.0, 300.0)]);
+    assert_eq!(300.0, widget::<DockArea>(&ui, "dock_area").layout().docked[0].size);
+    assert_close(&[0.0, 0.0, 300.0, 600.0], &pixels(&ui, "files").unwrap());
+    assert_close(&[300.0, 0.0, 700.0, 450.0], &pixels(&ui, "content").unwrap());
+    drag(&ui, (297.0, 300.0), &[(10.0, 300.0)]);
+    assert_eq!(48.0, widget::<DockArea>(&ui, "dock_area").layout().docked[0].size);
+
+    drag(&ui, (400.0, 452.0), &[(400.0, 300.0)]);
+    assert_eq!(300.0, widget::<DockArea>(&ui, "dock_area").layout().docked[1].size);
+    assert_close(&[48.0, 300.0, 800.0, 600.0], &pixels(&ui, "output").unwrap());
+
+    // A floating group is resized from its bottom right corner
+    with_area(&ui, |area| area.float(&ui, "props", 100.0, 100.0, 200.0, 150.0));
+    assert_close(&[100.0, 100.0, 300.0, 250.0], &pixels(&ui, "props").unwrap());
+    drag(&ui, (296.0, 246.0), &[(400.0, 400.0)]);
+    assert_close(&[100.0, 100.0, 400.0, 400.0], &pixels(&ui, "props").unwrap());
+    drag(&ui, (396.0, 396.0), &[(0.0, 0.0)]);
+    assert_close(&[100.0, 100.0, 148.0, 148.0], &pixels(&ui, "props").unwrap());
+}
+
+#[test]
+fn test_dock_area_serialize() {
+    let layout = DockLayout {
+        docked:   vec![docked(Orientation::Left, 240.0, &["files", "search"], "search")],
+        floating: vec![FloatingGroup {
+            x:      100.0,
+            y:      80.0,
+            width:  300.0,
+            height: 200.0,
+            panels: vec![String::from("props")],
+            active: None,
+        }],
+    };
+
+    let text = layout.to_toml().unwrap();
+    assert!(text.contains("edge = \"Left\""), "{}", text);
+    assert_eq!(layout, DockLayout::from_toml(&text).unwrap());
+    assert_eq!(DockLayout::default(), DockLayout::from_toml("").unwrap());
+    assert!(DockLayout::from_toml("[[docked]]\nedge = \"Middle\"").is_err());
+    assert_eq!(vec!["files", "search", "props"], layout.panels());
+}
+
+#[test]
+fn test_dock_area_set_layout() {
+    let ui = edges_ui();
+
+    // The unknown and repeated panels, the empty groups and the stray active panels are left out
+    let layout = DockLayout {
+        docked:   vec![
+            docked(Orientation::Top, 120.0, &["output", "ghost", "props"], "ghost"),
+            docked(Orientation::Left, 200.0, &["ghost"], "ghost"),
+        ],
+        floating: vec![FloatingGroup {
+            x:      100.0,
+            y:      200.0,
+            width:  200.0,
+            height: 100.0,
+            panels: vec![String::from("output")],
+            active: None,
+        }],
+    };
+    let layout = DockLayout::from_toml(&layout.to_toml().unwrap()).unwrap();
+    with_area(&ui, |area| area.set_layout(&ui, layout));
+
+    assert_eq!(
+        DockLayout {
+            docked:   vec![docked(Orientation::Top, 120.0, &["output", "props"], "output")],
+            floating: Vec::new(),
+        },
+        widget::<DockArea>(&ui, "dock_area").layout()
+    );
+    assert_close(&[0.0, 0.0, 800.0, 120.0], &pixels(&ui, "output").unwrap());
+    assert!(pixels(&ui, "props").is_none());
+    assert!(pixels(&ui, "files").is_none());
+    assert_close(&[0.0, 120.0, 800.0, 600.0], &pixels(&ui, "content").unwrap());
+}
+
+#[test]
+fn test_dock_area_builder() {
+    let mut ui = edges_ui();
+
+    assert!(DockAreaBuilder::new("bad", "wcontainer", Position::Center)
+        .with_tab_height(0.0)
+        .build(&mut ui)
+        .is_err());
+    assert!(DockPanelBuilder::new("stray", "content").build(&mut ui).is_err());
+    assert!(DockPanelBuilder::new("stray", "dock_area")
+        .with_floating(0.0, 0.0, 0.0, 100.0)
+        .build(&mut ui)
+        .is_err());
+
+    DockPanelBuilder::new("search", "dock_area")
+        .with_title("Search")
+        .with_floating(50.0, 60.0, 300.0, 200.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!("Search", widget::<DockPanel>(&ui, "search").title());
+    assert!(widget::<DockPanel>(&ui, "search").floating());
+    assert_close(&[50.0, 60.0, 350.0, 260.0], &pixels(&ui, "search").unwrap());
+}
@@ -0,0 +1,101 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::fill_vertices;
+use o2tk::prelude::*;
+
+#[test]
+fn test_fill_solid() {
+    let vertices = fill_vertices("#FF0000FF");
+    assert_eq!(6, vertices.len());
+    for vertex in vertices {
+        assert_eq!([1.0, 0.0, 0.0, 1.0], vertex.color());
+    }
+}
+
+#[test]
+fn test_fill_linear_gradient() {
+    let vertices = fill_vertices("linear-gradient(0, #000000FF 0.0, #FF0000FF 0.5, #FFFFFFFF 1.0)");
+    assert_eq!(0, vertices.len() % 3);
+    for vertex in vertices {
+        let (bl, tr) = vertex.bounds().unwrap();
+        let offset = (vertex.position().x() - bl.x()) / (tr.x() - bl.x());
+        let color = vertex.color();
+        if offset <= 0.5 {
+            assert!((color[0] - (offset * 2.0)).abs() < 0.001);
+            assert!(color[1].abs() < 0.001);
+        } else {
+            assert!((color[0] - 1.0).abs() < 0.001);
+            assert!((color[1] - ((offset - 0.5) * 2.0)).abs() < 0.001);
+        }
+    }
+}
+
+#[test]
+fn test_fill_radial_gradient() {
+    let vertices = fill_vertices("radial-gradient(0.5 0.5, 0.5, #FFFFFFFF, #000000FF)");
+    assert_eq!(0, vertices.len() % 3);
+    for vertex in vertices {
+        let (bl, tr) = vertex.bounds().unwrap();
+        let position = vertex.position();
+        assert!((position.x() >= bl.x()) & (position.x() <= tr.x()));
+        assert!((position.y() >= bl.y()) & (position.y() <= tr.y()));
+        assert!(vertex.color()[3] > 0.999);
+    }
+}
+
+#[test]
+fn test_fill_parse() {
+    let stops = vec![
+        ColorStop::new(1.0, Color::from_hex("#FFFFFFFF").unwrap()).unwrap(),
+        ColorStop::new(0.0, Color::from_hex("#000000FF").unwrap()).unwrap(),
+    ];
+    let fill = Fill::linear_gradient(45.0, stops).unwrap();
+    assert_eq!(
+        fill,
+        Fill::parse("linear-gradient(45deg, #000000FF, #FFFFFFFF)").unwrap()
+    );
+    assert_eq!(fill, Fill::parse(fill.clone().into_string()).unwrap());
+
+    assert!(Fill::parse("linear-gradient(45, #000000FF 0.0, #FFFFFFFF)").is_err());
+    assert!(Fill::parse("radial-gradient(0.5, 0.5, #000000FF, #FFFFFFFF)").is_err());
+    assert!(Fill::parse("conic-gradient(0, #000000FF, #FFFFFFFF)").is_err());
+    assert!(Fill::parse("radial-gradient(0.5 0.5, 1.0, #ffffffff)").is_err());
+    assert!(ColorStop::new(1.5, Color::default()).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_fill_invalid() {
+    fill_vertices("linear-gradient(0, #000000FF)");
+}
+
+#[test]
+fn test_fill_clipped_vertices() {
+    // Every vertex stays inside the widget, even where the cells of an off-center radial gradient
+    // are cut by its sides and the rounding errors used to put them outside of it
+    let fills = [
+        "linear-gradient(30, #000000FF 0.0, #FF0000FF 0.35, #FFFFFFFF 1.0)",
+        "linear-gradient(135, #000000FF, #FF0000FF, #00FF00FF, #FFFFFFFF)",
+        "linear-gradient(290, #000000FF 0.1, #FFFFFFFF 0.9)",
+        "radial-gradient(0.1 0.2, 0.9, #FFFFFFFF, #FF0000FF, #000000FF)",
+        "radial-gradient(0.3 0.7, 0.4, #FFFFFFFF, #000000FF)",
+    ];
+    for fill in fills.iter() {
+        for vertex in fill_vertices(fill) {
+            let (bl, tr) = vertex.bounds().unwrap();
+            let position = vertex.position();
+            assert!((position.x() >= bl.x()) & (position.x() <= tr.x()), "{}", fill);
+            assert!((position.y() >= bl.y()) & (position.y() <= tr.y()), "{}", fill);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    image_vertices,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    Image,
+    ImageBuilder,
+};
+
+// A 2x1 PNG with an opaque red pixel and a translucent blue pixel
+const PNG: [u8; 71] = [
+    137, 80, 78, 71, 13, 10, 26, 10, 0, 0, 0, 13, 73, 72, 68, 82, 0, 0, 0, 2, 0, 0, 0, 1, 8, 6, 0, 0, 0, 244, 34, 127,
+    138, 0, 0, 0, 14, 73, 68, 65, 84, 120, 156, 99, 248, 207, 192, 0, 66, 13, 0, 15, 122, 3, 126, 119, 233, 127, 151,
+    0, 0, 0, 0, 73, 69, 78, 68, 174, 66, 96, 130,
+];
+
+// The size in pixels of the drawn quad and its uv range, in a 800x600 window
+fn quad(fit: Fit) -> ([f32; 2], [f32; 2], [f32; 2]) {
+    let vertices = image_vertices(fit);
+    assert_eq!(6, vertices.len());
+
+    let x: Vec<f32> = vertices.iter().map(|vertex| vertex.position().x()).collect();
+    let y: Vec<f32> = vertices.iter().map(|vertex| vertex.position().y()).collect();
+    let u: Vec<f32> = vertices.iter().map(|vertex| vertex.uv()[0]).collect();
+    let v: Vec<f32> = vertices.iter().map(|vertex| vertex.uv()[1]).collect();
+    let span = |values: &[f32]| {
+        let min = values.iter().cloned().fold(std::f32::MAX, f32::min);
+        let max = values.iter().cloned().fold(std::f32::MIN, f32::max);
+        [min, max]
+    };
+
+    let (x, y) = (span(&x), span(&y));
+    (
+        [((x[1] - x[0]) * 800.0) / 2.0, ((y[1] - y[0]) * 600.0) / 2.0],
+        span(&u),
+        span(&v),
+    )
+}
+
+fn assert_close(expected: [f32; 2], actual: [f32; 2]) {
+    assert!((expected[0] - actual[0]).abs() < 0.01, "{:?} != {:?}", expected, actual);
+    assert!((expected[1] - actual[1]).abs() < 0.01, "{:?} != {:?}", expected, actual);
+}
+
+#[test]
+fn test_image_stretch() {
+    let (size, u, v) = quad(Fit::Stretch);
+    assert_close([400.0, 300.0], size);
+    assert_close([0.0, 1.0], u);
+    assert_close([0.0, 1.0], v);
+}
+
+#[test]
+fn test_image_contain() {
+    let (size, u, v) = quad(Fit::Contain);
+    assert_close([400.0, 200.0], size);
+    assert_close([0.0, 1.0], u);
+    assert_close([0.0, 1.0], v);
+}
+
+#[test]
+fn test_image_cover() {
+    let (size, u, v) = quad(Fit::Cover);
+    assert_close([400.0, 300.0], size);
+    assert_close([1.0 / 6.0, 5.0 / 6.0], u);
+    assert_close([0.0, 1.0], v);
+}
+
+#[test]
+fn test_image_none() {
+    let (size, u, v) = quad(Fit::None);
+    assert_close([200.0, 100.0], size);
+    assert_close([0.0, 1.0], u);
+    assert_close([0.0, 1.0], v);
+}
+
+#[test]
+fn test_image_decode() {
+    let texture = Texture::from_bytes(&PNG).unwrap();
+    assert_eq!((2, 1), (texture.width(), texture.height()));
+    assert_eq!(&[255, 0, 0, 255, 0, 0, 255, 128], texture.pixels());
+    assert!(Texture::from_bytes(&PNG[..32]).is_err());
+    assert!(Texture::from_path("assets/missing.png").is_err());
+}
+
+/// Render the 2x1 PNG on the CPU in a 400x300 image widget
+fn render(fit: Fit, tint: &str) -> Texture {
+    let mut ui = window_ui();
+    ImageBuilder::new("image", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_bytes(&PNG)
+        .with_fit(fit)
+        .with_tint(tint)
+        .build(&mut ui)
+        .unwrap();
+
+    let image = widget::<Image>(&ui, "image");
+    image.render(&ui).unwrap().unwrap()
+}
+
+fn pixel(texture: &Texture, x: u32, y: u32) -> &[u8] {
+    let i = (((y * texture.width()) + x) * 4) as usize;
+    &texture.pixels()[i..i + 4]
+}
+
+#[test]
+fn test_image_render() {
+    let texture = render(Fit::Stretch, "#FFFFFFFF");
+    assert_eq!((400, 300), (texture.width(), texture.height()));
+    assert_eq!(&[255, 0, 0, 255], pixel(&texture, 50, 10));
+    assert_eq!(&[0, 0, 255, 128], pixel(&texture, 350, 290));
+
+    // The image keeps its aspect ratio between transparent bands, tinted by a color
+    let texture = render(Fit::Contain, "#FFFFFF80");
+    assert_eq!(0, pixel(&texture, 50, 10)[3]);
+    assert_eq!(&[255, 0, 0, 128], pixel(&texture, 50, 150));
+    assert_eq!(&[0, 0, 255, 64], pixel(&texture, 350, 150));
+    assert_eq!(0, pixel(&texture, 350, 290)[3]);
+}
@@ -0,0 +1,294 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    ListView,
+    ListViewBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+
+fn list_view_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut ListViewBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = ListViewBuilder::new("list_view", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 50.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+/// A model of many rows counting the rows asked for
+#[derive(Default)]
+struct Counting {
+    asked: AtomicUsize,
+}
+
+impl ListModel for Counting {
+    fn len(&self) -> usize {
+        100_000
+    }
+
+    fn text(&self, row: usize) -> String {
+        self.asked.fetch_add(1, Ordering::SeqCst);
+        format!("Row {}", row)
+    }
+}
+
+fn items() -> Vec<String> {
+    (0..100).map(|row| format!("Row {}", row)).collect()
+}
+
+fn shift() -> ModifiersState {
+    ModifiersState {
+        shift: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn ctrl() -> ModifiersState {
+    ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap()
+}
+
+/// Click the row at a position in the list view, whose rows are 24 pixels high from 150 pixels
+fn click(ui: &Ui, row: usize, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::MousePressed {
+        x: 400.0,
+        y: 150.0 + (row as f32 * 24.0) + 12.0,
+        button: MouseButton::Left,
+        modifiers,
+    })
+    .unwrap()
+}
+
+fn wheel(ui: &Ui, delta_y: f32) -> bool {
+    ui.dispatch(InputEvent::MouseWheel {
+        x: 400.0,
+        y: 300.0,
+        delta_x: 0.0,
+        delta_y,
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_list_view_virtualized() {
+    let model = Arc::new(Counting::default());
+    let ui = {
+        let model = model.clone();
+        list_view_ui(move |builder| {
+            builder.with_model(model);
+        })
+    };
+
+    // Only the rows in the 300 pixels of the list view are asked for
+    let shown = widget::<ListView>(&ui, "list_view");
+    shown.draw(&ui).unwrap();
+    assert_eq!((0..13).collect::<Vec<_>>(), shown.rows());
+    assert_eq!(13, model.asked.load(Ordering::SeqCst));
+
+    // Scrolling a row down only asks for the row coming into view
+    assert!(wheel(&ui, -24.0));
+    let shown = widget::<ListView>(&ui, "list_view");
+    shown.draw(&ui).unwrap();
+    assert_eq!((1..14).collect::<Vec<_>>(), shown.rows());
+    assert_eq!(14, model.asked.load(Ordering::SeqCst));
+
+    assert!(wheel(&ui, -1_000_000_000.0));
+    assert!(!wheel(&ui, -24.0));
+    let shown = widget::<ListView>(&ui, "list_view");
+    assert_eq!(2_399_700.0, shown.scroll());
+    shown.draw(&ui).unwrap();
+    assert_eq!((99_987..100_000).collect::<Vec<_>>(), shown.rows());
+    assert_eq!(27, model.asked.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_list_view_single_selection() {
+    let selections = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let selections = selections.clone();
+        list_view_ui(move |builder| {
+            builder
+                .with_items(items())
+                .with_on_select(move |rows| selections.lock().push(rows.to_vec()));
+        })
+    };
+
+    assert!(click(&ui, 2, ModifiersState::default()));
+    assert_eq!(Some(String::from("list_view")), ui.focused());
+    assert_eq!(vec![2], widget::<ListView>(&ui, "list_view").selected());
+    assert_eq!(Some(2), widget::<ListView>(&ui, "list_view").current());
+
+    // The modifier keys don't select several rows
+    assert!(click(&ui, 5, ctrl()));
+    assert!(click(&ui, 7, shift()));
+    assert_eq!(vec![7], widget::<ListView>(&ui, "list_view").selected());
+    assert_eq!(vec![vec![2], vec![5], vec![7]], *selections.lock());
+}
+
+#[test]
+fn test_list_view_multiple_selection() {
+    let ui = list_view_ui(|builder| {
+        builder.with_items(items()).with_selection_mode(SelectionMode::Multiple);
+    });
+
+    assert!(click(&ui, 1, ModifiersState::default()));
+    assert!(click(&ui, 4, shift()));
+    assert_eq!(vec![1, 2, 3, 4], widget::<ListView>(&ui, "list_view").selected());
+    assert!(click(&ui, 2, ctrl()));
+    assert_eq!(vec![1, 3, 4], widget::<ListView>(&ui, "list_view").selected());
+    assert!(click(&ui, 6, ctrl()));
+    assert_eq!(vec![1, 3, 4, 6], widget::<ListView>(&ui, "list_view").selected());
+
+    // The range starts from the row last clicked without shift
+    assert!(click(&ui, 8, shift()));
+    assert_eq!(vec![6, 7, 8], widget::<ListView>(&ui, "list_view").selected());
+
+    assert!(press(&ui, VirtualKeyCode::A, ctrl()));
+    assert_eq!(100, widget::<ListView>(&ui, "list_view").selected().len());
+}
+
+#[test]
+fn test_list_view_keys() {
+    let activated = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let activated = activated.clone();
+        list_view_ui(move |builder| {
+            builder
+                .with_items(items())
+                .with_on_activate(move |row| activated.lock().push(row));
+        })
+    };
+    ui.set_focus(Some(String::from("list_view")));
+
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert_eq!(vec![0], widget::<ListView>(&ui, "list_view").selected());
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert_eq!(vec![2], widget::<ListView>(&ui, "list_view").selected());
+
+    // The current row is scrolled into view
+    assert!(press(&ui, VirtualKeyCode::End, ModifiersState::default()));
+    assert_eq!(vec![99], widget::<ListView>(&ui, "list_view").selected());
+    assert_eq!(2100.0, widget::<ListView>(&ui, "list_view").scroll());
+    assert!(press(&ui, VirtualKeyCode::Home, ModifiersState::default()));
+    assert_eq!(0.0, widget::<ListView>(&ui, "list_view").scroll());
+
+    // A page is the 12 rows fully shown
+    assert!(press(&ui, VirtualKeyCode::PageDown, ModifiersState::default()));
+    assert_eq!(vec![12], widget::<ListView>(&ui, "list_view").selected());
+    assert_eq!(12.0, widget::<ListView>(&ui, "list_view").scroll());
+    assert!(press(&ui, VirtualKeyCode::Up, ModifiersState::default()));
+    assert_eq!(vec![11], widget::<ListView>(&ui, "list_view").selected());
+
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert!(!press(&ui, VirtualKeyCode::B, ModifiersState::default()));
+    assert_eq!(vec![11], *activated.lock());
+}
+
+#[test]
+fn test_list_view_multiple_keys() {
+    let ui = list_view_ui(|builder| {
+        builder.with_items(items()).with_selection_mode(SelectionMode::Multiple);
+    });
+    ui.set_focus(Some(String::from("list_view")));
+
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Down, shift()));
+    assert!(press(&ui, VirtualKeyCode::Down, shift()));
+    assert_eq!(vec![0, 1, 2], widget::<ListView>(&ui, "list_view").selected());
+
+    // The control key moves without selecting, and space adds the current row
+    assert!(press(&ui, VirtualKeyCode::Down, ctrl()));
+    assert!(press(&ui, VirtualKeyCode::Down, ctrl()));
+    assert_eq!(Some(4), widget::<ListView>(&ui, "list_view").current());
+    assert_eq!(vec![0, 1, 2], widget::<ListView>(&ui, "list_view").selected());
+    assert!(press(&ui, VirtualKeyCode::Space, ctrl()));
+    assert_eq!(vec![0, 1, 2, 4], widget::<ListView>(&ui, "list_view").selected());
+}
+
+#[test]
+fn test_list_view_double_click() {
+    let activated = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let activated = activated.clone();
+        list_view_ui(move |builder| {
+            builder
+                .with_items(items())
+                .with_on_activate(move |row| activated.lock().push(row));
+        })
+    };
+
+    assert!(click(&ui, 3, ModifiersState::default()));
+    assert!(click(&ui, 4, ModifiersState::default()));
+    assert!(activated.lock().is_empty());
+    assert!(click(&ui, 4, ModifiersState::default()));
+    assert_eq!(vec![4], *activated.lock());
+}
+
+#[test]
+fn test_list_view_model() {
+    let ui = list_view_ui(|builder| {
+        builder
+            .with_items(items())
+            .with_selection_mode(SelectionMode::Multiple)
+            .with_selected(&[8, 20, 500]);
+    });
+    assert_eq!(vec![8, 20], widget::<ListView>(&ui, "list_view").selected());
+
+    let mut list_view = widget::<ListView>(&ui, "list_view");
+    list_view.set_model(Arc::new(vec![String::from("Only")]));
+    assert!(list_view.selected().is_empty());
+    assert_eq!(None, list_view.current());
+    list_view.draw(&ui).unwrap();
+    assert_eq!(vec![0], list_view.rows());
+}
+
+#[test]
+fn test_list_view_hidden() {
+    let ui = list_view_ui(|builder| {
+        builder.with_items(items()).with_visible(false);
+    });
+    assert!(!ui.widgets()["list_view"].lock().visible());
+    assert!(!click(&ui, 0, ModifiersState::default()));
+
+    ui.widgets()["list_view"].lock().show();
+    assert!(click(&ui, 0, ModifiersState::default()));
+}
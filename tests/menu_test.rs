@@ -0,0 +1,392 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    Container,
+    ContainerBuilder,
+    Menu,
+    MenuBar,
+    MenuBarBuilder,
+    MenuBuilder,
+    MenuItem,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn menu_bar_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut MenuBuilder),
+{
+    let mut ui = window_ui();
+
+    MenuBarBuilder::new("bar", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_menu("file", "File")
+        .with_menu("edit", "Edit")
+        .build(&mut ui)
+        .unwrap();
+
+    let mut builder = MenuBuilder::new("file", "bar");
+    builder
+        .with_item(MenuItem::new("new", "New").with_shortcut("Ctrl+N"))
+        .with_item(MenuItem::new("open", "Open"))
+        .with_item(MenuItem::separator())
+        .with_item(
+            MenuItem::new("recent", "Recent")
+                .with_item(MenuItem::new("first", "First"))
+                .with_item(MenuItem::new("second", "Second")),
+        )
+        .with_item(MenuItem::new("wrap", "Wrap").with_checked(false))
+        .with_item(MenuItem::new("quit", "Quit").with_enabled(false));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    MenuBuilder::new("edit", "bar")
+        .with_item(MenuItem::new("undo", "Undo"))
+        .with_item(MenuItem::new("redo", "Redo"))
+        .build(&mut ui)
+        .unwrap();
+
+    ui
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode) -> bool {
+    ui.dispatch(InputEvent::KeyPressed {
+        key,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+/// Press and release a mouse button at a position in pixels in the window
+fn click(ui: &Ui, x: f32, y: f32, button: MouseButton) -> bool {
+    let handled = ui
+        .dispatch(InputEvent::MousePressed {
+            x,
+            y,
+            button,
+            modifiers: ModifiersState::default(),
+        })
+        .unwrap();
+    ui.dispatch(InputEvent::MouseReleased { x, y, button }).unwrap();
+    handled
+}
+
+fn hover(ui: &Ui, x: f32, y: f32) {
+    ui.dispatch(InputEvent::CursorMoved { x, y }).unwrap();
+}
+
+/// A window with a container covering its center and a menu attached to the container
+fn context_ui() -> Ui {
+    let mut ui = window_ui();
+
+    ContainerBuilder::new("target", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .build(&mut ui)
+        .unwrap();
+
+    MenuBuilder::new("context", "wcontainer")
+        .with_item(MenuItem::new("cut", "Cut"))
+        .with_item(MenuItem::new("copy", "Copy"))
+        .with_item(MenuItem::new("more", "More").with_item(MenuItem::new("paste", "Paste")))
+        .with_target("target")
+        .build(&mut ui)
+        .unwrap();
+
+    ui
+}
+
+#[test]
+fn test_menu_bar_click() {
+    let ui = menu_bar_ui(|_| {});
+
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    assert!(widget::<Menu>(&ui, "file").is_open());
+    assert_eq!(Some(String::from("file")), ui.focused());
+    assert_eq!(Some(String::from("file")), ui.hit_test(1.75, 1.0 / 6.0).unwrap());
+
+    // Pressing the title again closes the menu, pressing another title switches menus
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+    assert_eq!(Some(String::from("bar")), ui.focused());
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    assert!(click(&ui, 260.0, 300.0, MouseButton::Left));
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+    assert!(widget::<Menu>(&ui, "edit").is_open());
+
+    // Pressing anywhere else closes the menu
+    assert!(click(&ui, 700.0, 50.0, MouseButton::Left));
+    assert!(!widget::<Menu>(&ui, "edit").is_open());
+    assert_eq!(Some(String::from("bar")), ui.focused());
+}
+
+#[test]
+fn test_menu_select() {
+    let selected = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let selected = selected.clone();
+        menu_bar_ui(move |builder| {
+            builder.with_on_select(move |id| selected.lock().push(String::from(id)));
+        })
+    };
+
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    hover(&ui, 250.0, 490.0);
+    assert_eq!(vec![1], widget::<Menu>(&ui, "file").highlighted());
+    assert!(click(&ui, 250.0, 490.0, MouseButton::Left));
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+
+    // Picking a checkable item toggles its checkmark
+    assert_eq!(Some(false), widget::<Menu>(&ui, "file").checked("wrap"));
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    assert!(click(&ui, 250.0, 547.0, MouseButton::Left));
+    assert_eq!(Some(true), widget::<Menu>(&ui, "file").checked("wrap"));
+    assert_eq!(None, widget::<Menu>(&ui, "file").checked("open"));
+
+    // Separators and disabled items can't be picked
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    assert!(click(&ui, 250.0, 506.0, MouseButton::Left));
+    assert!(click(&ui, 250.0, 570.0, MouseButton::Left));
+    assert!(widget::<Menu>(&ui, "file").is_open());
+    hover(&ui, 250.0, 570.0);
+    assert!(widget::<Menu>(&ui, "file").highlighted().is_empty());
+
+    assert_eq!(vec!["open", "wrap"], *selected.lock());
+}
+
+#[test]
+fn test_menu_submenu() {
+    let selected = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let selected = selected.clone();
+        menu_bar_ui(move |builder| {
+            builder.with_on_select(move |id| selected.lock().push(String::from(id)));
+        })
+    };
+
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    hover(&ui, 250.0, 520.0);
+    assert_eq!(vec![3], widget::<Menu>(&ui, "file").highlighted());
+    assert_eq!(2, widget::<Menu>(&ui, "file").levels());
+
+    // The submenu opens at the right of its item
+    hover(&ui, 400.0, 545.0);
+    assert_eq!(vec![3, 1], widget::<Menu>(&ui, "file").highlighted());
+    assert!(click(&ui, 250.0, 520.0, MouseButton::Left));
+    assert!(widget::<Menu>(&ui, "file").is_open());
+    assert!(click(&ui, 400.0, 545.0, MouseButton::Left));
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+    assert_eq!(vec!["second"], *selected.lock());
+
+    // Moving to another item closes the submenu
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    hover(&ui, 250.0, 520.0);
+    hover(&ui, 250.0, 466.0);
+    assert_eq!(vec![0], widget::<Menu>(&ui, "file").highlighted());
+    assert_eq!(1, widget::<Menu>(&ui, "file").levels());
+}
+
+#[test]
+fn test_menu_keyboard() {
+    let selected = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let selected = selected.clone();
+        menu_bar_ui(move |builder| {
+            builder.with_on_select(move |id| selected.lock().push(String::from(id)));
+        })
+    };
+
+    ui.set_focus(Some(String::from("bar")));
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert!(widget::<Menu>(&ui, "file").is_open());
+    assert_eq!(Some(String::from("file")), ui.focused());
+
+    // The separator and the disabled item are skipped, wrapping around
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert_eq!(vec![0], widget::<Menu>(&ui, "file").highlighted());
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert_eq!(vec![3], widget::<Menu>(&ui, "file").highlighted());
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert_eq!(vec![0], widget::<Menu>(&ui, "file").highlighted());
+    assert!(press(&ui, VirtualKeyCode::Up));
+    assert_eq!(vec![4], widget::<Menu>(&ui, "file").highlighted());
+    assert!(press(&ui, VirtualKeyCode::Home));
+    assert_eq!(vec![0], widget::<Menu>(&ui, "file").highlighted());
+    assert!(press(&ui, VirtualKeyCode::End));
+    assert_eq!(vec![4], widget::<Menu>(&ui, "file").highlighted());
+
+    // Right opens a submenu and Left closes it
+    assert!(press(&ui, VirtualKeyCode::Up));
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert_eq!(vec![3, 0], widget::<Menu>(&ui, "file").highlighted());
+    assert_eq!(2, widget::<Menu>(&ui, "file").levels());
+    assert!(press(&ui, VirtualKeyCode::Left));
+    assert_eq!(vec![3], widget::<Menu>(&ui, "file").highlighted());
+    assert_eq!(1, widget::<Menu>(&ui, "file").levels());
+
+    // Otherwise they move along the menu bar
+    assert!(press(&ui, VirtualKeyCode::Home));
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+    assert!(widget::<Menu>(&ui, "edit").is_open());
+    assert_eq!(Some(String::from("edit")), ui.focused());
+    assert!(press(&ui, VirtualKeyCode::Left));
+    assert!(widget::<Menu>(&ui, "file").is_open());
+    assert!(!widget::<Menu>(&ui, "edit").is_open());
+
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert!(press(&ui, VirtualKeyCode::Return));
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+    assert_eq!(Some(String::from("bar")), ui.focused());
+    assert_eq!(vec!["new"], *selected.lock());
+
+    // Escape closes a submenu, then the menu
+    assert!(press(&ui, VirtualKeyCode::Return));
+    assert!(press(&ui, VirtualKeyCode::Up));
+    assert!(press(&ui, VirtualKeyCode::Up));
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert_eq!(2, widget::<Menu>(&ui, "file").levels());
+    assert!(press(&ui, VirtualKeyCode::Escape));
+    assert_eq!(1, widget::<Menu>(&ui, "file").levels());
+    assert!(press(&ui, VirtualKeyCode::Escape));
+    assert!(!widget::<Menu>(&ui, "file").is_open());
+    assert_eq!(Some(String::from("bar")), ui.focused());
+}
+
+#[test]
+fn test_context_menu() {
+    let ui = context_ui();
+    assert_eq!(Some(String::from("context")), ui.context_menu("target"));
+
+    // Only the right button over the widget opens the menu, where it was pressed
+    assert!(!click(&ui, 100.0, 100.0, MouseButton::Right));
+    assert!(!click(&ui, 300.0, 200.0, MouseButton::Left));
+    assert!(!widget::<Menu>(&ui, "context").is_open());
+    assert!(click(&ui, 300.0, 200.0, MouseButton::Right));
+    assert!(widget::<Menu>(&ui, "context").is_open());
+    hover(&ui, 350.0, 216.0);
+    assert_eq!(vec![0], widget::<Menu>(&ui, "context").highlighted());
+
+    assert!(click(&ui, 100.0, 100.0, MouseButton::Left));
+    assert!(!widget::<Menu>(&ui, "context").is_open());
+
+    ui.set_context_menu("target", None);
+    assert!(!click(&ui, 300.0, 200.0, MouseButton::Right));
+    assert!(!widget::<Menu>(&ui, "context").is_open());
+}
+
+#[test]
+fn test_menu_edges() {
+    let mut ui = context_ui();
+    ContainerBuilder::new("corner", "wcontainer", Position::BottomRight)
+        .with_size(Size::Size(10.0, 10.0))
+        .build(&mut ui)
+        .unwrap();
+    ui.set_context_menu("corner", Some(String::from("context")));
+
+    // The menu moves away from the edges of the window to stay inside it
+    assert!(click(&ui, 790.0, 590.0, MouseButton::Right));
+    hover(&ui, 700.0, 530.0);
+    assert_eq!(vec![0], widget::<Menu>(&ui, "context").highlighted());
+    assert!(click(&ui, 700.0, 10.0, MouseButton::Left));
+
+    // A submenu with no room at the right of its menu opens at its left
+    assert!(click(&ui, 590.0, 440.0, MouseButton::Right));
+    hover(&ui, 700.0, 500.0);
+    assert_eq!(vec![2], widget::<Menu>(&ui, "context").highlighted());
+    hover(&ui, 560.0, 500.0);
+    assert_eq!(vec![2, 0], widget::<Menu>(&ui, "context").highlighted());
+    assert!(!ui.widgets()["context"].lock().draw(&ui).unwrap().is_empty());
+}
+
+#[test]
+fn test_menu_theme() {
+    let mut ui = themed_ui();
+    MenuBarBuilder::new("bar", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_menu("file", "File")
+        .build(&mut ui)
+        .unwrap();
+    let menu = MenuBuilder::new("file", "bar")
+        .with_item(MenuItem::new("new", "New"))
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(2.0, menu.border().width());
+    assert_eq!([3.0; 4], menu.corner_radius().as_array());
+    assert_eq!((0.0, 6.0), menu.shadow().offset());
+
+    let bar = widget::<MenuBar>(&ui, "bar");
+    assert_eq!(2.0, bar.border().width());
+    assert_eq!([3.0; 4], bar.corner_radius().as_array());
+
+    let bar = MenuBarBuilder::new("raised", "wcontainer", Position::Center)
+        .with_elevation(4.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 4.0), bar.shadow().offset());
+}
+
+#[test]
+fn test_menu_shadow() {
+    let ui = menu_bar_ui(|builder| {
+        builder.with_elevation(4.0);
+    });
+    assert_eq!((0.0, 4.0), widget::<Menu>(&ui, "file").shadow().offset());
+    assert!(ui.widgets()["file"].lock().draw_shadow(&ui).unwrap().is_empty());
+
+    // Only the open panel casts a shadow, not the whole window the menu covers
+    assert!(click(&ui, 210.0, 300.0, MouseButton::Left));
+    let vertices = ui.widgets()["file"].lock().draw_shadow(&ui).unwrap();
+    assert!(!vertices.is_empty());
+    for vertex in vertices {
+        let (bl_vertex, tr_vertex) = vertex.bounds().unwrap();
+        assert!((bl_vertex.x() > 0.0) & (tr_vertex.x() < 2.0));
+        assert!((bl_vertex.y() > 0.0) & (tr_vertex.y() < 2.0));
+        assert_eq!((0.0, 4.0), vertex.offset());
+    }
+}
+
+#[test]
+fn test_menu_normalize_sizes() {
+    let mut ui = window_ui();
+    ContainerBuilder::new("content", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    MenuBuilder::new("menu", "wcontainer")
+        .with_item(MenuItem::new("cut", "Cut"))
+        .with_target("content")
+        .build(&mut ui)
+        .unwrap();
+
+    // The menu is an overlay, open or not it leaves the whole center to the container
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Size::Size(100.0, 100.0), widget::<Container>(&ui, "content").size());
+    assert_eq!(Size::Full, widget::<Menu>(&ui, "menu").size());
+    assert!(click(&ui, 400.0, 300.0, MouseButton::Right));
+    assert!(widget::<Menu>(&ui, "menu").is_open());
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Size::Size(100.0, 100.0), widget::<Container>(&ui, "content").size());
+}
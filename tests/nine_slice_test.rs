@@ -0,0 +1,61 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::nine_slice_container;
+
+fn sorted(mut values: Vec<f32>) -> Vec<f32> {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values.dedup_by(|a, b| (*a - *b).abs() < 0.0001);
+    values
+}
+
+#[test]
+fn test_nine_slice_corners_keep_their_size() {
+    let (vertices, texture) = nine_slice_container([30, 40, 60, 80]);
+    let texture = texture.unwrap();
+
+    let textured: Vec<_> = vertices.iter().filter(|vertex| vertex.texture().is_some()).collect();
+    assert_eq!(6, vertices.len() - textured.len());
+    assert_eq!(54, textured.len());
+    assert!(textured.iter().all(|vertex| vertex.texture() == Some(texture.id())));
+
+    let xs = sorted(textured.iter().map(|vertex| vertex.position().as_array()[0]).collect());
+    let ys = sorted(textured.iter().map(|vertex| vertex.position().as_array()[1]).collect());
+    let us = sorted(textured.iter().map(|vertex| vertex.uv()[0]).collect());
+    let vs = sorted(textured.iter().map(|vertex| vertex.uv()[1]).collect());
+
+    for (expected, actual) in [
+        (vec![-1.0, -0.8, 0.9, 1.0], xs),
+        (vec![-1.0, -0.9, 0.8, 1.0], ys),
+        (vec![0.0, 0.5, 0.75, 1.0], us),
+        (vec![0.0, 0.25, 0.5, 1.0], vs),
+    ]
+    .iter()
+    {
+        assert_eq!(expected.len(), actual.len());
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!((e - a).abs() < 0.0001, "expected {:?}, got {:?}", expected, actual);
+        }
+    }
+}
+
+#[test]
+fn test_nine_slice_without_insets() {
+    let (vertices, _) = nine_slice_container([0, 0, 0, 0]);
+    assert_eq!(12, vertices.len());
+}
+
+#[test]
+#[should_panic]
+fn test_nine_slice_insets_too_large() {
+    nine_slice_container([60, 0, 60, 0]);
+}
@@ -0,0 +1,28 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::opacity_alpha;
+
+#[test]
+fn test_opacity_opaque() {
+    assert_eq!(vec![1.0; 6], opacity_alpha(1.0, 1.0));
+}
+
+#[test]
+fn test_opacity_inherited() {
+    assert_eq!(vec![0.25; 6], opacity_alpha(0.5, 0.5));
+}
+
+#[test]
+fn test_opacity_invisible_parent() {
+    assert_eq!(vec![0.0; 6], opacity_alpha(0.0, 1.0));
+}
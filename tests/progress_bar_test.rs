@@ -0,0 +1,91 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    ProgressBar,
+    ProgressBarBuilder,
+};
+
+fn progress_bar_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut ProgressBarBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = ProgressBarBuilder::new("progress_bar", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 10.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+#[test]
+fn test_progress_bar_progress() {
+    let ui = progress_bar_ui(|builder| {
+        builder.with_progress(1.5);
+    });
+    assert_eq!(1.0, widget::<ProgressBar>(&ui, "progress_bar").progress());
+
+    let mut progress_bar = widget::<ProgressBar>(&ui, "progress_bar");
+    progress_bar.set_progress(0.25);
+    assert_eq!(0.25, progress_bar.progress());
+    progress_bar.set_progress(-1.0);
+    assert_eq!(0.0, progress_bar.progress());
+}
+
+#[test]
+fn test_progress_bar_draw() {
+    let ui = progress_bar_ui(|_| ());
+
+    // The bar is only drawn with some progress
+    let empty = widget::<ProgressBar>(&ui, "progress_bar").draw(&ui).unwrap().len();
+    let mut half = widget::<ProgressBar>(&ui, "progress_bar");
+    half.set_progress(0.5);
+    assert!(half.draw(&ui).unwrap().len() > empty);
+}
+
+#[test]
+fn test_progress_bar_indeterminate() {
+    let ui = progress_bar_ui(|builder| {
+        builder.with_indeterminate(true).with_orientation(Orientation::Left);
+    });
+
+    let mut progress_bar = widget::<ProgressBar>(&ui, "progress_bar");
+    assert!(progress_bar.indeterminate());
+    assert!(!progress_bar.draw(&ui).unwrap().is_empty());
+    progress_bar.set_indeterminate(false);
+    assert!(!progress_bar.indeterminate());
+}
+
+#[test]
+fn test_progress_bar_theme() {
+    let mut ui = themed_ui();
+    let progress_bar = ProgressBarBuilder::new("progress_bar", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(2.0, progress_bar.border().width());
+    assert_eq!([3.0; 4], progress_bar.corner_radius().as_array());
+    assert!(!progress_bar.shadow().visible());
+
+    let progress_bar = ProgressBarBuilder::new("raised", "wcontainer", Position::Center)
+        .with_elevation(4.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 4.0), progress_bar.shadow().offset());
+}
@@ -0,0 +1,183 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    RadioButton,
+    RadioButtonBuilder,
+    RadioGroup,
+    RadioGroupBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// A radio group filling the window with the radio buttons "a", "b" and "c"
+fn radio_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut RadioGroupBuilder, &mut [RadioButtonBuilder]),
+{
+    let mut ui = window_ui();
+
+    let mut group = RadioGroupBuilder::new("group", "wcontainer", Position::Center);
+    let mut radios = [
+        RadioButtonBuilder::new("a", "group", Position::TopLeft),
+        RadioButtonBuilder::new("b", "group", Position::Center),
+        RadioButtonBuilder::new("c", "group", Position::BottomRight),
+    ];
+    for radio in radios.iter_mut() {
+        radio.with_size(Size::Size(20.0, 10.0));
+    }
+    configure(&mut group, &mut radios);
+
+    group.build(&mut ui).unwrap();
+    for radio in radios.iter() {
+        radio.build(&mut ui).unwrap();
+    }
+
+    ui
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode) -> bool {
+    ui.dispatch(InputEvent::KeyPressed {
+        key,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+fn checked(ui: &Ui) -> Vec<bool> {
+    ["a", "b", "c"]
+        .iter()
+        .map(|id| widget::<RadioButton>(ui, id).checked())
+        .collect()
+}
+
+fn themed<W: WidgetTrait>(widget: &W) {
+    assert_eq!(2.0, widget.border().width());
+    assert_eq!([3.0; 4], widget.corner_radius().as_array());
+    assert_eq!(Fill::parse("#11223344").unwrap(), widget.fill());
+}
+
+#[test]
+fn test_radio_exclusive() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        radio_ui(move |group, radios| {
+            group.with_on_change(move |value| changes.lock().push(value.to_string()));
+            radios[0].with_checked(true);
+            radios[1].with_value("second");
+        })
+    };
+    assert_eq!(vec![true, false, false], checked(&ui));
+    assert_eq!(Some(String::from("a")), widget::<RadioGroup>(&ui, "group").selected());
+
+    // Clicking the radio button in the center of the window
+    let pressed = InputEvent::MousePressed {
+        x:         400.0,
+        y:         300.0,
+        button:    MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    };
+    assert!(ui.dispatch(pressed).unwrap());
+    let released = InputEvent::MouseReleased {
+        x:      400.0,
+        y:      300.0,
+        button: MouseButton::Left,
+    };
+    assert!(ui.dispatch(released).unwrap());
+    assert_eq!(vec![false, true, false], checked(&ui));
+    assert_eq!(
+        Some(String::from("second")),
+        widget::<RadioGroup>(&ui, "group").selected()
+    );
+
+    // Choosing the checked radio button again changes nothing
+    assert!(press(&ui, VirtualKeyCode::Space));
+    assert_eq!(vec![String::from("second")], *changes.lock());
+}
+
+#[test]
+fn test_radio_arrows() {
+    let ui = radio_ui(|_, radios| {
+        radios[1].with_disabled(true);
+    });
+    assert_eq!(None, widget::<RadioGroup>(&ui, "group").selected());
+
+    ui.set_focus(Some(String::from("a")));
+    assert!(press(&ui, VirtualKeyCode::Space));
+    assert_eq!(vec![true, false, false], checked(&ui));
+
+    // The disabled radio button is skipped, and the arrows wrap around
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert_eq!(vec![false, false, true], checked(&ui));
+    assert_eq!(Some(String::from("c")), ui.focused());
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert_eq!(vec![true, false, false], checked(&ui));
+    assert!(press(&ui, VirtualKeyCode::Up));
+    assert_eq!(Some(String::from("c")), widget::<RadioGroup>(&ui, "group").selected());
+    assert!(!press(&ui, VirtualKeyCode::A));
+}
+
+#[test]
+fn test_radio_group_select() {
+    let changes = Arc::new(Mutex::new(0));
+    let ui = {
+        let changes = changes.clone();
+        radio_ui(move |group, _| {
+            group.with_on_change(move |_| *changes.lock() += 1);
+        })
+    };
+
+    {
+        let mut group = ui.widgets()["group"].lock();
+        let group = group.downcast_mut::<RadioGroup>().unwrap();
+        group.select(&ui, Some("b"));
+        assert_eq!(Some(String::from("b")), group.selected());
+    }
+    assert_eq!(vec![false, true, false], checked(&ui));
+
+    ui.widgets()["group"]
+        .lock()
+        .downcast_mut::<RadioGroup>()
+        .unwrap()
+        .select(&ui, None);
+    assert_eq!(vec![false, false, false], checked(&ui));
+    assert_eq!(None, widget::<RadioGroup>(&ui, "group").selected());
+    assert_eq!(0, *changes.lock());
+}
+
+#[test]
+fn test_radio_theme() {
+    let mut ui = themed_ui();
+    let group = RadioGroupBuilder::new("group", "wcontainer", Position::Center)
+        .with_elevation(4.0)
+        .build(&mut ui)
+        .unwrap();
+    let button = RadioButtonBuilder::new("a", "group", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    themed(&*group);
+    themed(&*button);
+    assert_eq!((0.0, 4.0), group.shadow().offset());
+    assert!(!button.shadow().visible());
+}
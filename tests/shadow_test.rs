@@ -0,0 +1,37 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::shadow_vertices;
+
+#[test]
+fn test_shadow_elevation() {
+    let vertices = shadow_vertices(Some(4.0));
+    assert_eq!(6, vertices.len());
+    for vertex in vertices {
+        let (bl, tr) = vertex.bounds().unwrap();
+        assert_eq!([[-1.0, -1.0], [1.0, 1.0]], [bl.as_array(), tr.as_array()]);
+        assert_eq!((0.0, 4.0), vertex.offset());
+        assert_eq!(8.0, vertex.blur_radius());
+        assert!((vertex.color()[3] - (102.0 / 255.0)).abs() < 0.001);
+    }
+}
+
+#[test]
+fn test_shadow_none() {
+    assert!(shadow_vertices(None).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_shadow_negative_elevation() {
+    shadow_vertices(Some(-4.0));
+}
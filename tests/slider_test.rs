@@ -0,0 +1,188 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    Slider,
+    SliderBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn slider_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut SliderBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = SliderBuilder::new("slider", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 10.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode) -> bool {
+    ui.dispatch(InputEvent::KeyPressed {
+        key,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+fn mouse_pressed(x: f32, y: f32) -> InputEvent {
+    InputEvent::MousePressed {
+        x,
+        y,
+        button: MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    }
+}
+
+#[test]
+fn test_slider_drag() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        slider_ui(move |builder| {
+            builder.with_on_change(move |value| changes.lock().push(value));
+        })
+    };
+    assert_eq!(0.0, widget::<Slider>(&ui, "slider").value());
+
+    // The track starts after the padding and the radius of the thumb, 12 pixels in
+    assert!(ui.dispatch(mouse_pressed(400.0, 300.0)).unwrap());
+    assert_eq!(50.0, widget::<Slider>(&ui, "slider").value());
+    assert_eq!(Some(String::from("slider")), ui.focused());
+
+    assert!(ui.dispatch(InputEvent::CursorMoved { x: 212.0, y: 500.0 }).unwrap());
+    assert_eq!(0.0, widget::<Slider>(&ui, "slider").value());
+    assert!(ui.dispatch(InputEvent::CursorMoved { x: 790.0, y: 300.0 }).unwrap());
+    assert_eq!(100.0, widget::<Slider>(&ui, "slider").value());
+
+    let released = InputEvent::MouseReleased {
+        x:      790.0,
+        y:      300.0,
+        button: MouseButton::Left,
+    };
+    assert!(ui.dispatch(released).unwrap());
+    assert!(!ui.dispatch(InputEvent::CursorMoved { x: 400.0, y: 300.0 }).unwrap());
+    assert_eq!(vec![50.0, 0.0, 100.0], *changes.lock());
+}
+
+#[test]
+fn test_slider_vertical() {
+    let ui = slider_ui(|builder| {
+        builder
+            .with_orientation(Orientation::Left)
+            .with_size(Size::Size(10.0, 50.0))
+            .with_range(-1.0, 1.0)
+            .with_step(0.5);
+    });
+
+    // Vertical sliders grow upwards
+    assert!(ui.dispatch(mouse_pressed(400.0, 162.0)).unwrap());
+    assert_eq!(1.0, widget::<Slider>(&ui, "slider").value());
+    assert!(ui.dispatch(InputEvent::CursorMoved { x: 400.0, y: 280.0 }).unwrap());
+    assert_eq!(0.0, widget::<Slider>(&ui, "slider").value());
+}
+
+#[test]
+fn test_slider_keys() {
+    let ui = slider_ui(|builder| {
+        builder.with_value(10.0).with_step(2.0);
+    });
+    ui.set_focus(Some(String::from("slider")));
+
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert_eq!(12.0, widget::<Slider>(&ui, "slider").value());
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert_eq!(8.0, widget::<Slider>(&ui, "slider").value());
+    assert!(press(&ui, VirtualKeyCode::PageUp));
+    assert_eq!(28.0, widget::<Slider>(&ui, "slider").value());
+    assert!(press(&ui, VirtualKeyCode::End));
+    assert_eq!(100.0, widget::<Slider>(&ui, "slider").value());
+    assert!(press(&ui, VirtualKeyCode::Up));
+    assert_eq!(100.0, widget::<Slider>(&ui, "slider").value());
+    assert!(press(&ui, VirtualKeyCode::Home));
+    assert_eq!(0.0, widget::<Slider>(&ui, "slider").value());
+    assert!(!press(&ui, VirtualKeyCode::A));
+}
+
+#[test]
+fn test_slider_continuous() {
+    let ui = slider_ui(|builder| {
+        builder.with_range(0.0, 2.0).with_step(0.0).with_value(0.5);
+    });
+    ui.set_focus(Some(String::from("slider")));
+
+    // Without a step the arrows move by a hundredth of the range
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert_eq!(0.52, widget::<Slider>(&ui, "slider").value());
+}
+
+#[test]
+fn test_slider_snap() {
+    let ui = slider_ui(|builder| {
+        builder.with_step(5.0).with_value(12.0);
+    });
+    assert_eq!(10.0, widget::<Slider>(&ui, "slider").value());
+
+    let mut slider = widget::<Slider>(&ui, "slider");
+    slider.set_value(1000.0);
+    assert_eq!(100.0, slider.value());
+    slider.set_value(13.0);
+    assert_eq!(15.0, slider.value());
+}
+
+#[test]
+fn test_slider_disabled() {
+    let ui = slider_ui(|builder| {
+        builder.with_disabled(true);
+    });
+    assert!(!ui.dispatch(mouse_pressed(400.0, 300.0)).unwrap());
+    assert_eq!(None, ui.focused());
+
+    ui.set_focus(Some(String::from("slider")));
+    assert!(!press(&ui, VirtualKeyCode::Right));
+    assert_eq!(0.0, widget::<Slider>(&ui, "slider").value());
+}
+
+#[test]
+fn test_slider_theme() {
+    let mut ui = themed_ui();
+    let slider = SliderBuilder::new("slider", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(2.0, slider.border().width());
+    assert_eq!([3.0; 4], slider.corner_radius().as_array());
+    assert_eq!(Fill::parse("#11223344").unwrap(), slider.fill());
+    assert!(!slider.shadow().visible());
+
+    let slider = SliderBuilder::new("raised", "wcontainer", Position::Center)
+        .with_elevation(4.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 4.0), slider.shadow().offset());
+}
@@ -0,0 +1,189 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    SpinBox,
+    SpinBoxBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn spin_box_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut SpinBoxBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = SpinBoxBuilder::new("spin_box", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 10.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn type_text(ui: &Ui, text: &str) {
+    for c in text.chars() {
+        assert!(ui.dispatch(InputEvent::Character(c)).unwrap());
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap()
+}
+
+fn select_all(ui: &Ui) {
+    let ctrl = ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    };
+    assert!(press(ui, VirtualKeyCode::A, ctrl));
+}
+
+#[test]
+fn test_spin_box_typing() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        spin_box_ui(move |builder| {
+            builder.with_on_change(move |value| changes.lock().push(value));
+        })
+    };
+    ui.set_focus(Some(String::from("spin_box")));
+    assert_eq!("0", widget::<SpinBox>(&ui, "spin_box").text());
+
+    // Only the characters of a number are accepted, and the number counts once enter is pressed
+    select_all(&ui);
+    type_text(&ui, "4a-.2");
+    assert_eq!("42", widget::<SpinBox>(&ui, "spin_box").text());
+    assert_eq!(0.0, widget::<SpinBox>(&ui, "spin_box").value());
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert_eq!(42.0, widget::<SpinBox>(&ui, "spin_box").value());
+
+    // The number is kept within the range
+    type_text(&ui, "0");
+    assert!(press(&ui, VirtualKeyCode::NumpadEnter, ModifiersState::default()));
+    assert_eq!(100.0, widget::<SpinBox>(&ui, "spin_box").value());
+    assert_eq!("100", widget::<SpinBox>(&ui, "spin_box").text());
+    assert_eq!(vec![42.0, 100.0], *changes.lock());
+}
+
+#[test]
+fn test_spin_box_revert() {
+    let ui = spin_box_ui(|builder| {
+        builder.with_value(7.0);
+    });
+    ui.set_focus(Some(String::from("spin_box")));
+    assert!(!press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+
+    type_text(&ui, "5");
+    assert!(press(&ui, VirtualKeyCode::Escape, ModifiersState::default()));
+    assert_eq!("7", widget::<SpinBox>(&ui, "spin_box").text());
+
+    // A text that isn't a number yet is reverted when committed
+    select_all(&ui);
+    assert!(press(&ui, VirtualKeyCode::Back, ModifiersState::default()));
+    assert_eq!("", widget::<SpinBox>(&ui, "spin_box").text());
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert_eq!("7", widget::<SpinBox>(&ui, "spin_box").text());
+    assert_eq!(7.0, widget::<SpinBox>(&ui, "spin_box").value());
+}
+
+#[test]
+fn test_spin_box_decimals() {
+    let ui = spin_box_ui(|builder| {
+        builder.with_range(-5.0, 5.0).with_step(0.5).with_decimals(1);
+    });
+    ui.set_focus(Some(String::from("spin_box")));
+    assert_eq!("0.0", widget::<SpinBox>(&ui, "spin_box").text());
+
+    select_all(&ui);
+    type_text(&ui, "-1.25");
+    assert_eq!("-1.2", widget::<SpinBox>(&ui, "spin_box").text());
+    assert!(press(&ui, VirtualKeyCode::Up, ModifiersState::default()));
+    assert_eq!("-0.7", widget::<SpinBox>(&ui, "spin_box").text());
+    assert!(press(&ui, VirtualKeyCode::PageDown, ModifiersState::default()));
+    assert_eq!("-5.0", widget::<SpinBox>(&ui, "spin_box").text());
+    assert_eq!(-5.0, widget::<SpinBox>(&ui, "spin_box").value());
+}
+
+#[test]
+fn test_spin_box_paste() {
+    let ui = spin_box_ui(|_| ());
+    ui.set_focus(Some(String::from("spin_box")));
+    let ctrl = ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    };
+
+    select_all(&ui);
+    ui.set_clipboard("-3");
+    assert!(press(&ui, VirtualKeyCode::V, ctrl));
+    assert_eq!("0", widget::<SpinBox>(&ui, "spin_box").text());
+    ui.set_clipboard("31\n");
+    assert!(press(&ui, VirtualKeyCode::V, ctrl));
+    assert_eq!("31", widget::<SpinBox>(&ui, "spin_box").text());
+}
+
+#[test]
+fn test_spin_box_buttons() {
+    let ui = spin_box_ui(|builder| {
+        builder.with_value(10.0).with_step(5.0);
+    });
+
+    // The buttons take the last 20 pixels of the spin box, the increment one above
+    let click = |x, y| {
+        ui.dispatch(InputEvent::MousePressed {
+            x,
+            y,
+            button: MouseButton::Left,
+            modifiers: ModifiersState::default(),
+        })
+        .unwrap()
+    };
+    assert!(click(590.0, 280.0));
+    assert_eq!(15.0, widget::<SpinBox>(&ui, "spin_box").value());
+    assert_eq!(Some(String::from("spin_box")), ui.focused());
+    assert!(click(590.0, 320.0));
+    assert!(click(590.0, 320.0));
+    assert_eq!(5.0, widget::<SpinBox>(&ui, "spin_box").value());
+    assert!(click(400.0, 300.0));
+    assert_eq!(5.0, widget::<SpinBox>(&ui, "spin_box").value());
+}
+
+#[test]
+fn test_spin_box_theme() {
+    let mut ui = themed_ui();
+    let spin_box = SpinBoxBuilder::new("spin_box", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(2.0, spin_box.border().width());
+    assert_eq!([3.0; 4], spin_box.corner_radius().as_array());
+    assert!(!spin_box.shadow().visible());
+
+    let spin_box = SpinBoxBuilder::new("raised", "wcontainer", Position::Center)
+        .with_elevation(4.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 4.0), spin_box.shadow().offset());
+}
@@ -0,0 +1,282 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+    },
+    ContainerBuilder,
+    Splitter,
+    SplitterBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn splitter_ui<F>(panes: &[&str], configure: F) -> Ui
+where
+    F: FnOnce(&mut SplitterBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = SplitterBuilder::new("splitter", "wcontainer", Position::Center);
+    builder.with_size(Size::Full);
+    for pane in panes {
+        builder.with_pane(*pane);
+    }
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    for pane in panes {
+        ContainerBuilder::new(*pane, "splitter", Position::Center)
+            .with_size(Size::Full)
+            .build(&mut ui)
+            .unwrap();
+    }
+
+    ui
+}
+
+fn press(ui: &Ui, x: f32, y: f32) -> bool {
+    ui.dispatch(InputEvent::MousePressed {
+        x,
+        y,
+        button: MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+fn release(ui: &Ui, x: f32, y: f32) -> bool {
+    ui.dispatch(InputEvent::MouseReleased {
+        x,
+        y,
+        button: MouseButton::Left,
+    })
+    .unwrap()
+}
+
+fn hover(ui: &Ui, x: f32, y: f32) -> bool {
+    ui.dispatch(InputEvent::CursorMoved { x, y }).unwrap()
+}
+
+/// Press and release the left button twice at a position in pixels in the window
+fn double_click(ui: &Ui, x: f32, y: f32) {
+    for _ in 0..2 {
+        press(ui, x, y);
+        release(ui, x, y);
+    }
+}
+
+/// The bounds of a widget in pixels, left, top, right and bottom, in an 800x600 window
+fn pixels(ui: &Ui, id: &str) -> Option<[f32; 4]> {
+    ui.widgets()[id]
+        .lock()
+        .bounds(ui)
+        .unwrap()
+        .map(|(bl, tr)| [bl.x() * 400.0, bl.y() * 300.0, tr.x() * 400.0, tr.y() * 300.0])
+}
+
+fn assert_close(expected: &[f32], actual: &[f32]) {
+    assert_eq!(expected.len(), actual.len());
+    for (expected, actual) in expected.iter().zip(actual.iter()) {
+        assert!((expected - actual).abs() < 1e-2, "{:?} != {:?}", expected, actual);
+    }
+}
+
+#[test]
+fn test_splitter_layout() {
+    let ui = splitter_ui(&["left", "center", "right"], |builder| {
+        builder.with_ratios(vec![1.0, 2.0, 1.0]);
+    });
+
+    // The 788 pixels left by the two dividers are shared following the ratios
+    assert_close(&[0.25, 0.5, 0.25], &widget::<Splitter>(&ui, "splitter").ratios());
+    assert_close(
+        &[197.0, 394.0, 197.0],
+        &widget::<Splitter>(&ui, "splitter").pane_sizes(&ui).unwrap(),
+    );
+    assert_close(&[0.0, 0.0, 197.0, 600.0], &pixels(&ui, "left").unwrap());
+    assert_close(&[203.0, 0.0, 597.0, 600.0], &pixels(&ui, "center").unwrap());
+    assert_close(&[603.0, 0.0, 800.0, 600.0], &pixels(&ui, "right").unwrap());
+
+    // The fill and the two dividers are drawn
+    assert!(ui.widgets()["splitter"].lock().draw(&ui).unwrap().len() >= 18);
+}
+
+#[test]
+fn test_splitter_normalize_sizes() {
+    let ui = splitter_ui(&["left", "center", "right"], |builder| {
+        builder.with_ratios(vec![1.0, 2.0, 1.0]);
+    });
+
+    // The full panes are placed by the splitter, each fills the whole of its pane
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Size::Full, ui.widgets()["center"].lock().size());
+    assert_close(&[0.0, 0.0, 197.0, 600.0], &pixels(&ui, "left").unwrap());
+    assert_close(&[203.0, 0.0, 597.0, 600.0], &pixels(&ui, "center").unwrap());
+    assert_close(&[603.0, 0.0, 800.0, 600.0], &pixels(&ui, "right").unwrap());
+}
+
+#[test]
+fn test_splitter_drag() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        splitter_ui(&["left", "right"], move |builder| {
+            builder
+                .with_min_size("left", 100.0)
+                .with_max_size("left", 500.0)
+                .with_min_size("right", 150.0)
+                .with_on_change(move |ratios| changes.lock().push(ratios.to_vec()));
+        })
+    };
+
+    assert_close(
+        &[397.0, 397.0],
+        &widget::<Splitter>(&ui, "splitter").pane_sizes(&ui).unwrap(),
+    );
+
+    // Pressing a pane does not drag anything
+    assert!(!press(&ui, 200.0, 300.0));
+    release(&ui, 200.0, 300.0);
+
+    // The divider follows the cursor from where it was grabbed
+    assert!(press(&ui, 400.0, 300.0));
+    assert!(hover(&ui, 303.0, 100.0));
+    assert_close(
+        &[300.0, 494.0],
+        &widget::<Splitter>(&ui, "splitter").pane_sizes(&ui).unwrap(),
+    );
+    assert_close(&[0.0, 0.0, 300.0, 600.0], &pixels(&ui, "left").unwrap());
+    assert_close(&[306.0, 0.0, 800.0, 600.0], &pixels(&ui, "right").unwrap());
+    assert_eq!(1, changes.lock().len());
+    assert_close(&[300.0 / 794.0, 494.0 / 794.0], &changes.lock()[0]);
+
+    // The panes stay within their limits
+    hover(&ui, 50.0, 300.0);
+    assert_close(
+        &[100.0, 694.0],
+        &widget::<Splitter>(&ui, "splitter").pane_sizes(&ui).unwrap(),
+    );
+    hover(&ui, 790.0, 300.0);
+    assert_close(
+        &[500.0, 294.0],
+        &widget::<Splitter>(&ui, "splitter").pane_sizes(&ui).unwrap(),
+    );
+    assert_eq!(3, changes.lock().len());
+
+    // Releasing the button stops the drag
+    release(&ui, 790.0, 300.0);
+    hover(&ui, 400.0, 300.0);
+    assert_close(
+        &[500.0, 294.0],
+        &widget::<Splitter>(&ui, "splitter").pane_sizes(&ui).unwrap(),
+    );
+    assert_eq!(3, changes.lock().len());
+}
+
+#[test]
+fn test_splitter_collapse() {
+    let ui = splitter_ui(&["left", "right"], |_| {});
+
+    // Double clicking the divider collapses the pane before it, and restores it
+    double_click(&ui, 400.0, 300.0);
+    assert!(widget::<Splitter>(&ui, "splitter").collapsed("left"));
+    assert_close(&[0.0, 1.0], &widget::<Splitter>(&ui, "splitter").ratios());
+    assert_eq!(None, pixels(&ui, "left"));
+    assert_close(&[6.0, 0.0, 800.0, 600.0], &pixels(&ui, "right").unwrap());
+
+    double_click(&ui, 3.0, 300.0);
+    assert!(!widget::<Splitter>(&ui, "splitter").collapsed("left"));
+    assert_close(&[0.5, 0.5], &widget::<Splitter>(&ui, "splitter").ratios());
+
+    // The pane after the divider collapses when the one before cannot
+    ui.widgets()["splitter"]
+        .lock()
+        .downcast_mut::<Splitter>()
+        .unwrap()
+        .set_collapsible("left", false);
+    double_click(&ui, 400.0, 300.0);
+    assert!(widget::<Splitter>(&ui, "splitter").collapsed("right"));
+    assert_close(&[0.0, 0.0, 794.0, 600.0], &pixels(&ui, "left").unwrap());
+    assert_eq!(None, pixels(&ui, "right"));
+
+    // Dragging the divider restores the pane too
+    press(&ui, 797.0, 300.0);
+    hover(&ui, 597.0, 300.0);
+    release(&ui, 597.0, 300.0);
+    assert!(!widget::<Splitter>(&ui, "splitter").collapsed("right"));
+    assert_close(
+        &[594.0, 200.0],
+        &widget::<Splitter>(&ui, "splitter").pane_sizes(&ui).unwrap(),
+    );
+}
+
+#[test]
+fn test_splitter_ratios() {
+    let ui = splitter_ui(&["top", "bottom"], |builder| {
+        builder.with_orientation(Orientation::Left);
+    });
+
+    {
+        let widget = ui.widgets()["splitter"].clone();
+        let mut widget = widget.lock();
+        let splitter = widget.downcast_mut::<Splitter>().unwrap();
+
+        splitter.set_ratios(&[3.0, 1.0]);
+        assert_close(&[0.75, 0.25], &splitter.ratios());
+
+        // Ratios that do not fit the panes are ignored
+        splitter.set_ratios(&[1.0]);
+        splitter.set_ratios(&[1.0, -1.0]);
+        splitter.set_ratios(&[0.0, 0.0]);
+        assert_close(&[0.75, 0.25], &splitter.ratios());
+    }
+
+    // The panes are stacked from top to bottom
+    assert_close(&[0.0, 0.0, 800.0, 445.5], &pixels(&ui, "top").unwrap());
+    assert_close(&[0.0, 451.5, 800.0, 600.0], &pixels(&ui, "bottom").unwrap());
+
+    press(&ui, 400.0, 448.0);
+    hover(&ui, 400.0, 299.0);
+    release(&ui, 400.0, 299.0);
+    assert_close(&[0.5, 0.5], &widget::<Splitter>(&ui, "splitter").ratios());
+}
+
+#[test]
+fn test_splitter_builder() {
+    let mut ui = splitter_ui(&[], |_| {});
+
+    let mut builder = SplitterBuilder::new("invalid", "wcontainer", Position::Center);
+    builder.with_pane("a").with_pane("b");
+    assert!(builder.clone().with_ratios(vec![1.0]).build(&mut ui).is_err());
+    assert!(builder.clone().with_ratios(vec![0.0, 0.0]).build(&mut ui).is_err());
+    assert!(builder.clone().with_min_size("c", 10.0).build(&mut ui).is_err());
+    assert!(builder
+        .clone()
+        .with_min_size("a", 200.0)
+        .with_max_size("a", 100.0)
+        .build(&mut ui)
+        .is_err());
+    assert!(builder.clone().with_pane("a").build(&mut ui).is_err());
+    assert!(builder.clone().with_divider_width(0.0).build(&mut ui).is_err());
+
+    let splitter = builder.with_ratios(vec![2.0, 6.0]).build(&mut ui).unwrap();
+    assert_eq!(vec![String::from("a"), String::from("b")], splitter.panes());
+    assert_close(&[0.25, 0.75], &splitter.ratios());
+}
@@ -0,0 +1,50 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::svg_image;
+use o2tk::prelude::*;
+
+#[test]
+fn test_svg_size() {
+    let logo = Svg::from_path("assets/logo.svg").unwrap();
+    assert!((logo.width() / logo.height() - (6.277_077_7 / 2.369_418_1)).abs() < 0.001);
+}
+
+#[test]
+fn test_svg_rasterize() {
+    let logo = Svg::from_path("assets/textlogo.svg").unwrap();
+    let texture = logo.rasterize(256, 96).unwrap();
+    assert_eq!((256, 96), (texture.width(), texture.height()));
+    assert!(texture.pixels().chunks(4).any(|pixel| pixel[3] > 0));
+    assert!(logo.rasterize(0, 96).is_err());
+}
+
+#[test]
+fn test_svg_invalid() {
+    assert!(Svg::from_bytes(b"not an svg").is_err());
+    assert!(Svg::from_path("assets/missing.svg").is_err());
+}
+
+#[test]
+fn test_svg_image() {
+    let (vertices, texture) = svg_image(Fit::Contain);
+    assert_eq!(400, texture.width());
+    for vertex in vertices {
+        assert_eq!(Some(texture.id()), vertex.texture());
+    }
+}
+
+#[test]
+fn test_svg_image_stretch() {
+    let (_, texture) = svg_image(Fit::Stretch);
+    assert_eq!((400, 300), (texture.width(), texture.height()));
+}
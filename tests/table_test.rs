@@ -0,0 +1,327 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    Table,
+    TableBuilder,
+    TableColumn,
+};
+use parking_lot::Mutex;
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+
+fn table_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut TableBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = TableBuilder::new("table", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 50.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+/// A model of many rows counting the cells asked for
+#[derive(Default)]
+struct Counting {
+    asked: AtomicUsize,
+}
+
+impl TableModel for Counting {
+    fn len(&self) -> usize {
+        100_000
+    }
+
+    fn text(&self, row: usize, column: usize) -> String {
+        self.asked.fetch_add(1, Ordering::SeqCst);
+        format!("{}:{}", row, column)
+    }
+}
+
+fn data() -> Vec<Vec<String>> {
+    vec![vec!["b", "2", "x"], vec!["a", "10", "y"], vec!["c", "1", "z"]]
+        .into_iter()
+        .map(|row| row.into_iter().map(String::from).collect())
+        .collect()
+}
+
+/// Three columns, 150, 100 and 100 pixels wide, the last one not sortable
+fn columns(builder: &mut TableBuilder) -> &mut TableBuilder {
+    builder
+        .with_column(TableColumn::new("Name").with_width(150.0).with_min_width(50.0))
+        .with_column(TableColumn::new("Size"))
+        .with_column(TableColumn::new("Kind").with_sortable(false))
+}
+
+fn shift() -> ModifiersState {
+    ModifiersState {
+        shift: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn ctrl() -> ModifiersState {
+    ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap()
+}
+
+fn mouse_pressed(ui: &Ui, x: f32, y: f32, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::MousePressed {
+        x,
+        y,
+        button: MouseButton::Left,
+        modifiers,
+    })
+    .unwrap()
+}
+
+/// Click the header of a column, in the 24 pixels from the top of the table at 150 pixels
+fn click_header(ui: &Ui, column: usize) -> bool {
+    mouse_pressed(ui, [275.0, 400.0, 500.0][column], 160.0, ModifiersState::default())
+}
+
+/// Click a cell, the rows are 24 pixels high under the header
+fn click_cell(ui: &Ui, row: usize, column: usize, modifiers: ModifiersState) -> bool {
+    mouse_pressed(
+        ui,
+        [275.0, 400.0, 500.0][column],
+        174.0 + (row as f32 * 24.0) + 12.0,
+        modifiers,
+    )
+}
+
+fn shown(ui: &Ui) -> Vec<usize> {
+    let table = widget::<Table>(ui, "table");
+    table.draw(ui).unwrap();
+    table.rows()
+}
+
+#[test]
+fn test_table_virtualized() {
+    let model = Arc::new(Counting::default());
+    let ui = {
+        let model = model.clone();
+        table_ui(move |builder| {
+            columns(builder).with_model(model);
+        })
+    };
+
+    // Only the cells of the rows in the 276 pixels under the header are asked for
+    assert_eq!((0..12).collect::<Vec<_>>(), shown(&ui));
+    assert_eq!(36, model.asked.load(Ordering::SeqCst));
+
+    assert!(ui
+        .dispatch(InputEvent::MouseWheel {
+            x:       400.0,
+            y:       300.0,
+            delta_x: 0.0,
+            delta_y: -24.0,
+        })
+        .unwrap());
+    assert_eq!((1..13).collect::<Vec<_>>(), shown(&ui));
+    assert_eq!(39, model.asked.load(Ordering::SeqCst));
+
+    // The header stays in place
+    assert!(click_header(&ui, 0));
+    assert_eq!(Some((0, SortOrder::Ascending)), widget::<Table>(&ui, "table").sort());
+    assert!(widget::<Table>(&ui, "table").selected().is_empty());
+}
+
+#[test]
+fn test_table_sort() {
+    let ui = table_ui(|builder| {
+        columns(builder).with_rows(data());
+    });
+    assert_eq!(vec![0, 1, 2], shown(&ui));
+
+    assert!(click_header(&ui, 0));
+    assert_eq!(Some((0, SortOrder::Ascending)), widget::<Table>(&ui, "table").sort());
+    assert_eq!(vec![1, 0, 2], shown(&ui));
+    assert!(click_header(&ui, 0));
+    assert_eq!(Some((0, SortOrder::Descending)), widget::<Table>(&ui, "table").sort());
+    assert_eq!(vec![2, 0, 1], shown(&ui));
+
+    // The texts are compared without a comparator
+    assert!(click_header(&ui, 1));
+    assert_eq!(vec![2, 1, 0], shown(&ui));
+
+    assert!(click_header(&ui, 2));
+    assert_eq!(Some((1, SortOrder::Ascending)), widget::<Table>(&ui, "table").sort());
+
+    let mut table = widget::<Table>(&ui, "table");
+    table.set_sort(None);
+    table.draw(&ui).unwrap();
+    assert_eq!(vec![0, 1, 2], table.rows());
+}
+
+#[test]
+fn test_table_comparator() {
+    let rows = data();
+    let ui = table_ui(move |builder| {
+        let numbers: Vec<f32> = rows.iter().map(|row| row[1].parse().unwrap()).collect();
+        columns(builder)
+            .with_rows(rows)
+            .with_sort(1, SortOrder::Ascending)
+            .with_comparator(move |_, a, b| numbers[a].partial_cmp(&numbers[b]).unwrap());
+    });
+    assert_eq!(vec![2, 0, 1], shown(&ui));
+}
+
+#[test]
+fn test_table_resize() {
+    let ui = table_ui(|builder| {
+        columns(builder).with_rows(data());
+    });
+
+    // The edge between the first two headers is at 350 pixels
+    assert!(mouse_pressed(&ui, 352.0, 160.0, ModifiersState::default()));
+    assert!(ui.dispatch(InputEvent::CursorMoved { x: 420.0, y: 300.0 }).unwrap());
+    assert_eq!(220.0, widget::<Table>(&ui, "table").columns()[0].width());
+    assert!(ui.dispatch(InputEvent::CursorMoved { x: 210.0, y: 300.0 }).unwrap());
+    assert_eq!(50.0, widget::<Table>(&ui, "table").columns()[0].width());
+    assert_eq!(None, widget::<Table>(&ui, "table").sort());
+
+    let released = InputEvent::MouseReleased {
+        x:      210.0,
+        y:      300.0,
+        button: MouseButton::Left,
+    };
+    assert!(ui.dispatch(released).unwrap());
+    assert!(!ui.dispatch(InputEvent::CursorMoved { x: 400.0, y: 300.0 }).unwrap());
+    assert_eq!(50.0, widget::<Table>(&ui, "table").columns()[0].width());
+}
+
+#[test]
+fn test_table_cell_selection() {
+    let selections = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let selections = selections.clone();
+        table_ui(move |builder| {
+            columns(builder)
+                .with_rows(data())
+                .with_on_select(move |cells| selections.lock().push(cells.to_vec()));
+        })
+    };
+
+    assert!(click_cell(&ui, 1, 1, ModifiersState::default()));
+    assert_eq!(Some(String::from("table")), ui.focused());
+    assert_eq!(vec![(1, 1)], widget::<Table>(&ui, "table").selected());
+
+    assert!(press(&ui, VirtualKeyCode::Right, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Right, ModifiersState::default()));
+    assert_eq!(Some((1, 2)), widget::<Table>(&ui, "table").current());
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert_eq!(vec![(2, 2)], widget::<Table>(&ui, "table").selected());
+    assert!(press(&ui, VirtualKeyCode::Home, ctrl()));
+    assert_eq!(vec![(0, 2)], widget::<Table>(&ui, "table").selected());
+    assert!(press(&ui, VirtualKeyCode::Home, ModifiersState::default()));
+    assert_eq!(vec![(0, 0)], widget::<Table>(&ui, "table").selected());
+    assert_eq!(
+        vec![vec![(1, 1)], vec![(1, 2)], vec![(2, 2)], vec![(0, 2)], vec![(0, 0)]],
+        *selections.lock()
+    );
+
+    // The selected cells follow their rows once sorted
+    assert!(click_header(&ui, 0));
+    assert_eq!(vec![(0, 0)], widget::<Table>(&ui, "table").selected());
+    assert_eq!(Some((0, 0)), widget::<Table>(&ui, "table").current());
+    assert!(press(&ui, VirtualKeyCode::Down, ModifiersState::default()));
+    assert_eq!(vec![(2, 0)], widget::<Table>(&ui, "table").selected());
+}
+
+#[test]
+fn test_table_multiple_selection() {
+    let ui = table_ui(|builder| {
+        columns(builder)
+            .with_rows(data())
+            .with_selection_mode(SelectionMode::Multiple);
+    });
+
+    assert!(click_cell(&ui, 0, 0, ModifiersState::default()));
+    assert!(click_cell(&ui, 1, 1, shift()));
+    assert_eq!(
+        vec![(0, 0), (0, 1), (1, 0), (1, 1)],
+        widget::<Table>(&ui, "table").selected()
+    );
+    assert!(click_cell(&ui, 2, 2, ctrl()));
+    assert!(click_cell(&ui, 0, 1, ctrl()));
+    assert_eq!(
+        vec![(0, 0), (1, 0), (1, 1), (2, 2)],
+        widget::<Table>(&ui, "table").selected()
+    );
+
+    assert!(press(&ui, VirtualKeyCode::A, ctrl()));
+    assert_eq!(9, widget::<Table>(&ui, "table").selected().len());
+}
+
+#[test]
+fn test_table_activate() {
+    let activated = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let activated = activated.clone();
+        table_ui(move |builder| {
+            columns(builder)
+                .with_rows(data())
+                .with_sort(0, SortOrder::Descending)
+                .with_on_activate(move |row, column| activated.lock().push((row, column)));
+        })
+    };
+
+    // The callback is given the row of the model
+    assert!(click_cell(&ui, 0, 1, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Return, ModifiersState::default()));
+    assert!(click_cell(&ui, 2, 0, ModifiersState::default()));
+    assert!(click_cell(&ui, 2, 0, ModifiersState::default()));
+    assert_eq!(vec![(2, 1), (1, 0)], *activated.lock());
+}
+
+#[test]
+fn test_table_builder() {
+    let mut ui = table_ui(|builder| {
+        columns(builder);
+    });
+    let id = String::from("wcontainer");
+
+    let mut builder = TableBuilder::new("sorted", &id, Position::Center);
+    builder
+        .with_column(TableColumn::new("Name"))
+        .with_sort(1, SortOrder::Ascending);
+    assert!(builder.build(&mut ui).is_err());
+
+    let mut builder = TableBuilder::new("narrow", &id, Position::Center);
+    builder.with_column(TableColumn::new("Name").with_width(10.0).with_min_width(20.0));
+    assert!(builder.build(&mut ui).is_err());
+}
@@ -0,0 +1,282 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    ContainerBuilder,
+    Tabs,
+    TabsBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn tabs_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut TabsBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = TabsBuilder::new("tabs", "wcontainer", Position::Center);
+    builder
+        .with_size(Size::Size(50.0, 50.0))
+        .with_page("first", "First")
+        .with_page("second", "Second")
+        .with_page("third", "Third");
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    for page in &["first", "second", "third"] {
+        ContainerBuilder::new(*page, "tabs", Position::Center)
+            .build(&mut ui)
+            .unwrap();
+    }
+
+    ui
+}
+
+fn ctrl() -> ModifiersState {
+    ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn ctrl_shift() -> ModifiersState {
+    ModifiersState {
+        ctrl: true,
+        shift: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap()
+}
+
+/// Press a mouse button over the tabs, 48 pixels wide from 200 pixels and 32 pixels high from
+/// 150 pixels, at a distance from the left of the tabs
+fn click(ui: &Ui, x: f32, button: MouseButton) -> bool {
+    ui.dispatch(InputEvent::MousePressed {
+        x: 200.0 + x,
+        y: 166.0,
+        button,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+/// The bounds of a widget in pixels, as left, top, right and bottom
+fn bounds(ui: &Ui, id: &str) -> Option<[f32; 4]> {
+    ui.widgets()[id].lock().bounds(ui).unwrap().map(|(bl, tr)| {
+        [
+            (bl.x() * 800.0 / 2.0).round(),
+            (bl.y() * 600.0 / 2.0).round(),
+            (tr.x() * 800.0 / 2.0).round(),
+            (tr.y() * 600.0 / 2.0).round(),
+        ]
+    })
+}
+
+#[test]
+fn test_tabs_layout() {
+    let mut ui = tabs_ui(|_| {});
+    ContainerBuilder::new("inner", "second", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    ContainerBuilder::new("other", "tabs", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+
+    // Only the active page is laid out, below the tabs
+    assert_eq!(Some([200.0, 182.0, 600.0, 450.0]), bounds(&ui, "first"));
+    assert_eq!(None, bounds(&ui, "second"));
+    assert_eq!(None, bounds(&ui, "inner"));
+    assert_eq!(None, bounds(&ui, "other"));
+    assert_eq!(Some(String::from("first")), ui.hit_test(1.0, 1.0).unwrap());
+
+    {
+        let widget = &ui.widgets()["tabs"];
+        let mut widget = widget.lock();
+        let tabs = widget.downcast_mut::<Tabs>().unwrap();
+        tabs.set_active("second");
+        tabs.set_active("other");
+    }
+    assert_eq!(Some(String::from("second")), widget::<Tabs>(&ui, "tabs").active());
+    assert_eq!(None, bounds(&ui, "first"));
+    assert_eq!(Some([200.0, 182.0, 600.0, 450.0]), bounds(&ui, "inner"));
+}
+
+#[test]
+fn test_tabs_normalize_sizes() {
+    let ui = tabs_ui(|_| {});
+
+    // The pages are placed by the tabs, they don't split the room between them
+    ui.normalize_sizes().unwrap();
+    for page in &["first", "second", "third"] {
+        assert_eq!(Size::Full, ui.widgets()[*page].lock().size());
+    }
+    assert_eq!(Some([200.0, 182.0, 600.0, 450.0]), bounds(&ui, "first"));
+
+    ui.widgets()["tabs"]
+        .lock()
+        .downcast_mut::<Tabs>()
+        .unwrap()
+        .set_active("third");
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Some([200.0, 182.0, 600.0, 450.0]), bounds(&ui, "third"));
+}
+
+#[test]
+fn test_tabs_click() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        tabs_ui(move |builder| {
+            builder.with_on_change(move |id| changes.lock().push(String::from(id)));
+        })
+    };
+
+    assert!(click(&ui, 60.0, MouseButton::Left));
+    assert_eq!(Some(String::from("second")), widget::<Tabs>(&ui, "tabs").active());
+    assert_eq!(Some(String::from("tabs")), ui.focused());
+    assert!(click(&ui, 60.0, MouseButton::Left));
+    assert!(!click(&ui, 200.0, MouseButton::Left));
+    assert!(click(&ui, 100.0, MouseButton::Left));
+    assert_eq!(vec!["second", "third"], *changes.lock());
+
+    // The tabs can't be closed unless they are closable
+    assert!(!click(&ui, 110.0, MouseButton::Middle));
+    assert_eq!(3, widget::<Tabs>(&ui, "tabs").pages().len());
+}
+
+#[test]
+fn test_tabs_close() {
+    let closed = Arc::new(Mutex::new(Vec::new()));
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let closed = closed.clone();
+        let changes = changes.clone();
+        tabs_ui(move |builder| {
+            builder
+                .with_closable(true)
+                .with_on_close(move |id| closed.lock().push(String::from(id)))
+                .with_on_change(move |id| changes.lock().push(String::from(id)));
+        })
+    };
+
+    // The cross is at the right of the tab
+    assert!(click(&ui, 30.0, MouseButton::Left));
+    assert_eq!(vec!["second", "third"], widget::<Tabs>(&ui, "tabs").pages());
+    assert_eq!(Some(String::from("second")), widget::<Tabs>(&ui, "tabs").active());
+    assert_eq!(None, bounds(&ui, "first"));
+    assert_eq!(Some([200.0, 182.0, 600.0, 450.0]), bounds(&ui, "second"));
+
+    assert!(click(&ui, 60.0, MouseButton::Middle));
+    assert_eq!(vec!["second"], widget::<Tabs>(&ui, "tabs").pages());
+    assert_eq!(vec!["first", "third"], *closed.lock());
+    assert_eq!(vec!["second"], *changes.lock());
+
+    ui.set_focus(Some(String::from("tabs")));
+    assert!(press(&ui, VirtualKeyCode::W, ctrl()));
+    assert!(widget::<Tabs>(&ui, "tabs").pages().is_empty());
+    assert_eq!(None, widget::<Tabs>(&ui, "tabs").active());
+    assert_eq!(None, bounds(&ui, "second"));
+}
+
+#[test]
+fn test_tabs_reorder() {
+    let orders = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let orders = orders.clone();
+        tabs_ui(move |builder| {
+            builder.with_on_reorder(move |pages| orders.lock().push(pages.to_vec()));
+        })
+    };
+
+    assert!(click(&ui, 10.0, MouseButton::Left));
+    assert!(ui.dispatch(InputEvent::CursorMoved { x: 260.0, y: 170.0 }).unwrap());
+    assert_eq!(vec!["second", "first", "third"], widget::<Tabs>(&ui, "tabs").pages());
+    assert!(ui.dispatch(InputEvent::CursorMoved { x: 400.0, y: 300.0 }).unwrap());
+    assert_eq!(vec!["second", "third", "first"], widget::<Tabs>(&ui, "tabs").pages());
+    assert!(orders.lock().is_empty());
+
+    assert!(ui
+        .dispatch(InputEvent::MouseReleased {
+            x:      400.0,
+            y:      300.0,
+            button: MouseButton::Left,
+        })
+        .unwrap());
+    assert_eq!(vec![vec!["second", "third", "first"]], *orders.lock());
+    assert_eq!(Some(String::from("first")), widget::<Tabs>(&ui, "tabs").active());
+
+    // The cursor no longer drags the tab once released
+    assert!(!ui.dispatch(InputEvent::CursorMoved { x: 210.0, y: 166.0 }).unwrap());
+    assert_eq!(vec!["second", "third", "first"], widget::<Tabs>(&ui, "tabs").pages());
+}
+
+#[test]
+fn test_tabs_keys() {
+    let ui = tabs_ui(|_| {});
+
+    // The control key switches pages from inside a page, moving the focus out of the page hidden
+    ui.set_focus(Some(String::from("first")));
+    assert!(!press(&ui, VirtualKeyCode::Right, ModifiersState::default()));
+    assert!(press(&ui, VirtualKeyCode::Tab, ctrl()));
+    assert_eq!(Some(String::from("second")), widget::<Tabs>(&ui, "tabs").active());
+    assert_eq!(Some(String::from("tabs")), ui.focused());
+
+    assert!(press(&ui, VirtualKeyCode::Right, ModifiersState::default()));
+    assert_eq!(Some(String::from("third")), widget::<Tabs>(&ui, "tabs").active());
+    assert!(press(&ui, VirtualKeyCode::Right, ModifiersState::default()));
+    assert_eq!(Some(String::from("third")), widget::<Tabs>(&ui, "tabs").active());
+    assert!(press(&ui, VirtualKeyCode::Tab, ctrl()));
+    assert_eq!(Some(String::from("first")), widget::<Tabs>(&ui, "tabs").active());
+    assert!(press(&ui, VirtualKeyCode::Tab, ctrl_shift()));
+    assert_eq!(Some(String::from("third")), widget::<Tabs>(&ui, "tabs").active());
+    assert!(press(&ui, VirtualKeyCode::Home, ModifiersState::default()));
+    assert_eq!(Some(String::from("first")), widget::<Tabs>(&ui, "tabs").active());
+
+    // The tabs can't be closed unless they are closable
+    assert!(!press(&ui, VirtualKeyCode::W, ctrl()));
+}
+
+#[test]
+fn test_tabs_pages() {
+    let mut tabs = widget::<Tabs>(
+        &tabs_ui(|builder| {
+            builder.with_active("third");
+        }),
+        "tabs",
+    );
+    assert_eq!(Some(String::from("third")), tabs.active());
+    assert_eq!(Some(String::from("Second")), tabs.title("second"));
+
+    tabs.add_page("fourth", "Fourth");
+    tabs.add_page("second", "Renamed");
+    tabs.move_page("fourth", 0);
+    assert_eq!(vec!["fourth", "first", "second", "third"], tabs.pages());
+    assert_eq!(Some(String::from("Renamed")), tabs.title("second"));
+
+    tabs.close("third");
+    assert_eq!(Some(String::from("second")), tabs.active());
+    assert_eq!(None, tabs.title("third"));
+}
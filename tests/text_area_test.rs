@@ -0,0 +1,181 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        VirtualKeyCode,
+    },
+    TextArea,
+    TextAreaBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn text_area_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut TextAreaBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = TextAreaBuilder::new("area", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 10.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn type_text(ui: &Ui, text: &str) {
+    for c in text.chars() {
+        assert!(ui.dispatch(InputEvent::Character(c)).unwrap());
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) {
+    assert!(ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap());
+}
+
+fn ctrl() -> ModifiersState {
+    ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    }
+}
+
+#[test]
+fn test_text_area_new_lines() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        text_area_ui(move |builder| {
+            builder.with_on_change(move |text| changes.lock().push(text.to_string()));
+        })
+    };
+    ui.set_focus(Some(String::from("area")));
+
+    type_text(&ui, "ab");
+    press(&ui, VirtualKeyCode::Return, ModifiersState::default());
+    type_text(&ui, "cde");
+    assert_eq!("ab\ncde", widget::<TextArea>(&ui, "area").text());
+    assert_eq!((1, 3), widget::<TextArea>(&ui, "area").line_column());
+    assert_eq!(2, widget::<TextArea>(&ui, "area").edit().line_count());
+    assert_eq!(Some(&String::from("ab\n")), changes.lock().get(2));
+}
+
+#[test]
+fn test_text_area_line_keys() {
+    let ui = text_area_ui(|builder| {
+        builder.with_text("first\nsecond\nthird");
+    });
+    ui.set_focus(Some(String::from("area")));
+    assert_eq!((2, 5), widget::<TextArea>(&ui, "area").line_column());
+
+    press(&ui, VirtualKeyCode::Up, ModifiersState::default());
+    assert_eq!(1, widget::<TextArea>(&ui, "area").line_column().0);
+    press(&ui, VirtualKeyCode::End, ModifiersState::default());
+    assert_eq!((1, 6), widget::<TextArea>(&ui, "area").line_column());
+    press(&ui, VirtualKeyCode::Home, ModifiersState::default());
+    assert_eq!((1, 0), widget::<TextArea>(&ui, "area").line_column());
+    press(&ui, VirtualKeyCode::Up, ModifiersState::default());
+    press(&ui, VirtualKeyCode::Up, ModifiersState::default());
+    assert_eq!(0, widget::<TextArea>(&ui, "area").edit().caret());
+    // Moving past the last line goes to the end of the text
+    press(&ui, VirtualKeyCode::PageDown, ModifiersState::default());
+    assert_eq!((2, 5), widget::<TextArea>(&ui, "area").line_column());
+    press(&ui, VirtualKeyCode::Home, ctrl());
+    assert_eq!((0, 0), widget::<TextArea>(&ui, "area").line_column());
+}
+
+#[test]
+fn test_text_area_undo() {
+    let ui = text_area_ui(|builder| {
+        builder.with_text("text");
+    });
+    ui.set_focus(Some(String::from("area")));
+
+    type_text(&ui, " more");
+    press(&ui, VirtualKeyCode::Return, ModifiersState::default());
+    press(&ui, VirtualKeyCode::Back, ModifiersState::default());
+    press(&ui, VirtualKeyCode::Back, ModifiersState::default());
+    assert_eq!("text mor", widget::<TextArea>(&ui, "area").text());
+
+    press(&ui, VirtualKeyCode::Z, ctrl());
+    assert_eq!("text more\n", widget::<TextArea>(&ui, "area").text());
+    press(&ui, VirtualKeyCode::Z, ctrl());
+    assert_eq!("text", widget::<TextArea>(&ui, "area").text());
+    press(
+        &ui,
+        VirtualKeyCode::Z,
+        ModifiersState {
+            ctrl: true,
+            shift: true,
+            ..ModifiersState::default()
+        },
+    );
+    assert_eq!("text more\n", widget::<TextArea>(&ui, "area").text());
+
+    let mut area = widget::<TextArea>(&ui, "area");
+    assert!(area.undo());
+    assert_eq!("text", area.text());
+    assert!(!area.undo());
+}
+
+#[test]
+fn test_text_area_paste_keeps_new_lines() {
+    let ui = text_area_ui(|_| ());
+    ui.set_focus(Some(String::from("area")));
+    ui.set_clipboard("one\r\ntwo\u{7}");
+
+    press(&ui, VirtualKeyCode::V, ctrl());
+    assert_eq!("one\ntwo", widget::<TextArea>(&ui, "area").text());
+}
+
+#[test]
+fn test_text_area_scrolling() {
+    let text: Vec<String> = (0..40).map(|i| format!("line {}", i)).collect();
+    let ui = text_area_ui(|builder| {
+        builder.with_text(text.join("\n"));
+    });
+    ui.set_focus(Some(String::from("area")));
+    assert_eq!((0.0, 0.0), widget::<TextArea>(&ui, "area").scroll());
+
+    // The caret is scrolled into view at the end of the text
+    press(&ui, VirtualKeyCode::End, ctrl());
+    let bottom = widget::<TextArea>(&ui, "area").scroll().1;
+    assert!(bottom > 0.0);
+
+    let wheel = InputEvent::MouseWheel {
+        x:       400.0,
+        y:       300.0,
+        delta_x: 0.0,
+        delta_y: 40.0,
+    };
+    assert!(ui.dispatch(wheel).unwrap());
+    assert_eq!(bottom - 40.0, widget::<TextArea>(&ui, "area").scroll().1);
+
+    press(&ui, VirtualKeyCode::Home, ctrl());
+    assert_eq!(0.0, widget::<TextArea>(&ui, "area").scroll().1);
+    assert!(!ui
+        .dispatch(InputEvent::MouseWheel {
+            x:       400.0,
+            y:       300.0,
+            delta_x: 0.0,
+            delta_y: 40.0,
+        })
+        .unwrap());
+}
@@ -0,0 +1,283 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use o2tk::prelude::*;
+
+#[test]
+fn test_text_edit_insert_at_caret() {
+    let mut edit = TextEdit::new("helo");
+    edit.move_left(false);
+    assert!(edit.insert("l"));
+    assert_eq!("hello", edit.text());
+    assert_eq!(4, edit.caret());
+}
+
+#[test]
+fn test_text_edit_replace_selection() {
+    let mut edit = TextEdit::new("hello world");
+    edit.move_home(false);
+    edit.move_word_right(true);
+    assert_eq!("hello", edit.selected_text());
+    assert!(edit.insert("goodbye"));
+    assert_eq!("goodbye world", edit.text());
+    assert_eq!(None, edit.selection());
+}
+
+#[test]
+fn test_text_edit_move_collapses_selection() {
+    let mut edit = TextEdit::new("abcdef");
+    edit.set_caret(1, false);
+    edit.set_caret(4, true);
+    edit.move_left(false);
+    assert_eq!(1, edit.caret());
+    edit.set_caret(4, true);
+    edit.move_right(false);
+    assert_eq!(4, edit.caret());
+    assert_eq!(None, edit.selection());
+}
+
+#[test]
+fn test_text_edit_delete() {
+    let mut edit = TextEdit::new("abc");
+    assert!(edit.delete_backward());
+    assert_eq!("ab", edit.text());
+    assert!(!edit.delete_forward());
+    edit.move_home(false);
+    assert!(edit.delete_forward());
+    assert_eq!("b", edit.text());
+    assert!(!edit.delete_backward());
+}
+
+#[test]
+fn test_text_edit_words() {
+    let mut edit = TextEdit::new("let x = foo_bar(1);");
+    edit.move_word_left(false);
+    assert_eq!(17, edit.caret());
+    edit.move_word_left(false);
+    assert_eq!(16, edit.caret());
+    edit.move_word_left(false);
+    assert_eq!(15, edit.caret());
+    edit.move_word_left(false);
+    assert_eq!(8, edit.caret());
+    assert!(edit.delete_word_forward());
+    assert_eq!("let x = (1);", edit.text());
+    edit.move_end(false);
+    assert!(edit.delete_word_backward());
+    assert_eq!("let x = (1", edit.text());
+}
+
+#[test]
+fn test_text_edit_multibyte() {
+    let mut edit = TextEdit::new("añb");
+    edit.move_left(false);
+    edit.move_left(false);
+    assert_eq!(1, edit.caret());
+    edit.move_right(true);
+    assert_eq!("ñ", edit.selected_text());
+    edit.set_caret(2, false);
+    assert_eq!(1, edit.caret());
+}
+
+#[test]
+fn test_text_edit_max_length() {
+    let mut edit = TextEdit::new("abc");
+    edit.set_max_length(Some(5));
+    assert!(edit.insert("defgh"));
+    assert_eq!("abcde", edit.text());
+    assert!(!edit.insert("f"));
+    edit.select_all();
+    assert!(edit.insert("xyzxyzxyz"));
+    assert_eq!("xyzxy", edit.text());
+    edit.set_max_length(Some(2));
+    assert_eq!("xy", edit.text());
+}
+
+#[test]
+fn test_text_edit_max_length_graphemes() {
+    // The combining acute accent at the limit stays with its letter
+    let mut edit = TextEdit::new("");
+    edit.set_max_length(Some(2));
+    assert!(edit.insert("ae\u{301}b"));
+    assert_eq!("ae\u{301}", edit.text());
+    assert!(!edit.insert("c"));
+
+    edit.set_text("xe\u{301}\u{301}y");
+    assert_eq!("xe\u{301}\u{301}", edit.text());
+    edit.set_max_length(Some(1));
+    assert_eq!("x", edit.text());
+}
+
+#[test]
+fn test_text_edit_cut() {
+    let mut edit = TextEdit::new("copy paste");
+    assert_eq!(None, edit.cut());
+    edit.move_word_left(true);
+    assert_eq!(Some(String::from("paste")), edit.cut());
+    assert_eq!("copy ", edit.text());
+}
+
+#[test]
+fn test_text_edit_undo_groups_by_kind() {
+    let mut edit = TextEdit::new("");
+    assert!(!edit.can_undo());
+    edit.insert("a");
+    edit.insert("b");
+    edit.insert("c");
+    edit.delete_backward();
+    edit.delete_backward();
+    edit.paste("xyz");
+    edit.paste("!");
+    assert_eq!("axyz!", edit.text());
+
+    assert!(edit.undo());
+    assert_eq!("axyz", edit.text());
+    assert!(edit.undo());
+    assert_eq!("a", edit.text());
+    assert!(edit.undo());
+    assert_eq!("abc", edit.text());
+    assert!(edit.undo());
+    assert_eq!("", edit.text());
+    assert!(!edit.undo());
+
+    assert!(edit.redo());
+    assert_eq!("abc", edit.text());
+    assert_eq!(3, edit.caret());
+    edit.insert("d");
+    assert!(!edit.can_redo());
+    assert!(!edit.redo());
+}
+
+#[test]
+fn test_text_edit_moving_ends_undo_group() {
+    let mut edit = TextEdit::new("");
+    edit.insert("a");
+    edit.move_home(false);
+    edit.insert("b");
+    assert_eq!("ba", edit.text());
+    assert!(edit.undo());
+    assert_eq!("a", edit.text());
+    assert!(edit.undo());
+    assert_eq!("", edit.text());
+
+    edit.set_text("new");
+    assert!(!edit.can_undo());
+}
+
+#[test]
+fn test_text_edit_line_column() {
+    let edit = TextEdit::new("first\nsecond\n\nlast");
+    assert_eq!(4, edit.line_count());
+    assert_eq!((0, 0), edit.line_column(0));
+    assert_eq!((0, 5), edit.line_column(5));
+    assert_eq!((1, 0), edit.line_column(6));
+    assert_eq!((2, 0), edit.line_column(13));
+    assert_eq!((3, 4), edit.line_column(edit.text().len()));
+
+    assert_eq!(8, edit.position_of(1, 2));
+    assert_eq!(12, edit.position_of(1, 99));
+    assert_eq!(13, edit.position_of(2, 3));
+    assert_eq!(16, edit.position_of(9, 2));
+
+    let edit = TextEdit::new("e\u{301}t\u{e9}\ne\u{301}");
+    assert_eq!((0, 3), edit.line_column(6));
+    assert_eq!((1, 1), edit.line_column(edit.text().len()));
+    assert_eq!(3, edit.position_of(0, 1));
+    assert_eq!(7, edit.position_of(1, 0));
+}
+
+#[test]
+fn test_text_edit_grapheme_clusters() {
+    // An accent combined with its letter, a family joined into one emoji and two flags
+    let mut edit = TextEdit::new("e\u{301}👨\u{200D}👩\u{200D}👧🇫🇷🇩🇪");
+    edit.move_home(false);
+    edit.move_right(false);
+    assert_eq!(3, edit.caret());
+    edit.move_right(false);
+    assert_eq!(3 + 18, edit.caret());
+    edit.move_right(false);
+    assert_eq!(3 + 18 + 8, edit.caret());
+    edit.move_left(false);
+    assert_eq!(3 + 18, edit.caret());
+
+    assert!(edit.delete_backward());
+    assert_eq!("e\u{301}🇫🇷🇩🇪", edit.text());
+    assert!(edit.delete_forward());
+    assert_eq!("e\u{301}🇩🇪", edit.text());
+    edit.move_end(false);
+    assert!(edit.delete_backward());
+    assert_eq!("e\u{301}", edit.text());
+
+    // The caret never lands inside a cluster
+    edit.set_caret(2, false);
+    assert_eq!(0, edit.caret());
+}
+
+#[test]
+fn test_text_edit_grapheme_hangul_and_new_lines() {
+    let mut edit = TextEdit::new("\u{1100}\u{1161}\u{11A8}한\r\nx");
+    edit.move_home(false);
+    edit.move_right(false);
+    assert_eq!(9, edit.caret());
+    edit.move_right(false);
+    assert_eq!(12, edit.caret());
+    edit.move_right(false);
+    assert_eq!(14, edit.caret());
+    edit.move_word_left(false);
+    assert_eq!(0, edit.caret());
+}
+
+#[test]
+fn test_text_edit_grapheme_marks_jamo_and_emoji() {
+    // Two combining marks on a letter, a syllable with a trailing jamo and a woman technologist
+    // with a skin tone
+    let text = "a\u{301}\u{323}\u{AC00}\u{11A8}👩🏽\u{200D}💻x";
+    let mut edit = TextEdit::new(text);
+    edit.move_home(false);
+    for caret in [5, 11, 26, 27].iter() {
+        edit.move_right(false);
+        assert_eq!(*caret, edit.caret());
+    }
+    for caret in [26, 11, 5, 0].iter() {
+        edit.move_left(false);
+        assert_eq!(*caret, edit.caret());
+    }
+
+    edit.set_caret(26, false);
+    assert!(edit.delete_backward());
+    assert_eq!("a\u{301}\u{323}\u{AC00}\u{11A8}x", edit.text());
+    edit.move_home(false);
+    assert!(edit.delete_forward());
+    assert_eq!("\u{AC00}\u{11A8}x", edit.text());
+    edit.set_caret(4, false);
+    assert_eq!(0, edit.caret());
+}
+
+#[test]
+fn test_text_edit_composition() {
+    let mut edit = TextEdit::new("ab");
+    edit.move_left(false);
+    assert!(!edit.composing());
+
+    edit.set_composition("にほ", 4);
+    assert!(edit.composing());
+    assert_eq!("にほ", edit.composition());
+    assert_eq!(3, edit.composition_caret());
+    assert_eq!("ab", edit.text());
+
+    assert!(edit.commit("日本"));
+    assert!(!edit.composing());
+    assert_eq!("a日本b", edit.text());
+    assert_eq!(7, edit.caret());
+
+    edit.set_composition("x", 1);
+    edit.set_composition("", 0);
+    assert!(!edit.composing());
+    assert_eq!("a日本b", edit.text());
+}
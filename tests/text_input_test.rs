@@ -0,0 +1,230 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    TextInput,
+    TextInputBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn text_input_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut TextInputBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = TextInputBuilder::new("input", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 10.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn type_text(ui: &Ui, text: &str) {
+    for c in text.chars() {
+        assert!(ui.dispatch(InputEvent::Character(c)).unwrap());
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode, modifiers: ModifiersState) {
+    assert!(ui.dispatch(InputEvent::KeyPressed { key, modifiers }).unwrap());
+}
+
+fn ctrl() -> ModifiersState {
+    ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn shift() -> ModifiersState {
+    ModifiersState {
+        shift: true,
+        ..ModifiersState::default()
+    }
+}
+
+#[test]
+fn test_text_input_focus_on_click() {
+    let ui = text_input_ui(|_| ());
+    assert_eq!(None, ui.focused());
+    assert!(!ui.dispatch(InputEvent::Character('a')).unwrap());
+
+    let click = InputEvent::MousePressed {
+        x:         400.0,
+        y:         300.0,
+        button:    MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    };
+    assert!(ui.dispatch(click).unwrap());
+    assert_eq!(Some(String::from("input")), ui.focused());
+
+    let click = InputEvent::MousePressed {
+        x:         10.0,
+        y:         10.0,
+        button:    MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    };
+    ui.dispatch(click).unwrap();
+    assert_eq!(None, ui.focused());
+}
+
+#[test]
+fn test_text_input_typing() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let submitted = Arc::new(Mutex::new(String::new()));
+    let ui = {
+        let changes = changes.clone();
+        let submitted = submitted.clone();
+        text_input_ui(move |builder| {
+            builder
+                .with_on_change(move |text| changes.lock().push(text.to_string()))
+                .with_on_submit(move |text| *submitted.lock() = text.to_string());
+        })
+    };
+    ui.set_focus(Some(String::from("input")));
+
+    type_text(&ui, "hix");
+    press(&ui, VirtualKeyCode::Back, ModifiersState::default());
+    press(&ui, VirtualKeyCode::Return, ModifiersState::default());
+    // Control characters come along with the keys and are ignored
+    assert!(!ui.dispatch(InputEvent::Character('\u{8}')).unwrap());
+
+    assert_eq!("hi", widget::<TextInput>(&ui, "input").text());
+    assert_eq!(vec!["h", "hi", "hix", "hi"], *changes.lock());
+    assert_eq!("hi", *submitted.lock());
+}
+
+#[test]
+fn test_text_input_editing_keys() {
+    let ui = text_input_ui(|builder| {
+        builder.with_text("hello world");
+    });
+    ui.set_focus(Some(String::from("input")));
+
+    press(&ui, VirtualKeyCode::Back, ctrl());
+    assert_eq!("hello ", widget::<TextInput>(&ui, "input").text());
+    press(&ui, VirtualKeyCode::Home, ModifiersState::default());
+    press(&ui, VirtualKeyCode::Right, shift());
+    press(&ui, VirtualKeyCode::Delete, ModifiersState::default());
+    assert_eq!("ello ", widget::<TextInput>(&ui, "input").text());
+}
+
+#[test]
+fn test_text_input_clipboard() {
+    let ui = text_input_ui(|builder| {
+        builder.with_text("copy me");
+    });
+    ui.set_focus(Some(String::from("input")));
+
+    press(&ui, VirtualKeyCode::A, ctrl());
+    press(&ui, VirtualKeyCode::X, ctrl());
+    assert_eq!("copy me", ui.clipboard());
+    assert_eq!("", widget::<TextInput>(&ui, "input").text());
+
+    press(&ui, VirtualKeyCode::V, ctrl());
+    press(&ui, VirtualKeyCode::V, ctrl());
+    assert_eq!("copy mecopy me", widget::<TextInput>(&ui, "input").text());
+}
+
+#[test]
+fn test_text_input_password_is_not_copied() {
+    let ui = text_input_ui(|builder| {
+        builder.with_text("secret").with_password(true);
+    });
+    ui.set_focus(Some(String::from("input")));
+    ui.set_clipboard("unchanged");
+
+    press(&ui, VirtualKeyCode::A, ctrl());
+    press(&ui, VirtualKeyCode::C, ctrl());
+    press(&ui, VirtualKeyCode::X, ctrl());
+    assert_eq!("unchanged", ui.clipboard());
+    assert_eq!("secret", widget::<TextInput>(&ui, "input").text());
+}
+
+#[test]
+fn test_text_input_max_length() {
+    let ui = text_input_ui(|builder| {
+        builder.with_max_length(3).with_placeholder("abc");
+    });
+    ui.set_focus(Some(String::from("input")));
+
+    type_text(&ui, "abcdef");
+    assert_eq!("abc", widget::<TextInput>(&ui, "input").text());
+    assert_eq!("abc", widget::<TextInput>(&ui, "input").placeholder());
+}
+
+#[test]
+fn test_text_input_undo() {
+    let ui = text_input_ui(|builder| {
+        builder.with_text("start");
+    });
+    ui.set_focus(Some(String::from("input")));
+
+    type_text(&ui, " typed");
+    press(&ui, VirtualKeyCode::Back, ModifiersState::default());
+    assert_eq!("start type", widget::<TextInput>(&ui, "input").text());
+
+    press(&ui, VirtualKeyCode::Z, ctrl());
+    assert_eq!("start typed", widget::<TextInput>(&ui, "input").text());
+    press(&ui, VirtualKeyCode::Z, ctrl());
+    assert_eq!("start", widget::<TextInput>(&ui, "input").text());
+    press(&ui, VirtualKeyCode::Y, ctrl());
+    assert_eq!("start typed", widget::<TextInput>(&ui, "input").text());
+}
+
+#[test]
+fn test_text_input_composition() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        text_input_ui(move |builder| {
+            builder
+                .with_text("café")
+                .with_on_change(move |text| changes.lock().push(text.to_string()));
+        })
+    };
+    ui.set_focus(Some(String::from("input")));
+
+    let composition = InputEvent::Composition {
+        text:  String::from("にほん"),
+        caret: 9,
+    };
+    assert!(ui.dispatch(composition).unwrap());
+    assert_eq!("にほん", widget::<TextInput>(&ui, "input").edit().composition());
+    assert_eq!("café", widget::<TextInput>(&ui, "input").text());
+
+    // The keys go to the input method while it composes
+    press(&ui, VirtualKeyCode::Back, ModifiersState::default());
+    assert_eq!("café", widget::<TextInput>(&ui, "input").text());
+    assert!(changes.lock().is_empty());
+
+    assert!(ui.dispatch(InputEvent::Commit(String::from("日本"))).unwrap());
+    assert_eq!("café日本", widget::<TextInput>(&ui, "input").text());
+    assert!(!widget::<TextInput>(&ui, "input").edit().composing());
+    assert_eq!(vec![String::from("café日本")], *changes.lock());
+
+    press(&ui, VirtualKeyCode::Back, ModifiersState::default());
+    assert_eq!("café日", widget::<TextInput>(&ui, "input").text());
+}
@@ -0,0 +1,130 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    Toggle,
+    ToggleBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn toggle_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut ToggleBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = ToggleBuilder::new("toggle", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 10.0)).with_label("Toggle");
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn space(ui: &Ui) -> bool {
+    ui.dispatch(InputEvent::KeyPressed {
+        key:       VirtualKeyCode::Space,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_toggle_click() {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let changes = changes.clone();
+        toggle_ui(move |builder| {
+            builder.with_on_change(move |on| changes.lock().push(on));
+        })
+    };
+    assert!(!widget::<Toggle>(&ui, "toggle").on());
+
+    for _ in 0..2 {
+        let pressed = InputEvent::MousePressed {
+            x:         300.0,
+            y:         300.0,
+            button:    MouseButton::Left,
+            modifiers: ModifiersState::default(),
+        };
+        assert!(ui.dispatch(pressed).unwrap());
+        let released = InputEvent::MouseReleased {
+            x:      300.0,
+            y:      300.0,
+            button: MouseButton::Left,
+        };
+        assert!(ui.dispatch(released).unwrap());
+    }
+    assert_eq!(vec![true, false], *changes.lock());
+}
+
+#[test]
+fn test_toggle_space() {
+    let ui = toggle_ui(|builder| {
+        builder.with_on(true);
+    });
+    ui.set_focus(Some(String::from("toggle")));
+
+    assert!(space(&ui));
+    assert!(!widget::<Toggle>(&ui, "toggle").on());
+    assert!(space(&ui));
+    assert!(widget::<Toggle>(&ui, "toggle").on());
+}
+
+#[test]
+fn test_toggle_disabled() {
+    let ui = toggle_ui(|builder| {
+        builder.with_disabled(true);
+    });
+    ui.set_focus(Some(String::from("toggle")));
+    assert!(!space(&ui));
+    assert!(!widget::<Toggle>(&ui, "toggle").on());
+
+    // Setting the state from the application works while disabled
+    ui.widgets()["toggle"]
+        .lock()
+        .downcast_mut::<Toggle>()
+        .unwrap()
+        .set_on(true);
+    assert!(widget::<Toggle>(&ui, "toggle").on());
+}
+
+#[test]
+fn test_toggle_theme() {
+    let mut ui = themed_ui();
+    let toggle = ToggleBuilder::new("toggle", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!(2.0, toggle.border().width());
+    assert_eq!([3.0; 4], toggle.corner_radius().as_array());
+    assert_eq!(Fill::parse("#11223344").unwrap(), toggle.fill());
+    assert!(!toggle.shadow().visible());
+
+    let toggle = ToggleBuilder::new("raised", "wcontainer", Position::Center)
+        .with_elevation(2.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 2.0), toggle.shadow().offset());
+    assert_eq!(4.0, toggle.shadow().blur_radius());
+}
@@ -0,0 +1,242 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    themed_ui,
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+    },
+    Container,
+    ContainerBuilder,
+    Tooltip,
+    TooltipBuilder,
+};
+use std::{
+    thread::sleep,
+    time::Duration,
+};
+
+fn tooltip_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut TooltipBuilder),
+{
+    let mut ui = window_ui();
+
+    ContainerBuilder::new("target", "wcontainer", Position::TopLeft)
+        .with_size(Size::Size(25.0, 10.0))
+        .build(&mut ui)
+        .unwrap();
+    ContainerBuilder::new("inner", "target", Position::Center)
+        .with_size(Size::Full)
+        .build(&mut ui)
+        .unwrap();
+    ContainerBuilder::new("corner", "wcontainer", Position::BottomRight)
+        .with_size(Size::Size(25.0, 10.0))
+        .build(&mut ui)
+        .unwrap();
+
+    let mut builder = TooltipBuilder::new("tooltip", "wcontainer");
+    builder.with_text("Save the file").with_target("target");
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+fn hover(ui: &Ui, x: f32, y: f32) {
+    ui.dispatch(InputEvent::CursorMoved { x, y }).unwrap();
+}
+
+/// The bounds of a widget in pixels, left, top, right and bottom, in an 800x600 window
+fn pixels(ui: &Ui, id: &str) -> Option<[f32; 4]> {
+    ui.widgets()[id]
+        .lock()
+        .bounds(ui)
+        .unwrap()
+        .map(|(bl, tr)| [bl.x() * 400.0, bl.y() * 300.0, tr.x() * 400.0, tr.y() * 300.0])
+}
+
+fn assert_pixels(expected: [f32; 4], actual: Option<[f32; 4]>) {
+    let actual = actual.unwrap();
+    for (expected, actual) in expected.iter().zip(actual.iter()) {
+        assert!((expected - actual).abs() < 1e-2, "{:?} != {:?}", expected, actual);
+    }
+}
+
+#[test]
+fn test_tooltip_delay() {
+    let ui = tooltip_ui(|builder| {
+        builder.with_delay(Duration::from_millis(50));
+    });
+
+    assert_eq!(Some(String::from("tooltip")), ui.tooltip("target"));
+    assert_eq!(None, ui.tooltip("corner"));
+
+    // The tooltip waits for the delay, following the cursor until then
+    hover(&ui, 50.0, 20.0);
+    assert_eq!(None, ui.shown_tooltip());
+    assert_eq!(None, pixels(&ui, "tooltip"));
+    assert!(ui.widgets()["tooltip"].lock().draw(&ui).unwrap().is_empty());
+
+    hover(&ui, 100.0, 30.0);
+    sleep(Duration::from_millis(60));
+    assert_eq!(Some(String::from("tooltip")), ui.shown_tooltip());
+
+    // Without a font the tooltip is only as large as its padding and a line
+    assert_pixels([112.0, 50.0, 120.0, 74.0], pixels(&ui, "tooltip"));
+    assert_eq!(6, ui.widgets()["tooltip"].lock().draw(&ui).unwrap().len());
+
+    // Once shown the tooltip stays where it is while the cursor moves over the widget
+    hover(&ui, 150.0, 40.0);
+    assert_pixels([112.0, 50.0, 120.0, 74.0], pixels(&ui, "tooltip"));
+}
+
+#[test]
+fn test_tooltip_hide() {
+    let ui = tooltip_ui(|builder| {
+        builder.with_delay(Duration::from_millis(0));
+    });
+
+    // The children of the widget show its tooltip too
+    hover(&ui, 100.0, 30.0);
+    assert_eq!(Some(String::from("tooltip")), ui.shown_tooltip());
+
+    // Leaving the widget hides the tooltip
+    hover(&ui, 400.0, 300.0);
+    assert_eq!(None, ui.shown_tooltip());
+    assert_eq!(None, pixels(&ui, "tooltip"));
+
+    // Pressing a button hides it until the cursor leaves the widget
+    hover(&ui, 100.0, 30.0);
+    assert_eq!(Some(String::from("tooltip")), ui.shown_tooltip());
+    ui.dispatch(InputEvent::MousePressed {
+        x:         100.0,
+        y:         30.0,
+        button:    MouseButton::Left,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap();
+    assert_eq!(None, ui.shown_tooltip());
+    hover(&ui, 110.0, 30.0);
+    assert_eq!(None, ui.shown_tooltip());
+    hover(&ui, 400.0, 300.0);
+    hover(&ui, 110.0, 30.0);
+    assert_eq!(Some(String::from("tooltip")), ui.shown_tooltip());
+
+    // A detached tooltip is no longer shown
+    ui.set_tooltip("target", None);
+    hover(&ui, 120.0, 30.0);
+    assert_eq!(None, ui.shown_tooltip());
+}
+
+#[test]
+fn test_tooltip_window_edges() {
+    let ui = tooltip_ui(|builder| {
+        builder
+            .with_delay(Duration::from_millis(0))
+            .with_content_size(100.0, 40.0)
+            .with_target("corner");
+    });
+
+    assert_eq!(Some(String::from("tooltip")), ui.tooltip("corner"));
+
+    // Below and to the right of the cursor away from the edges
+    hover(&ui, 100.0, 30.0);
+    assert_pixels([112.0, 50.0, 212.0, 90.0], pixels(&ui, "tooltip"));
+
+    // Moved left at the right edge and above the cursor at the bottom edge
+    hover(&ui, 790.0, 590.0);
+    assert_pixels([700.0, 546.0, 800.0, 586.0], pixels(&ui, "tooltip"));
+}
+
+#[test]
+fn test_tooltip_children() {
+    let mut ui = tooltip_ui(|builder| {
+        builder
+            .with_delay(Duration::from_millis(0))
+            .with_content_size(100.0, 40.0);
+    });
+    ContainerBuilder::new("content", "tooltip", Position::Center)
+        .with_size(Size::Full)
+        .build(&mut ui)
+        .unwrap();
+
+    assert_eq!(None, pixels(&ui, "content"));
+
+    // The children are laid out over the tooltip, and keep it shown under the cursor
+    hover(&ui, 100.0, 30.0);
+    assert_pixels([112.0, 50.0, 212.0, 90.0], pixels(&ui, "content"));
+    hover(&ui, 150.0, 70.0);
+    assert_eq!(Some(String::from("tooltip")), ui.shown_tooltip());
+    assert_pixels([112.0, 50.0, 212.0, 90.0], pixels(&ui, "content"));
+
+    assert_eq!(Some((100.0, 40.0)), widget::<Tooltip>(&ui, "tooltip").content_size());
+}
+
+#[test]
+fn test_tooltip_normalize_sizes() {
+    let mut ui = tooltip_ui(|_| {});
+    ContainerBuilder::new("content", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+
+    // The tooltip is an overlay, it leaves the whole center to the container
+    ui.normalize_sizes().unwrap();
+    assert_eq!(Size::Size(100.0, 100.0), widget::<Container>(&ui, "content").size());
+    assert_eq!(Size::Full, widget::<Tooltip>(&ui, "tooltip").size());
+}
+
+#[test]
+fn test_tooltip_shadow() {
+    let ui = tooltip_ui(|builder| {
+        builder.with_delay(Duration::from_millis(0)).with_elevation(4.0);
+    });
+    assert_eq!((0.0, 4.0), widget::<Tooltip>(&ui, "tooltip").shadow().offset());
+    assert!(ui.widgets()["tooltip"].lock().draw_shadow(&ui).unwrap().is_empty());
+
+    // Only the bubble casts a shadow
+    hover(&ui, 100.0, 30.0);
+    let vertices = ui.widgets()["tooltip"].lock().draw_shadow(&ui).unwrap();
+    assert!(!vertices.is_empty());
+    for vertex in vertices {
+        let (bl, tr) = vertex.bounds().unwrap();
+        assert_pixels(
+            [112.0, 50.0, 120.0, 74.0],
+            Some([bl.x() * 400.0, bl.y() * 300.0, tr.x() * 400.0, tr.y() * 300.0]),
+        );
+        assert_eq!((0.0, 4.0), vertex.offset());
+    }
+}
+
+#[test]
+fn test_tooltip_theme() {
+    let mut ui = themed_ui();
+    let tooltip = TooltipBuilder::new("tooltip", "wcontainer").build(&mut ui).unwrap();
+    let shadow = tooltip.shadow();
+    assert_eq!((1.0, 2.0), shadow.offset());
+    assert_eq!(3.0, shadow.blur_radius());
+    assert_eq!(Color::from_hex("#11223344").unwrap(), shadow.color());
+
+    // The elevation keeps the color of the theme
+    let tooltip = TooltipBuilder::new("raised", "wcontainer")
+        .with_elevation(8.0)
+        .build(&mut ui)
+        .unwrap();
+    assert_eq!((0.0, 8.0), tooltip.shadow().offset());
+    assert_eq!(Color::from_hex("#11223344").unwrap(), tooltip.shadow().color());
+}
@@ -0,0 +1,288 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::{
+    widget,
+    window_ui,
+};
+use o2tk::{
+    prelude::*,
+    window::{
+        ModifiersState,
+        MouseButton,
+        VirtualKeyCode,
+    },
+    TreeNode,
+    TreeView,
+    TreeViewBuilder,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+fn tree_view_ui<F>(configure: F) -> Ui
+where
+    F: FnOnce(&mut TreeViewBuilder),
+{
+    let mut ui = window_ui();
+
+    let mut builder = TreeViewBuilder::new("tree_view", "wcontainer", Position::Center);
+    builder.with_size(Size::Size(50.0, 50.0));
+    configure(&mut builder);
+    builder.build(&mut ui).unwrap();
+
+    ui
+}
+
+/// A model of three levels of a hundred nodes each, recording the nodes whose children are asked
+#[derive(Default)]
+struct Lazy {
+    asked: Mutex<Vec<Vec<usize>>>,
+}
+
+impl TreeModel for Lazy {
+    fn children(&self, path: &[usize]) -> usize {
+        self.asked.lock().push(path.to_vec());
+        if path.len() < 3 {
+            100
+        } else {
+            0
+        }
+    }
+
+    fn has_children(&self, path: &[usize]) -> bool {
+        path.len() < 3
+    }
+
+    fn text(&self, path: &[usize]) -> String {
+        format!("Node {:?}", path)
+    }
+}
+
+/// A, with the children A1 and A2, A2 with the child A2a, and B
+fn nodes() -> Vec<TreeNode> {
+    vec![
+        TreeNode::new("A")
+            .with_child(TreeNode::new("A1"))
+            .with_child(TreeNode::new("A2").with_child(TreeNode::new("A2a"))),
+        TreeNode::new("B"),
+    ]
+}
+
+fn shift() -> ModifiersState {
+    ModifiersState {
+        shift: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn ctrl() -> ModifiersState {
+    ModifiersState {
+        ctrl: true,
+        ..ModifiersState::default()
+    }
+}
+
+fn press(ui: &Ui, key: VirtualKeyCode) -> bool {
+    ui.dispatch(InputEvent::KeyPressed {
+        key,
+        modifiers: ModifiersState::default(),
+    })
+    .unwrap()
+}
+
+/// Click a row of the tree view, whose rows are 24 pixels high from 150 pixels, at a distance
+/// from its left edge at 200 pixels
+fn click_at(ui: &Ui, row: usize, x: f32, modifiers: ModifiersState) -> bool {
+    ui.dispatch(InputEvent::MousePressed {
+        x: 200.0 + x,
+        y: 150.0 + (row as f32 * 24.0) + 12.0,
+        button: MouseButton::Left,
+        modifiers,
+    })
+    .unwrap()
+}
+
+fn click(ui: &Ui, row: usize, modifiers: ModifiersState) -> bool {
+    click_at(ui, row, 200.0, modifiers)
+}
+
+/// Click the arrow of a node at a depth, the levels being indented by 16 pixels
+fn toggle(ui: &Ui, row: usize, depth: usize) -> bool {
+    click_at(ui, row, (depth as f32 * 16.0) + 8.0, ModifiersState::default())
+}
+
+#[test]
+fn test_tree_view_lazy() {
+    let model = Arc::new(Lazy::default());
+    let expands = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let model = model.clone();
+        let expands = expands.clone();
+        tree_view_ui(move |builder| {
+            builder
+                .with_model(model)
+                .with_on_expand(move |path, expanded| expands.lock().push((path.to_vec(), expanded)));
+        })
+    };
+
+    // Only the children of the top level are asked until a node is expanded
+    assert_eq!(vec![Vec::<usize>::new()], *model.asked.lock());
+    let shown = widget::<TreeView>(&ui, "tree_view");
+    assert_eq!(100, shown.nodes().len());
+    shown.draw(&ui).unwrap();
+    assert_eq!((0..13).map(|node| vec![node]).collect::<Vec<_>>(), shown.rows());
+
+    assert!(toggle(&ui, 0, 0));
+    assert!(widget::<TreeView>(&ui, "tree_view").expanded(&[0]));
+    assert_eq!(vec![vec![], vec![0]], *model.asked.lock());
+    let shown = widget::<TreeView>(&ui, "tree_view");
+    assert_eq!(200, shown.nodes().len());
+    shown.draw(&ui).unwrap();
+    assert_eq!(vec![vec![0], vec![0, 0], vec![0, 1]], shown.rows()[..3].to_vec());
+
+    // The children are only asked once
+    assert!(toggle(&ui, 0, 0));
+    assert!(toggle(&ui, 0, 0));
+    assert_eq!(2, model.asked.lock().len());
+    assert_eq!(
+        vec![(vec![0], true), (vec![0], false), (vec![0], true)],
+        *expands.lock()
+    );
+
+    let mut shown = widget::<TreeView>(&ui, "tree_view");
+    shown.refresh();
+    assert_eq!(4, model.asked.lock().len());
+
+    // Expanding a node programmatically expands its parents
+    shown.set_expanded(&[1, 2], true);
+    assert!(shown.expanded(&[1]));
+    assert_eq!(400, shown.nodes().len());
+    assert_eq!(3, expands.lock().len());
+}
+
+#[test]
+fn test_tree_view_keys() {
+    let activated = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let activated = activated.clone();
+        tree_view_ui(move |builder| {
+            builder
+                .with_nodes(nodes())
+                .with_on_activate(move |path| activated.lock().push(path.to_vec()));
+        })
+    };
+    ui.set_focus(Some(String::from("tree_view")));
+
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert_eq!(vec![vec![0]], widget::<TreeView>(&ui, "tree_view").selected());
+
+    // Right expands the current node, then moves to its first child
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert!(widget::<TreeView>(&ui, "tree_view").expanded(&[0]));
+    assert_eq!(Some(vec![0]), widget::<TreeView>(&ui, "tree_view").current());
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert_eq!(Some(vec![0, 0]), widget::<TreeView>(&ui, "tree_view").current());
+    assert!(!press(&ui, VirtualKeyCode::Right));
+    assert!(press(&ui, VirtualKeyCode::Down));
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert!(press(&ui, VirtualKeyCode::Right));
+    assert_eq!(vec![vec![0, 1, 0]], widget::<TreeView>(&ui, "tree_view").selected());
+    assert_eq!(
+        vec![vec![0], vec![0, 0], vec![0, 1], vec![0, 1, 0], vec![1]],
+        widget::<TreeView>(&ui, "tree_view").nodes()
+    );
+
+    // Left moves to the parent, then collapses it
+    assert!(press(&ui, VirtualKeyCode::Left));
+    assert_eq!(vec![vec![0, 1]], widget::<TreeView>(&ui, "tree_view").selected());
+    assert!(press(&ui, VirtualKeyCode::Left));
+    assert!(!widget::<TreeView>(&ui, "tree_view").expanded(&[0, 1]));
+    assert!(press(&ui, VirtualKeyCode::Left));
+    assert!(press(&ui, VirtualKeyCode::Left));
+    assert_eq!(vec![vec![0], vec![1]], widget::<TreeView>(&ui, "tree_view").nodes());
+    assert!(!press(&ui, VirtualKeyCode::Left));
+
+    assert!(press(&ui, VirtualKeyCode::End));
+    assert!(press(&ui, VirtualKeyCode::Return));
+    assert_eq!(vec![vec![1]], *activated.lock());
+}
+
+#[test]
+fn test_tree_view_collapse_current() {
+    let ui = tree_view_ui(|builder| {
+        builder.with_nodes(nodes()).with_expanded(&[0, 1]);
+    });
+    assert!(widget::<TreeView>(&ui, "tree_view").expanded(&[0]));
+
+    assert!(click(&ui, 3, ModifiersState::default()));
+    assert_eq!(Some(vec![0, 1, 0]), widget::<TreeView>(&ui, "tree_view").current());
+
+    // Collapsing a parent of the current node moves to the parent, keeping the selection
+    assert!(toggle(&ui, 0, 0));
+    assert_eq!(Some(vec![0]), widget::<TreeView>(&ui, "tree_view").current());
+    assert_eq!(vec![vec![0, 1, 0]], widget::<TreeView>(&ui, "tree_view").selected());
+
+    // Clicking the arrow of a node without children selects it
+    assert!(toggle(&ui, 1, 0));
+    assert_eq!(vec![vec![1]], widget::<TreeView>(&ui, "tree_view").selected());
+}
+
+#[test]
+fn test_tree_view_selection() {
+    let selections = Arc::new(Mutex::new(Vec::new()));
+    let ui = {
+        let selections = selections.clone();
+        tree_view_ui(move |builder| {
+            builder
+                .with_nodes(nodes())
+                .with_expanded(&[0, 1])
+                .with_selection_mode(SelectionMode::Multiple)
+                .with_on_select(move |paths| selections.lock().push(paths.to_vec()));
+        })
+    };
+
+    assert!(click(&ui, 1, ModifiersState::default()));
+    assert!(click(&ui, 4, shift()));
+    assert_eq!(
+        vec![vec![0, 0], vec![0, 1], vec![0, 1, 0], vec![1]],
+        widget::<TreeView>(&ui, "tree_view").selected()
+    );
+    assert!(click(&ui, 2, ctrl()));
+    assert_eq!(
+        vec![vec![0, 0], vec![0, 1, 0], vec![1]],
+        widget::<TreeView>(&ui, "tree_view").selected()
+    );
+
+    // Expanding or collapsing a node doesn't change the selection
+    assert!(toggle(&ui, 2, 1));
+    assert!(toggle(&ui, 2, 1));
+    assert_eq!(3, selections.lock().len());
+    assert_eq!(vec![vec![0, 0]], selections.lock()[0]);
+}
+
+#[test]
+fn test_tree_view_model() {
+    let ui = tree_view_ui(|builder| {
+        builder.with_nodes(nodes()).with_selected(&[vec![1], vec![0, 0]]);
+    });
+
+    // Only the first of the nodes shown is selected
+    assert_eq!(vec![vec![1]], widget::<TreeView>(&ui, "tree_view").selected());
+
+    let mut tree_view = widget::<TreeView>(&ui, "tree_view");
+    tree_view.set_expanded(&[0], true);
+    tree_view.set_model(Arc::new(vec![TreeNode::new("Only")]));
+    assert!(tree_view.selected().is_empty());
+    assert!(!tree_view.expanded(&[0]));
+    tree_view.draw(&ui).unwrap();
+    assert_eq!(vec![vec![0]], tree_view.rows());
+}
@@ -13,8 +13,10 @@
 use o2tk::{
     prelude::*,
     window::dpi::LogicalSize,
+    CanvasBuilder,
     ContainerBuilder,
     DockBuilder,
+    ImageBuilder,
     ToolbarBuilder,
     WindowContainerBuilder,
 };
@@ -67,6 +69,32 @@ pub fn compare(expected: Vec<[f32; 2]>, actual: Vec<[f32; 2]>) {
     }
 }
 
+/// A ui with a window of 800x600 named "wcontainer"
+pub fn window_ui() -> Ui {
+    with_window(Ui::init(APP_ID).unwrap())
+}
+
+/// A window of 800x600 in a ui themed by tests/theme.toml
+pub fn themed_ui() -> Ui {
+    with_window(Ui::init_with_theme(APP_ID, concat!(env!("CARGO_MANIFEST_DIR"), "/tests/theme.toml")).unwrap())
+}
+
+fn with_window(mut ui: Ui) -> Ui {
+    WindowContainerBuilder::new("wcontainer")
+        .with_title("O2TK Demo")
+        .with_dimensions(LogicalSize::new(800.0, 600.0))
+        .with_min_dimensions(LogicalSize::new(800.0, 600.0))
+        .build(&mut ui)
+        .unwrap();
+
+    ui
+}
+
+/// Retrieve a copy of the widget of a type with an id
+pub fn widget<T: WidgetTrait + Clone>(ui: &Ui, id: &str) -> T {
+    ui.widgets()[id].lock().downcast_ref::<T>().unwrap().clone()
+}
+
 pub fn bar_vertices(orientation: Orientation, size: DockSize) -> Vec<[f32; 2]> {
     let mut ui = Ui::init(APP_ID).unwrap();
 
@@ -135,3 +163,161 @@ pub fn dock_vertices(length: f32, orientation: Orientation, size: DockSize) -> V
     }
     to_return
 }
+
+pub fn clip_vertices(overflow: Overflow) -> Option<[[f32; 2]; 2]> {
+    let mut ui = window_ui();
+
+    let container = ContainerBuilder::new("container", "wcontainer", Position::TopLeft)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_overflow(overflow)
+        .build(&mut ui)
+        .unwrap();
+
+    let child = ContainerBuilder::new("child", &container.id(), Position::Center)
+        .build(&mut ui)
+        .unwrap();
+
+    child.clip(&ui).unwrap().map(|(bl, tr)| [bl.as_array(), tr.as_array()])
+}
+
+pub fn opacity_alpha(parent_opacity: f32, child_opacity: f32) -> Vec<f32> {
+    let mut ui = window_ui();
+
+    let container = ContainerBuilder::new("container", "wcontainer", Position::Center)
+        .with_color("#123456FF")
+        .with_opacity(parent_opacity)
+        .build(&mut ui)
+        .unwrap();
+
+    let child = ContainerBuilder::new("child", &container.id(), Position::Center)
+        .with_color("#654321FF")
+        .with_opacity(child_opacity)
+        .build(&mut ui)
+        .unwrap();
+
+    let mut to_return = Vec::new();
+    for vertex in child.draw(&ui).unwrap() {
+        to_return.push(vertex.color()[3]);
+    }
+    to_return
+}
+
+pub fn layered_ui() -> Ui {
+    let mut ui = window_ui();
+
+    let popup = ContainerBuilder::new("popup", "wcontainer", Position::Center)
+        .with_size(Size::Size(25.0, 25.0))
+        .with_layer(Layer::Overlay)
+        .build(&mut ui)
+        .unwrap();
+
+    let _popup_child = ContainerBuilder::new("popup_child", &popup.id(), Position::Center)
+        .build(&mut ui)
+        .unwrap();
+
+    let front = ContainerBuilder::new("front", "wcontainer", Position::Center)
+        .with_z_index(1)
+        .build(&mut ui)
+        .unwrap();
+
+    let _front_child = ContainerBuilder::new("front_child", &front.id(), Position::TopLeft)
+        .with_size(Size::Size(10.0, 10.0))
+        .build(&mut ui)
+        .unwrap();
+
+    let _back = ContainerBuilder::new("back", "wcontainer", Position::Center)
+        .build(&mut ui)
+        .unwrap();
+
+    ui
+}
+
+pub fn border_vertices(border_width: f32, corner_radius: [f32; 4]) -> Vec<DrawVertex> {
+    let mut ui = window_ui();
+
+    let container = ContainerBuilder::new("container", "wcontainer", Position::Center)
+        .with_border_color("#FFFFFFFF")
+        .with_border_width(border_width)
+        .with_corner_radii(corner_radius[0], corner_radius[1], corner_radius[2], corner_radius[3])
+        .build(&mut ui)
+        .unwrap();
+
+    container.draw(&ui).unwrap()
+}
+
+pub fn fill_vertices(fill: &str) -> Vec<DrawVertex> {
+    let mut ui = window_ui();
+
+    let container = ContainerBuilder::new("container", "wcontainer", Position::Center)
+        .with_color(fill)
+        .build(&mut ui)
+        .unwrap();
+
+    container.draw(&ui).unwrap()
+}
+
+pub fn shadow_vertices(elevation: Option<f32>) -> Vec<DrawVertex> {
+    let mut ui = window_ui();
+
+    let mut builder = ContainerBuilder::new("container", "wcontainer", Position::Center);
+    if let Some(val) = elevation {
+        builder.with_elevation(val);
+    }
+    let container = builder.build(&mut ui).unwrap();
+
+    container.draw_shadow(&ui).unwrap()
+}
+
+pub fn image_vertices(fit: Fit) -> Vec<DrawVertex> {
+    let mut ui = window_ui();
+
+    let image = ImageBuilder::new("image", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_texture(Texture::new(200, 100, vec![255; 200 * 100 * 4]).unwrap())
+        .with_fit(fit)
+        .build(&mut ui)
+        .unwrap();
+
+    image.draw(&ui).unwrap()
+}
+
+pub fn svg_image(fit: Fit) -> (Vec<DrawVertex>, Texture) {
+    let mut ui = window_ui();
+
+    let image = ImageBuilder::new("image", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_path("assets/logo.svg")
+        .with_fit(fit)
+        .build(&mut ui)
+        .unwrap();
+
+    let vertices = image.draw(&ui).unwrap();
+    (vertices, image.texture().unwrap())
+}
+
+pub fn nine_slice_container(insets: [u32; 4]) -> (Vec<DrawVertex>, Option<Texture>) {
+    let mut ui = window_ui();
+
+    let container = ContainerBuilder::new("container", "wcontainer", Position::Center)
+        .with_background_texture(Texture::new(160, 120, vec![255; 160 * 120 * 4]).unwrap())
+        .with_background_insets(insets[0], insets[1], insets[2], insets[3])
+        .build(&mut ui)
+        .unwrap();
+
+    (container.draw(&ui).unwrap(), container.texture())
+}
+
+pub fn canvas_vertices<F>(on_draw: F) -> Vec<DrawVertex>
+where
+    F: Fn(&mut Painter) + Send + Sync + 'static,
+{
+    let mut ui = window_ui();
+
+    let canvas = CanvasBuilder::new("canvas", "wcontainer", Position::Center)
+        .with_size(Size::Size(50.0, 50.0))
+        .with_on_draw(on_draw)
+        .build(&mut ui)
+        .unwrap();
+
+    canvas.draw(&ui).unwrap()
+}
@@ -0,0 +1,32 @@
+// Copyright © 2018-2019 red-oxide developers
+// This program is free software: you can redistribute it and/or modify it under the terms of the
+// GNU Lesser General Public License as published by the Free Software Foundation, version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See
+// the GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+mod utils;
+
+use self::utils::layered_ui;
+
+#[test]
+fn test_z_order_draw_order() {
+    let expected = vec!["wcontainer", "back", "front", "front_child", "popup", "popup_child"];
+    assert_eq!(expected, layered_ui().draw_order());
+}
+
+#[test]
+fn test_z_order_hit_test_overlay() {
+    let ui = layered_ui();
+    assert_eq!(Some(String::from("popup_child")), ui.hit_test(1.0, 1.0).unwrap());
+}
+
+#[test]
+fn test_z_order_hit_test_content() {
+    let ui = layered_ui();
+    assert_eq!(Some(String::from("front_child")), ui.hit_test(0.1, 0.1).unwrap());
+    assert_eq!(Some(String::from("front")), ui.hit_test(1.9, 1.9).unwrap());
+}